@@ -1,12 +1,29 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::path::Path;
 
+use porter_math::Axis;
+use porter_math::Matrix4x4;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use porter_model::up_axis_rotation;
+use porter_model::ModelConversion;
+use porter_model::ModelUnits;
+use porter_model::Skeleton;
+
 use crate::animation_file_type_cast;
+use crate::animation_file_type_fbx;
 use crate::animation_file_type_seanim;
+use crate::animation_file_type_smd;
+use crate::AnimationConvertOptions;
 use crate::AnimationError;
 use crate::AnimationFileType;
 use crate::Curve;
 use crate::CurveAttribute;
 use crate::CurveDataType;
+use crate::CurveTolerance;
 use crate::KeyframeValue;
 
 // A 3d animation.
@@ -15,6 +32,9 @@ pub struct Animation {
     pub framerate: f32,
     pub looping: bool,
     pub curves: Vec<Curve>,
+    pub metadata: BTreeMap<String, String>,
+    pub up_axis: Axis,
+    pub units: ModelUnits,
 }
 
 impl Animation {
@@ -24,10 +44,20 @@ impl Animation {
             framerate,
             looping,
             curves: Vec::new(),
+            metadata: BTreeMap::new(),
+            up_axis: Axis::Z,
+            units: ModelUnits::Centimeters,
         }
     }
 
+    /// Sets a custom metadata value, such as the source archive or asset guid, written to formats which support it.
+    pub fn set_metadata<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.metadata.insert(key.into(), value.into());
+    }
+
     /// Saves the animation to the given file path in the given animation format.
+    ///
+    /// Formats which are bound to a skeleton, see `AnimationFileType::requires_skeleton`, must be saved with `Animation::save_with_options`.
     pub fn save<P: AsRef<Path>>(
         &self,
         path: P,
@@ -36,9 +66,102 @@ impl Animation {
         match file_type {
             AnimationFileType::SEAnim => animation_file_type_seanim::to_seanim(path, self),
             AnimationFileType::Cast => animation_file_type_cast::to_cast(path, self),
+            AnimationFileType::Fbx | AnimationFileType::Smd => {
+                Err(AnimationError::SkeletonRequired(file_type))
+            }
+        }
+    }
+
+    /// Saves the animation to the given file path in the given animation format, converting it with the given options first.
+    ///
+    /// The skeleton is used to find the root bones when changing the up axis, see `Animation::set_up_axis`.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AnimationFileType,
+        options: AnimationConvertOptions,
+        skeleton: &Skeleton,
+    ) -> Result<(), AnimationError> {
+        if options.compress.is_none() && options.conversion.is_identity() {
+            return self.save_with_skeleton(path, file_type, skeleton);
+        }
+
+        let mut animation = self.clone();
+
+        animation.convert(&options.conversion, skeleton);
+
+        if let Some(tolerance) = options.compress {
+            animation.compress(&tolerance);
+        }
+
+        if file_type.requires_skeleton() {
+            let skeleton = self.convert_skeleton(&options.conversion, skeleton);
+
+            return animation.save_with_skeleton(path, file_type, &skeleton);
+        }
+
+        animation.save(path, file_type)
+    }
+
+    /// Saves the animation in the given animation format, binding it to the skeleton when the format requires one.
+    fn save_with_skeleton<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AnimationFileType,
+        skeleton: &Skeleton,
+    ) -> Result<(), AnimationError> {
+        match file_type {
+            AnimationFileType::Fbx => self.save_fbx(path, skeleton, self.up_axis),
+            AnimationFileType::Smd => self.save_smd(path, skeleton),
+            _ => self.save(path, file_type),
         }
     }
 
+    /// Converts a copy of the skeleton in the same way `Model::convert` does, so the bind pose matches the exported model.
+    fn convert_skeleton(&self, conversion: &ModelConversion, skeleton: &Skeleton) -> Skeleton {
+        let mut result = skeleton.clone();
+
+        if let Some(axis) = conversion.mirror {
+            result.mirror(axis);
+        }
+
+        if let Some(up_axis) = conversion
+            .up_axis
+            .filter(|up_axis| *up_axis != self.up_axis)
+        {
+            result.rotate(up_axis_rotation(self.up_axis, up_axis));
+        }
+
+        if let Some(units) = conversion.units {
+            result.scale(self.units.scale_to(units));
+        }
+
+        result
+    }
+
+    /// Saves the animation in smd format to the given file path, sampling every frame with the given skeleton.
+    ///
+    /// The skeleton should be the same one exported with the reference smd, so the nodes match.
+    pub fn save_smd<P: AsRef<Path>>(
+        &self,
+        path: P,
+        skeleton: &Skeleton,
+    ) -> Result<(), AnimationError> {
+        animation_file_type_smd::to_smd(path, self, skeleton)
+    }
+
+    /// Saves the animation in fbx format to the given file path, as a take bound to the joints of the given skeleton.
+    ///
+    /// The skeleton and up axis should be the same ones exported with the model, so the joints match.
+    pub fn save_fbx<P: AsRef<Path>>(
+        &self,
+        path: P,
+        skeleton: &Skeleton,
+        up_axis: Axis,
+    ) -> Result<(), AnimationError> {
+        animation_file_type_fbx::to_fbx(path, self, skeleton, up_axis)
+    }
+
     /// Returns the most common curve data type.
     pub fn average_data_type(&self) -> CurveDataType {
         let mut data_types: [usize; 3] = [0, 0, 0];
@@ -84,6 +207,290 @@ impl Animation {
             .sum()
     }
 
+    /// Removes redundant keyframes from every curve within the given tolerance, returns the number removed.
+    pub fn compress(&mut self, tolerance: &CurveTolerance) -> usize {
+        self.curves
+            .iter_mut()
+            .map(|curve| curve.compress(tolerance))
+            .sum()
+    }
+
+    /// Adds a notification, such as a footstep or sound event, with the given name at the given frame.
+    pub fn add_notification<N: AsRef<str>>(&mut self, name: N, frame: u32) {
+        let name = name.as_ref();
+
+        let index = self.curves.iter().position(|curve| {
+            matches!(curve.attribute(), CurveAttribute::Notetrack) && curve.name() == name
+        });
+
+        let index = match index {
+            Some(index) => index,
+            None => {
+                self.curves.push(Curve::new(
+                    name,
+                    CurveAttribute::Notetrack,
+                    CurveDataType::Absolute,
+                ));
+
+                self.curves.len() - 1
+            }
+        };
+
+        self.curves[index].insert(frame, ());
+    }
+
+    /// Returns every notification as the name and frame, sorted by frame.
+    pub fn notifications(&self) -> Vec<(&str, u32)> {
+        let mut result: Vec<(&str, u32)> = self
+            .curves
+            .iter()
+            .filter(|curve| matches!(curve.attribute(), CurveAttribute::Notetrack))
+            .flat_map(|curve| {
+                curve
+                    .keyframes()
+                    .iter()
+                    .map(|keyframe| (curve.name(), keyframe.time))
+            })
+            .collect();
+
+        result.sort_by_key(|(_, frame)| *frame);
+
+        result
+    }
+
+    /// Computes the additive form of this animation, relative to the given frame of a reference pose.
+    pub fn additive_from_pose(&self, reference: &Animation, frame: u32) -> Animation {
+        self.additive(reference, |_| frame as f32)
+    }
+
+    /// Computes the additive form of this animation, relative to the same frame of a reference animation.
+    pub fn additive_from_clip(&self, reference: &Animation) -> Animation {
+        self.additive(reference, |time| time as f32)
+    }
+
+    /// Computes the per node delta between this animation and the reference sampled at the time returned by the callback.
+    ///
+    /// Translations and scales are subtracted, and rotations are made relative to the reference rotation.
+    /// Curves without a matching absolute reference curve, visibility, and notetracks are copied unchanged.
+    fn additive<F: Fn(u32) -> f32>(&self, reference: &Animation, reference_time: F) -> Animation {
+        let mut result = Animation::new(self.framerate, self.looping);
+
+        result.metadata = self.metadata.clone();
+        result.up_axis = self.up_axis;
+        result.units = self.units;
+
+        for curve in &self.curves {
+            let transform = matches!(
+                curve.attribute(),
+                CurveAttribute::Translate | CurveAttribute::Rotation | CurveAttribute::Scale
+            );
+
+            let reference = reference.curves.iter().find(|reference| {
+                transform
+                    && reference.name() == curve.name()
+                    && reference.attribute() == curve.attribute()
+                    && matches!(reference.data_type(), CurveDataType::Absolute)
+            });
+
+            let (Some(reference), CurveDataType::Absolute) = (reference, curve.data_type()) else {
+                result.curves.push(curve.clone());
+                continue;
+            };
+
+            let mut additive = Curve::new(curve.name(), curve.attribute(), CurveDataType::Additive);
+
+            for keyframe in curve.keyframes() {
+                let base = reference.sample(reference_time(keyframe.time));
+
+                match (keyframe.value, base) {
+                    (KeyframeValue::Vector3(value), Some(KeyframeValue::Vector3(base))) => {
+                        additive.insert(keyframe.time, value - base);
+                    }
+                    (KeyframeValue::Quaternion(value), Some(KeyframeValue::Quaternion(base))) => {
+                        additive.insert(keyframe.time, (base.inverse() * value).normalized());
+                    }
+                    (value, _) => {
+                        additive.insert(keyframe.time, value);
+                    }
+                }
+            }
+
+            result.curves.push(additive);
+        }
+
+        result
+    }
+
+    /// Samples this animation at the given frame time, returning the local position, rotation, and scale of each bone in the skeleton.
+    ///
+    /// The skeleton must have local transforms, which are used as the rest pose for bones without curves.
+    pub(crate) fn bake_to_local_transforms(
+        &self,
+        skeleton: &Skeleton,
+        time: f32,
+    ) -> Vec<(Vector3, Quaternion, Vector3)> {
+        let mut transforms: Vec<(Vector3, Quaternion, Vector3)> =
+            Vec::with_capacity(skeleton.bones.len());
+
+        let mut bones: HashMap<&str, usize> = HashMap::with_capacity(skeleton.bones.len());
+
+        for (index, bone) in skeleton.bones.iter().enumerate() {
+            transforms.push((
+                bone.local_position.unwrap_or_default(),
+                bone.local_rotation.unwrap_or_default(),
+                bone.local_scale.unwrap_or(Vector3::one()),
+            ));
+
+            if let Some(name) = &bone.name {
+                bones.insert(name.as_str(), index);
+            }
+        }
+
+        for curve in &self.curves {
+            let Some(&index) = bones.get(curve.name()) else {
+                continue;
+            };
+
+            let absolute = matches!(curve.data_type(), CurveDataType::Absolute);
+            let (position, rotation, scale) = &mut transforms[index];
+
+            // Relative and additive curves are both applied on top of the rest pose.
+            match (curve.attribute(), curve.sample(time)) {
+                (CurveAttribute::Translate, Some(KeyframeValue::Vector3(value))) => {
+                    *position = if absolute { value } else { *position + value };
+                }
+                (CurveAttribute::Rotation, Some(KeyframeValue::Quaternion(value))) => {
+                    *rotation = if absolute { value } else { *rotation * value };
+                }
+                (CurveAttribute::Scale, Some(KeyframeValue::Vector3(value))) => {
+                    *scale = if absolute { value } else { *scale * value };
+                }
+                _ => {}
+            }
+        }
+
+        transforms
+    }
+
+    /// Samples this animation at the given frame time, returning the world matrix of each bone in the skeleton.
+    ///
+    /// The skeleton must have local transforms, which are used as the rest pose for bones without curves.
+    pub fn bake_to_matrices(&self, skeleton: &Skeleton, time: f32) -> Vec<Matrix4x4> {
+        let transforms = self.bake_to_local_transforms(skeleton, time);
+
+        let mut result: Vec<Matrix4x4> = Vec::with_capacity(skeleton.bones.len());
+
+        for (bone, (position, rotation, scale)) in skeleton.bones.iter().zip(transforms) {
+            let local = Matrix4x4::create_position(position)
+                * Matrix4x4::create_rotation(rotation)
+                * Matrix4x4::create_scale(scale);
+
+            // Parents always come before their children in a skeleton.
+            let world = match usize::try_from(bone.parent)
+                .ok()
+                .and_then(|parent| result.get(parent))
+            {
+                Some(parent) => *parent * local,
+                None => local,
+            };
+
+            result.push(world);
+        }
+
+        result
+    }
+
+    /// Mirrors this animation across the plane perpendicular to the given axis, used to convert between left and right handed rigs.
+    pub fn mirror(&mut self, axis: Axis) {
+        for curve in &mut self.curves {
+            let attribute = curve.attribute();
+
+            for keyframe in curve.keyframes_mut() {
+                match (attribute, &mut keyframe.value) {
+                    (CurveAttribute::Translate, KeyframeValue::Vector3(vector)) => {
+                        *vector = vector.mirrored(axis);
+                    }
+                    (CurveAttribute::Rotation, KeyframeValue::Quaternion(rotation)) => {
+                        *rotation = rotation.mirrored(axis);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Rotates this animation so that the given axis points up, like `Model::set_up_axis` does for the skeleton.
+    ///
+    /// Only the curves of the skeleton's root bones are rotated, children stay relative to their parents.
+    pub fn set_up_axis(&mut self, up_axis: Axis, skeleton: &Skeleton) {
+        if self.up_axis == up_axis {
+            return;
+        }
+
+        let rotation = up_axis_rotation(self.up_axis, up_axis);
+        let matrix = Matrix4x4::create_rotation(rotation);
+
+        let roots: HashSet<&str> = skeleton
+            .bones
+            .iter()
+            .filter(|bone| bone.parent < 0)
+            .filter_map(|bone| bone.name.as_deref())
+            .collect();
+
+        for curve in &mut self.curves {
+            if !roots.contains(curve.name()) {
+                continue;
+            }
+
+            let attribute = curve.attribute();
+            let data_type = curve.data_type();
+
+            for keyframe in curve.keyframes_mut() {
+                match (attribute, &mut keyframe.value) {
+                    (CurveAttribute::Translate, KeyframeValue::Vector3(vector)) => {
+                        *vector = vector.transform(&matrix);
+                    }
+                    // Additive and relative rotations are applied in the bone's own space, so they don't change.
+                    (CurveAttribute::Rotation, KeyframeValue::Quaternion(value))
+                        if matches!(data_type, CurveDataType::Absolute) =>
+                    {
+                        *value = rotation * *value;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.up_axis = up_axis;
+    }
+
+    /// Scales this animation so that it's measured in the given units.
+    pub fn set_units(&mut self, units: ModelUnits) {
+        if self.units == units {
+            return;
+        }
+
+        self.scale(self.units.scale_to(units));
+        self.units = units;
+    }
+
+    /// Applies the given conversion to the animation, in the same order as `Model::convert`.
+    ///
+    /// The skeleton should be the same one exported with the model, so the same root bones are rotated.
+    pub fn convert(&mut self, conversion: &ModelConversion, skeleton: &Skeleton) {
+        if let Some(axis) = conversion.mirror {
+            self.mirror(axis);
+        }
+
+        if let Some(up_axis) = conversion.up_axis {
+            self.set_up_axis(up_axis, skeleton);
+        }
+
+        if let Some(units) = conversion.units {
+            self.set_units(units);
+        }
+    }
+
     /// Scales this animation by the given factor.
     pub fn scale(&mut self, factor: f32) {
         for curve in &mut self.curves {
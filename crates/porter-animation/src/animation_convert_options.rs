@@ -0,0 +1,38 @@
+use porter_model::ModelConversion;
+
+use crate::CurveTolerance;
+
+/// Options used when converting animations for export.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AnimationConvertOptions {
+    /// The tolerance used to remove redundant keyframes, or none to keep every keyframe.
+    pub compress: Option<CurveTolerance>,
+    /// The unit, up axis, and handedness conversion, which should match the one used for models.
+    pub conversion: ModelConversion,
+}
+
+impl AnimationConvertOptions {
+    /// Constructs a new instance of animation convert options.
+    pub const fn new() -> Self {
+        Self {
+            compress: None,
+            conversion: ModelConversion {
+                units: None,
+                up_axis: None,
+                mirror: None,
+            },
+        }
+    }
+
+    /// Sets the tolerance used to remove redundant keyframes.
+    pub const fn compress(mut self, compress: Option<CurveTolerance>) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Sets the unit, up axis, and handedness conversion.
+    pub const fn conversion(mut self, conversion: ModelConversion) -> Self {
+        self.conversion = conversion;
+        self
+    }
+}
@@ -6,4 +6,21 @@ use bincode::Encode;
 pub enum AnimationFileType {
     SEAnim,
     Cast,
+    Fbx,
+    Smd,
+}
+
+impl AnimationFileType {
+    /// All of the supported animation file types.
+    pub const ALL: [Self; 4] = [Self::SEAnim, Self::Cast, Self::Fbx, Self::Smd];
+
+    /// Whether or not the animation file type can only be written to, animations can't be loaded.
+    pub const fn is_write_only(&self) -> bool {
+        true
+    }
+
+    /// Whether or not the animation file type is bound to a skeleton, and must be saved with `Animation::save_with_options`.
+    pub const fn requires_skeleton(&self) -> bool {
+        matches!(self, Self::Fbx | Self::Smd)
+    }
 }
@@ -2,9 +2,11 @@ use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
 
+use porter_cast::CastAnimationBuilder;
+use porter_cast::CastCurveBuilder;
+use porter_cast::CastCurveMode;
 use porter_cast::CastFile;
 use porter_cast::CastId;
-use porter_cast::CastNode;
 use porter_cast::CastPropertyId;
 
 use crate::Animation;
@@ -15,16 +17,20 @@ use crate::KeyframeValue;
 
 /// Writes an animation in cast format to the given path.
 pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), AnimationError> {
-    let mut root = CastNode::root();
+    let mut file = CastFile::new();
+    let mut root = file.create_root();
+
+    if !animation.metadata.is_empty() {
+        let meta_node = root.create(CastId::Metadata);
 
-    let animation_node = root.create(CastId::Animation);
+        for (key, value) in &animation.metadata {
+            meta_node.create_metadata(key, value);
+        }
+    }
 
-    animation_node
-        .create_property(CastPropertyId::Float, "fr")
-        .push(animation.framerate);
-    animation_node
-        .create_property(CastPropertyId::Byte, "lo")
-        .push(animation.looping as u8);
+    let mut animation_node = CastAnimationBuilder::new(animation.framerate)
+        .looping(animation.looping)
+        .build(&mut root);
 
     for curve in &animation.curves {
         if matches!(curve.attribute(), CurveAttribute::Notetrack) {
@@ -39,79 +45,43 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
             _ => (0, ["", "", ""]),
         };
 
-        for i in 0..num_curves {
-            let curve_node = animation_node.create(CastId::Curve);
-
-            curve_node
-                .create_property(CastPropertyId::String, "nn")
-                .push(curve.name());
+        let mode = match curve.data_type() {
+            CurveDataType::Absolute => CastCurveMode::Absolute,
+            CurveDataType::Additive => CastCurveMode::Additive,
+            CurveDataType::Relative => CastCurveMode::Relative,
+        };
 
-            match curve.data_type() {
-                CurveDataType::Absolute => {
-                    curve_node
-                        .create_property(CastPropertyId::String, "m")
-                        .push("absolute");
-                }
-                CurveDataType::Additive => {
-                    curve_node
-                        .create_property(CastPropertyId::String, "m")
-                        .push("additive");
+        for (i, curve_prop) in curve_props.iter().enumerate().take(num_curves) {
+            let builder = CastCurveBuilder::new(curve.name(), *curve_prop, mode);
+            let keyframes = curve.keyframes().iter();
+
+            match curve.attribute() {
+                CurveAttribute::Rotation => {
+                    builder.build(
+                        &mut animation_node,
+                        keyframes.filter_map(|keyframe| match keyframe.value {
+                            KeyframeValue::Quaternion(rotation) => Some((keyframe.time, rotation)),
+                            _ => None,
+                        }),
+                    );
                 }
-                CurveDataType::Relative => {
-                    curve_node
-                        .create_property(CastPropertyId::String, "m")
-                        .push("relative");
+                CurveAttribute::Translate | CurveAttribute::Scale => {
+                    builder.build(
+                        &mut animation_node,
+                        keyframes.filter_map(|keyframe| match keyframe.value {
+                            KeyframeValue::Vector3(vector) => Some((keyframe.time, vector[i])),
+                            _ => None,
+                        }),
+                    );
                 }
-            }
-
-            curve_node
-                .create_property(CastPropertyId::String, "kp")
-                .push(curve_props[i]);
-
-            let largest_frame_time = curve.largest_frame_time();
-
-            let keyframe_buffer = if largest_frame_time <= 0xFF {
-                curve_node.create_property(CastPropertyId::Byte, "kb")
-            } else if largest_frame_time <= 0xFFFF {
-                curve_node.create_property(CastPropertyId::Short, "kb")
-            } else {
-                curve_node.create_property(CastPropertyId::Integer32, "kb")
-            };
-
-            for keyframe in curve.keyframes() {
-                if largest_frame_time <= 0xFF {
-                    keyframe_buffer.push(keyframe.time as u8);
-                } else if largest_frame_time <= 0xFFFF {
-                    keyframe_buffer.push(keyframe.time as u16);
-                } else {
-                    keyframe_buffer.push(keyframe.time);
-                }
-            }
-
-            let property_type = match curve.attribute() {
-                CurveAttribute::Rotation => CastPropertyId::Vector4,
-                CurveAttribute::Translate => CastPropertyId::Float,
-                CurveAttribute::Scale => CastPropertyId::Float,
-                CurveAttribute::Visibility => CastPropertyId::Byte,
-                _ => CastPropertyId::Byte,
-            };
-
-            let keyvalue_buffer = curve_node.create_property(property_type, "kv");
-
-            for keyframe in curve.keyframes() {
-                match keyframe.value {
-                    KeyframeValue::Bool(bool) => {
-                        keyvalue_buffer.push(bool as u8);
-                    }
-                    KeyframeValue::Quaternion(rotation) => {
-                        keyvalue_buffer.push(rotation);
-                    }
-                    KeyframeValue::Vector3(vector) => {
-                        keyvalue_buffer.push(vector[i]);
-                    }
-                    KeyframeValue::None => {
-                        // No value.
-                    }
+                _ => {
+                    builder.build(
+                        &mut animation_node,
+                        keyframes.filter_map(|keyframe| match keyframe.value {
+                            KeyframeValue::Bool(bool) => Some((keyframe.time, bool)),
+                            _ => None,
+                        }),
+                    );
                 }
             }
         }
@@ -137,9 +107,6 @@ pub fn to_cast<P: AsRef<Path>>(path: P, animation: &Animation) -> Result<(), Ani
 
     let writer = BufWriter::new(File::create(path.as_ref().with_extension("cast"))?);
 
-    let mut file = CastFile::new();
-
-    file.push(root);
     file.write(writer)?;
 
     Ok(())
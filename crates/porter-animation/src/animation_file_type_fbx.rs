@@ -0,0 +1,352 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use porter_fbx::FbxDocument;
+use porter_fbx::FbxNode;
+use porter_fbx::FbxPropertyType;
+use porter_fbx::FbxPropertyValue;
+
+use porter_math::Angles;
+use porter_math::Axis;
+use porter_math::Vector3;
+
+use porter_model::Skeleton;
+
+use crate::Animation;
+use crate::AnimationError;
+use crate::CurveAttribute;
+
+/// The number of fbx time units in one second.
+const FBX_TIME_SECOND: f64 = 46186158000.0;
+
+/// The channels of a curve node, one curve per vector component.
+const FBX_CHANNELS: [&str; 3] = ["d|X", "d|Y", "d|Z"];
+
+/// Linear interpolation, with generic time independent tangents.
+const FBX_KEY_ATTR_FLAGS: u32 = 24836;
+
+/// Adds an object connection from->to.
+fn add_object_connection<F: Into<FbxPropertyValue>, T: Into<FbxPropertyValue>>(
+    connection_node: &mut FbxNode,
+    from: F,
+    to: T,
+) {
+    let connection = connection_node.create("C");
+
+    connection
+        .create_property(FbxPropertyType::String)
+        .push_string("OO");
+    connection
+        .create_property(FbxPropertyType::Integer64)
+        .push(from);
+    connection
+        .create_property(FbxPropertyType::Integer64)
+        .push(to);
+}
+
+/// Adds an object property connection from->to[property].
+fn add_object_property_connection<
+    F: Into<FbxPropertyValue>,
+    T: Into<FbxPropertyValue>,
+    P: Into<String>,
+>(
+    connection_node: &mut FbxNode,
+    from: F,
+    to: T,
+    property: P,
+) {
+    let connection = connection_node.create("C");
+
+    connection
+        .create_property(FbxPropertyType::String)
+        .push_string("OP");
+    connection
+        .create_property(FbxPropertyType::Integer64)
+        .push(from);
+    connection
+        .create_property(FbxPropertyType::Integer64)
+        .push(to);
+    connection
+        .create_property(FbxPropertyType::String)
+        .push_string(property);
+}
+
+/// Adds a time property, used for the start and stop times of the stack.
+fn add_time_property(properties: &mut FbxNode, name: &str, time: u64) {
+    let props = properties.create("P");
+
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string(name);
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string("KTime");
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string("Time");
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string("");
+    props.create_property(FbxPropertyType::Integer64).push(time);
+}
+
+/// Wraps each euler angle so it's the closest equivalent to the previous frame, which prevents flips when interpolating.
+fn unwrap_euler(previous: Vector3, mut current: Vector3) -> Vector3 {
+    for (previous, current) in [
+        (previous.x, &mut current.x),
+        (previous.y, &mut current.y),
+        (previous.z, &mut current.z),
+    ] {
+        *current -= ((*current - previous) / 360.0).round() * 360.0;
+    }
+
+    current
+}
+
+/// Writes an animation in fbx format to the given path, as a take bound to the joints of the given skeleton.
+pub fn to_fbx<P: AsRef<Path>>(
+    path: P,
+    animation: &Animation,
+    skeleton: &Skeleton,
+    up_axis: Axis,
+) -> Result<(), AnimationError> {
+    let path = path.as_ref().with_extension("fbx");
+
+    let take_name = path
+        .file_stem()
+        .map(|x| x.to_string_lossy().to_string())
+        .unwrap_or_else(|| String::from("Take 001"));
+
+    let framerate = if animation.framerate > 0.0 {
+        animation.framerate as f64
+    } else {
+        30.0
+    };
+
+    let frame_count = animation.frame_count();
+    let frame_time = |frame: u32| ((frame as f64 / framerate) * FBX_TIME_SECOND) as u64;
+    let stop_time = frame_time(frame_count - 1);
+
+    let mut root = FbxDocument::new();
+
+    let joints_map = skeleton.to_fbx(&mut root, up_axis);
+
+    let stack = root.objects_node().create("AnimationStack");
+
+    stack.create_hash();
+    stack
+        .create_property(FbxPropertyType::String)
+        .push_string(format!("{}\u{0000}\u{0001}AnimStack", take_name));
+    stack
+        .create_property(FbxPropertyType::String)
+        .push_string("");
+
+    let properties = stack.create("Properties70");
+
+    add_time_property(properties, "LocalStop", stop_time);
+    add_time_property(properties, "ReferenceStop", stop_time);
+
+    let stack_hash = FbxPropertyValue::from(stack);
+
+    let layer = root.objects_node().create("AnimationLayer");
+
+    layer.create_hash();
+    layer
+        .create_property(FbxPropertyType::String)
+        .push_string("BaseLayer\u{0000}\u{0001}AnimLayer");
+    layer
+        .create_property(FbxPropertyType::String)
+        .push_string("");
+
+    let layer_hash = FbxPropertyValue::from(layer);
+
+    add_object_connection(root.connections_node(), layer_hash, stack_hash);
+
+    // Curves are sampled at every frame, so additive and relative curves are applied to the rest pose just like smd.
+    let frames: Vec<_> = (0..frame_count)
+        .map(|frame| animation.bake_to_local_transforms(skeleton, frame as f32))
+        .collect();
+
+    let animated: HashSet<(&str, CurveAttribute)> = animation
+        .curves
+        .iter()
+        .map(|curve| (curve.name(), curve.attribute()))
+        .collect();
+
+    for (bone_index, bone) in skeleton.bones.iter().enumerate() {
+        let Some(name) = bone.name.as_deref() else {
+            continue;
+        };
+
+        let joint_hash = joints_map[&bone_index];
+
+        for (attribute, property, node_name) in [
+            (CurveAttribute::Translate, "Lcl Translation", "T"),
+            (CurveAttribute::Rotation, "Lcl Rotation", "R"),
+            (CurveAttribute::Scale, "Lcl Scaling", "S"),
+        ] {
+            if !animated.contains(&(name, attribute)) {
+                continue;
+            }
+
+            let mut previous_rotation = Vector3::zero();
+
+            let values: Vec<Vector3> = frames
+                .iter()
+                .map(|transforms| {
+                    let (position, rotation, scale) = transforms[bone_index];
+
+                    match attribute {
+                        CurveAttribute::Rotation => {
+                            previous_rotation =
+                                unwrap_euler(previous_rotation, rotation.to_euler(Angles::Degrees));
+                            previous_rotation
+                        }
+                        CurveAttribute::Scale => scale,
+                        _ => position,
+                    }
+                })
+                .collect();
+
+            let curve_node = root.objects_node().create("AnimationCurveNode");
+
+            curve_node.create_hash();
+            curve_node
+                .create_property(FbxPropertyType::String)
+                .push_string(format!("{}\u{0000}\u{0001}AnimCurveNode", node_name));
+            curve_node
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+
+            let properties = curve_node.create("Properties70");
+
+            for (component, channel) in FBX_CHANNELS.into_iter().enumerate() {
+                let props = properties.create("P");
+
+                props
+                    .create_property(FbxPropertyType::String)
+                    .push_string(channel);
+                props
+                    .create_property(FbxPropertyType::String)
+                    .push_string("Number");
+                props
+                    .create_property(FbxPropertyType::String)
+                    .push_string("");
+                props
+                    .create_property(FbxPropertyType::String)
+                    .push_string("A");
+                props
+                    .create_property(FbxPropertyType::Float64)
+                    .push(values[0][component] as f64);
+            }
+
+            let curve_node_hash = FbxPropertyValue::from(curve_node);
+
+            add_object_connection(root.connections_node(), curve_node_hash, layer_hash);
+            add_object_property_connection(
+                root.connections_node(),
+                curve_node_hash,
+                joint_hash,
+                property,
+            );
+
+            for (component, channel) in FBX_CHANNELS.into_iter().enumerate() {
+                let curve = root.objects_node().create("AnimationCurve");
+
+                curve.create_hash();
+                curve
+                    .create_property(FbxPropertyType::String)
+                    .push_string("\u{0000}\u{0001}AnimCurve");
+                curve
+                    .create_property(FbxPropertyType::String)
+                    .push_string("");
+
+                curve
+                    .create("Default")
+                    .create_property(FbxPropertyType::Float64)
+                    .push(values[0][component] as f64);
+                curve
+                    .create("KeyVer")
+                    .create_property(FbxPropertyType::Integer32)
+                    .push(4009u32);
+
+                let key_time = curve
+                    .create("KeyTime")
+                    .create_property(FbxPropertyType::Integer64Array);
+
+                for frame in 0..frame_count {
+                    key_time.push(frame_time(frame));
+                }
+
+                let key_value = curve
+                    .create("KeyValueFloat")
+                    .create_property(FbxPropertyType::Float32Array);
+
+                for value in &values {
+                    key_value.push(value[component]);
+                }
+
+                curve
+                    .create("KeyAttrFlags")
+                    .create_property(FbxPropertyType::Integer32Array)
+                    .push(FBX_KEY_ATTR_FLAGS);
+
+                let key_attr_data = curve
+                    .create("KeyAttrDataFloat")
+                    .create_property(FbxPropertyType::Float32Array);
+
+                for _ in 0..4 {
+                    key_attr_data.push(0.0f32);
+                }
+
+                curve
+                    .create("KeyAttrRefCount")
+                    .create_property(FbxPropertyType::Integer32Array)
+                    .push(frame_count);
+
+                let curve_hash = FbxPropertyValue::from(curve);
+
+                add_object_property_connection(
+                    root.connections_node(),
+                    curve_hash,
+                    curve_node_hash,
+                    channel,
+                );
+            }
+        }
+    }
+
+    let take = root.takes_node().create("Take");
+
+    take.create_property(FbxPropertyType::String)
+        .push_string(take_name.as_str());
+    take.create("FileName")
+        .create_property(FbxPropertyType::String)
+        .push_string(format!("{}.tak", take_name));
+
+    let local_time = take.create("LocalTime");
+
+    local_time
+        .create_property(FbxPropertyType::Integer64)
+        .push(0u64);
+    local_time
+        .create_property(FbxPropertyType::Integer64)
+        .push(stop_time);
+
+    let reference_time = take.create("ReferenceTime");
+
+    reference_time
+        .create_property(FbxPropertyType::Integer64)
+        .push(0u64);
+    reference_time
+        .create_property(FbxPropertyType::Integer64)
+        .push(stop_time);
+
+    let writer = BufWriter::new(File::create(path)?);
+
+    root.write(writer)?;
+
+    Ok(())
+}
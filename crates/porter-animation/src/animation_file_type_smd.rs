@@ -0,0 +1,57 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+
+use porter_math::Angles;
+
+use porter_model::Skeleton;
+
+use crate::Animation;
+use crate::AnimationError;
+
+/// Writes an animation in smd format to the given path.
+pub fn to_smd<P: AsRef<Path>>(
+    path: P,
+    animation: &Animation,
+    skeleton: &Skeleton,
+) -> Result<(), AnimationError> {
+    let mut smd = BufWriter::new(File::create(path.as_ref().with_extension("smd"))?);
+
+    writeln!(smd, "version 1\n// Exported by PorterLib\n// Please credit DTZxPorter for use of this asset!\nnodes")?;
+
+    // Node names must match the reference smd exactly, so unnamed bones use the same fallback.
+    for (bone_index, bone) in skeleton.bones.iter().enumerate() {
+        writeln!(
+            smd,
+            "{} \"{}\" {}",
+            bone_index,
+            bone.name
+                .as_ref()
+                .unwrap_or(&format!("porter_bone_{}", bone_index)),
+            bone.parent
+        )?;
+    }
+
+    writeln!(smd, "end\nskeleton")?;
+
+    for frame in 0..animation.frame_count() {
+        writeln!(smd, "time {}", frame)?;
+
+        let transforms = animation.bake_to_local_transforms(skeleton, frame as f32);
+
+        for (bone_index, (position, rotation, _)) in transforms.into_iter().enumerate() {
+            let rotation = rotation.to_euler(Angles::Radians);
+
+            writeln!(
+                smd,
+                "{} {:.6} {:.6} {:.6} {:.6} {:.6} {:.6}",
+                bone_index, position.x, position.y, position.z, rotation.x, rotation.y, rotation.z
+            )?;
+        }
+    }
+
+    writeln!(smd, "end")?;
+
+    Ok(())
+}
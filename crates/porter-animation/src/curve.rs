@@ -1,6 +1,12 @@
+use porter_math::Vector4;
+
+use crate::CurveTolerance;
 use crate::Keyframe;
 use crate::KeyframeValue;
 
+/// The most keyframes that can be removed in a row, keeping compression linear in the number of keyframes.
+const COMPRESS_WINDOW_MAX: usize = 32;
+
 /// The attribute of the node a curve is animating.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CurveAttribute {
@@ -102,6 +108,85 @@ impl Curve {
         self.keyframes.push(Keyframe { time, value });
     }
 
+    /// Samples the value of this curve at the given frame time, interpolating between keyframes sorted by time.
+    ///
+    /// Times outside of the curve hold the first or last keyframe value.
+    pub fn sample(&self, time: f32) -> Option<KeyframeValue> {
+        let next = self
+            .keyframes
+            .partition_point(|keyframe| (keyframe.time as f32) <= time);
+
+        if next == 0 {
+            return self.keyframes.first().map(|keyframe| keyframe.value);
+        }
+
+        let start = &self.keyframes[next - 1];
+
+        let Some(end) = self.keyframes.get(next) else {
+            return Some(start.value);
+        };
+
+        let time = (time - start.time as f32) / (end.time - start.time) as f32;
+
+        Some(match (start.value, end.value) {
+            (KeyframeValue::Vector3(start), KeyframeValue::Vector3(end)) => {
+                KeyframeValue::Vector3(start.lerp(end, time))
+            }
+            (KeyframeValue::Quaternion(start), KeyframeValue::Quaternion(end)) => {
+                KeyframeValue::Quaternion(start.slerp(end, time))
+            }
+            (value, _) => value,
+        })
+    }
+
+    /// Removes keyframes that can be interpolated from their neighbors within the given tolerance, returns the number removed.
+    ///
+    /// The first and last keyframes are always kept, so the length of the curve doesn't change.
+    pub fn compress(&mut self, tolerance: &CurveTolerance) -> usize {
+        if self.keyframes.len() < 3 {
+            return 0;
+        }
+
+        let tolerance = match self.attribute {
+            CurveAttribute::Translate => tolerance.translate,
+            CurveAttribute::Rotation => tolerance.rotation,
+            CurveAttribute::Scale => tolerance.scale,
+            CurveAttribute::Visibility => 0.0,
+            CurveAttribute::Notetrack => return 0,
+        };
+
+        self.keyframes.sort_by_key(|keyframe| keyframe.time);
+
+        let keyframes = std::mem::take(&mut self.keyframes);
+        let last = keyframes.len() - 1;
+
+        let mut anchor = 0;
+
+        self.keyframes.push(keyframes[0]);
+
+        for index in 1..last {
+            let start = &keyframes[anchor];
+            let end = &keyframes[index + 1];
+
+            // The keyframe is redundant if every keyframe since the last kept one can be interpolated from the next one.
+            // The window is bounded so each keyframe is only checked a fixed number of times.
+            let redundant = index - anchor <= COMPRESS_WINDOW_MAX
+                && keyframes[anchor + 1..=index]
+                    .iter()
+                    .all(|keyframe| interpolation_error(start, end, keyframe) <= tolerance);
+
+            if !redundant {
+                self.keyframes.push(keyframes[index]);
+
+                anchor = index;
+            }
+        }
+
+        self.keyframes.push(keyframes[last]);
+
+        keyframes.len() - self.keyframes.len()
+    }
+
     /// Returns the number of keyframes in this curve.
     pub fn len(&self) -> usize {
         self.keyframes.len()
@@ -112,3 +197,50 @@ impl Curve {
         self.keyframes.is_empty()
     }
 }
+
+/// Calculates the error between a keyframe, and the value interpolated at its time between two other keyframes.
+fn interpolation_error(start: &Keyframe, end: &Keyframe, keyframe: &Keyframe) -> f32 {
+    let time = if end.time > start.time {
+        (keyframe.time - start.time) as f32 / (end.time - start.time) as f32
+    } else {
+        0.0
+    };
+
+    match (start.value, end.value, keyframe.value) {
+        (
+            KeyframeValue::Vector3(start),
+            KeyframeValue::Vector3(end),
+            KeyframeValue::Vector3(value),
+        ) => (start.lerp(end, time) - value).length(),
+        (
+            KeyframeValue::Quaternion(start),
+            KeyframeValue::Quaternion(end),
+            KeyframeValue::Quaternion(value),
+        ) => {
+            let interpolated = start.slerp(end, time);
+            let mut value = value.normalized();
+
+            if interpolated.dot(value) < 0.0 {
+                value = -value;
+            }
+
+            // The chord between the rotations is more precise than acos for small angles.
+            let chord = Vector4::new(
+                interpolated.x - value.x,
+                interpolated.y - value.y,
+                interpolated.z - value.z,
+                interpolated.w - value.w,
+            )
+            .length();
+
+            4.0 * (chord * 0.5).min(1.0).asin()
+        }
+        // Stepped values are only redundant when the value doesn't change.
+        (KeyframeValue::Bool(start), KeyframeValue::Bool(end), KeyframeValue::Bool(value))
+            if start == value && end == value =>
+        {
+            0.0
+        }
+        _ => f32::INFINITY,
+    }
+}
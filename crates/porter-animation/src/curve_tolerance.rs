@@ -0,0 +1,31 @@
+/// The maximum error allowed when removing redundant keyframes from a curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurveTolerance {
+    /// The maximum distance a translation can move, in units.
+    pub translate: f32,
+    /// The maximum angle a rotation can change, in radians.
+    pub rotation: f32,
+    /// The maximum difference a scale can change.
+    pub scale: f32,
+}
+
+impl CurveTolerance {
+    /// Constructs a new curve tolerance with the given errors.
+    pub fn new(translate: f32, rotation: f32, scale: f32) -> Self {
+        Self {
+            translate,
+            rotation,
+            scale,
+        }
+    }
+}
+
+impl Default for CurveTolerance {
+    fn default() -> Self {
+        Self {
+            translate: 0.001,
+            rotation: 0.0005,
+            scale: 0.0001,
+        }
+    }
+}
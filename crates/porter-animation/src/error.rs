@@ -1,6 +1,9 @@
+use crate::AnimationFileType;
+
 #[derive(Debug)]
 pub enum AnimationError {
     IoError(std::io::Error),
+    SkeletonRequired(AnimationFileType),
 }
 
 impl From<std::io::Error> for AnimationError {
@@ -1,16 +1,22 @@
 #![deny(unsafe_code)]
 
 mod animation;
+mod animation_convert_options;
 mod animation_file_type;
 mod curve;
+mod curve_tolerance;
 mod error;
 mod keyframe;
 
 pub use animation::*;
+pub use animation_convert_options::*;
 pub use animation_file_type::*;
 pub use curve::*;
+pub use curve_tolerance::*;
 pub use error::*;
 pub use keyframe::*;
 
 pub(crate) mod animation_file_type_cast;
+pub(crate) mod animation_file_type_fbx;
 pub(crate) mod animation_file_type_seanim;
+pub(crate) mod animation_file_type_smd;
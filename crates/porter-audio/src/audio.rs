@@ -0,0 +1,321 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Read;
+use std::io::Seek;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::audio_file_type_flac;
+use crate::audio_file_type_wav;
+use crate::decompress_audio;
+use crate::software_resample;
+use crate::AudioChannelMode;
+use crate::AudioCodec;
+use crate::AudioConvertOptions;
+use crate::AudioError;
+use crate::AudioFileType;
+use crate::AudioLoop;
+use crate::AudioSpeaker;
+
+/// Decoded pcm audio, stored as interleaved floating point samples.
+#[derive(Debug, Clone)]
+pub struct Audio {
+    channels: u32,
+    sample_rate: u32,
+    samples: Vec<f32>,
+    loop_points: Option<AudioLoop>,
+}
+
+impl Audio {
+    /// Constructs a new audio instance from interleaved samples in the range of -1.0 to 1.0.
+    pub fn new(channels: u32, sample_rate: u32, samples: Vec<f32>) -> Self {
+        Self {
+            channels,
+            sample_rate,
+            samples,
+            loop_points: None,
+        }
+    }
+
+    /// Constructs a new audio instance by decoding compressed samples with the given codec.
+    pub fn from_compressed<I: AsRef<[u8]>>(
+        codec: AudioCodec,
+        input: I,
+        channels: u32,
+        sample_rate: u32,
+        block_align: u32,
+    ) -> Result<Self, AudioError> {
+        let samples = decompress_audio(codec, input, channels, block_align)?;
+
+        Ok(Self::new(channels, sample_rate, samples))
+    }
+
+    /// The number of channels.
+    pub fn channels(&self) -> u32 {
+        self.channels
+    }
+
+    /// The number of frames per second.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// The interleaved samples.
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    /// The interleaved samples, mutable.
+    pub fn samples_mut(&mut self) -> &mut [f32] {
+        &mut self.samples
+    }
+
+    /// The number of frames, where each frame has one sample per channel.
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1) as usize
+    }
+
+    /// The length of the audio.
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.frames() as f64 / self.sample_rate.max(1) as f64)
+    }
+
+    /// The loop region, if any.
+    pub fn loop_points(&self) -> Option<AudioLoop> {
+        self.loop_points
+    }
+
+    /// Sets the loop region.
+    pub fn set_loop_points(&mut self, loop_points: Option<AudioLoop>) {
+        self.loop_points = loop_points;
+    }
+
+    /// Shortens the audio to the given number of frames, longer audio is unchanged.
+    pub fn truncate(&mut self, frames: usize) {
+        self.samples.truncate(frames * self.channels as usize);
+    }
+
+    /// Appends the other audio to the end of this audio, resampling it to this sample rate if necessary.
+    pub fn append(&mut self, other: &Self) -> Result<(), AudioError> {
+        if other.channels != self.channels {
+            return Err(AudioError::ChannelCountMismatch(
+                self.channels,
+                other.channels,
+            ));
+        }
+
+        if other.sample_rate != self.sample_rate {
+            self.samples
+                .extend_from_slice(other.resample(self.sample_rate).samples());
+        } else {
+            self.samples.extend_from_slice(&other.samples);
+        }
+
+        Ok(())
+    }
+
+    /// Downmixes the audio to stereo, audio with two or less channels is unchanged.
+    pub fn downmix(&self) -> Self {
+        if self.channels <= 2 {
+            return self.clone();
+        }
+
+        let coefficients: Vec<(f32, f32)> = AudioSpeaker::layout(self.channels)
+            .iter()
+            .map(|speaker| speaker.downmix_coefficients())
+            .collect();
+
+        let mut samples = Vec::with_capacity(self.frames() * 2);
+
+        for frame in self.samples.chunks_exact(self.channels as usize) {
+            let (left, right) = frame.iter().zip(&coefficients).fold(
+                (0.0, 0.0),
+                |(left, right), (sample, coefficient)| {
+                    (
+                        left + sample * coefficient.0,
+                        right + sample * coefficient.1,
+                    )
+                },
+            );
+
+            // Only the samples that would clip are limited, so the mix keeps the level of the source.
+            samples.push(left.clamp(-1.0, 1.0));
+            samples.push(right.clamp(-1.0, 1.0));
+        }
+
+        Self {
+            channels: 2,
+            sample_rate: self.sample_rate,
+            samples,
+            loop_points: self.loop_points,
+        }
+    }
+
+    /// Splits the audio into one mono audio per channel, along with the speaker of each channel.
+    pub fn split(&self) -> Vec<(AudioSpeaker, Self)> {
+        AudioSpeaker::layout(self.channels)
+            .into_iter()
+            .enumerate()
+            .map(|(channel, speaker)| {
+                let samples = self
+                    .samples
+                    .iter()
+                    .skip(channel)
+                    .step_by(self.channels as usize)
+                    .copied()
+                    .collect();
+
+                (
+                    speaker,
+                    Self {
+                        channels: 1,
+                        sample_rate: self.sample_rate,
+                        samples,
+                        loop_points: self.loop_points,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Resamples the audio to the given sample rate.
+    pub fn resample(&self, sample_rate: u32) -> Self {
+        if sample_rate == self.sample_rate || sample_rate == 0 {
+            return self.clone();
+        }
+
+        let ratio = sample_rate as f64 / self.sample_rate.max(1) as f64;
+
+        let samples = software_resample::resample(
+            &self.samples,
+            self.channels as usize,
+            self.sample_rate,
+            sample_rate,
+        );
+
+        let loop_points = self.loop_points.map(|loop_points| {
+            AudioLoop::new(
+                (loop_points.start as f64 * ratio).round() as u64,
+                (loop_points.end as f64 * ratio).round() as u64,
+            )
+        });
+
+        Self {
+            channels: self.channels,
+            sample_rate,
+            samples,
+            loop_points,
+        }
+    }
+
+    /// Loads the audio from the given file path with the given file type.
+    pub fn load<P: AsRef<Path>>(path: P, file_type: AudioFileType) -> Result<Self, AudioError> {
+        let input = File::open(path)?;
+        let mut buffered = BufReader::new(input);
+
+        Self::load_from(&mut buffered, file_type)
+    }
+
+    /// Loads the audio from the given input buffer with the given file type.
+    pub fn load_from<I: Read + Seek>(
+        input: &mut I,
+        file_type: AudioFileType,
+    ) -> Result<Self, AudioError> {
+        match file_type {
+            AudioFileType::Wav => audio_file_type_wav::from_wav(input),
+            AudioFileType::Flac => Err(AudioError::ContainerWriteOnly(file_type)),
+        }
+    }
+
+    /// Saves the audio to the given file path in the given audio file type.
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AudioFileType,
+    ) -> Result<(), AudioError> {
+        let output = File::create(path)?;
+        let mut buffered = BufWriter::new(output);
+
+        self.save_to(&mut buffered, file_type)?;
+
+        buffered.flush()?;
+
+        Ok(())
+    }
+
+    /// Saves the audio to the given file path in the given audio file type, converting it with the given options first.
+    ///
+    /// When splitting channels, each channel is saved next to the path with the speaker name appended to the file name.
+    pub fn save_with_options<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AudioFileType,
+        options: AudioConvertOptions,
+    ) -> Result<(), AudioError> {
+        let path = path.as_ref();
+
+        if self.channels <= 2 {
+            return self.save_resampled(path, file_type, options.sample_rate);
+        }
+
+        match options.channel_mode {
+            AudioChannelMode::Keep => self.save_resampled(path, file_type, options.sample_rate),
+            AudioChannelMode::Downmix => {
+                self.downmix()
+                    .save_resampled(path, file_type, options.sample_rate)
+            }
+            AudioChannelMode::Split => {
+                let file_stem = path
+                    .file_stem()
+                    .map(|file_stem| file_stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+
+                for (speaker, audio) in self.split() {
+                    let mut file_name = format!("{}_{}", file_stem, speaker.name());
+
+                    if let Some(extension) = path.extension() {
+                        file_name.push('.');
+                        file_name.push_str(&extension.to_string_lossy());
+                    }
+
+                    audio.save_resampled(
+                        path.with_file_name(file_name),
+                        file_type,
+                        options.sample_rate,
+                    )?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Saves the audio to the given output buffer in the given audio file type.
+    pub fn save_to<O: Write + Seek>(
+        &self,
+        output: &mut O,
+        file_type: AudioFileType,
+    ) -> Result<(), AudioError> {
+        match file_type {
+            AudioFileType::Wav => audio_file_type_wav::to_wav(self, output),
+            AudioFileType::Flac => audio_file_type_flac::to_flac(self, output),
+        }
+    }
+
+    /// Saves the audio, resampling it first if the sample rate differs.
+    fn save_resampled<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: AudioFileType,
+        sample_rate: Option<u32>,
+    ) -> Result<(), AudioError> {
+        match sample_rate {
+            Some(sample_rate) if sample_rate != self.sample_rate => {
+                self.resample(sample_rate).save(path, file_type)
+            }
+            _ => self.save(path, file_type),
+        }
+    }
+}
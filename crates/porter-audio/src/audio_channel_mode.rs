@@ -0,0 +1,14 @@
+use bincode::Decode;
+use bincode::Encode;
+
+/// How audio with more than two channels is handled on export.
+#[derive(Decode, Encode, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AudioChannelMode {
+    /// Export every channel to a single file.
+    #[default]
+    Keep,
+    /// Downmix to stereo.
+    Downmix,
+    /// Export each channel to its own mono file.
+    Split,
+}
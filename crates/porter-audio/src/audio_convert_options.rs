@@ -0,0 +1,38 @@
+use crate::AudioChannelMode;
+
+/// Options used when converting audio for export.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AudioConvertOptions {
+    /// How audio with more than two channels is handled.
+    pub channel_mode: AudioChannelMode,
+    /// The sample rate to resample to, or the source rate when none.
+    pub sample_rate: Option<u32>,
+}
+
+impl AudioConvertOptions {
+    /// Constructs a new instance of audio convert options.
+    pub const fn new() -> Self {
+        Self {
+            channel_mode: AudioChannelMode::Keep,
+            sample_rate: None,
+        }
+    }
+
+    /// Sets how audio with more than two channels is handled.
+    pub const fn channel_mode(mut self, channel_mode: AudioChannelMode) -> Self {
+        self.channel_mode = channel_mode;
+        self
+    }
+
+    /// Sets the sample rate to resample to.
+    pub const fn sample_rate(mut self, sample_rate: Option<u32>) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+}
+
+impl From<AudioChannelMode> for AudioConvertOptions {
+    fn from(value: AudioChannelMode) -> Self {
+        Self::new().channel_mode(value)
+    }
+}
@@ -7,3 +7,13 @@ pub enum AudioFileType {
     Wav,
     Flac,
 }
+
+impl AudioFileType {
+    /// All of the supported audio file types.
+    pub const ALL: [Self; 2] = [Self::Wav, Self::Flac];
+
+    /// Whether or not the audio file type can only be written to.
+    pub const fn is_write_only(&self) -> bool {
+        matches!(self, Self::Flac)
+    }
+}
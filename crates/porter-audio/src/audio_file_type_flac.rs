@@ -0,0 +1,183 @@
+use std::io::Seek;
+use std::io::Write;
+
+use crate::Audio;
+use crate::AudioError;
+use crate::AudioFileType;
+
+/// Flac stream magic.
+const FLAC_MAGIC: &[u8] = b"fLaC";
+/// The number of frames in each flac block.
+const FLAC_BLOCK_SIZE: usize = 4096;
+/// The maximum number of channels in a flac stream.
+const FLAC_MAXIMUM_CHANNELS: u32 = 8;
+/// The vendor string written to the vorbis comment block.
+const FLAC_VENDOR: &str = "porter-lib";
+
+/// Stream info metadata block type.
+const BLOCK_STREAMINFO: u8 = 0;
+/// Vorbis comment metadata block type.
+const BLOCK_VORBIS_COMMENT: u8 = 4;
+
+/// Computes the crc-8 (0x07) of a flac frame header.
+fn crc8(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Computes the crc-16 (0x8005) of a flac frame.
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, byte| {
+        (0..8).fold(crc ^ ((*byte as u16) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+/// Writes a metadata block header.
+fn write_block_header<O: Write>(
+    output: &mut O,
+    block_type: u8,
+    last: bool,
+    size: usize,
+) -> Result<(), AudioError> {
+    let size = (size as u32).to_be_bytes();
+
+    output.write_all(&[((last as u8) << 7) | block_type, size[1], size[2], size[3]])?;
+
+    Ok(())
+}
+
+/// Encodes the frame number using the extended utf-8 coding flac uses.
+fn encode_frame_number(frame: &mut Vec<u8>, number: u64) {
+    if number < 0x80 {
+        frame.push(number as u8);
+        return;
+    }
+
+    let mut continuation = 1;
+
+    // Each continuation byte holds six bits, and the lead byte loses one bit for each of them.
+    while continuation < 6 && number >= 1 << (5 * continuation + 6) {
+        continuation += 1;
+    }
+
+    let lead = (0xFF00u16 >> (continuation + 1)) as u8;
+
+    frame.push(lead | (number >> (6 * continuation)) as u8);
+
+    for shift in (0..continuation).rev() {
+        frame.push(0x80 | ((number >> (6 * shift)) & 0x3F) as u8);
+    }
+}
+
+/// Writes an audio to a flac file to the output stream as 16bit verbatim frames.
+///
+/// Loop points are stored as `LOOPSTART` and `LOOPLENGTH` vorbis comments.
+pub fn to_flac<O: Write + Seek>(audio: &Audio, output: &mut O) -> Result<(), AudioError> {
+    let channels = audio.channels();
+
+    if channels == 0 || channels > FLAC_MAXIMUM_CHANNELS {
+        return Err(AudioError::ContainerChannelsInvalid(
+            channels,
+            AudioFileType::Flac,
+        ));
+    }
+
+    let frames = audio.frames() as u64;
+
+    output.write_all(FLAC_MAGIC)?;
+
+    write_block_header(output, BLOCK_STREAMINFO, false, 34)?;
+
+    output.write_all(&(FLAC_BLOCK_SIZE as u16).to_be_bytes())?;
+    output.write_all(&(FLAC_BLOCK_SIZE as u16).to_be_bytes())?;
+    output.write_all(&[0; 6])?;
+
+    let info = ((audio.sample_rate() as u64 & 0xFFFFF) << 44)
+        | (((channels - 1) as u64) << 41)
+        | (15 << 36)
+        | (frames & 0xFFFFFFFFF);
+
+    output.write_all(&info.to_be_bytes())?;
+    output.write_all(&[0; 16])?;
+
+    let mut comments = Vec::new();
+
+    if let Some(loop_points) = audio.loop_points() {
+        comments.push(format!("LOOPSTART={}", loop_points.start));
+        comments.push(format!("LOOPLENGTH={}", loop_points.length()));
+    }
+
+    let comments_size = 4
+        + FLAC_VENDOR.len()
+        + 4
+        + comments
+            .iter()
+            .map(|comment| 4 + comment.len())
+            .sum::<usize>();
+
+    write_block_header(output, BLOCK_VORBIS_COMMENT, true, comments_size)?;
+
+    output.write_all(&(FLAC_VENDOR.len() as u32).to_le_bytes())?;
+    output.write_all(FLAC_VENDOR.as_bytes())?;
+    output.write_all(&(comments.len() as u32).to_le_bytes())?;
+
+    for comment in comments {
+        output.write_all(&(comment.len() as u32).to_le_bytes())?;
+        output.write_all(comment.as_bytes())?;
+    }
+
+    let mut frame = Vec::with_capacity(FLAC_BLOCK_SIZE * channels as usize * 2 + 32);
+
+    for (number, block) in audio
+        .samples()
+        .chunks(FLAC_BLOCK_SIZE * channels as usize)
+        .enumerate()
+    {
+        let block_size = block.len() / channels as usize;
+
+        if block_size == 0 {
+            break;
+        }
+
+        frame.clear();
+
+        // Fixed block size, 16bit block size at the end of the header, rate from stream info, 16bit samples.
+        frame.extend_from_slice(&[0xFF, 0xF8, 0x70, (((channels - 1) as u8) << 4) | 0x08]);
+
+        encode_frame_number(&mut frame, number as u64);
+
+        frame.extend_from_slice(&((block_size - 1) as u16).to_be_bytes());
+        frame.push(crc8(&frame));
+
+        for channel in 0..channels as usize {
+            // Verbatim subframe, no wasted bits.
+            frame.push(0x02);
+
+            for sample in block.iter().skip(channel).step_by(channels as usize) {
+                frame
+                    .extend_from_slice(&((sample.clamp(-1.0, 1.0) * 32767.0) as i16).to_be_bytes());
+            }
+        }
+
+        let crc = crc16(&frame);
+
+        frame.extend_from_slice(&crc.to_be_bytes());
+
+        output.write_all(&frame)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,358 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+
+use porter_utils::StructReadExt;
+use porter_utils::StructWriteExt;
+
+use crate::decompress_audio;
+use crate::Audio;
+use crate::AudioCodec;
+use crate::AudioError;
+use crate::AudioFileType;
+use crate::AudioLoop;
+
+/// Little endian riff container magic.
+const RIFF_MAGIC: u32 = 0x46464952;
+/// Big endian riff container magic.
+const RIFX_MAGIC: u32 = 0x58464952;
+/// Wave form type magic.
+const WAVE_MAGIC: u32 = 0x45564157;
+/// Format chunk magic.
+const FMT_MAGIC: u32 = 0x20746D66;
+/// Sample data chunk magic.
+const DATA_MAGIC: u32 = 0x61746164;
+/// Sampler chunk magic.
+const SMPL_MAGIC: u32 = 0x6C706D73;
+
+/// Integer pcm format tag.
+const FORMAT_PCM: u16 = 0x1;
+/// Floating point pcm format tag.
+const FORMAT_FLOAT: u16 = 0x3;
+/// Ima adpcm format tag.
+const FORMAT_IMA_ADPCM: u16 = 0x11;
+/// Xbox ima adpcm format tag.
+const FORMAT_XBOX_ADPCM: u16 = 0x69;
+/// Extensible format tag, the real tag is stored in the sub format.
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+struct WavFormat {
+    format_tag: u16,
+    channels: u16,
+    sample_rate: u32,
+    byte_rate: u32,
+    block_align: u16,
+    bits_per_sample: u16,
+}
+
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+struct WavSampler {
+    manufacturer: u32,
+    product: u32,
+    sample_period: u32,
+    midi_unity_note: u32,
+    midi_pitch_fraction: u32,
+    smpte_format: u32,
+    smpte_offset: u32,
+    sample_loops: u32,
+    sampler_data: u32,
+}
+
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+struct WavSamplerLoop {
+    cue_point_id: u32,
+    loop_type: u32,
+    start: u32,
+    end: u32,
+    fraction: u32,
+    play_count: u32,
+}
+
+/// Reads a riff u32 in the containers byte order.
+fn read_u32<I: Read>(input: &mut I, big_endian: bool) -> Result<u32, AudioError> {
+    let value: u32 = input.read_struct()?;
+
+    Ok(if big_endian {
+        value.swap_bytes()
+    } else {
+        value
+    })
+}
+
+/// Reads the riff header and returns whether or not the container is big endian.
+fn read_riff_header<I: Read>(input: &mut I) -> Result<bool, AudioError> {
+    let big_endian = match input.read_struct::<u32>()? {
+        RIFF_MAGIC => false,
+        RIFX_MAGIC => true,
+        _ => return Err(AudioError::ContainerInvalid(AudioFileType::Wav)),
+    };
+
+    let _size = read_u32(input, big_endian)?;
+
+    if input.read_struct::<u32>()? != WAVE_MAGIC {
+        return Err(AudioError::ContainerInvalid(AudioFileType::Wav));
+    }
+
+    Ok(big_endian)
+}
+
+/// Walks each chunk in the riff container, passing the chunk id and size, the callback must consume at most size bytes.
+fn read_riff_chunks<I: Read + Seek, F: FnMut(&mut I, u32, u32) -> Result<(), AudioError>>(
+    input: &mut I,
+    big_endian: bool,
+    mut callback: F,
+) -> Result<(), AudioError> {
+    loop {
+        let id: u32 = match input.read_struct() {
+            Ok(id) => id,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        let size = read_u32(input, big_endian)?;
+        let start = input.stream_position()?;
+
+        callback(input, id, size)?;
+
+        // Chunks are always padded to an even size.
+        input.seek(SeekFrom::Start(start + size as u64 + (size & 1) as u64))?;
+    }
+
+    Ok(())
+}
+
+/// Reads the first loop from a smpl chunk.
+fn read_sampler_loop<I: Read>(
+    input: &mut I,
+    big_endian: bool,
+) -> Result<Option<AudioLoop>, AudioError> {
+    let sampler: WavSampler = input.read_struct()?;
+
+    let sample_loops = if big_endian {
+        sampler.sample_loops.swap_bytes()
+    } else {
+        sampler.sample_loops
+    };
+
+    if sample_loops == 0 {
+        return Ok(None);
+    }
+
+    let sampler_loop: WavSamplerLoop = input.read_struct()?;
+
+    let (start, end) = if big_endian {
+        (
+            sampler_loop.start.swap_bytes(),
+            sampler_loop.end.swap_bytes(),
+        )
+    } else {
+        (sampler_loop.start, sampler_loop.end)
+    };
+
+    // The sampler loop end is inclusive.
+    Ok(Some(AudioLoop::new(start as u64, end as u64 + 1)))
+}
+
+/// Reads the first loop from the smpl chunk of a riff container.
+pub fn loop_from_riff<I: Read + Seek>(input: &mut I) -> Result<Option<AudioLoop>, AudioError> {
+    let big_endian = read_riff_header(input)?;
+
+    let mut result = None;
+
+    read_riff_chunks(input, big_endian, |input, id, _| {
+        if id == SMPL_MAGIC && result.is_none() {
+            result = read_sampler_loop(input, big_endian)?;
+        }
+
+        Ok(())
+    })?;
+
+    Ok(result)
+}
+
+/// Converts the raw sample data to floating point samples.
+fn convert_samples(format_tag: u16, bits_per_sample: u16, data: &[u8]) -> Option<Vec<f32>> {
+    Some(match (format_tag, bits_per_sample) {
+        (FORMAT_PCM, 8) => data
+            .iter()
+            .map(|sample| (*sample as f32 - 128.0) / 128.0)
+            .collect(),
+        (FORMAT_PCM, 16) => data
+            .chunks_exact(2)
+            .map(|sample| i16::from_le_bytes([sample[0], sample[1]]) as f32 / 32768.0)
+            .collect(),
+        (FORMAT_PCM, 24) => data
+            .chunks_exact(3)
+            .map(|sample| {
+                i32::from_le_bytes([0, sample[0], sample[1], sample[2]]) as f32 / 2147483648.0
+            })
+            .collect(),
+        (FORMAT_PCM, 32) => data
+            .chunks_exact(4)
+            .map(|sample| {
+                i32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]) as f32
+                    / 2147483648.0
+            })
+            .collect(),
+        (FORMAT_FLOAT, 32) => data
+            .chunks_exact(4)
+            .map(|sample| f32::from_le_bytes([sample[0], sample[1], sample[2], sample[3]]))
+            .collect(),
+        _ => return None,
+    })
+}
+
+/// Reads a wav file from the input stream to an audio.
+pub fn from_wav<I: Read + Seek>(input: &mut I) -> Result<Audio, AudioError> {
+    if read_riff_header(input)? {
+        return Err(AudioError::ContainerInvalid(AudioFileType::Wav));
+    }
+
+    let mut format: Option<(WavFormat, u16)> = None;
+    let mut data: Option<Vec<u8>> = None;
+    let mut loop_points: Option<AudioLoop> = None;
+
+    read_riff_chunks(input, false, |input, id, size| {
+        match id {
+            FMT_MAGIC => {
+                let header: WavFormat = input.read_struct()?;
+
+                let format_tag = if header.format_tag == FORMAT_EXTENSIBLE && size >= 40 {
+                    let _extension_size: u16 = input.read_struct()?;
+                    let _valid_bits_per_sample: u16 = input.read_struct()?;
+                    let _channel_mask: u32 = input.read_struct()?;
+
+                    input.read_struct::<u16>()?
+                } else {
+                    header.format_tag
+                };
+
+                format = Some((header, format_tag));
+            }
+            DATA_MAGIC => {
+                let mut buffer = Vec::new();
+
+                input.take(size as u64).read_to_end(&mut buffer)?;
+
+                data = Some(buffer);
+            }
+            SMPL_MAGIC if loop_points.is_none() => {
+                loop_points = read_sampler_loop(input, false)?;
+            }
+            _ => {
+                // Unused chunk.
+            }
+        }
+
+        Ok(())
+    })?;
+
+    let (Some((header, format_tag)), Some(data)) = (format, data) else {
+        return Err(AudioError::ContainerInvalid(AudioFileType::Wav));
+    };
+
+    let samples = match format_tag {
+        FORMAT_IMA_ADPCM => decompress_audio(
+            AudioCodec::ImaAdpcm,
+            &data,
+            header.channels as u32,
+            header.block_align as u32,
+        )?,
+        FORMAT_XBOX_ADPCM => decompress_audio(
+            AudioCodec::XboxAdpcm,
+            &data,
+            header.channels as u32,
+            header.block_align as u32,
+        )?,
+        _ => {
+            let Some(samples) = convert_samples(format_tag, header.bits_per_sample, &data) else {
+                return Err(AudioError::UnsupportedFormat(
+                    format_tag,
+                    header.bits_per_sample,
+                ));
+            };
+
+            samples
+        }
+    };
+
+    let mut audio = Audio::new(header.channels as u32, header.sample_rate, samples);
+
+    audio.set_loop_points(loop_points);
+
+    Ok(audio)
+}
+
+/// Writes an audio to a wav file to the output stream as 16bit pcm.
+pub fn to_wav<O: Write + Seek>(audio: &Audio, output: &mut O) -> Result<(), AudioError> {
+    let channels = audio.channels() as u16;
+    let sample_rate = audio.sample_rate();
+
+    let data_size = (audio.samples().len() * std::mem::size_of::<i16>()) as u32;
+    let smpl_size = audio.loop_points().map_or(0, |_| {
+        (std::mem::size_of::<WavSampler>() + std::mem::size_of::<WavSamplerLoop>()) as u32
+    });
+
+    let mut riff_size = 4 + 8 + std::mem::size_of::<WavFormat>() as u32 + 8 + data_size;
+
+    if smpl_size > 0 {
+        riff_size += 8 + smpl_size;
+    }
+
+    output.write_struct(RIFF_MAGIC)?;
+    output.write_struct(riff_size)?;
+    output.write_struct(WAVE_MAGIC)?;
+
+    output.write_struct(FMT_MAGIC)?;
+    output.write_struct(std::mem::size_of::<WavFormat>() as u32)?;
+    output.write_struct(WavFormat {
+        format_tag: FORMAT_PCM,
+        channels,
+        sample_rate,
+        byte_rate: sample_rate * channels as u32 * 2,
+        block_align: channels * 2,
+        bits_per_sample: 16,
+    })?;
+
+    if let Some(loop_points) = audio.loop_points() {
+        output.write_struct(SMPL_MAGIC)?;
+        output.write_struct(smpl_size)?;
+        output.write_struct(WavSampler {
+            manufacturer: 0,
+            product: 0,
+            sample_period: 1_000_000_000 / sample_rate.max(1),
+            midi_unity_note: 60,
+            midi_pitch_fraction: 0,
+            smpte_format: 0,
+            smpte_offset: 0,
+            sample_loops: 1,
+            sampler_data: 0,
+        })?;
+        output.write_struct(WavSamplerLoop {
+            cue_point_id: 0,
+            loop_type: 0,
+            start: loop_points.start as u32,
+            end: loop_points.end.saturating_sub(1) as u32,
+            fraction: 0,
+            play_count: 0,
+        })?;
+    }
+
+    output.write_struct(DATA_MAGIC)?;
+    output.write_struct(data_size)?;
+
+    let mut buffer = Vec::with_capacity(data_size as usize);
+
+    for sample in audio.samples() {
+        buffer.extend_from_slice(&((sample.clamp(-1.0, 1.0) * 32767.0) as i16).to_le_bytes());
+    }
+
+    output.write_all(&buffer)?;
+
+    Ok(())
+}
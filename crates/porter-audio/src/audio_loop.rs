@@ -0,0 +1,31 @@
+use std::io::Read;
+use std::io::Seek;
+
+use crate::audio_file_type_wav;
+use crate::AudioError;
+
+/// A loop region of audio, in frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioLoop {
+    /// The first frame of the loop.
+    pub start: u64,
+    /// The frame after the last frame of the loop.
+    pub end: u64,
+}
+
+impl AudioLoop {
+    /// Constructs a new loop from the start frame to the end frame, exclusive.
+    pub fn new(start: u64, end: u64) -> Self {
+        Self { start, end }
+    }
+
+    /// The number of frames in the loop.
+    pub fn length(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Reads the first loop from the smpl chunk of a riff container, such as a wav or a wwise wem.
+    pub fn from_riff<I: Read + Seek>(input: &mut I) -> Result<Option<Self>, AudioError> {
+        audio_file_type_wav::loop_from_riff(input)
+    }
+}
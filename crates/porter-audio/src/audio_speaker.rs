@@ -0,0 +1,90 @@
+/// -3dB, used for center and surround channels when downmixing.
+const MINUS_3DB: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// A speaker position of an audio channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioSpeaker {
+    FrontLeft,
+    FrontRight,
+    FrontCenter,
+    LowFrequency,
+    BackLeft,
+    BackRight,
+    BackCenter,
+    SideLeft,
+    SideRight,
+    Unknown(u32),
+}
+
+impl AudioSpeaker {
+    /// Gets the speaker layout for the given number of channels, in wave channel order.
+    pub fn layout(channels: u32) -> Vec<Self> {
+        use AudioSpeaker::*;
+
+        match channels {
+            1 => vec![FrontCenter],
+            2 => vec![FrontLeft, FrontRight],
+            3 => vec![FrontLeft, FrontRight, FrontCenter],
+            4 => vec![FrontLeft, FrontRight, BackLeft, BackRight],
+            5 => vec![FrontLeft, FrontRight, FrontCenter, BackLeft, BackRight],
+            6 => vec![
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                BackLeft,
+                BackRight,
+            ],
+            7 => vec![
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                BackCenter,
+                SideLeft,
+                SideRight,
+            ],
+            8 => vec![
+                FrontLeft,
+                FrontRight,
+                FrontCenter,
+                LowFrequency,
+                BackLeft,
+                BackRight,
+                SideLeft,
+                SideRight,
+            ],
+            _ => (0..channels).map(Unknown).collect(),
+        }
+    }
+
+    /// The short name of the speaker.
+    pub fn name(&self) -> String {
+        match self {
+            Self::FrontLeft => String::from("FL"),
+            Self::FrontRight => String::from("FR"),
+            Self::FrontCenter => String::from("FC"),
+            Self::LowFrequency => String::from("LFE"),
+            Self::BackLeft => String::from("BL"),
+            Self::BackRight => String::from("BR"),
+            Self::BackCenter => String::from("BC"),
+            Self::SideLeft => String::from("SL"),
+            Self::SideRight => String::from("SR"),
+            Self::Unknown(index) => format!("CH{}", index),
+        }
+    }
+
+    /// The left and right stereo downmix coefficients (ITU-R BS.775), the low frequency channel is dropped.
+    pub fn downmix_coefficients(&self) -> (f32, f32) {
+        match self {
+            Self::FrontLeft => (1.0, 0.0),
+            Self::FrontRight => (0.0, 1.0),
+            Self::FrontCenter | Self::BackCenter => (MINUS_3DB, MINUS_3DB),
+            Self::LowFrequency => (0.0, 0.0),
+            Self::BackLeft | Self::SideLeft => (MINUS_3DB, 0.0),
+            Self::BackRight | Self::SideRight => (0.0, MINUS_3DB),
+            Self::Unknown(index) if index % 2 == 0 => (1.0, 0.0),
+            Self::Unknown(_) => (0.0, 1.0),
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use porter_threads::IntoParallelIterator;
+use porter_threads::ParallelIterator;
+
+use crate::Audio;
+
+/// A min/max peak envelope of audio, used to draw a waveform.
+#[derive(Debug, Clone, Default)]
+pub struct AudioWaveform {
+    peaks: Vec<(f32, f32)>,
+}
+
+impl AudioWaveform {
+    /// Computes the peak envelope across every channel of the audio, split into the given number of buckets.
+    pub fn from_audio(audio: &Audio, buckets: usize) -> Self {
+        let channels = audio.channels().max(1) as usize;
+        let frames = audio.frames();
+        let buckets = buckets.min(frames);
+
+        if buckets == 0 {
+            return Self::default();
+        }
+
+        let peaks = (0..buckets)
+            .into_par_iter()
+            .map(|bucket| {
+                let start = bucket * frames / buckets;
+                let end = ((bucket + 1) * frames / buckets).max(start + 1);
+
+                audio.samples()[start * channels..end * channels]
+                    .iter()
+                    .fold((0.0f32, 0.0f32), |(min, max), sample| {
+                        (min.min(*sample), max.max(*sample))
+                    })
+            })
+            .collect();
+
+        Self { peaks }
+    }
+
+    /// The minimum and maximum sample of each bucket.
+    pub fn peaks(&self) -> &[(f32, f32)] {
+        &self.peaks
+    }
+}
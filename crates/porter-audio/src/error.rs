@@ -0,0 +1,24 @@
+use crate::AudioCodec;
+use crate::AudioFileType;
+
+/// Errors that can occur in the audio crate.
+#[derive(Debug)]
+pub enum AudioError {
+    ContainerInvalid(AudioFileType),
+    ContainerWriteOnly(AudioFileType),
+    ContainerChannelsInvalid(u32, AudioFileType),
+    UnsupportedFormat(u16, u16),
+    ChannelCountMismatch(u32, u32),
+    CodecNotEnabled(AudioCodec),
+    CodecDataInvalid(AudioCodec),
+    OggPageInvalid(u64),
+    ExternalDecoderNotFound,
+    ExternalDecoderFailed(String),
+    IoError(std::io::Error),
+}
+
+impl From<std::io::Error> for AudioError {
+    fn from(value: std::io::Error) -> Self {
+        Self::IoError(value)
+    }
+}
@@ -0,0 +1,154 @@
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::OnceLock;
+
+use crate::is_ogg;
+use crate::ogg_split_chained;
+use crate::Audio;
+use crate::AudioError;
+use crate::AudioFileType;
+use crate::AudioLoop;
+
+/// The globally installed ffmpeg executable.
+static FFMPEG: OnceLock<PathBuf> = OnceLock::new();
+
+/// Installs the ffmpeg executable at the given path for use with [`ffmpeg_decode`], fails if one is already installed or it doesn't run.
+pub fn ffmpeg_install<P: AsRef<Path>>(path: P) -> Result<(), AudioError> {
+    let path = path.as_ref().to_path_buf();
+
+    let status = Command::new(&path)
+        .arg("-version")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+
+    if !status.success() {
+        return Err(AudioError::ExternalDecoderFailed(format!(
+            "{} -version exited with {}",
+            path.display(),
+            status
+        )));
+    }
+
+    FFMPEG.set(path).map_err(|_| {
+        AudioError::IoError(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            "An ffmpeg executable is already installed",
+        ))
+    })
+}
+
+/// Whether or not an ffmpeg executable has been installed.
+pub fn ffmpeg_installed() -> bool {
+    FFMPEG.get().is_some()
+}
+
+/// Decodes a complete audio file, such as an atrac9 (.at9) or xma2 (.xma) riff, using the installed ffmpeg executable.
+///
+/// Loop points are read from the smpl chunk of the input when it is a riff container. Chained ogg files are decoded one logical stream at a time and joined.
+pub fn ffmpeg_decode<I: AsRef<[u8]>>(input: I) -> Result<Audio, AudioError> {
+    let Some(ffmpeg) = FFMPEG.get() else {
+        return Err(AudioError::ExternalDecoderNotFound);
+    };
+
+    let input = input.as_ref();
+
+    if is_ogg(input) {
+        return ffmpeg_decode_chained(ffmpeg, input);
+    }
+
+    let mut audio = ffmpeg_run(ffmpeg, input)?;
+
+    if let Ok(loop_points) = AudioLoop::from_riff(&mut Cursor::new(input)) {
+        audio.set_loop_points(loop_points);
+    }
+
+    Ok(audio)
+}
+
+/// Decodes each logical stream of an ogg file on its own, trimming each to its last granule position so they join without gaps.
+fn ffmpeg_decode_chained(ffmpeg: &Path, input: &[u8]) -> Result<Audio, AudioError> {
+    let mut result: Option<Audio> = None;
+
+    for segment in ogg_split_chained(input)? {
+        let mut audio = ffmpeg_run(ffmpeg, &segment.data)?;
+
+        // Ffmpeg already drops the pre skip, so only the padding at the end needs to be trimmed.
+        if segment.end_granule > 0 {
+            audio.truncate(segment.frames() as usize);
+        }
+
+        match result.as_mut() {
+            Some(result) => result.append(&audio)?,
+            None => result = Some(audio),
+        }
+    }
+
+    result.ok_or(AudioError::OggPageInvalid(0))
+}
+
+/// Runs ffmpeg on the input, decoding it to floating point wav.
+fn ffmpeg_run(ffmpeg: &Path, input: &[u8]) -> Result<Audio, AudioError> {
+    let mut child = Command::new(ffmpeg)
+        .args([
+            "-hide_banner",
+            "-loglevel",
+            "error",
+            "-i",
+            "pipe:0",
+            "-f",
+            "wav",
+            "-c:a",
+            "pcm_f32le",
+            "pipe:1",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    let mut stdin = child.stdin.take();
+    let mut stderr = child.stderr.take();
+
+    let output = std::thread::scope(|scope| {
+        // Feed the input and drain the error output on other threads so a full pipe can't stall the process.
+        scope.spawn(move || {
+            if let Some(stdin) = stdin.as_mut() {
+                let _ = stdin.write_all(input);
+            }
+        });
+
+        let errors = scope.spawn(move || {
+            let mut errors = String::new();
+
+            if let Some(stderr) = stderr.as_mut() {
+                let _ = stderr.read_to_string(&mut errors);
+            }
+
+            errors
+        });
+
+        let mut output = Vec::new();
+
+        if let Some(stdout) = child.stdout.as_mut() {
+            stdout.read_to_end(&mut output)?;
+        }
+
+        let status = child.wait()?;
+        let errors = errors.join().unwrap_or_default();
+
+        if !status.success() {
+            return Err(AudioError::ExternalDecoderFailed(errors.trim().to_owned()));
+        }
+
+        Ok(output)
+    })?;
+
+    Audio::load_from(&mut Cursor::new(output), AudioFileType::Wav)
+}
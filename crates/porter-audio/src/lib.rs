@@ -1,3 +1,32 @@
+mod audio;
+mod audio_channel_mode;
+mod audio_convert_options;
 mod audio_file_type;
+mod audio_loop;
+mod audio_speaker;
+mod audio_waveform;
+mod error;
+#[cfg(feature = "ffmpeg")]
+mod ffmpeg;
+mod ogg_stream;
+mod software_decompress;
+mod software_resample;
 
+pub(crate) mod audio_file_type_flac;
+pub(crate) mod audio_file_type_wav;
+
+pub use audio::*;
+pub use audio_channel_mode::*;
+pub use audio_convert_options::*;
 pub use audio_file_type::*;
+pub use audio_loop::*;
+pub use audio_speaker::*;
+pub use audio_waveform::*;
+pub use error::*;
+#[cfg(feature = "ffmpeg")]
+pub use ffmpeg::*;
+pub use ogg_stream::*;
+pub use software_decompress::*;
+
+/// Whether or not decoding through an installed ffmpeg executable is enabled in this build.
+pub const FFMPEG_ENABLED: bool = cfg!(feature = "ffmpeg");
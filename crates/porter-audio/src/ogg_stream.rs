@@ -0,0 +1,130 @@
+use std::io::Cursor;
+use std::io::Read;
+
+use porter_utils::Crc32;
+use porter_utils::StructReadExt;
+
+use crate::AudioError;
+
+/// Ogg page capture pattern.
+const OGG_MAGIC: u32 = 0x5367674F;
+/// The crc32 algorithm used to checksum ogg pages.
+const OGG_CRC: Crc32 = Crc32::new(0x04C11DB7, 0, 0, false);
+/// The offset of the checksum in the page header.
+const OGG_CRC_OFFSET: usize = 22;
+/// The page begins a logical stream.
+const OGG_FLAG_BEGIN: u8 = 0x2;
+/// Opus identification header magic.
+const OPUS_HEAD_MAGIC: &[u8] = b"OpusHead";
+
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+struct OggPageHeader {
+    magic: u32,
+    version: u8,
+    header_type: u8,
+    granule_position: u64,
+    serial: u32,
+    sequence: u32,
+    checksum: u32,
+    segments: u8,
+}
+
+/// A single logical stream of a chained ogg file.
+#[derive(Debug, Clone)]
+pub struct OggSegment {
+    /// The serial number of the logical stream.
+    pub serial: u32,
+    /// The pages of the logical stream, which form a complete ogg file.
+    pub data: Vec<u8>,
+    /// The number of frames the decoder skips at the start of the stream.
+    pub pre_skip: u64,
+    /// The granule position of the last page, the number of frames including the pre skip.
+    pub end_granule: u64,
+}
+
+impl OggSegment {
+    /// The number of frames in the stream after trimming.
+    pub fn frames(&self) -> u64 {
+        self.end_granule.saturating_sub(self.pre_skip)
+    }
+}
+
+/// Whether or not the input starts with an ogg page.
+pub fn is_ogg<I: AsRef<[u8]>>(input: I) -> bool {
+    input.as_ref().starts_with(&OGG_MAGIC.to_le_bytes())
+}
+
+/// Splits an ogg file into each of its chained logical streams, validating every page checksum.
+///
+/// Pages of streams multiplexed into another stream are skipped.
+pub fn ogg_split_chained<I: AsRef<[u8]>>(input: I) -> Result<Vec<OggSegment>, AudioError> {
+    let input = input.as_ref();
+
+    let mut result: Vec<OggSegment> = Vec::new();
+    let mut offset = 0;
+
+    while offset < input.len() {
+        let mut reader = Cursor::new(&input[offset..]);
+
+        let header: OggPageHeader = reader.read_struct()?;
+
+        if header.magic != OGG_MAGIC {
+            return Err(AudioError::OggPageInvalid(offset as u64));
+        }
+
+        let mut lacing = vec![0u8; header.segments as usize];
+
+        reader.read_exact(&mut lacing)?;
+
+        let header_size = std::mem::size_of::<OggPageHeader>() + lacing.len();
+        let body_size: usize = lacing.iter().map(|lace| *lace as usize).sum();
+        let page_size = header_size + body_size;
+
+        let Some(page) = input.get(offset..offset + page_size) else {
+            return Err(AudioError::OggPageInvalid(offset as u64));
+        };
+
+        let mut checked = page.to_vec();
+
+        checked[OGG_CRC_OFFSET..OGG_CRC_OFFSET + 4].fill(0);
+
+        if OGG_CRC.checksum(&checked) != header.checksum {
+            return Err(AudioError::OggPageInvalid(offset as u64));
+        }
+
+        if header.header_type & OGG_FLAG_BEGIN != 0 {
+            let body = &page[header_size..];
+
+            // Opus streams carry the number of frames to skip in their identification header.
+            let pre_skip = if body.starts_with(OPUS_HEAD_MAGIC) && body.len() >= 12 {
+                u16::from_le_bytes([body[10], body[11]]) as u64
+            } else {
+                0
+            };
+
+            result.push(OggSegment {
+                serial: header.serial,
+                data: Vec::new(),
+                pre_skip,
+                end_granule: 0,
+            });
+        }
+
+        if let Some(segment) = result
+            .last_mut()
+            .filter(|segment| segment.serial == header.serial)
+        {
+            segment.data.extend_from_slice(page);
+
+            // A granule position of -1 means no packet finished on this page.
+            if header.granule_position != u64::MAX {
+                segment.end_granule = header.granule_position;
+            }
+        }
+
+        offset += page_size;
+    }
+
+    Ok(result)
+}
@@ -0,0 +1,255 @@
+use crate::AudioError;
+
+/// A compressed audio codec used to decode samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioCodec {
+    /// Microsoft ima adpcm blocks, requires the `ima-adpcm` feature.
+    ImaAdpcm,
+    /// Xbox ima adpcm blocks, which don't output the header sample, requires the `ima-adpcm` feature.
+    XboxAdpcm,
+    /// Sony ps-adpcm (vag) frames, requires the `ps-adpcm` feature.
+    PsAdpcm,
+}
+
+impl AudioCodec {
+    /// All of the audio codecs.
+    pub const ALL: [Self; 3] = [Self::ImaAdpcm, Self::XboxAdpcm, Self::PsAdpcm];
+
+    /// Whether or not the codec is enabled in this build.
+    pub const fn is_enabled(&self) -> bool {
+        match self {
+            Self::ImaAdpcm | Self::XboxAdpcm => cfg!(feature = "ima-adpcm"),
+            Self::PsAdpcm => cfg!(feature = "ps-adpcm"),
+        }
+    }
+}
+
+/// Ima adpcm step sizes.
+#[cfg(feature = "ima-adpcm")]
+const IMA_STEP_TABLE: [i32; 89] = [
+    7, 8, 9, 10, 11, 12, 13, 14, 16, 17, 19, 21, 23, 25, 28, 31, 34, 37, 41, 45, 50, 55, 60, 66,
+    73, 80, 88, 97, 107, 118, 130, 143, 157, 173, 190, 209, 230, 253, 279, 307, 337, 371, 408, 449,
+    494, 544, 598, 658, 724, 796, 876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878, 2066, 2272,
+    2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358, 5894, 6484, 7132, 7845, 8630, 9493,
+    10442, 11487, 12635, 13899, 15289, 16818, 18500, 20350, 22385, 24623, 27086, 29794, 32767,
+];
+
+/// Ima adpcm step index adjustments.
+#[cfg(feature = "ima-adpcm")]
+const IMA_INDEX_TABLE: [i32; 16] = [-1, -1, -1, -1, 2, 4, 6, 8, -1, -1, -1, -1, 2, 4, 6, 8];
+
+/// Ps-adpcm predictor coefficients, scaled by 64.
+#[cfg(feature = "ps-adpcm")]
+const PS_ADPCM_COEFFICIENTS: [(i32, i32); 5] = [(0, 0), (60, 0), (115, -52), (98, -55), (122, -60)];
+
+/// The size of a ps-adpcm frame.
+#[cfg(feature = "ps-adpcm")]
+const PS_ADPCM_FRAME_SIZE: usize = 16;
+
+/// Decodes a single ima adpcm nibble, updating the predictor and step index.
+#[cfg(feature = "ima-adpcm")]
+#[inline]
+fn decode_ima_nibble(nibble: u8, predictor: &mut i32, index: &mut i32) -> f32 {
+    let step = IMA_STEP_TABLE[*index as usize];
+
+    let mut diff = step >> 3;
+
+    if nibble & 1 != 0 {
+        diff += step >> 2;
+    }
+
+    if nibble & 2 != 0 {
+        diff += step >> 1;
+    }
+
+    if nibble & 4 != 0 {
+        diff += step;
+    }
+
+    if nibble & 8 != 0 {
+        diff = -diff;
+    }
+
+    *predictor = (*predictor + diff).clamp(i16::MIN as i32, i16::MAX as i32);
+    *index = (*index + IMA_INDEX_TABLE[nibble as usize]).clamp(0, 88);
+
+    *predictor as f32 / 32768.0
+}
+
+/// Decodes ima adpcm blocks, where each block has a header per channel followed by 4 byte groups of nibbles per channel.
+#[cfg(feature = "ima-adpcm")]
+fn decompress_ima(
+    codec: AudioCodec,
+    input: &[u8],
+    channels: usize,
+    block_align: usize,
+) -> Result<Vec<f32>, AudioError> {
+    let header_size = channels * 4;
+
+    if block_align <= header_size {
+        return Err(AudioError::CodecDataInvalid(codec));
+    }
+
+    // Each group holds 4 bytes of nibbles for every channel.
+    let groups = (block_align - header_size) / header_size;
+
+    if groups == 0 {
+        return Err(AudioError::CodecDataInvalid(codec));
+    }
+
+    let include_header_sample = codec == AudioCodec::ImaAdpcm;
+    let samples_per_block = groups * 8 + include_header_sample as usize;
+
+    let mut result = Vec::with_capacity(input.len() / block_align * samples_per_block * channels);
+    let mut block_samples = vec![0.0f32; samples_per_block * channels];
+
+    for block in input.chunks_exact(block_align) {
+        let mut state: Vec<(i32, i32)> = block[..header_size]
+            .chunks_exact(4)
+            .map(|header| {
+                (
+                    i16::from_le_bytes([header[0], header[1]]) as i32,
+                    (header[2] as i32).clamp(0, 88),
+                )
+            })
+            .collect();
+
+        let mut offset = 0;
+
+        if include_header_sample {
+            for (channel, (predictor, _)) in state.iter().enumerate() {
+                block_samples[channel] = *predictor as f32 / 32768.0;
+            }
+
+            offset = 1;
+        }
+
+        for (group, data) in block[header_size..header_size + groups * header_size]
+            .chunks_exact(4)
+            .enumerate()
+        {
+            let channel = group % channels;
+            let frame = offset + (group / channels) * 8;
+            let (predictor, index) = &mut state[channel];
+
+            for (byte_index, byte) in data.iter().enumerate() {
+                let frame = frame + byte_index * 2;
+
+                block_samples[frame * channels + channel] =
+                    decode_ima_nibble(byte & 0xF, predictor, index);
+                block_samples[(frame + 1) * channels + channel] =
+                    decode_ima_nibble(byte >> 4, predictor, index);
+            }
+        }
+
+        result.extend_from_slice(&block_samples);
+    }
+
+    Ok(result)
+}
+
+/// Decodes ps-adpcm frames, where each channel is interleaved in blocks of the given size.
+#[cfg(feature = "ps-adpcm")]
+fn decompress_ps(input: &[u8], channels: usize, interleave: usize) -> Result<Vec<f32>, AudioError> {
+    let interleave = if channels == 1 {
+        input.len().max(PS_ADPCM_FRAME_SIZE)
+    } else {
+        interleave
+    };
+
+    if interleave < PS_ADPCM_FRAME_SIZE {
+        return Err(AudioError::CodecDataInvalid(AudioCodec::PsAdpcm));
+    }
+
+    let mut channel_samples: Vec<Vec<f32>> = vec![Vec::new(); channels];
+    let mut history = vec![(0i32, 0i32); channels];
+    let mut ended = vec![false; channels];
+
+    for (index, block) in input.chunks(interleave).enumerate() {
+        let channel = index % channels;
+
+        if ended[channel] {
+            continue;
+        }
+
+        let (history_1, history_2) = &mut history[channel];
+
+        for frame in block.chunks_exact(PS_ADPCM_FRAME_SIZE) {
+            let predictor = (frame[0] >> 4) as usize;
+            let shift = match frame[0] & 0xF {
+                shift @ 0..=12 => shift as i32,
+                _ => 9,
+            };
+            let flags = frame[1];
+
+            // The end flag with no loop, the rest of the channel is padding.
+            if flags == 7 {
+                ended[channel] = true;
+                break;
+            }
+
+            let (coefficient_1, coefficient_2) = PS_ADPCM_COEFFICIENTS
+                .get(predictor)
+                .copied()
+                .unwrap_or_default();
+
+            for byte in &frame[2..] {
+                for nibble in [byte & 0xF, byte >> 4] {
+                    let scale = (((nibble as i32) << 28) >> 28) << 12 >> shift;
+                    let sample = (scale
+                        + ((*history_1 * coefficient_1 + *history_2 * coefficient_2) >> 6))
+                        .clamp(i16::MIN as i32, i16::MAX as i32);
+
+                    *history_2 = *history_1;
+                    *history_1 = sample;
+
+                    channel_samples[channel].push(sample as f32 / 32768.0);
+                }
+            }
+        }
+    }
+
+    let frames = channel_samples
+        .iter()
+        .map(|samples| samples.len())
+        .min()
+        .unwrap_or_default();
+
+    let mut result = Vec::with_capacity(frames * channels);
+
+    for frame in 0..frames {
+        for samples in &channel_samples {
+            result.push(samples[frame]);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Decompresses the input buffer using the given codec to interleaved floating point samples.
+///
+/// For ima adpcm the block align is the size of each block, for ps-adpcm it is the interleave size of each channel.
+#[allow(unused_variables)]
+pub fn decompress_audio<I: AsRef<[u8]>>(
+    codec: AudioCodec,
+    input: I,
+    channels: u32,
+    block_align: u32,
+) -> Result<Vec<f32>, AudioError> {
+    let input = input.as_ref();
+
+    if channels == 0 {
+        return Err(AudioError::CodecDataInvalid(codec));
+    }
+
+    match codec {
+        #[cfg(feature = "ima-adpcm")]
+        AudioCodec::ImaAdpcm | AudioCodec::XboxAdpcm => {
+            decompress_ima(codec, input, channels as usize, block_align as usize)
+        }
+        #[cfg(feature = "ps-adpcm")]
+        AudioCodec::PsAdpcm => decompress_ps(input, channels as usize, block_align as usize),
+        #[allow(unreachable_patterns)]
+        _ => Err(AudioError::CodecNotEnabled(codec)),
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::OnceLock;
+
+use porter_threads::IntoParallelIterator;
+use porter_threads::ParallelIterator;
+
+/// The number of zero crossings on each side of the sinc kernel.
+const ZERO_CROSSINGS: usize = 32;
+/// The number of kernel table entries between each zero crossing.
+const TABLE_RESOLUTION: usize = 512;
+/// The fraction of the nyquist frequency that is kept, leaving room for the filter to roll off.
+const ROLLOFF: f64 = 0.95;
+/// The kaiser window shape, roughly 90dB of stopband attenuation.
+const KAISER_BETA: f64 = 8.6;
+
+/// Global windowed sinc kernel table.
+static KERNEL_TABLE: OnceLock<Vec<f32>> = OnceLock::new();
+
+/// The zeroth order modified bessel function of the first kind.
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let half = x / 2.0;
+
+    for k in 1..64 {
+        term *= half / k as f64;
+
+        let squared = term * term;
+
+        sum += squared;
+
+        if squared < sum * 1e-12 {
+            break;
+        }
+    }
+
+    sum
+}
+
+/// Builds one side of the kaiser windowed sinc kernel, sampled at the table resolution.
+fn build_kernel_table() -> Vec<f32> {
+    let length = ZERO_CROSSINGS * TABLE_RESOLUTION;
+    let denominator = bessel_i0(KAISER_BETA);
+
+    (0..=length + 1)
+        .map(|index| {
+            if index >= length {
+                return 0.0;
+            }
+
+            let x = index as f64 / TABLE_RESOLUTION as f64;
+            let sinc = if index == 0 {
+                1.0
+            } else {
+                (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+            };
+
+            let position = x / ZERO_CROSSINGS as f64;
+            let window = bessel_i0(KAISER_BETA * (1.0 - position * position).sqrt()) / denominator;
+
+            (sinc * window) as f32
+        })
+        .collect()
+}
+
+/// Looks up the kernel at the given distance in zero crossings, linearly interpolating the table.
+#[inline]
+fn kernel(table: &[f32], distance: f64) -> f32 {
+    let position = distance * TABLE_RESOLUTION as f64;
+    let index = position as usize;
+
+    if index >= ZERO_CROSSINGS * TABLE_RESOLUTION {
+        return 0.0;
+    }
+
+    let fraction = (position - index as f64) as f32;
+
+    table[index] + (table[index + 1] - table[index]) * fraction
+}
+
+/// Resamples interleaved samples from the source rate to the target rate using a windowed sinc filter.
+pub fn resample(samples: &[f32], channels: usize, source_rate: u32, target_rate: u32) -> Vec<f32> {
+    let channels = channels.max(1);
+
+    if source_rate == target_rate || source_rate == 0 || target_rate == 0 {
+        return samples.to_vec();
+    }
+
+    let table = KERNEL_TABLE.get_or_init(build_kernel_table);
+
+    let frames = samples.len() / channels;
+    let ratio = target_rate as f64 / source_rate as f64;
+    let step = source_rate as f64 / target_rate as f64;
+
+    // When downsampling the kernel is stretched to filter out everything above the new nyquist frequency.
+    let cutoff = ratio.min(1.0) * ROLLOFF;
+    let width = ZERO_CROSSINGS as f64 / cutoff;
+
+    let output_frames = (frames as f64 * ratio).ceil() as usize;
+
+    (0..output_frames)
+        .into_par_iter()
+        .flat_map_iter(|frame| {
+            let time = frame as f64 * step;
+
+            let start = (time - width).ceil().max(0.0) as usize;
+            let end = ((time + width).floor() as usize).min(frames.saturating_sub(1));
+
+            let mut result = vec![0.0f32; channels];
+
+            for source in start..=end {
+                let weight = kernel(table, (time - source as f64).abs() * cutoff);
+
+                if weight == 0.0 {
+                    continue;
+                }
+
+                for (channel, result) in result.iter_mut().enumerate() {
+                    *result += samples[source * channels + channel] * weight;
+                }
+            }
+
+            result.into_iter().map(move |sample| sample * cutoff as f32)
+        })
+        .collect()
+}
@@ -0,0 +1,59 @@
+use crate::CastAnimationNode;
+use crate::CastNodeRef;
+use crate::CastPropertyId;
+use crate::CastRootNode;
+
+/// Builds a cast animation node, which holds the curves and notification tracks of an animation.
+#[derive(Debug, Clone)]
+pub struct CastAnimationBuilder {
+    name: Option<String>,
+    framerate: f32,
+    looping: bool,
+}
+
+impl CastAnimationBuilder {
+    /// Constructs a new animation builder with the given framerate.
+    pub fn new(framerate: f32) -> Self {
+        Self {
+            name: None,
+            framerate,
+            looping: false,
+        }
+    }
+
+    /// Sets an optional name for the animation.
+    pub fn name<N: Into<String>>(mut self, name: Option<N>) -> Self {
+        self.name = name.map(|x| x.into());
+        self
+    }
+
+    /// Sets whether or not the animation loops.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Creates the animation node in the given root node, returning it so curves can be added.
+    pub fn build<'a>(
+        self,
+        root: &'a mut CastNodeRef<'_, CastRootNode>,
+    ) -> CastNodeRef<'a, CastAnimationNode> {
+        let mut animation_node = root.create_node::<CastAnimationNode>();
+
+        if let Some(name) = self.name {
+            animation_node
+                .create_property(CastPropertyId::String, "n")
+                .push(name);
+        }
+
+        animation_node
+            .create_property(CastPropertyId::Float, "fr")
+            .push(self.framerate);
+
+        animation_node
+            .create_property(CastPropertyId::Byte, "lo")
+            .push(self.looping);
+
+        animation_node
+    }
+}
@@ -0,0 +1,100 @@
+use crate::CastAnimationNode;
+use crate::CastCurveMode;
+use crate::CastCurveNode;
+use crate::CastNodeRef;
+use crate::CastPropertyId;
+use crate::CastPropertyType;
+
+/// Builds a cast curve node, which animates a single property of a node.
+#[derive(Debug, Clone)]
+pub struct CastCurveBuilder {
+    node_name: String,
+    key_property: String,
+    mode: CastCurveMode,
+    additive_blend_weight: Option<f32>,
+}
+
+impl CastCurveBuilder {
+    /// Constructs a new curve builder for the given node and property, such as `rq` or `tx`.
+    pub fn new<N: Into<String>, P: Into<String>>(
+        node_name: N,
+        key_property: P,
+        mode: CastCurveMode,
+    ) -> Self {
+        Self {
+            node_name: node_name.into(),
+            key_property: key_property.into(),
+            mode,
+            additive_blend_weight: None,
+        }
+    }
+
+    /// Sets the weight used to blend an additive curve.
+    pub fn additive_blend_weight(mut self, weight: f32) -> Self {
+        self.additive_blend_weight = Some(weight);
+        self
+    }
+
+    /// Creates the curve node in the given animation node, from pairs of frame time and value.
+    ///
+    /// The smallest keyframe buffer type that fits the largest frame time is used, and the value buffer type comes from the value type.
+    pub fn build<'a, T, K>(
+        self,
+        animation: &'a mut CastNodeRef<'_, CastAnimationNode>,
+        keyframes: K,
+    ) -> CastNodeRef<'a, CastCurveNode>
+    where
+        T: CastPropertyType,
+        K: IntoIterator<Item = (u32, T)>,
+    {
+        let keyframes: Vec<(u32, T)> = keyframes.into_iter().collect();
+
+        let mut curve_node = animation.create_node::<CastCurveNode>();
+
+        curve_node
+            .create_property(CastPropertyId::String, "nn")
+            .push(self.node_name);
+
+        curve_node
+            .create_property(CastPropertyId::String, "kp")
+            .push(self.key_property);
+
+        curve_node
+            .create_property(CastPropertyId::String, "m")
+            .push(self.mode.as_str());
+
+        if let Some(weight) = self.additive_blend_weight {
+            curve_node
+                .create_property(CastPropertyId::Float, "ab")
+                .push(weight);
+        }
+
+        let largest_frame_time = keyframes.iter().map(|(time, _)| *time).max().unwrap_or(0);
+
+        let keyframe_buffer = if largest_frame_time <= 0xFF {
+            curve_node.create_property(CastPropertyId::Byte, "kb")
+        } else if largest_frame_time <= 0xFFFF {
+            curve_node.create_property(CastPropertyId::Short, "kb")
+        } else {
+            curve_node.create_property(CastPropertyId::Integer32, "kb")
+        };
+
+        for (time, _) in &keyframes {
+            if largest_frame_time <= 0xFF {
+                keyframe_buffer.push(*time as u8);
+            } else if largest_frame_time <= 0xFFFF {
+                keyframe_buffer.push(*time as u16);
+            } else {
+                keyframe_buffer.push(*time);
+            }
+        }
+
+        let keyvalue_buffer = curve_node.create_property(T::PROPERTY_ID, "kv");
+
+        for (_, value) in keyframes {
+            keyvalue_buffer.push(value);
+        }
+
+        curve_node
+    }
+}
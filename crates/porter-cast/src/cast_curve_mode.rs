@@ -0,0 +1,21 @@
+/// How the keyframes of a cast curve are applied to the rest pose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastCurveMode {
+    /// Keyframes replace the rest pose.
+    Absolute,
+    /// Keyframes are added on top of the current pose.
+    Additive,
+    /// Keyframes are relative to the rest pose.
+    Relative,
+}
+
+impl CastCurveMode {
+    /// The name of the mode as written in the curve node.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Absolute => "absolute",
+            Self::Additive => "additive",
+            Self::Relative => "relative",
+        }
+    }
+}
@@ -12,6 +12,8 @@ use porter_utils::StructReadExt;
 use porter_utils::StructWriteExt;
 
 use crate::CastNode;
+use crate::CastNodeRef;
+use crate::CastRootNode;
 
 #[repr(C, packed)]
 #[derive(Debug, Clone, Copy)]
@@ -41,6 +43,15 @@ impl CastFile {
         self.root_nodes.push(node);
     }
 
+    /// Appends a new root node to the file, returning it so models and animations can be added.
+    pub fn create_root(&mut self) -> CastNodeRef<'_, CastRootNode> {
+        self.root_nodes.push(CastNode::root());
+
+        let index = self.root_nodes.len() - 1;
+
+        CastNodeRef::new_unchecked(self.root_nodes.get_mut(index).unwrap())
+    }
+
     /// Returns an iterator over the root nodes in this file.
     pub fn roots(&self) -> Iter<'_, CastNode> {
         self.root_nodes.iter()
@@ -53,14 +64,7 @@ impl CastFile {
 
     /// Serializes the cast file to the writer.
     pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
-        let header = CastHeader {
-            magic: 0x74736163,
-            version: 1,
-            root_nodes: self.root_nodes.len() as u32,
-            flags: 0,
-        };
-
-        writer.write_struct(header)?;
+        Self::write_header(&mut writer, self.root_nodes.len() as u32)?;
 
         for root in &self.root_nodes {
             root.write(&mut writer)?;
@@ -69,6 +73,18 @@ impl CastFile {
         Ok(())
     }
 
+    /// Serializes a cast file header to the writer, the root nodes must follow it.
+    pub(crate) fn write_header<W: Write>(writer: &mut W, root_nodes: u32) -> Result<(), Error> {
+        let header = CastHeader {
+            magic: 0x74736163,
+            version: 1,
+            root_nodes,
+            flags: 0,
+        };
+
+        writer.write_struct(header)
+    }
+
     /// Deserializes a cast file from the reader.
     pub fn read<R: Read>(mut reader: R) -> Result<Self, Error> {
         let header: CastHeader = reader.read_struct()?;
@@ -0,0 +1,104 @@
+use std::collections::HashSet;
+
+use crate::CastId;
+use crate::CastModelNode;
+use crate::CastNodeRef;
+use crate::CastPropertyId;
+use crate::CastPropertyType;
+use crate::CastPropertyValue;
+
+/// Builds a cast material node, with its texture slots and extra properties.
+#[derive(Debug, Clone)]
+pub struct CastMaterialBuilder {
+    name: String,
+    material_type: String,
+    textures: Vec<(String, String)>,
+    properties: Vec<(CastPropertyId, String, CastPropertyValue)>,
+}
+
+impl CastMaterialBuilder {
+    /// Constructs a new pbr material builder with the given name.
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into(),
+            material_type: String::from("pbr"),
+            textures: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Sets the type of the material, which defaults to `pbr`.
+    pub fn material_type<T: Into<String>>(mut self, material_type: T) -> Self {
+        self.material_type = material_type.into();
+        self
+    }
+
+    /// Adds a texture file in the given slot, such as `albedo` or `normal`.
+    ///
+    /// Slots which are already in use are renamed to `extra{index}`, so no texture is lost.
+    pub fn texture<S: Into<String>, P: Into<String>>(mut self, slot: S, path: P) -> Self {
+        self.textures.push((slot.into(), path.into()));
+        self
+    }
+
+    /// Adds an extra property to the material, such as a color or scalar parameter.
+    ///
+    /// Properties which conflict with the name, type, or a texture slot are skipped, the property type comes from the value type.
+    pub fn property<N: Into<String>, V: CastPropertyType>(mut self, name: N, value: V) -> Self {
+        self.properties
+            .push((V::PROPERTY_ID, name.into(), value.into()));
+        self
+    }
+
+    /// Creates the material node in the given model node, returning the hash used to reference it from meshes.
+    pub fn build(self, model: &mut CastNodeRef<'_, CastModelNode>) -> CastPropertyValue {
+        let material_node = model.create(CastId::Material);
+
+        material_node
+            .create_property(CastPropertyId::String, "n")
+            .push(self.name);
+
+        material_node
+            .create_property(CastPropertyId::String, "t")
+            .push(self.material_type);
+
+        let mut used_names: HashSet<String> = HashSet::new();
+
+        used_names.insert(String::from("n"));
+        used_names.insert(String::from("t"));
+
+        for (index, (slot, path)) in self.textures.into_iter().enumerate() {
+            let slot = if used_names.contains(&slot) {
+                format!("extra{}", index)
+            } else {
+                slot
+            };
+
+            used_names.insert(slot.clone());
+
+            let file = material_node.create(CastId::File);
+
+            file.create_property(CastPropertyId::String, "p").push(path);
+
+            let hash = CastPropertyValue::from(file);
+
+            material_node
+                .create_property(CastPropertyId::Integer64, slot)
+                .push(hash);
+        }
+
+        for (property_type, name, value) in self.properties {
+            if name.is_empty() || used_names.contains(&name) {
+                continue;
+            }
+
+            material_node
+                .create_property(property_type, name.as_str())
+                .push(value);
+
+            used_names.insert(name);
+        }
+
+        CastPropertyValue::from(material_node)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::CastModelNode;
+use crate::CastNodeRef;
+use crate::CastPropertyId;
+use crate::CastRootNode;
+
+/// Builds a cast model node, which holds the skeleton, meshes, and materials of a model.
+#[derive(Debug, Clone, Default)]
+pub struct CastModelBuilder {
+    name: Option<String>,
+}
+
+impl CastModelBuilder {
+    /// Constructs a new model builder.
+    pub fn new() -> Self {
+        Self { name: None }
+    }
+
+    /// Sets an optional name for the model.
+    pub fn name<N: Into<String>>(mut self, name: Option<N>) -> Self {
+        self.name = name.map(|x| x.into());
+        self
+    }
+
+    /// Creates the model node in the given root node, returning it so the skeleton, meshes, and materials can be added.
+    pub fn build<'a>(
+        self,
+        root: &'a mut CastNodeRef<'_, CastRootNode>,
+    ) -> CastNodeRef<'a, CastModelNode> {
+        let mut model_node = root.create_node::<CastModelNode>();
+
+        if let Some(name) = self.name {
+            model_node
+                .create_property(CastPropertyId::String, "n")
+                .push(name);
+        }
+
+        model_node
+    }
+}
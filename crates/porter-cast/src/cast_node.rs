@@ -17,7 +17,7 @@ use crate::CastProperty;
 use crate::CastPropertyId;
 
 /// Base hash constant used to generate hashes.
-const HASH_BASE: u64 = 0x534E495752545250;
+pub(crate) const HASH_BASE: u64 = 0x534E495752545250;
 
 #[repr(C, packed)]
 #[derive(Debug, Clone, Copy)]
@@ -29,6 +29,17 @@ struct CastNodeHeader {
     child_count: u32,
 }
 
+/// Converts a custom metadata key to a property name, or `None` if the key is reserved by the format.
+pub(crate) fn metadata_key(key: &str) -> Option<String> {
+    let key = key.to_lowercase();
+
+    if key.is_empty() || matches!(key.as_str(), "a" | "s" | "up") {
+        None
+    } else {
+        Some(key)
+    }
+}
+
 /// A cast node.
 #[derive(Debug)]
 pub struct CastNode {
@@ -83,6 +94,44 @@ impl CastNode {
         self.properties.get_mut(index).unwrap()
     }
 
+    /// The identifier of this node.
+    pub fn identifier(&self) -> CastId {
+        self.identifier
+    }
+
+    /// Adds a custom metadata value, such as the source archive or asset guid, to this metadata node.
+    ///
+    /// Keys are lowercase, and keys reserved by the format such as `a`, `s`, and `up` are skipped.
+    pub fn create_metadata<K: AsRef<str>, V: AsRef<str>>(&mut self, key: K, value: V) {
+        debug_assert!(matches!(self.identifier, CastId::Metadata));
+
+        let Some(key) = metadata_key(key.as_ref()) else {
+            return;
+        };
+
+        if self.property(&key).is_some() {
+            return;
+        }
+
+        self.create_property(CastPropertyId::String, key)
+            .push(value.as_ref());
+    }
+
+    /// Collects the metadata values from the metadata nodes of this root node.
+    pub fn metadata(&self) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+
+        for metadata in self.children_of_type(CastId::Metadata) {
+            for property in &metadata.properties {
+                if let Some(value) = property.values::<String>().next() {
+                    result.push((property.name().to_string(), value));
+                }
+            }
+        }
+
+        result
+    }
+
     /// Finds a property by the given name.
     pub fn property<N: AsRef<str>>(&self, name: N) -> Option<&CastProperty> {
         self.properties.iter().find(|x| x.name() == name.as_ref())
@@ -112,15 +161,14 @@ impl CastNode {
 
     /// Serializes the node to the writer.
     pub(crate) fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
-        let header = CastNodeHeader {
-            identifier: self.identifier,
-            node_size: self.length(),
-            node_hash: self.hash,
-            property_count: self.properties.len() as u32,
-            child_count: self.children.len() as u32,
-        };
-
-        writer.write_struct(header)?;
+        Self::write_header(
+            writer,
+            self.identifier,
+            self.length(),
+            self.hash,
+            self.properties.len() as u32,
+            self.children.len() as u32,
+        )?;
 
         for property in &self.properties {
             property.write(writer)?;
@@ -133,6 +181,26 @@ impl CastNode {
         Ok(())
     }
 
+    /// Serializes a node header to the writer, the properties and children must follow it.
+    pub(crate) fn write_header<W: Write>(
+        writer: &mut W,
+        identifier: CastId,
+        node_size: u32,
+        node_hash: u64,
+        property_count: u32,
+        child_count: u32,
+    ) -> Result<(), Error> {
+        let header = CastNodeHeader {
+            identifier,
+            node_size,
+            node_hash,
+            property_count,
+            child_count,
+        };
+
+        writer.write_struct(header)
+    }
+
     /// Deserializes the node from the reader.
     pub(crate) fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
         let header: CastNodeHeader = reader.read_struct()?;
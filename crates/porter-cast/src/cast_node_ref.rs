@@ -0,0 +1,100 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::CastId;
+use crate::CastNode;
+
+/// A kind of cast node, used to type node references so builders only accept the right parent.
+pub trait CastNodeKind {
+    /// The identifier of nodes of this kind.
+    const ID: CastId;
+}
+
+/// A cast root node.
+#[derive(Debug, Clone, Copy)]
+pub struct CastRootNode;
+
+/// A cast model node.
+#[derive(Debug, Clone, Copy)]
+pub struct CastModelNode;
+
+/// A cast animation node.
+#[derive(Debug, Clone, Copy)]
+pub struct CastAnimationNode;
+
+/// A cast curve node.
+#[derive(Debug, Clone, Copy)]
+pub struct CastCurveNode;
+
+impl CastNodeKind for CastRootNode {
+    const ID: CastId = CastId::Root;
+}
+
+impl CastNodeKind for CastModelNode {
+    const ID: CastId = CastId::Model;
+}
+
+impl CastNodeKind for CastAnimationNode {
+    const ID: CastId = CastId::Animation;
+}
+
+impl CastNodeKind for CastCurveNode {
+    const ID: CastId = CastId::Curve;
+}
+
+/// A reference to a cast node of a known kind.
+#[derive(Debug)]
+pub struct CastNodeRef<'a, K: CastNodeKind> {
+    node: &'a mut CastNode,
+    kind: PhantomData<K>,
+}
+
+impl<'a, K: CastNodeKind> CastNodeRef<'a, K> {
+    /// Wraps the given node, or `None` if it isn't of this kind.
+    pub fn new(node: &'a mut CastNode) -> Option<Self> {
+        if node.identifier() == K::ID {
+            Some(Self {
+                node,
+                kind: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new child node of the given kind.
+    pub(crate) fn create_node<C: CastNodeKind>(&mut self) -> CastNodeRef<'_, C> {
+        CastNodeRef {
+            node: self.node.create(C::ID),
+            kind: PhantomData,
+        }
+    }
+
+    /// Wraps a node which is known to be of this kind.
+    pub(crate) fn new_unchecked(node: &'a mut CastNode) -> Self {
+        Self {
+            node,
+            kind: PhantomData,
+        }
+    }
+
+    /// Returns the underlying node.
+    pub fn into_inner(self) -> &'a mut CastNode {
+        self.node
+    }
+}
+
+impl<K: CastNodeKind> Deref for CastNodeRef<'_, K> {
+    type Target = CastNode;
+
+    fn deref(&self) -> &Self::Target {
+        self.node
+    }
+}
+
+impl<K: CastNodeKind> DerefMut for CastNodeRef<'_, K> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.node
+    }
+}
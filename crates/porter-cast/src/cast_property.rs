@@ -6,6 +6,7 @@ use std::io::Write;
 use porter_math::Quaternion;
 use porter_math::Vector2;
 use porter_math::Vector3;
+use porter_math::Vector4;
 
 use porter_utils::StringReadExt;
 use porter_utils::StringWriteExt;
@@ -84,53 +85,56 @@ impl CastProperty {
 
     /// Serializes the property to the writer.
     pub(crate) fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
-        let header = CastPropertyHeader {
-            identifier: self.property_type,
-            name_size: self.property_name.len() as u16,
-            array_length: self.property_values.len() as u32,
-        };
-
-        writer.write_struct(header)?;
-        writer.write_all(self.property_name.as_bytes())?;
+        Self::write_header(
+            writer,
+            self.property_type,
+            &self.property_name,
+            self.property_values.len() as u32,
+        )?;
 
         for property_value in &self.property_values {
-            match property_value {
-                CastPropertyValue::Byte(byte) => {
-                    writer.write_all(&byte.to_le_bytes())?;
-                }
-                CastPropertyValue::Short(short) => {
-                    writer.write_all(&short.to_le_bytes())?;
-                }
-                CastPropertyValue::Integer32(integer32) => {
-                    writer.write_all(&integer32.to_le_bytes())?;
-                }
-                CastPropertyValue::Integer64(integer64) => {
-                    writer.write_all(&integer64.to_le_bytes())?;
-                }
-                CastPropertyValue::Float(float) => {
-                    writer.write_all(&float.to_le_bytes())?;
-                }
-                CastPropertyValue::Double(double) => {
-                    writer.write_all(&double.to_le_bytes())?;
-                }
-                CastPropertyValue::String(string) => {
-                    writer.write_null_terminated_string(string)?;
-                }
-                CastPropertyValue::Vector2(vector2) => {
-                    writer.write_struct(*vector2)?;
-                }
-                CastPropertyValue::Vector3(vector3) => {
-                    writer.write_struct(*vector3)?;
-                }
-                CastPropertyValue::Vector4(vector4) => {
-                    writer.write_struct(*vector4)?;
-                }
-            }
+            Self::write_value(writer, property_value)?;
         }
 
         Ok(())
     }
 
+    /// Serializes a property header to the writer, the values must follow it.
+    pub(crate) fn write_header<W: Write>(
+        writer: &mut W,
+        property_type: CastPropertyId,
+        name: &str,
+        array_length: u32,
+    ) -> Result<(), Error> {
+        let header = CastPropertyHeader {
+            identifier: property_type,
+            name_size: name.len() as u16,
+            array_length,
+        };
+
+        writer.write_struct(header)?;
+        writer.write_all(name.as_bytes())
+    }
+
+    /// Serializes a single property value to the writer.
+    pub(crate) fn write_value<W: Write>(
+        writer: &mut W,
+        property_value: &CastPropertyValue,
+    ) -> Result<(), Error> {
+        match property_value {
+            CastPropertyValue::Byte(byte) => writer.write_all(&byte.to_le_bytes()),
+            CastPropertyValue::Short(short) => writer.write_all(&short.to_le_bytes()),
+            CastPropertyValue::Integer32(integer32) => writer.write_all(&integer32.to_le_bytes()),
+            CastPropertyValue::Integer64(integer64) => writer.write_all(&integer64.to_le_bytes()),
+            CastPropertyValue::Float(float) => writer.write_all(&float.to_le_bytes()),
+            CastPropertyValue::Double(double) => writer.write_all(&double.to_le_bytes()),
+            CastPropertyValue::String(string) => writer.write_null_terminated_string(string),
+            CastPropertyValue::Vector2(vector2) => writer.write_struct(*vector2),
+            CastPropertyValue::Vector3(vector3) => writer.write_struct(*vector3),
+            CastPropertyValue::Vector4(vector4) => writer.write_struct(*vector4),
+        }
+    }
+
     /// Deserializes a property from the given reader.
     pub(crate) fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
         let header: CastPropertyHeader = reader.read_struct()?;
@@ -262,6 +266,64 @@ impl PartialEq<CastPropertyValue> for CastPropertyId {
     }
 }
 
+/// A value type which is always stored as the same cast property type.
+pub trait CastPropertyType: Into<CastPropertyValue> {
+    /// The property type used to store values of this type.
+    const PROPERTY_ID: CastPropertyId;
+}
+
+impl CastPropertyType for bool {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Byte;
+}
+
+impl CastPropertyType for u8 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Byte;
+}
+
+impl CastPropertyType for u16 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Short;
+}
+
+impl CastPropertyType for u32 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Integer32;
+}
+
+impl CastPropertyType for u64 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Integer64;
+}
+
+impl CastPropertyType for f32 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Float;
+}
+
+impl CastPropertyType for f64 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Double;
+}
+
+impl CastPropertyType for String {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::String;
+}
+
+impl CastPropertyType for &str {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::String;
+}
+
+impl CastPropertyType for Vector2 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Vector2;
+}
+
+impl CastPropertyType for Vector3 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Vector3;
+}
+
+impl CastPropertyType for Vector4 {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Vector4;
+}
+
+impl CastPropertyType for Quaternion {
+    const PROPERTY_ID: CastPropertyId = CastPropertyId::Vector4;
+}
+
 impl From<bool> for CastPropertyValue {
     fn from(value: bool) -> Self {
         if value {
@@ -499,6 +561,12 @@ impl From<Quaternion> for CastPropertyValue {
     }
 }
 
+impl From<Vector4> for CastPropertyValue {
+    fn from(value: Vector4) -> Self {
+        Self::Vector4(Quaternion::new(value.x, value.y, value.z, value.w))
+    }
+}
+
 impl TryFrom<CastPropertyValue> for Quaternion {
     type Error = Error;
 
@@ -0,0 +1,227 @@
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+
+use crate::metadata_key;
+use crate::CastFile;
+use crate::CastId;
+use crate::CastNode;
+use crate::CastProperty;
+use crate::CastPropertyId;
+use crate::CastPropertyValue;
+use crate::HASH_BASE;
+
+/// A node which has been started, but not yet finished.
+#[derive(Debug)]
+struct CastWriterNode {
+    identifier: CastId,
+    hash: u64,
+    offset: u64,
+    property_count: u32,
+    child_count: u32,
+}
+
+/// Writes a cast file as it's built, instead of holding every node in memory until the end.
+///
+/// Node headers are written with placeholder sizes, then patched once each node ends.
+#[derive(Debug)]
+pub struct CastWriter<W: Write + Seek> {
+    writer: W,
+    offset: u64,
+    nodes: Vec<CastWriterNode>,
+    root_nodes: u32,
+    hash_next: u64,
+}
+
+impl<W: Write + Seek> CastWriter<W> {
+    /// Constructs a new cast writer, writing the file header to the writer.
+    pub fn new(mut writer: W) -> Result<Self, Error> {
+        let offset = writer.stream_position()?;
+
+        CastFile::write_header(&mut writer, 0)?;
+
+        Ok(Self {
+            writer,
+            offset,
+            nodes: Vec::new(),
+            root_nodes: 0,
+            hash_next: HASH_BASE + 1,
+        })
+    }
+
+    /// Starts a new node with the given identifier as a child of the current node, returning its hash.
+    ///
+    /// Root nodes must be started at the top level, and every other node must be inside of one.
+    pub fn begin_node(&mut self, identifier: CastId) -> Result<u64, Error> {
+        let is_root = matches!(identifier, CastId::Root);
+
+        if is_root != self.nodes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Cast root nodes must be at the top level!",
+            ));
+        }
+
+        let hash = if is_root {
+            HASH_BASE
+        } else {
+            let hash = self.hash_next;
+
+            self.hash_next += 1;
+
+            hash
+        };
+
+        match self.nodes.last_mut() {
+            Some(parent) => parent.child_count += 1,
+            None => self.root_nodes += 1,
+        }
+
+        let offset = self.writer.stream_position()?;
+
+        CastNode::write_header(&mut self.writer, identifier, 0, hash, 0, 0)?;
+
+        self.nodes.push(CastWriterNode {
+            identifier,
+            hash,
+            offset,
+            property_count: 0,
+            child_count: 0,
+        });
+
+        Ok(hash)
+    }
+
+    /// Writes a property with a single value to the current node.
+    pub fn write_property<N: AsRef<str>, T: Into<CastPropertyValue>>(
+        &mut self,
+        property_type: CastPropertyId,
+        name: N,
+        value: T,
+    ) -> Result<(), Error> {
+        self.write_property_array(property_type, name, [value])
+    }
+
+    /// Writes a property with every value from the iterator to the current node.
+    pub fn write_property_array<N, T, I>(
+        &mut self,
+        property_type: CastPropertyId,
+        name: N,
+        values: I,
+    ) -> Result<(), Error>
+    where
+        N: AsRef<str>,
+        T: Into<CastPropertyValue>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let Some(node) = self.nodes.last_mut() else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Cast properties must be written inside of a node!",
+            ));
+        };
+
+        node.property_count += 1;
+
+        let values = values.into_iter();
+
+        let Ok(count) = u32::try_from(values.len()) else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cast properties can't have more than u32::MAX values!",
+            ));
+        };
+
+        CastProperty::write_header(
+            &mut self.writer,
+            property_type,
+            &name.as_ref().to_lowercase(),
+            count,
+        )?;
+
+        for value in values {
+            let value = value.into();
+
+            debug_assert!(property_type == value);
+
+            CastProperty::write_value(&mut self.writer, &value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a custom metadata value, such as the source archive or asset guid, to the current metadata node.
+    ///
+    /// Keys are lowercase, and keys reserved by the format such as `a`, `s`, and `up` are skipped.
+    pub fn write_metadata<K: AsRef<str>, V: AsRef<str>>(
+        &mut self,
+        key: K,
+        value: V,
+    ) -> Result<(), Error> {
+        debug_assert!(self
+            .nodes
+            .last()
+            .is_some_and(|node| matches!(node.identifier, CastId::Metadata)));
+
+        let Some(key) = metadata_key(key.as_ref()) else {
+            return Ok(());
+        };
+
+        self.write_property(CastPropertyId::String, key, value.as_ref())
+    }
+
+    /// Finishes the current node, patching its header with the final size and counts.
+    pub fn end_node(&mut self) -> Result<(), Error> {
+        let Some(node) = self.nodes.pop() else {
+            return Err(Error::new(ErrorKind::InvalidInput, "No cast node to end!"));
+        };
+
+        let end = self.writer.stream_position()?;
+
+        let Ok(size) = u32::try_from(end - node.offset) else {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "Cast nodes can't be larger than u32::MAX bytes!",
+            ));
+        };
+
+        self.writer.seek(SeekFrom::Start(node.offset))?;
+
+        CastNode::write_header(
+            &mut self.writer,
+            node.identifier,
+            size,
+            node.hash,
+            node.property_count,
+            node.child_count,
+        )?;
+
+        self.writer.seek(SeekFrom::Start(end))?;
+
+        Ok(())
+    }
+
+    /// Finishes the file, patching the header with the number of root nodes, and returns the writer.
+    pub fn finish(mut self) -> Result<W, Error> {
+        if !self.nodes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Every cast node must be ended before finishing!",
+            ));
+        }
+
+        let end = self.writer.stream_position()?;
+
+        self.writer.seek(SeekFrom::Start(self.offset))?;
+
+        CastFile::write_header(&mut self.writer, self.root_nodes)?;
+
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
@@ -1,11 +1,25 @@
 #![deny(unsafe_code)]
 
+mod cast_animation_builder;
+mod cast_curve_builder;
+mod cast_curve_mode;
 mod cast_file;
 mod cast_id;
+mod cast_material_builder;
+mod cast_model_builder;
 mod cast_node;
+mod cast_node_ref;
 mod cast_property;
+mod cast_writer;
 
+pub use cast_animation_builder::*;
+pub use cast_curve_builder::*;
+pub use cast_curve_mode::*;
 pub use cast_file::*;
 pub use cast_id::*;
+pub use cast_material_builder::*;
+pub use cast_model_builder::*;
 pub use cast_node::*;
+pub use cast_node_ref::*;
 pub use cast_property::*;
+pub use cast_writer::*;
@@ -0,0 +1,130 @@
+use std::fmt::Write as FmtWrite;
+use std::fs::File;
+use std::io::Error;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use log::Level;
+
+use crate::_FormatOp;
+use crate::timestamp;
+
+/// Where machine readable json lines are written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonOutput {
+    /// Writes json lines to stdout, instead of the colored console output.
+    Stdout,
+    /// Writes json lines to the given file, alongside the colored console output.
+    File(PathBuf),
+}
+
+impl JsonOutput {
+    /// The command line flag used to enable json output.
+    pub const FLAG: &'static str = "--json-output";
+
+    /// Parses the json output from the command line, `--json-output` writes to stdout and `--json-output=<file>` writes to the file.
+    pub fn from_args() -> Option<Self> {
+        std::env::args().skip(1).find_map(|arg| {
+            if arg == Self::FLAG {
+                Some(Self::Stdout)
+            } else {
+                arg.strip_prefix(Self::FLAG)?
+                    .strip_prefix('=')
+                    .filter(|path| !path.is_empty())
+                    .map(|path| Self::File(PathBuf::from(path)))
+            }
+        })
+    }
+}
+
+/// The open json output target.
+#[derive(Debug)]
+enum JsonSink {
+    Stdout,
+    File(File),
+}
+
+/// The active json output, if enabled.
+static JSON_OUTPUT: Mutex<Option<JsonSink>> = Mutex::new(None);
+
+/// Enables or disables writing every console message as a json line, with the level, header, message, and timestamp.
+pub fn set_json_output(output: Option<JsonOutput>) -> Result<(), Error> {
+    let sink = match output {
+        Some(JsonOutput::Stdout) => Some(JsonSink::Stdout),
+        Some(JsonOutput::File(path)) => Some(JsonSink::File(File::create(path)?)),
+        None => None,
+    };
+
+    if let Ok(mut output) = JSON_OUTPUT.lock() {
+        *output = sink;
+    }
+
+    Ok(())
+}
+
+/// Escapes a string for use as a json string value.
+fn escape_json(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(result, "\\u{:04x}", c as u32);
+            }
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Writes the message as a json line when json output is enabled, returns true if the console output should be skipped.
+pub(crate) fn write_json_line(
+    level: Level,
+    header: &str,
+    format_ops: &[_FormatOp<'_>],
+) -> Result<bool, Error> {
+    let Ok(mut output) = JSON_OUTPUT.lock() else {
+        return Ok(false);
+    };
+
+    let Some(sink) = output.as_mut() else {
+        return Ok(false);
+    };
+
+    let mut message = String::new();
+
+    for format_op in format_ops {
+        let _ = message.write_fmt(format_op.args);
+    }
+
+    let line = format!(
+        "{{\"level\":\"{}\",\"header\":\"{}\",\"message\":\"{}\",\"timestamp\":\"{}\"}}\n",
+        level.as_str().to_lowercase(),
+        escape_json(header),
+        escape_json(&message),
+        timestamp()
+    );
+
+    match sink {
+        JsonSink::Stdout => {
+            let mut stdout = std::io::stdout().lock();
+
+            stdout.write_all(line.as_bytes())?;
+            stdout.flush()?;
+
+            Ok(true)
+        }
+        JsonSink::File(file) => {
+            file.write_all(line.as_bytes())?;
+
+            Ok(false)
+        }
+    }
+}
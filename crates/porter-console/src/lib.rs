@@ -1,8 +1,16 @@
 #[macro_use]
 mod macros;
 mod color;
+mod json_output;
+mod logger;
+mod prompt;
 
 pub use color::*;
+pub use json_output::*;
+pub use logger::*;
+pub use prompt::*;
+
+pub use log::LevelFilter;
 
 pub use pico_args::Arguments;
 pub use pico_args::Error as PicoError;
@@ -10,6 +18,8 @@ pub use pico_args::Error as PicoError;
 use std::io::Write;
 use std::sync::OnceLock;
 
+use log::Level;
+
 use termcolor::BufferWriter;
 use termcolor::ColorChoice;
 use termcolor::ColorSpec;
@@ -32,59 +42,71 @@ pub struct _FormatOp<'a> {
 
 #[doc(hidden)]
 pub fn _write_header(header: &'static str, format_ops: &[_FormatOp<'_>], new_line: bool) {
-    let write = || -> Result<(), std::io::Error> {
-        let stdout = standard_stream();
-        let mut buffer = stdout.buffer();
+    if let Err(e) = write_header(Level::Info, header, format_ops, new_line) {
+        panic!("failed printing to stdout: {e}");
+    }
+}
 
-        buffer.set_color(
-            ColorSpec::new()
-                .set_bg(Some(Color::DarkGray.into()))
-                .set_fg(Some(Color::Blue.into())),
-        )?;
+/// Writes a line to the console with a colored header, returning any error instead of panicking.
+///
+/// The line is also written as json when json output is enabled.
+pub(crate) fn write_header(
+    level: Level,
+    header: &str,
+    format_ops: &[_FormatOp<'_>],
+    new_line: bool,
+) -> Result<(), std::io::Error> {
+    if write_json_line(level, header, format_ops)? {
+        return Ok(());
+    }
 
-        write!(
-            &mut buffer,
-            "{:width$}",
-            format!("[{}]", header),
-            width = 20
-        )?;
+    let stdout = standard_stream();
+    let mut buffer = stdout.buffer();
 
-        buffer.set_color(
-            ColorSpec::new()
-                .set_bg(None)
-                .set_fg(Some(Color::White.into())),
-        )?;
+    buffer.set_color(
+        ColorSpec::new()
+            .set_bg(Some(Color::DarkGray.into()))
+            .set_fg(Some(Color::Blue.into())),
+    )?;
 
-        write!(&mut buffer, ": ")?;
+    write!(
+        &mut buffer,
+        "{:width$}",
+        format!("[{}]", header),
+        width = 20
+    )?;
 
-        for format_op in format_ops {
-            buffer.set_color(
-                ColorSpec::new()
-                    .set_bg(format_op.background.map(Into::into))
-                    .set_fg(Some(format_op.foreground.into())),
-            )?;
+    buffer.set_color(
+        ColorSpec::new()
+            .set_bg(None)
+            .set_fg(Some(Color::White.into())),
+    )?;
 
-            buffer.write_fmt(format_op.args)?;
-        }
+    write!(&mut buffer, ": ")?;
 
+    for format_op in format_ops {
         buffer.set_color(
             ColorSpec::new()
-                .set_bg(None)
-                .set_fg(Some(Color::White.into())),
+                .set_bg(format_op.background.map(Into::into))
+                .set_fg(Some(format_op.foreground.into())),
         )?;
 
-        if new_line {
-            writeln!(&mut buffer)?;
-        }
-
-        stdout.print(&buffer)?;
+        buffer.write_fmt(format_op.args)?;
+    }
 
-        Ok(())
-    };
+    buffer.set_color(
+        ColorSpec::new()
+            .set_bg(None)
+            .set_fg(Some(Color::White.into())),
+    )?;
 
-    if let Err(e) = write() {
-        panic!("failed printing to stdout: {e}");
+    if new_line {
+        writeln!(&mut buffer)?;
     }
+
+    stdout.print(&buffer)?;
+
+    Ok(())
 }
 
 /// Informs the user they must press enter to continue.
@@ -0,0 +1,285 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::RwLock;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use directories::ProjectDirs;
+
+use log::Level;
+use log::LevelFilter;
+use log::Log;
+use log::Metadata;
+use log::Record;
+
+use crate::_FormatOp;
+use crate::write_header;
+use crate::Color;
+
+/// The size in bytes a log file can grow to before it's rotated.
+const LOG_MAX_SIZE: u64 = 8 * 1024 * 1024;
+/// The number of rotated log files kept, not including the active one.
+const LOG_MAX_FILES: usize = 4;
+
+/// The installed logger, which can be configured after installing.
+static LOGGER: OnceLock<PorterLogger> = OnceLock::new();
+
+/// The active log file and its size.
+#[derive(Debug)]
+struct LogFile {
+    path: PathBuf,
+    file: File,
+    size: u64,
+}
+
+/// A logger which writes to the console and rotating log files in the config directory.
+#[derive(Debug)]
+pub struct PorterLogger {
+    name: String,
+    level: LevelFilter,
+    targets: RwLock<Vec<(String, LevelFilter)>>,
+    console: bool,
+    file: Option<Mutex<LogFile>>,
+}
+
+impl PorterLogger {
+    /// Constructs a new logger for the given tool, which logs warnings and errors by default.
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into().to_lowercase(),
+            level: LevelFilter::Warn,
+            targets: RwLock::new(Vec::new()),
+            console: false,
+            file: None,
+        }
+    }
+
+    /// Sets the level for targets without a specific level.
+    pub fn level(mut self, level: LevelFilter) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the level for targets starting with the given prefix, such as a crate name.
+    pub fn target_level<T: Into<String>>(self, target: T, level: LevelFilter) -> Self {
+        self.set_target_level(target, level);
+        self
+    }
+
+    /// Sets whether or not to also write to the console.
+    pub fn console(mut self, console: bool) -> Self {
+        self.console = console;
+        self
+    }
+
+    /// Installs the logger as the global logger, returns false if a logger was already installed.
+    pub fn install(mut self) -> bool {
+        if LOGGER.get().is_some() {
+            return false;
+        }
+
+        self.file = log_directory().and_then(|directory| {
+            std::fs::create_dir_all(&directory).ok()?;
+
+            let path = directory.join(&self.name).with_extension("log");
+
+            rotate_log_files(&path);
+
+            File::create(&path).ok().map(|file| {
+                Mutex::new(LogFile {
+                    path,
+                    file,
+                    size: 0,
+                })
+            })
+        });
+
+        let logger = LOGGER.get_or_init(|| self);
+
+        if log::set_logger(logger).is_err() {
+            return false;
+        }
+
+        logger.update_max_level();
+
+        true
+    }
+
+    /// Sets the level for targets starting with the given prefix, replacing any previous level.
+    fn set_target_level<T: Into<String>>(&self, target: T, level: LevelFilter) {
+        let target = target.into();
+
+        let Ok(mut targets) = self.targets.write() else {
+            return;
+        };
+
+        match targets.iter_mut().find(|(prefix, _)| *prefix == target) {
+            Some((_, existing)) => *existing = level,
+            None => targets.push((target, level)),
+        }
+
+        // Longer prefixes are checked first, so the most specific target wins.
+        targets.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+    }
+
+    /// Gets the level for the given target.
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .read()
+            .ok()
+            .and_then(|targets| {
+                targets
+                    .iter()
+                    .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+                    .map(|(_, level)| *level)
+            })
+            .unwrap_or(self.level)
+    }
+
+    /// Updates the global max level, so disabled records are skipped before formatting.
+    fn update_max_level(&self) {
+        let max = self
+            .targets
+            .read()
+            .ok()
+            .and_then(|targets| targets.iter().map(|(_, level)| *level).max())
+            .unwrap_or(LevelFilter::Off);
+
+        log::set_max_level(max.max(self.level));
+    }
+}
+
+impl Log for PorterLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        if self.console {
+            let color = match record.level() {
+                Level::Error => Color::Red,
+                Level::Warn => Color::Yellow,
+                Level::Info => Color::White,
+                Level::Debug | Level::Trace => Color::DarkGray,
+            };
+
+            // The console may not exist for windowed apps, so errors are ignored.
+            let _ = write_header(
+                record.level(),
+                record.level().as_str(),
+                &[_FormatOp {
+                    foreground: color,
+                    background: None,
+                    args: format_args!("[{}] {}", record.target(), record.args()),
+                }],
+                true,
+            );
+        }
+
+        let Some(file) = &self.file else {
+            return;
+        };
+
+        let Ok(mut file) = file.lock() else {
+            return;
+        };
+
+        let line = format!(
+            "{} {:<5} [{}] {}\n",
+            timestamp(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        if file.size + line.len() as u64 > LOG_MAX_SIZE {
+            rotate_log_files(&file.path);
+
+            match File::create(&file.path) {
+                Ok(new_file) => {
+                    file.file = new_file;
+                    file.size = 0;
+                }
+                Err(_) => return,
+            }
+        }
+
+        if file.file.write_all(line.as_bytes()).is_ok() {
+            file.size += line.len() as u64;
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(Ok(mut file)) = self.file.as_ref().map(|file| file.lock()) {
+            let _ = file.file.flush();
+        }
+    }
+}
+
+/// Sets the level for targets starting with the given prefix on the installed logger.
+pub fn set_log_target_level<T: Into<String>>(target: T, level: LevelFilter) {
+    if let Some(logger) = LOGGER.get() {
+        logger.set_target_level(target, level);
+        logger.update_max_level();
+    }
+}
+
+/// Returns the directory log files are written to.
+pub fn log_directory() -> Option<PathBuf> {
+    ProjectDirs::from("com", "DTZxPorter", "GameTools")
+        .map(|project_directory| project_directory.config_dir().to_path_buf())
+}
+
+/// Shifts the existing log files up by one, removing the oldest.
+fn rotate_log_files(path: &Path) {
+    let rotated = |index: usize| path.with_extension(format!("{}.log", index));
+
+    let _ = std::fs::remove_file(rotated(LOG_MAX_FILES));
+
+    for index in (1..LOG_MAX_FILES).rev() {
+        let _ = std::fs::rename(rotated(index), rotated(index + 1));
+    }
+
+    let _ = std::fs::rename(path, rotated(1));
+}
+
+/// Formats the current utc time as `YYYY-MM-DD HH:MM:SS.mmm`.
+pub(crate) fn timestamp() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let seconds = now.as_secs();
+    let days = (seconds / 86400) as i64;
+    let time = seconds % 86400;
+
+    // Converts days since the epoch to a civil date, see: http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
+        year,
+        month,
+        day,
+        time / 3600,
+        (time / 60) % 60,
+        time % 60,
+        now.subsec_millis()
+    )
+}
@@ -0,0 +1,161 @@
+use std::io::BufRead;
+use std::io::Write;
+
+use log::Level;
+
+use crate::_FormatOp;
+use crate::write_header;
+use crate::Color;
+
+/// The header used for every prompt.
+const PROMPT_HEADER: &str = "User Input";
+
+/// Writes a prompt line, optionally followed by a hint, leaving the cursor on the same line.
+fn write_prompt(prompt: &str, hint: Option<&str>) {
+    let _ = write_header(
+        Level::Info,
+        PROMPT_HEADER,
+        &[
+            _FormatOp {
+                foreground: Color::White,
+                background: None,
+                args: format_args!("{} ", prompt),
+            },
+            _FormatOp {
+                foreground: Color::Blue,
+                background: None,
+                args: format_args!(
+                    "{}",
+                    hint.map(|hint| format!("{} ", hint)).unwrap_or_default()
+                ),
+            },
+        ],
+        false,
+    );
+}
+
+/// Writes a line explaining why the last input was rejected.
+fn write_invalid(message: &str) {
+    let _ = write_header(
+        Level::Warn,
+        PROMPT_HEADER,
+        &[_FormatOp {
+            foreground: Color::Red,
+            background: None,
+            args: format_args!("{}", message),
+        }],
+        true,
+    );
+}
+
+/// Reads a single line from standard input, without the line ending, or none if input was closed.
+fn read_line() -> Option<String> {
+    // The prompt is written without a line ending, so make sure it's visible before blocking.
+    let _ = std::io::stdout().flush();
+
+    let mut line = String::new();
+
+    match std::io::stdin().lock().read_line(&mut line) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+    }
+}
+
+/// Asks the user for a line of text, such as a game path, until the validator accepts it.
+///
+/// The validator returns a message explaining why the input was rejected. Returns none if input was closed.
+pub fn prompt_string<P, V>(prompt: P, validator: V) -> Option<String>
+where
+    P: AsRef<str>,
+    V: Fn(&str) -> Result<(), String>,
+{
+    loop {
+        write_prompt(prompt.as_ref(), None);
+
+        let line = read_line()?;
+        let line = line.trim();
+
+        match validator(line) {
+            Ok(()) => return Some(line.to_string()),
+            Err(message) => write_invalid(&message),
+        }
+    }
+}
+
+/// Asks the user to pick one of the options by number, returning the index of the option picked.
+///
+/// Returns none if there are no options, or input was closed.
+pub fn prompt_select<P: AsRef<str>, O: AsRef<str>>(prompt: P, options: &[O]) -> Option<usize> {
+    if options.is_empty() {
+        return None;
+    }
+
+    let _ = write_header(
+        Level::Info,
+        PROMPT_HEADER,
+        &[_FormatOp {
+            foreground: Color::White,
+            background: None,
+            args: format_args!("{}", prompt.as_ref()),
+        }],
+        true,
+    );
+
+    for (index, option) in options.iter().enumerate() {
+        let _ = write_header(
+            Level::Info,
+            PROMPT_HEADER,
+            &[
+                _FormatOp {
+                    foreground: Color::Pink,
+                    background: None,
+                    args: format_args!("  {}) ", index + 1),
+                },
+                _FormatOp {
+                    foreground: Color::White,
+                    background: None,
+                    args: format_args!("{}", option.as_ref()),
+                },
+            ],
+            true,
+        );
+    }
+
+    let hint = format!("[1-{}]", options.len());
+
+    loop {
+        write_prompt("Choose an option:", Some(&hint));
+
+        let line = read_line()?;
+
+        match line.trim().parse::<usize>() {
+            Ok(choice) if (1..=options.len()).contains(&choice) => return Some(choice - 1),
+            _ => write_invalid(&format!(
+                "Please enter a number between 1 and {}.",
+                options.len()
+            )),
+        }
+    }
+}
+
+/// Asks the user a yes or no question, such as whether to overwrite a file.
+///
+/// An empty answer, or closed input, returns the default.
+pub fn confirm<P: AsRef<str>>(prompt: P, default: bool) -> bool {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+
+    loop {
+        write_prompt(prompt.as_ref(), Some(hint));
+
+        let Some(line) = read_line() else {
+            return default;
+        };
+
+        match line.trim().to_lowercase().as_str() {
+            "" => return default,
+            "y" | "yes" => return true,
+            "n" | "no" => return false,
+            _ => write_invalid("Please answer yes or no."),
+        }
+    }
+}
@@ -59,7 +59,7 @@ const HEADER_FILE_ID_NODE: (&str, [u8; 16]) = (
 const HEADER_CREATION_TIME_NODE: (&str, &str) = ("CreationTime", "1970-01-01 10:00:00:000");
 
 /// Header node definitions.
-const HEADER_DEFINITIONS_NODE: [(&str, &str, &str); 8] = [
+const HEADER_DEFINITIONS_NODE: [(&str, &str, &str); 12] = [
     ("ObjectType", "GlobalSettings", ""),
     ("ObjectType", "NodeAttribute", ""),
     ("ObjectType", "Geometry", "FbxMesh"),
@@ -68,6 +68,10 @@ const HEADER_DEFINITIONS_NODE: [(&str, &str, &str); 8] = [
     ("ObjectType", "Deformer", ""),
     ("ObjectType", "Material", "FbxSurfacePhong"),
     ("ObjectType", "Texture", "FbxFileTexture"),
+    ("ObjectType", "AnimationStack", "FbxAnimStack"),
+    ("ObjectType", "AnimationLayer", "FbxAnimLayer"),
+    ("ObjectType", "AnimationCurveNode", "FbxAnimCurveNode"),
+    ("ObjectType", "AnimationCurve", ""),
 ];
 
 #[repr(C, packed)]
@@ -151,6 +155,22 @@ impl FbxDocument {
         Ok(())
     }
 
+    /// Serializes the document to the writer in ascii format, which is easier to debug but much larger.
+    pub fn write_ascii<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(b"; FBX 7.4.0 project file\n")?;
+        writer.write_all(b"; Exported by DTZxPorter\n")?;
+        writer.write_all(b"; ----------------------------------------------------\n\n")?;
+
+        for child in &self.root_nodes {
+            child.write_ascii(&mut writer, 0)?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+
     /// Gets the objects node of this document.
     pub fn objects_node(&mut self) -> &mut FbxNode {
         &mut self.root_nodes[self.objects_node]
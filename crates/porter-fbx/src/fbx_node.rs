@@ -110,6 +110,36 @@ impl FbxNode {
         Ok(())
     }
 
+    /// Serializes the node to the writer in ascii format, indented by the given depth.
+    pub(crate) fn write_ascii<W: Write>(&self, writer: &mut W, depth: usize) -> Result<(), Error> {
+        let indent = "\t".repeat(depth);
+
+        write!(writer, "{}{}: ", indent, self.name)?;
+
+        for (index, property) in self.properties.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b", ")?;
+            }
+
+            property.write_ascii(writer, depth)?;
+        }
+
+        // Mirrors where the binary writer adds an empty node, so both formats have the same layout.
+        if !self.children.is_empty() || self.properties.is_empty() {
+            writer.write_all(b" {\n")?;
+
+            for child in &self.children {
+                child.write_ascii(writer, depth + 1)?;
+            }
+
+            write!(writer, "{}}}", indent)?;
+        }
+
+        writer.write_all(b"\n")?;
+
+        Ok(())
+    }
+
     /// Gets the length of this node in bytes.
     pub(crate) fn length(&self) -> u32 {
         let mut result = std::mem::size_of::<u32>() as u32
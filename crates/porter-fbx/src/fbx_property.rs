@@ -61,6 +61,63 @@ impl FbxPropertyString {
     }
 }
 
+/// Writes a single property value in ascii format.
+fn write_ascii_value<W: Write>(writer: &mut W, value: &FbxPropertyValue) -> Result<(), Error> {
+    // Integers are stored unsigned, but fbx treats them as signed, such as negative polygon indices.
+    match value {
+        FbxPropertyValue::Boolean(bool) => write!(writer, "{}", if *bool { "T" } else { "F" }),
+        FbxPropertyValue::Byte(byte) => write!(writer, "{}", byte),
+        FbxPropertyValue::Integer16(integer16) => write!(writer, "{}", *integer16 as i16),
+        FbxPropertyValue::Integer32(integer32) => write!(writer, "{}", *integer32 as i32),
+        FbxPropertyValue::Integer64(integer64) => write!(writer, "{}", *integer64 as i64),
+        FbxPropertyValue::Float32(float32) => write!(writer, "{}", float32),
+        FbxPropertyValue::Float64(float64) => write!(writer, "{}", float64),
+    }
+}
+
+/// Escapes the characters which can't appear in an ascii fbx string.
+fn escape_ascii_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("&quot;"),
+            '\r' => result.push_str("&cr;"),
+            '\n' => result.push_str("&lf;"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+/// Encodes a binary buffer as a base64 string, which is how ascii fbx stores raw properties.
+fn encode_base64(buffer: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut result = String::with_capacity(buffer.len().div_ceil(3) * 4);
+
+    for chunk in buffer.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+
+        let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(ALPHABET[((value >> (18 - i * 6)) & 0x3F) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+
+    result
+}
+
 /// A fbx property of a node.
 #[derive(Debug)]
 pub struct FbxProperty {
@@ -172,6 +229,63 @@ impl FbxProperty {
         Ok(())
     }
 
+    /// Serializes the property to the writer in ascii format, arrays are indented by the given depth.
+    pub(crate) fn write_ascii<W: Write>(&self, writer: &mut W, depth: usize) -> Result<(), Error> {
+        let is_array = matches!(
+            self.property_type,
+            FbxPropertyType::BoolArray
+                | FbxPropertyType::ByteArray
+                | FbxPropertyType::Float32Array
+                | FbxPropertyType::Float64Array
+                | FbxPropertyType::Integer16Array
+                | FbxPropertyType::Integer32Array
+                | FbxPropertyType::Integer64Array
+        );
+
+        if is_array {
+            let indent = "\t".repeat(depth);
+
+            write!(
+                writer,
+                "*{} {{\n{}\ta: ",
+                self.property_values.len(),
+                indent
+            )?;
+
+            for (index, property_value) in self.property_values.iter().enumerate() {
+                if index > 0 {
+                    writer.write_all(b",")?;
+                }
+
+                write_ascii_value(writer, property_value)?;
+            }
+
+            return write!(writer, "\n{}}}", indent);
+        }
+
+        match &self.property_string {
+            FbxPropertyString::None => {
+                if let Some(property_value) = self.property_values.first() {
+                    write_ascii_value(writer, property_value)?;
+                }
+            }
+            FbxPropertyString::String(string) => {
+                // Binary object names are stored as `Name\0\x01Class`, ascii uses `Class::Name`.
+                let string = match string.split_once("\u{0000}\u{0001}") {
+                    Some((name, class)) => format!("{}::{}", class, name),
+                    None => string.clone(),
+                };
+
+                write!(writer, "\"{}\"", escape_ascii_string(&string))?;
+            }
+            FbxPropertyString::Buffer(buffer) => {
+                write!(writer, "\"{}\"", encode_base64(buffer))?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Gets the length of this property in bytes.
     pub(crate) fn length(&self) -> u32 {
         let mut result = std::mem::size_of::<u8>() as u32;
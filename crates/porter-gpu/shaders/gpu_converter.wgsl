@@ -34,6 +34,8 @@ struct OptionsUniform {
     input_unorm: u32,
     output_unorm: u32,
     invert_y: u32,
+    remap: vec4<u32>,
+    invert: vec4<u32>,
 }
 
 @group(0) @binding(0)
@@ -44,9 +46,26 @@ var t_input: texture_2d<f32>;
 @group(0) @binding(2)
 var s_input: sampler;
 
+// Picks each output channel from a source channel (4 = zero, 5 = one), then inverts it when requested.
+fn remap_channels(color: vec4<f32>) -> vec4<f32> {
+    var sources: array<f32, 6> = array<f32, 6>(color.x, color.y, color.z, color.w, 0.0, 1.0);
+
+    var result: vec4<f32>;
+
+    for (var i: i32 = 0; i < 4; i++) {
+        result[i] = sources[options.remap[i]];
+
+        if options.invert[i] == 1u {
+            result[i] = 1.0 - result[i];
+        }
+    }
+
+    return result;
+}
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    return textureSample(t_input, s_input, in.tex_coord);
+    return remap_channels(textureSample(t_input, s_input, in.tex_coord));
 }
 
 @fragment
@@ -66,11 +85,11 @@ fn fs_rz_main(in: VertexOutput) -> @location(0) vec4<f32> {
 
     if options.invert_y == 1u {
         if options.output_unorm == 1u {
-            return vec4<f32>(normalized.x, 1.0 - normalized.y, normalized.z, 1.0);
+            return remap_channels(vec4<f32>(normalized.x, 1.0 - normalized.y, normalized.z, 1.0));
         } else {
-            return vec4<f32>(normalized.x, -normalized.y, normalized.z, 1.0);
+            return remap_channels(vec4<f32>(normalized.x, -normalized.y, normalized.z, 1.0));
         }
     } else {
-        return vec4<f32>(normalized, 1.0);
+        return remap_channels(vec4<f32>(normalized, 1.0));
     }
 }
\ No newline at end of file
@@ -40,6 +40,15 @@ struct ImageOutput {
     @location(0) uv: vec2<f32>,
 }
 
+struct MaterialUniform {
+    gloss: u32,
+}
+
+struct ImageUniform {
+    tone_mapping: u32,
+    exposure: f32,
+}
+
 struct CameraUniform {
     target_c: vec3<f32>,
     view_matrix: mat4x4<f32>,
@@ -47,7 +56,7 @@ struct CameraUniform {
     projection_matrix: mat4x4<f32>,
     model_matrix: mat4x4<f32>,
     inverse_model_matrix: mat4x4<f32>,
-    default_shaded: u32,
+    shading: u32,
 }
 
 @group(0) @binding(0)
@@ -57,6 +66,66 @@ var<uniform> camera: CameraUniform;
 var t_albedo: texture_2d<f32>;
 @group(1) @binding(1)
 var s_albedo: sampler;
+@group(1) @binding(2)
+var t_normal: texture_2d<f32>;
+@group(1) @binding(3)
+var t_roughness: texture_2d<f32>;
+@group(1) @binding(4)
+var t_metalness: texture_2d<f32>;
+@group(1) @binding(5)
+var t_occlusion: texture_2d<f32>;
+@group(1) @binding(6)
+var<uniform> material: MaterialUniform;
+@group(1) @binding(7)
+var<uniform> image: ImageUniform;
+
+@group(2) @binding(0)
+var t_environment: texture_2d_array<f32>;
+@group(2) @binding(1)
+var t_brdf: texture_2d<f32>;
+@group(2) @binding(2)
+var s_environment: sampler;
+
+// Prefiltered roughness levels in the environment, the irradiance follows them.
+const ENVIRONMENT_LEVELS: i32 = 6;
+const PI: f32 = 3.14159265;
+
+// Builds a tangent frame from screen space derivatives, since preview meshes don't carry tangents.
+fn cotangent_frame(normal: vec3<f32>, position: vec3<f32>, uv: vec2<f32>) -> mat3x3<f32> {
+    let dp1: vec3<f32> = dpdx(position);
+    let dp2: vec3<f32> = dpdy(position);
+    let duv1: vec2<f32> = dpdx(uv);
+    let duv2: vec2<f32> = dpdy(uv);
+
+    let dp2perp: vec3<f32> = cross(dp2, normal);
+    let dp1perp: vec3<f32> = cross(normal, dp1);
+
+    let tangent: vec3<f32> = dp2perp * duv1.x + dp1perp * duv2.x;
+    let bitangent: vec3<f32> = dp2perp * duv1.y + dp1perp * duv2.y;
+
+    let scale: f32 = max(dot(tangent, tangent), dot(bitangent, bitangent));
+
+    if scale <= 0.0 {
+        return mat3x3<f32>(vec3<f32>(0.0), vec3<f32>(0.0), normal);
+    }
+
+    let inverse_scale: f32 = inverseSqrt(scale);
+
+    return mat3x3<f32>(tangent * inverse_scale, bitangent * inverse_scale, normal);
+}
+
+// Samples the equirectangular environment, blending between the two nearest levels.
+fn sample_environment(direction: vec3<f32>, level: f32) -> vec3<f32> {
+    let uv: vec2<f32> = vec2<f32>(atan2(direction.x, -direction.z) / (2.0 * PI) + 0.5, acos(clamp(direction.y, -1.0, 1.0)) / PI);
+
+    let lower: i32 = i32(floor(level));
+    let upper: i32 = min(lower + 1, ENVIRONMENT_LEVELS);
+
+    let a: vec3<f32> = textureSampleLevel(t_environment, s_environment, uv, lower, 0.0).xyz;
+    let b: vec3<f32> = textureSampleLevel(t_environment, s_environment, uv, upper, 0.0).xyz;
+
+    return mix(a, b, fract(level));
+}
 
 @vertex
 fn vs_main(in: VertexInput) -> VertexOutput {
@@ -77,18 +146,83 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let ambient_strength: f32 = 0.1;
     let ambient: vec3<f32> = ambient_strength * vec3<f32>(1.0, 1.0, 1.0);
 
-    let normal: vec3<f32> = normalize(in.normal);
+    let albedo: vec3<f32> = textureSample(t_albedo, s_albedo, in.uv).xyz;
+
+    // Two channel normal maps are common, so always rebuild z from xy.
+    let normal_xy: vec2<f32> = textureSample(t_normal, s_albedo, in.uv).xy * 2.0 - 1.0;
+    let normal_ts: vec3<f32> = vec3<f32>(normal_xy, sqrt(max(1.0 - dot(normal_xy, normal_xy), 0.0)));
+
+    let tbn: mat3x3<f32> = cotangent_frame(normalize(in.normal), in.frag_position, in.uv);
+    let normal: vec3<f32> = normalize(tbn * normal_ts);
+
     let light_dir: vec3<f32> = normalize(camera.inverse_view_matrix[3].xyz - in.frag_position);
     let diff: f32 = max(dot(normal, light_dir), 0.0);
     let diffuse: vec3<f32> = diff * vec3<f32>(1.0, 1.0, 1.0);
 
-    if camera.default_shaded == 1u {
+    if camera.shading == 1u {
         return vec4<f32>((ambient + diffuse) * vec3<f32>(0.603, 0.603, 0.603), 1.0);
+    } else if camera.shading == 2u {
+        return vec4<f32>(normal * 0.5 + 0.5, 1.0);
+    } else if camera.shading == 3u {
+        let view_normal: vec3<f32> = normalize((camera.view_matrix * vec4<f32>(normal, 0.0)).xyz);
+
+        // A clay matcap with a key light from the top left and a soft rim.
+        let key: f32 = max(dot(view_normal, normalize(vec3<f32>(-0.4, 0.6, 0.7))), 0.0);
+        let rim: f32 = pow(1.0 - max(view_normal.z, 0.0), 3.0);
+        let specular: f32 = pow(key, 32.0);
+
+        let clay: vec3<f32> = vec3<f32>(0.72, 0.62, 0.56);
+
+        return vec4<f32>(clay * (0.25 + 0.75 * key) + vec3<f32>(0.35 * rim + 0.25 * specular), 1.0);
     } else {
-        return vec4<f32>((ambient + diffuse) * textureSample(t_albedo, s_albedo, in.uv).xyz, 1.0);
+        return vec4<f32>((ambient + diffuse) * albedo, 1.0);
     }
 }
 
+@fragment
+fn fs_pbr_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let base_color: vec3<f32> = pow(textureSample(t_albedo, s_albedo, in.uv).xyz, vec3<f32>(2.2));
+    let occlusion: f32 = textureSample(t_occlusion, s_albedo, in.uv).x;
+    let metalness: f32 = textureSample(t_metalness, s_albedo, in.uv).x;
+
+    var roughness: f32 = textureSample(t_roughness, s_albedo, in.uv).x;
+
+    if material.gloss == 1u {
+        roughness = 1.0 - roughness;
+    }
+
+    roughness = clamp(roughness, 0.04, 1.0);
+
+    let normal_xy: vec2<f32> = textureSample(t_normal, s_albedo, in.uv).xy * 2.0 - 1.0;
+    let normal_ts: vec3<f32> = vec3<f32>(normal_xy, sqrt(max(1.0 - dot(normal_xy, normal_xy), 0.0)));
+
+    let tbn: mat3x3<f32> = cotangent_frame(normalize(in.normal), in.frag_position, in.uv);
+    let normal: vec3<f32> = normalize(tbn * normal_ts);
+
+    let view: vec3<f32> = normalize(camera.inverse_view_matrix[3].xyz - in.frag_position);
+    let reflection: vec3<f32> = reflect(-view, normal);
+    let n_dot_v: f32 = clamp(dot(normal, view), 0.0, 1.0);
+
+    let f0: vec3<f32> = mix(vec3<f32>(0.04), base_color, metalness);
+    let fresnel: vec3<f32> = f0 + (max(vec3<f32>(1.0 - roughness), f0) - f0) * pow(1.0 - n_dot_v, 5.0);
+
+    let irradiance: vec3<f32> = sample_environment(normal, f32(ENVIRONMENT_LEVELS));
+    let diffuse: vec3<f32> = (1.0 - fresnel) * (1.0 - metalness) * irradiance * base_color;
+
+    let prefiltered: vec3<f32> = sample_environment(reflection, roughness * f32(ENVIRONMENT_LEVELS - 1));
+    let brdf: vec2<f32> = textureSampleLevel(t_brdf, s_environment, vec2<f32>(n_dot_v, roughness), 0.0).xy;
+    let specular: vec3<f32> = prefiltered * (fresnel * brdf.x + brdf.y);
+
+    let color: vec3<f32> = (diffuse + specular) * occlusion;
+
+    return vec4<f32>(pow(color / (color + 1.0), vec3<f32>(1.0 / 2.2)), 1.0);
+}
+
+@fragment
+fn fs_wireframe_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return vec4<f32>(1.0, 1.0, 1.0, 0.45);
+}
+
 @vertex
 fn vs_grid_main(in: GridInput) -> GridOutput {
     let vp: mat4x4<f32> = camera.projection_matrix * camera.view_matrix;
@@ -136,5 +270,15 @@ fn vs_image_main(in: ImageInput) -> ImageOutput {
 
 @fragment
 fn fs_image_main(in: ImageOutput) -> @location(0) vec4<f32> {
-    return textureSample(t_albedo, s_albedo, in.uv);
+    let sample: vec4<f32> = textureSample(t_albedo, s_albedo, in.uv);
+    let color: vec3<f32> = max(sample.xyz * image.exposure, vec3<f32>(0.0));
+
+    if image.tone_mapping == 1u {
+        return vec4<f32>(color / (color + 1.0), sample.w);
+    } else if image.tone_mapping == 2u {
+        // Narkowicz's fit of the aces filmic curve.
+        return vec4<f32>(saturate((color * (2.51 * color + 0.03)) / (color * (2.43 * color + 0.59) + 0.14)), sample.w);
+    } else {
+        return vec4<f32>(color, sample.w);
+    }
 }
\ No newline at end of file
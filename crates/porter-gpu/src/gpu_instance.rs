@@ -1,21 +1,43 @@
 use std::fmt::Debug;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::OnceLock;
 
+use wgpu::Adapter;
+use wgpu::AdapterInfo;
 use wgpu::Backends;
 use wgpu::Device;
 use wgpu::DeviceDescriptor;
 use wgpu::Features;
 use wgpu::Instance;
 use wgpu::InstanceDescriptor;
+use wgpu::Limits;
 use wgpu::PowerPreference;
 use wgpu::Queue;
 use wgpu::RequestAdapterOptionsBase;
 use wgpu::ShaderModule;
+use wgpu::TextureFormat;
+use wgpu::TextureUsages;
+
+/// The backends used to find an adapter.
+const GPU_BACKENDS: Backends = Backends::all().difference(Backends::GL);
+
+/// The features required from an adapter.
+const GPU_REQUIRED_FEATURES: Features = Features::TEXTURE_COMPRESSION_BC
+    .union(Features::TEXTURE_FORMAT_16BIT_NORM)
+    .union(Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES)
+    .union(Features::POLYGON_MODE_LINE)
+    .union(Features::FLOAT32_FILTERABLE);
 
 /// Stores an active GPU device, queue, and compiled shaders.
 pub struct GPUInstance {
     instance: Instance,
+    adapter: Adapter,
+    adapter_info: AdapterInfo,
     device: Device,
+    device_lost: Arc<AtomicBool>,
     queue: Queue,
     gpu_converter_shader: ShaderModule,
     gpu_preview_shader: ShaderModule,
@@ -25,20 +47,69 @@ impl GPUInstance {
     /// Creates a new instance of the GPU instance.
     pub fn new(
         instance: Instance,
+        adapter: Adapter,
         device: Device,
         queue: Queue,
         gpu_converter_shader: ShaderModule,
         gpu_preview_shader: ShaderModule,
     ) -> Self {
+        let device_lost = Arc::new(AtomicBool::new(false));
+        let device_lost_callback = device_lost.clone();
+
+        device.set_device_lost_callback(move |reason, message| {
+            log::error!("Gpu device was lost: {:?} {}", reason, message);
+
+            device_lost_callback.store(true, Ordering::Release);
+        });
+
         Self {
             instance,
+            adapter_info: adapter.get_info(),
+            adapter,
             device,
+            device_lost,
             queue,
             gpu_converter_shader,
             gpu_preview_shader,
         }
     }
 
+    /// Returns the adapter information for the device.
+    pub fn adapter_info(&self) -> &AdapterInfo {
+        &self.adapter_info
+    }
+
+    /// Returns the features supported by the adapter.
+    pub fn adapter_features(&self) -> Features {
+        self.adapter.features()
+    }
+
+    /// Returns the limits of the adapter.
+    pub fn adapter_limits(&self) -> Limits {
+        self.adapter.limits()
+    }
+
+    /// Whether or not the adapter supports the given usages of a texture format.
+    pub fn supports_format(&self, format: TextureFormat, usages: TextureUsages) -> bool {
+        if !format
+            .required_features()
+            .difference(GPU_REQUIRED_FEATURES)
+            .is_empty()
+        {
+            return false;
+        }
+
+        self.adapter
+            .get_texture_format_features(format)
+            .allowed_usages
+            .contains(usages)
+    }
+
+    /// Whether or not the device was lost, after which it can no longer be used.
+    pub fn is_lost(&self) -> bool {
+        self.device_lost.load(Ordering::Acquire)
+    }
+
     /// Returns the device.
     pub fn device(&self) -> &Device {
         &self.device
@@ -66,32 +137,70 @@ impl GPUInstance {
 }
 
 /// Async initialization routine required for `wgpu`.
-async fn initialize() -> GPUInstance {
+async fn initialize() -> Option<GPUInstance> {
     let instance = Instance::new(InstanceDescriptor {
-        backends: Backends::all() & !Backends::GL,
+        backends: GPU_BACKENDS,
         ..Default::default()
     });
 
-    let adapter = instance
-        .request_adapter(&RequestAdapterOptionsBase {
-            power_preference: PowerPreference::HighPerformance,
-            force_fallback_adapter: false,
-            compatible_surface: None,
-        })
-        .await
-        .unwrap();
+    let selected = GPU_ADAPTER
+        .lock()
+        .ok()
+        .and_then(|adapter| adapter.clone())
+        .and_then(|name| {
+            let adapter = instance
+                .enumerate_adapters(GPU_BACKENDS)
+                .into_iter()
+                .find(|adapter| {
+                    adapter.get_info().name == name
+                        && adapter.features().contains(GPU_REQUIRED_FEATURES)
+                });
+
+            if adapter.is_none() {
+                log::warn!("Gpu adapter '{}' is not available, using the default", name);
+            }
+
+            adapter
+        });
+
+    let adapter = match selected {
+        Some(adapter) => adapter,
+        None => {
+            let Some(adapter) = instance
+                .request_adapter(&RequestAdapterOptionsBase {
+                    power_preference: PowerPreference::HighPerformance,
+                    force_fallback_adapter: false,
+                    compatible_surface: None,
+                })
+                .await
+            else {
+                log::warn!("No gpu adapter is available");
+                return None;
+            };
+
+            adapter
+        }
+    };
 
     let descriptor = DeviceDescriptor {
-        required_features: Features::TEXTURE_COMPRESSION_BC
-            | Features::TEXTURE_FORMAT_16BIT_NORM
-            | Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
-            | Features::POLYGON_MODE_LINE
-            | Features::FLOAT32_FILTERABLE,
+        required_features: GPU_REQUIRED_FEATURES,
         required_limits: adapter.limits(),
         ..Default::default()
     };
 
-    let (device, queue) = adapter.request_device(&descriptor, None).await.unwrap();
+    let (device, queue) = match adapter.request_device(&descriptor, None).await {
+        Ok(result) => result,
+        Err(error) => {
+            log::error!("Failed to create the gpu device: {}", error);
+            return None;
+        }
+    };
+
+    log::info!(
+        "Using gpu adapter '{}' ({:?})",
+        adapter.get_info().name,
+        adapter.get_info().backend
+    );
 
     let gpu_converter_shader =
         device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_converter.wgsl"));
@@ -99,19 +208,60 @@ async fn initialize() -> GPUInstance {
     let gpu_preview_shader =
         device.create_shader_module(wgpu::include_wgsl!("../shaders/gpu_preview.wgsl"));
 
-    GPUInstance::new(
+    Some(GPUInstance::new(
         instance,
+        adapter,
         device,
         queue,
         gpu_converter_shader,
         gpu_preview_shader,
-    )
+    ))
 }
 
+/// The name of the adapter to use when initializing, or the default adapter.
+static GPU_ADAPTER: Mutex<Option<String>> = Mutex::new(None);
+
 /// Global GPU instance, device, queue, and shaders.
-static GPU_INSTANCE: OnceLock<GPUInstance> = OnceLock::new();
+static GPU_INSTANCE: OnceLock<Option<GPUInstance>> = OnceLock::new();
+
+/// Lists the adapters on the system that have the features we require.
+pub fn gpu_adapters() -> Vec<AdapterInfo> {
+    let instance = Instance::new(InstanceDescriptor {
+        backends: GPU_BACKENDS,
+        ..Default::default()
+    });
+
+    instance
+        .enumerate_adapters(GPU_BACKENDS)
+        .into_iter()
+        .filter(|adapter| adapter.features().contains(GPU_REQUIRED_FEATURES))
+        .map(|adapter| adapter.get_info())
+        .collect()
+}
+
+/// Sets the name of the adapter to use, or `None` for the default adapter.
+///
+/// This must be called before the instance is initialized, returns `false` when it's too late.
+pub fn set_gpu_adapter(name: Option<String>) -> bool {
+    if GPU_INSTANCE.get().is_some() {
+        return false;
+    }
+
+    if let Ok(mut adapter) = GPU_ADAPTER.lock() {
+        *adapter = name;
+    }
+
+    true
+}
+
+/// Gets or initializes the current GPU instance, or `None` when no adapter is available.
+pub fn try_gpu_instance() -> Option<&'static GPUInstance> {
+    GPU_INSTANCE
+        .get_or_init(|| pollster::block_on(initialize()))
+        .as_ref()
+}
 
 /// Gets or initializes the current GPU instance.
 pub fn gpu_instance() -> &'static GPUInstance {
-    GPU_INSTANCE.get_or_init(|| pollster::block_on(initialize()))
+    try_gpu_instance().expect("No compatible gpu adapter was found!")
 }
@@ -5,6 +5,7 @@ use static_assertions::assert_eq_size;
 
 use crate::degrees_to_radians;
 use crate::Angles;
+use crate::Axis;
 use crate::Matrix3x3;
 use crate::Matrix4x4;
 use crate::Vector3;
@@ -74,6 +75,46 @@ impl Quaternion {
         normalize
     }
 
+    /// Calculates the dot product of the two quaternions.
+    /// `(x * rhs.x) + (y * rhs.y) + (z * rhs.z) + (w * rhs.w)`
+    #[inline]
+    pub fn dot(&self, rhs: Self) -> f32 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z) + (self.w * rhs.w)
+    }
+
+    /// Spherically interpolates between two rotations with the given time, taking the shortest path.
+    #[inline]
+    pub fn slerp(&self, rhs: Self, time: f32) -> Self {
+        let mut cos_theta = self.dot(rhs);
+        let mut rhs = rhs;
+
+        if cos_theta < 0.0 {
+            rhs = -rhs;
+            cos_theta = -cos_theta;
+        }
+
+        // Nearly identical rotations are linearly interpolated to avoid dividing by zero.
+        let (scale_lhs, scale_rhs) = if cos_theta > 0.9995 {
+            (1.0 - time, time)
+        } else {
+            let theta = cos_theta.acos();
+            let sin_theta = theta.sin();
+
+            (
+                ((1.0 - time) * theta).sin() / sin_theta,
+                (time * theta).sin() / sin_theta,
+            )
+        };
+
+        Self::new(
+            self.x * scale_lhs + rhs.x * scale_rhs,
+            self.y * scale_lhs + rhs.y * scale_rhs,
+            self.z * scale_lhs + rhs.z * scale_rhs,
+            self.w * scale_lhs + rhs.w * scale_rhs,
+        )
+        .normalized()
+    }
+
     /// Calculates the inverse of this quaternion.
     #[inline]
     pub fn inverse(&self) -> Self {
@@ -88,6 +129,16 @@ impl Quaternion {
         }
     }
 
+    /// Returns a rotation mirrored across the plane perpendicular to the given axis.
+    #[inline]
+    pub fn mirrored(self, axis: Axis) -> Self {
+        match axis {
+            Axis::X => Self::new(self.x, -self.y, -self.z, self.w),
+            Axis::Y => Self::new(-self.x, self.y, -self.z, self.w),
+            Axis::Z => Self::new(-self.x, -self.y, self.z, self.w),
+        }
+    }
+
     /// Reverses the byte order of the quaternion.
     #[inline]
     pub fn swap_bytes(self) -> Self {
@@ -3,6 +3,7 @@ use std::ops;
 
 use static_assertions::assert_eq_size;
 
+use crate::Axis;
 use crate::Matrix4x4;
 use crate::Vector4;
 
@@ -205,6 +206,16 @@ impl Vector3 {
             z: if self.z.is_nan() { 0.0 } else { self.z },
         }
     }
+
+    /// Returns a vector mirrored across the plane perpendicular to the given axis.
+    #[inline]
+    pub fn mirrored(self, axis: Axis) -> Self {
+        match axis {
+            Axis::X => Self::new(-self.x, self.y, self.z),
+            Axis::Y => Self::new(self.x, -self.y, self.z),
+            Axis::Z => Self::new(self.x, self.y, -self.z),
+        }
+    }
 }
 
 impl cmp::PartialEq for Vector3 {
@@ -1,7 +1,11 @@
+use crate::ModelDiagnostic;
+
 /// Errors that can occur in the model crate.
 #[derive(Debug)]
 pub enum ModelError {
     IoError(std::io::Error),
+    ValidationFailed(Vec<ModelDiagnostic>),
+    TooManyBones,
 }
 
 impl From<std::io::Error> for ModelError {
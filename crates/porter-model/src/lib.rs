@@ -7,9 +7,16 @@ mod face_buffer;
 mod ik_handle;
 mod material;
 mod material_remap;
+mod material_texture_classifier;
 mod mesh;
 mod model;
+mod model_conversion;
+mod model_diagnostic;
 mod model_file_type;
+mod model_units;
+mod qc_file;
+mod scene;
+mod scene_instance;
 mod skeleton;
 mod skinning_method;
 mod vertex;
@@ -26,9 +33,16 @@ pub use face_buffer::*;
 pub use ik_handle::*;
 pub use material::*;
 pub use material_remap::*;
+pub use material_texture_classifier::*;
 pub use mesh::*;
 pub use model::*;
+pub use model_conversion::*;
+pub use model_diagnostic::*;
 pub use model_file_type::*;
+pub use model_units::*;
+pub use qc_file::*;
+pub use scene::*;
+pub use scene_instance::*;
 pub use skeleton::*;
 pub use skinning_method::*;
 pub use vertex::*;
@@ -43,3 +57,4 @@ pub(crate) mod model_file_type_obj;
 pub(crate) mod model_file_type_smd;
 pub(crate) mod model_file_type_xmodel_export;
 pub(crate) mod model_file_type_xna_lara;
+pub(crate) mod scene_file_type_cast;
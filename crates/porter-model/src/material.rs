@@ -1,8 +1,15 @@
 use std::collections::HashSet;
 use std::fmt;
 
+use porter_math::Vector2;
+use porter_math::Vector3;
+use porter_math::Vector4;
+
+use porter_utils::NameDatabase;
 use porter_utils::SanitizeFilename;
 
+use crate::classify_texture_name;
+
 /// A material texture usage.
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -35,10 +42,22 @@ pub struct MaterialTextureRef {
     pub texture_hash: u64,
 }
 
+/// How a material is blended with what's behind it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaterialBlendMode {
+    #[default]
+    Opaque,
+    Masked,
+    Translucent,
+    Additive,
+}
+
 /// The parameter type.
 #[derive(Debug, Clone, PartialEq)]
 pub enum MaterialParameterType {
     BaseColor,
+    TwoSided,
+    BlendMode,
     Custom(String),
 }
 
@@ -58,6 +77,18 @@ pub enum MaterialParameterValue {
     String(String),
     /// A RGBA 32bit float color value.
     Color { r: f32, g: f32, b: f32, a: f32 },
+    /// A scalar value.
+    Float(f32),
+    /// A boolean flag value.
+    Bool(bool),
+    /// A 2d vector value.
+    Vector2(Vector2),
+    /// A 3d vector value.
+    Vector3(Vector3),
+    /// A 4d vector value.
+    Vector4(Vector4),
+    /// A blend mode value.
+    BlendMode(MaterialBlendMode),
 }
 
 /// A material which has a name, and is a collection of textures.
@@ -124,6 +155,50 @@ impl Material {
         });
     }
 
+    /// Sets a parameter on the material, replacing any existing parameter of the same type.
+    pub fn set_parameter<N: Into<MaterialParameterType>, P: Into<MaterialParameterValue>>(
+        &mut self,
+        param: N,
+        value: P,
+    ) {
+        let param = param.into();
+        let value = value.into();
+
+        match self.parameters.iter_mut().find(|x| x.param == param) {
+            Some(parameter) => parameter.value = value,
+            None => self.parameters.push(MaterialParameter { param, value }),
+        }
+    }
+
+    /// Finds the value of the given parameter in this material.
+    pub fn parameter<N: Into<MaterialParameterType>>(
+        &self,
+        param: N,
+    ) -> Option<&MaterialParameterValue> {
+        let param = param.into();
+
+        self.parameters
+            .iter()
+            .find(|x| x.param == param)
+            .map(|x| &x.value)
+    }
+
+    /// Whether or not both sides of each face are rendered.
+    pub fn two_sided(&self) -> bool {
+        matches!(
+            self.parameter(MaterialParameterType::TwoSided),
+            Some(MaterialParameterValue::Bool(true))
+        )
+    }
+
+    /// The blend mode of this material, opaque if it wasn't set.
+    pub fn blend_mode(&self) -> MaterialBlendMode {
+        match self.parameter(MaterialParameterType::BlendMode) {
+            Some(MaterialParameterValue::BlendMode(blend_mode)) => *blend_mode,
+            _ => MaterialBlendMode::Opaque,
+        }
+    }
+
     /// Removes the texture at the given index.
     pub fn remove(&mut self, index: usize) -> MaterialTextureRef {
         self.textures.remove(index)
@@ -149,6 +224,34 @@ impl Material {
             .collect::<HashSet<MaterialTextureRef>>()
     }
 
+    /// Infers the usage of textures with an unknown usage from their names.
+    pub fn classify_textures(&mut self) {
+        for texture in &mut self.textures {
+            if texture.texture_usage != MaterialTextureRefUsage::Unknown {
+                continue;
+            }
+
+            texture.texture_usage = match classify_texture_name(&texture.file_name) {
+                MaterialTextureRefUsage::Unknown => classify_texture_name(&texture.texture_alias),
+                usage => usage,
+            };
+        }
+    }
+
+    /// Rewrites texture file names which are only a hash to their names in the database, returns the number of textures renamed.
+    pub fn resolve_texture_names(&mut self, names: &NameDatabase) -> usize {
+        let mut resolved = 0;
+
+        for texture in &mut self.textures {
+            if let Some(file_name) = names.resolve_file_name(&texture.file_name) {
+                texture.file_name = file_name;
+                resolved += 1;
+            }
+        }
+
+        resolved
+    }
+
     /// Attempts to find the 'base' color parameter in this material.
     pub fn base_color(&self) -> Option<(f32, f32, f32, f32)> {
         self.parameters
@@ -245,6 +348,29 @@ impl fmt::Display for MaterialTextureRefUsage {
     }
 }
 
+impl MaterialParameterType {
+    /// The name of this parameter when written to a file.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BaseColor => "base_color",
+            Self::TwoSided => "two_sided",
+            Self::BlendMode => "blend_mode",
+            Self::Custom(name) => name,
+        }
+    }
+}
+
+impl fmt::Display for MaterialBlendMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaterialBlendMode::Opaque => write!(f, "opaque"),
+            MaterialBlendMode::Masked => write!(f, "masked"),
+            MaterialBlendMode::Translucent => write!(f, "translucent"),
+            MaterialBlendMode::Additive => write!(f, "additive"),
+        }
+    }
+}
+
 impl From<String> for MaterialParameterType {
     fn from(value: String) -> Self {
         Self::Custom(value)
@@ -279,3 +405,39 @@ impl From<(f32, f32, f32, f32)> for MaterialParameterValue {
         }
     }
 }
+
+impl From<f32> for MaterialParameterValue {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<bool> for MaterialParameterValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Vector2> for MaterialParameterValue {
+    fn from(value: Vector2) -> Self {
+        Self::Vector2(value)
+    }
+}
+
+impl From<Vector3> for MaterialParameterValue {
+    fn from(value: Vector3) -> Self {
+        Self::Vector3(value)
+    }
+}
+
+impl From<Vector4> for MaterialParameterValue {
+    fn from(value: Vector4) -> Self {
+        Self::Vector4(value)
+    }
+}
+
+impl From<MaterialBlendMode> for MaterialParameterValue {
+    fn from(value: MaterialBlendMode) -> Self {
+        Self::BlendMode(value)
+    }
+}
@@ -0,0 +1,205 @@
+use std::path::Path;
+
+use crate::MaterialTextureRefUsage;
+
+/// Name tokens which map to a texture usage, any token can match.
+const TEXTURE_NAME_TOKENS: &[(&str, MaterialTextureRefUsage)] = &[
+    ("albedo", MaterialTextureRefUsage::Albedo),
+    ("basecolor", MaterialTextureRefUsage::Albedo),
+    ("basecolour", MaterialTextureRefUsage::Albedo),
+    ("alb", MaterialTextureRefUsage::Albedo),
+    ("bc", MaterialTextureRefUsage::Albedo),
+    ("col", MaterialTextureRefUsage::Albedo),
+    ("color", MaterialTextureRefUsage::Albedo),
+    ("colour", MaterialTextureRefUsage::Albedo),
+    ("diffuse", MaterialTextureRefUsage::Diffuse),
+    ("diff", MaterialTextureRefUsage::Diffuse),
+    ("dif", MaterialTextureRefUsage::Diffuse),
+    ("normal", MaterialTextureRefUsage::Normal),
+    ("normalmap", MaterialTextureRefUsage::Normal),
+    ("norm", MaterialTextureRefUsage::Normal),
+    ("nrm", MaterialTextureRefUsage::Normal),
+    ("nml", MaterialTextureRefUsage::Normal),
+    ("nm", MaterialTextureRefUsage::Normal),
+    ("ddn", MaterialTextureRefUsage::Normal),
+    ("specular", MaterialTextureRefUsage::Specular),
+    ("spec", MaterialTextureRefUsage::Specular),
+    ("spc", MaterialTextureRefUsage::Specular),
+    ("gloss", MaterialTextureRefUsage::Gloss),
+    ("glossiness", MaterialTextureRefUsage::Gloss),
+    ("gls", MaterialTextureRefUsage::Gloss),
+    ("roughness", MaterialTextureRefUsage::Roughness),
+    ("rough", MaterialTextureRefUsage::Roughness),
+    ("rgh", MaterialTextureRefUsage::Roughness),
+    ("ao", MaterialTextureRefUsage::AmbientOcclusion),
+    ("occlusion", MaterialTextureRefUsage::AmbientOcclusion),
+    (
+        "ambientocclusion",
+        MaterialTextureRefUsage::AmbientOcclusion,
+    ),
+    ("occ", MaterialTextureRefUsage::AmbientOcclusion),
+    ("metalness", MaterialTextureRefUsage::Metalness),
+    ("metallic", MaterialTextureRefUsage::Metalness),
+    ("metal", MaterialTextureRefUsage::Metalness),
+    ("met", MaterialTextureRefUsage::Metalness),
+    ("emissive", MaterialTextureRefUsage::Emissive),
+    ("emission", MaterialTextureRefUsage::Emissive),
+    ("emit", MaterialTextureRefUsage::Emissive),
+    ("emi", MaterialTextureRefUsage::Emissive),
+    ("glow", MaterialTextureRefUsage::Emissive),
+    ("illum", MaterialTextureRefUsage::Emissive),
+    ("cavity", MaterialTextureRefUsage::Cavity),
+    ("cav", MaterialTextureRefUsage::Cavity),
+    ("anisotropy", MaterialTextureRefUsage::Anisotropy),
+    ("aniso", MaterialTextureRefUsage::Anisotropy),
+];
+
+/// Single letter suffixes which map to a texture usage, only the last token can match.
+const TEXTURE_NAME_SUFFIXES: &[(&str, MaterialTextureRefUsage)] = &[
+    ("c", MaterialTextureRefUsage::Albedo),
+    ("d", MaterialTextureRefUsage::Diffuse),
+    ("n", MaterialTextureRefUsage::Normal),
+    ("s", MaterialTextureRefUsage::Specular),
+    ("g", MaterialTextureRefUsage::Gloss),
+    ("r", MaterialTextureRefUsage::Roughness),
+    ("m", MaterialTextureRefUsage::Metalness),
+    ("e", MaterialTextureRefUsage::Emissive),
+];
+
+/// Channel statistics of a texture, with values normalized from 0.0 to 1.0.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MaterialTextureStats {
+    /// The mean of each rgba channel.
+    pub mean: [f32; 4],
+    /// The variance of each rgba channel.
+    pub variance: [f32; 4],
+    /// The mean difference between the rgb channels of each pixel, zero for grayscale textures.
+    pub chroma: f32,
+}
+
+impl MaterialTextureStats {
+    /// Computes the channel statistics from a buffer of rgba8 pixels.
+    pub fn from_rgba8(pixels: &[u8]) -> Self {
+        let count = pixels.len() / 4;
+
+        if count == 0 {
+            return Self::default();
+        }
+
+        let mut sum = [0.0f64; 4];
+        let mut sum_squared = [0.0f64; 4];
+        let mut chroma = 0.0f64;
+
+        for pixel in pixels.chunks_exact(4) {
+            for channel in 0..4 {
+                let value = pixel[channel] as f64 / 255.0;
+
+                sum[channel] += value;
+                sum_squared[channel] += value * value;
+            }
+
+            let max = pixel[0].max(pixel[1]).max(pixel[2]);
+            let min = pixel[0].min(pixel[1]).min(pixel[2]);
+
+            chroma += (max - min) as f64 / 255.0;
+        }
+
+        let mut result = Self {
+            chroma: (chroma / count as f64) as f32,
+            ..Default::default()
+        };
+
+        for channel in 0..4 {
+            let mean = sum[channel] / count as f64;
+
+            result.mean[channel] = mean as f32;
+            result.variance[channel] =
+                ((sum_squared[channel] / count as f64) - (mean * mean)).max(0.0) as f32;
+        }
+
+        result
+    }
+}
+
+/// Infers the texture usage from the texture name or path, returns unknown when it can't be inferred.
+pub fn classify_texture_name<N: AsRef<str>>(name: N) -> MaterialTextureRefUsage {
+    let name = name.as_ref();
+    let stem = Path::new(name)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(name)
+        .to_lowercase();
+
+    let tokens: Vec<&str> = stem
+        .split(['_', '-', '.', ' '])
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    if let Some(last) = tokens.last() {
+        if let Some((_, usage)) = TEXTURE_NAME_SUFFIXES
+            .iter()
+            .find(|(suffix, _)| suffix == last)
+        {
+            return *usage;
+        }
+    }
+
+    for token in tokens.iter().rev() {
+        if let Some((_, usage)) = TEXTURE_NAME_TOKENS.iter().find(|(name, _)| name == token) {
+            return *usage;
+        }
+    }
+
+    // Names without separators, such as 'brickwallnormal', can still end with a known token.
+    TEXTURE_NAME_TOKENS
+        .iter()
+        .filter(|(name, _)| name.len() > 3)
+        .find(|(name, _)| stem.ends_with(name))
+        .map(|(_, usage)| *usage)
+        .unwrap_or(MaterialTextureRefUsage::Unknown)
+}
+
+/// Infers the texture usage from the channel statistics of the texture.
+pub fn classify_texture_stats(stats: &MaterialTextureStats) -> MaterialTextureRefUsage {
+    let [r, g, b, _] = stats.mean;
+
+    // Tangent space normal maps center around (0.5, 0.5, 1.0).
+    if (r - 0.5).abs() < 0.1 && (g - 0.5).abs() < 0.1 && b > 0.75 {
+        return MaterialTextureRefUsage::Normal;
+    }
+
+    // Two channel normal maps center around (0.5, 0.5) with an empty blue channel.
+    if (r - 0.5).abs() < 0.1 && (g - 0.5).abs() < 0.1 && b < 0.05 {
+        return MaterialTextureRefUsage::Normal;
+    }
+
+    if stats.chroma > 0.05 {
+        return MaterialTextureRefUsage::Albedo;
+    }
+
+    // Grayscale masks which are mostly black or white are likely metalness.
+    let binary = r * (1.0 - r);
+
+    if binary > 0.01 && stats.variance[0] / binary > 0.8 {
+        return MaterialTextureRefUsage::Metalness;
+    }
+
+    if r > 0.75 {
+        return MaterialTextureRefUsage::AmbientOcclusion;
+    }
+
+    MaterialTextureRefUsage::Roughness
+}
+
+/// Infers the texture usage from the texture name first, then the channel statistics if provided.
+pub fn classify_texture<N: AsRef<str>>(
+    name: N,
+    stats: Option<&MaterialTextureStats>,
+) -> MaterialTextureRefUsage {
+    match classify_texture_name(name) {
+        MaterialTextureRefUsage::Unknown => stats
+            .map(classify_texture_stats)
+            .unwrap_or(MaterialTextureRefUsage::Unknown),
+        usage => usage,
+    }
+}
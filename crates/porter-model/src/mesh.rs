@@ -4,10 +4,14 @@ use porter_math::Matrix4x4;
 use porter_math::Vector3;
 
 use crate::BlendShape;
+use crate::Face;
 use crate::FaceBuffer;
+use crate::ModelDiagnostic;
+use crate::ModelDiagnostics;
 use crate::Skeleton;
 use crate::SkinningMethod;
 use crate::VertexBuffer;
+use crate::VertexWeight;
 use crate::WeightBoneId;
 
 /// A polygon mesh for a model.
@@ -25,6 +29,35 @@ pub struct Mesh {
     pub blend_shapes: Vec<BlendShape>,
     /// The method used to skin this mesh.
     pub skinning_method: SkinningMethod,
+    /// Optional names of the uv layers, by layer index.
+    pub uv_layer_names: Vec<Option<String>>,
+    /// Optional names of the vertex color layers, by layer index.
+    pub color_layer_names: Vec<Option<String>>,
+}
+
+/// Cleans a uv or color layer name, so it's a valid set name in every format.
+fn sanitize_layer_name(name: String) -> String {
+    let mut name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.as_bytes().first().is_some_and(|c| c.is_ascii_digit()) {
+        name = format!("_{}", name);
+    }
+
+    name
+}
+
+/// Sets the name of a layer, growing the names as needed.
+fn set_layer_name<S: Into<String>>(names: &mut Vec<Option<String>>, index: usize, name: Option<S>) {
+    if names.len() <= index {
+        names.resize(index + 1, None);
+    }
+
+    names[index] = name
+        .map(|name| sanitize_layer_name(name.into()))
+        .filter(|name| !name.is_empty());
 }
 
 impl Mesh {
@@ -37,6 +70,8 @@ impl Mesh {
             vertices,
             blend_shapes: Vec::new(),
             skinning_method: SkinningMethod::Linear,
+            uv_layer_names: Vec::new(),
+            color_layer_names: Vec::new(),
         }
     }
 
@@ -53,6 +88,8 @@ impl Mesh {
             vertices,
             blend_shapes: Vec::new(),
             skinning_method,
+            uv_layer_names: Vec::new(),
+            color_layer_names: Vec::new(),
         }
     }
 
@@ -62,6 +99,26 @@ impl Mesh {
         self
     }
 
+    /// Sets the name of the uv layer at the given index, such as `lightmap`.
+    pub fn set_uv_layer_name<S: Into<String>>(&mut self, index: usize, name: Option<S>) {
+        set_layer_name(&mut self.uv_layer_names, index, name);
+    }
+
+    /// Returns the name of the uv layer at the given index, if it has one.
+    pub fn uv_layer_name(&self, index: usize) -> Option<&str> {
+        self.uv_layer_names.get(index)?.as_deref()
+    }
+
+    /// Sets the name of the vertex color layer at the given index.
+    pub fn set_color_layer_name<S: Into<String>>(&mut self, index: usize, name: Option<S>) {
+        set_layer_name(&mut self.color_layer_names, index, name);
+    }
+
+    /// Returns the name of the vertex color layer at the given index, if it has one.
+    pub fn color_layer_name(&self, index: usize) -> Option<&str> {
+        self.color_layer_names.get(index)?.as_deref()
+    }
+
     /// Scales the mesh by the given factor.
     pub fn scale(&mut self, factor: f32) {
         for i in 0..self.vertices.len() {
@@ -135,20 +192,147 @@ impl Mesh {
         }
     }
 
-    /// Validates the mesh has some form of valid data.
-    #[cfg(debug_assertions)]
-    pub fn validate(&self, bone_count: usize) {
+    /// Creates a new mesh from the given faces of this mesh, keeping only the vertices they use.
+    pub(crate) fn extract_faces(&self, faces: &[usize]) -> Mesh {
+        let mut mesh = Mesh::with_skinning_method(
+            FaceBuffer::with_capacity(faces.len()),
+            VertexBuffer::builder()
+                .colors(self.vertices.colors())
+                .uv_layers(self.vertices.uv_layers())
+                .maximum_influence(self.vertices.maximum_influence())
+                .build(),
+            self.skinning_method,
+        )
+        .name(self.name.clone());
+
+        mesh.material = self.material;
+        mesh.uv_layer_names = self.uv_layer_names.clone();
+        mesh.color_layer_names = self.color_layer_names.clone();
+
+        let mut blend_shapes: Vec<BlendShape> = self
+            .blend_shapes
+            .iter()
+            .map(|x| BlendShape::new(x.name.clone()).target_scale(x.target_scale))
+            .collect();
+
+        let mut vertex_remap: BTreeMap<u32, u32> = BTreeMap::new();
+
+        let mut remap_index = |index: u32| {
+            if let Some(vertex) = vertex_remap.get(&index) {
+                return *vertex;
+            }
+
+            let vertex = mesh.vertices.len() as u32;
+
+            vertex_remap.insert(index, vertex);
+
+            mesh.vertices
+                .create()
+                .copy_from(&self.vertices.vertex(index as usize));
+
+            for (blend_shape, new_shape) in self.blend_shapes.iter().zip(&mut blend_shapes) {
+                if let Some(delta) = blend_shape.vertex_deltas.get(&index) {
+                    new_shape.vertex_deltas.insert(vertex, *delta);
+                }
+            }
+
+            vertex
+        };
+
+        for face in faces {
+            let face = &self.faces[*face];
+
+            let i1 = remap_index(face.i1);
+            let i2 = remap_index(face.i2);
+            let i3 = remap_index(face.i3);
+
+            mesh.faces.push(Face::new(i1, i2, i3));
+        }
+
+        mesh.blend_shapes = blend_shapes
+            .into_iter()
+            .filter(|x| !x.vertex_deltas.is_empty())
+            .collect();
+
+        mesh
+    }
+
+    /// Normalizes the weights of each vertex so they add up to 1.0, ordered by most influence first.
+    pub fn normalize_weights(&mut self) {
+        self.limit_weights(self.vertices.maximum_influence());
+    }
+
+    /// Keeps only the given number of most influential weights per vertex, then normalizes them.
+    pub fn prune_weights(&mut self, maximum_influence: usize) {
+        let maximum_influence = maximum_influence.min(self.vertices.maximum_influence());
+
+        self.limit_weights(maximum_influence);
+        self.vertices.set_maximum_influence(maximum_influence);
+    }
+
+    /// Sorts, merges, and normalizes the weights of each vertex, keeping at most `limit` weights.
+    fn limit_weights(&mut self, limit: usize) {
+        let maximum_influence = self.vertices.maximum_influence();
+
+        if maximum_influence == 0 {
+            return;
+        }
+
+        for v in 0..self.vertices.len() {
+            let mut vertex = self.vertices.vertex_mut(v);
+
+            let mut weights: Vec<(WeightBoneId, f32)> = vertex
+                .unique_weights()
+                .into_iter()
+                .filter(|(_, value)| *value > 0.0)
+                .collect();
+
+            // Sorting is stable, so ties keep the lowest bone index first.
+            weights.sort_by(|a, b| b.1.total_cmp(&a.1));
+            weights.truncate(limit);
+
+            let total: f32 = weights.iter().map(|(_, value)| value).sum();
+
+            for w in 0..maximum_influence {
+                let weight = match weights.get(w) {
+                    Some((bone, value)) => VertexWeight::new(*bone, *value / total),
+                    None => VertexWeight::new(0, 0.0),
+                };
+
+                vertex.set_weight(w, weight);
+            }
+        }
+    }
+
+    /// Validates the mesh has some form of valid data, adding any problems found to the diagnostics.
+    pub(crate) fn validate(
+        &self,
+        mesh: usize,
+        bone_count: usize,
+        material_count: usize,
+        diagnostics: &mut ModelDiagnostics,
+    ) {
+        if let Some(material) = self.material.filter(|x| *x >= material_count) {
+            diagnostics.push(ModelDiagnostic::InvalidMaterial { mesh, material });
+        }
+
         for v in 0..self.vertices.len() {
             let vertex = self.vertices.vertex(v);
 
-            let normal = vertex.normal();
-            let length_squared = normal.length_squared();
+            let position = vertex.position();
+
+            if !(position.x.is_finite() && position.y.is_finite() && position.z.is_finite()) {
+                diagnostics.push(ModelDiagnostic::InvalidPosition { mesh, vertex: v });
+            }
+
+            let length_squared = vertex.normal().length_squared();
 
             if !(0.98..=1.025).contains(&length_squared) {
-                println!(
-                    "Validation Error: Found normal with non-1.0 square sum: {} - {}, {}, {} [{}]",
-                    length_squared, normal.x, normal.y, normal.z, v
-                );
+                diagnostics.push(ModelDiagnostic::UnnormalizedNormal {
+                    mesh,
+                    vertex: v,
+                    length_squared,
+                });
             }
 
             let mut total = 0.0;
@@ -156,73 +340,45 @@ impl Mesh {
             for w in 0..self.vertices.maximum_influence() {
                 let weight = vertex.weight(w);
 
-                if weight.bone >= bone_count as WeightBoneId {
-                    println!(
-                        "Validation Error: Found weight bone outside of skeleton: {} [{}:{}]",
-                        { weight.bone },
-                        v,
-                        w
-                    );
+                // Unused influences are zero weights to the first bone, which may not exist in an unskinned model.
+                if weight.value != 0.0 && weight.bone as usize >= bone_count {
+                    diagnostics.push(ModelDiagnostic::InvalidWeightBone {
+                        mesh,
+                        vertex: v,
+                        bone: weight.bone as usize,
+                    });
                 }
 
                 total += weight.value;
             }
 
             if self.vertices.maximum_influence() > 0 && !(0.9825..=1.125).contains(&total) {
-                println!(
-                    "Validation Error: Found weight doesn't add up to 1.0: {} [{}]",
-                    total, v
-                );
+                diagnostics.push(ModelDiagnostic::UnnormalizedWeights {
+                    mesh,
+                    vertex: v,
+                    total,
+                });
             }
         }
 
         let vertex_count = self.vertices.len() as u32;
 
         for (index, face) in self.faces.iter().enumerate() {
-            if face.i1 < vertex_count && face.i2 < vertex_count && face.i3 < vertex_count {
-                continue;
+            if face.i1 >= vertex_count || face.i2 >= vertex_count || face.i3 >= vertex_count {
+                diagnostics.push(ModelDiagnostic::InvalidFaceIndex { mesh, face: index });
+            } else if face.i1 == face.i2 || face.i1 == face.i3 || face.i2 == face.i3 {
+                diagnostics.push(ModelDiagnostic::DegenerateFace { mesh, face: index });
             }
-
-            println!(
-                "Validate Error: Found face with invalid indexes: {}, {}, {} [{}]",
-                { face.i1 },
-                { face.i2 },
-                { face.i3 },
-                index
-            );
         }
 
-        for (index, face) in self.faces.iter().enumerate() {
-            let degenerate = face.i1 == face.i2 || face.i1 == face.i3 || face.i2 == face.i3;
-
-            if !degenerate {
-                continue;
-            }
-
-            println!(
-                "Validate Error: Found face with degenerate tris: {}, {}, {} [{}]",
-                { face.i1 },
-                { face.i2 },
-                { face.i3 },
-                index
-            );
-        }
-
-        for blend_shape in self.blend_shapes.iter() {
-            if blend_shape.vertex_deltas.len() > self.vertices.len() {
-                println!(
-                    "Validate Error: Found invalid blend shape too much data: {:?}",
-                    blend_shape.name
-                );
-                continue;
-            }
-
-            for (index, vertex_index) in blend_shape.vertex_deltas.keys().enumerate() {
-                if *vertex_index as usize >= self.vertices.len() {
-                    println!(
-                        "Validate Error: Found invalid blend shape index: {:?} [{}:{}]",
-                        vertex_index, blend_shape.name, index
-                    );
+        for blend_shape in &self.blend_shapes {
+            for vertex_index in blend_shape.vertex_deltas.keys() {
+                if *vertex_index >= vertex_count {
+                    diagnostics.push(ModelDiagnostic::InvalidBlendShapeIndex {
+                        mesh,
+                        blend_shape: blend_shape.name.clone(),
+                        vertex: *vertex_index as usize,
+                    });
                 }
             }
         }
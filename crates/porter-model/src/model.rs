@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 
 use std::path::Path;
@@ -7,6 +8,8 @@ use porter_math::Axis;
 use porter_math::Matrix4x4;
 use porter_math::Vector3;
 
+use porter_utils::NameDatabase;
+
 use crate::model_file_type_cast;
 use crate::model_file_type_fbx;
 use crate::model_file_type_maya;
@@ -14,6 +17,7 @@ use crate::model_file_type_obj;
 use crate::model_file_type_smd;
 use crate::model_file_type_xmodel_export;
 use crate::model_file_type_xna_lara;
+use crate::up_axis_rotation;
 use crate::Aabb;
 use crate::BlendShape;
 use crate::Face;
@@ -23,9 +27,14 @@ use crate::MaterialRemapFaces;
 use crate::MaterialRemapVertices;
 use crate::MaterialTextureRef;
 use crate::Mesh;
+use crate::ModelConversion;
+use crate::ModelDiagnostic;
+use crate::ModelDiagnostics;
 use crate::ModelError;
 use crate::ModelFileType;
+use crate::ModelUnits;
 use crate::Skeleton;
+use crate::SkinningMethod;
 use crate::VertexBuffer;
 use crate::WeightBoneId;
 
@@ -40,6 +49,10 @@ pub struct Model {
     pub materials: Vec<Material>,
     /// The up axis for this model.
     pub up_axis: Axis,
+    /// The units of measurement for this model.
+    pub units: ModelUnits,
+    /// Custom metadata, such as the source archive or asset guid, written to formats which support it.
+    pub metadata: BTreeMap<String, String>,
 }
 
 impl Model {
@@ -50,6 +63,8 @@ impl Model {
             meshes: Vec::new(),
             materials: Vec::new(),
             up_axis: Axis::Z,
+            units: ModelUnits::Centimeters,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -60,9 +75,16 @@ impl Model {
             meshes: Vec::with_capacity(meshes),
             materials: Vec::new(),
             up_axis: Axis::Z,
+            units: ModelUnits::Centimeters,
+            metadata: BTreeMap::new(),
         }
     }
 
+    /// Sets a custom metadata value, such as the source archive or asset guid.
+    pub fn set_metadata<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.metadata.insert(key.into(), value.into());
+    }
+
     /// Returns the total number of vertices in the model.
     pub fn vertex_count(&self) -> usize {
         self.meshes.iter().map(|x| x.vertices.len()).sum()
@@ -91,6 +113,59 @@ impl Model {
         self.skeleton.transform(matrix);
     }
 
+    /// Mirrors the model across the plane perpendicular to the given axis, used to convert between left and right handed rigs.
+    pub fn mirror(&mut self, axis: Axis) {
+        let matrix = Matrix4x4::create_scale(Vector3::one().mirrored(axis));
+
+        for mesh in &mut self.meshes {
+            mesh.transform(&matrix);
+        }
+
+        self.skeleton.mirror(axis);
+    }
+
+    /// Rotates the model so that the given axis points up.
+    pub fn set_up_axis(&mut self, up_axis: Axis) {
+        if self.up_axis == up_axis {
+            return;
+        }
+
+        let rotation = up_axis_rotation(self.up_axis, up_axis);
+        let matrix = Matrix4x4::create_rotation(rotation);
+
+        for mesh in &mut self.meshes {
+            mesh.transform(&matrix);
+        }
+
+        self.skeleton.rotate(rotation);
+        self.up_axis = up_axis;
+    }
+
+    /// Scales the model so that it's measured in the given units.
+    pub fn set_units(&mut self, units: ModelUnits) {
+        if self.units == units {
+            return;
+        }
+
+        self.scale(self.units.scale_to(units));
+        self.units = units;
+    }
+
+    /// Applies the given conversion to the model, mirroring first, then changing the up axis and units.
+    pub fn convert(&mut self, conversion: &ModelConversion) {
+        if let Some(axis) = conversion.mirror {
+            self.mirror(axis);
+        }
+
+        if let Some(up_axis) = conversion.up_axis {
+            self.set_up_axis(up_axis);
+        }
+
+        if let Some(units) = conversion.units {
+            self.set_units(units);
+        }
+    }
+
     /// Applies a different bind pose to the model meshes.
     pub fn apply_bind_pose(&mut self, inv_bind_poses: &BTreeMap<WeightBoneId, Matrix4x4>) {
         for mesh in &mut self.meshes {
@@ -367,6 +442,201 @@ impl Model {
         }
     }
 
+    /// Merges meshes which share the same material and vertex layout into a single mesh.
+    pub fn merge_meshes_by_material(&mut self) {
+        let old_meshes = std::mem::take(&mut self.meshes);
+
+        let mut merged: HashMap<(Option<usize>, usize, usize, usize, SkinningMethod), usize> =
+            HashMap::with_capacity(self.materials.len());
+
+        for mesh in old_meshes {
+            let key = (
+                mesh.material,
+                mesh.vertices.colors(),
+                mesh.vertices.uv_layers(),
+                mesh.vertices.maximum_influence(),
+                mesh.skinning_method,
+            );
+
+            let Some(index) = merged.get(&key).copied() else {
+                merged.insert(key, self.meshes.len());
+                self.meshes.push(mesh);
+                continue;
+            };
+
+            let target = &mut self.meshes[index];
+            let offset = target.vertices.len() as u32;
+
+            for v in 0..mesh.vertices.len() {
+                target.vertices.create().copy_from(&mesh.vertices.vertex(v));
+            }
+
+            for face in &mesh.faces {
+                target.faces.push(Face::new(
+                    face.i1 + offset,
+                    face.i2 + offset,
+                    face.i3 + offset,
+                ));
+            }
+
+            for blend_shape in mesh.blend_shapes {
+                let shape = match target
+                    .blend_shapes
+                    .iter()
+                    .position(|x| x.name == blend_shape.name)
+                {
+                    Some(shape) => shape,
+                    None => {
+                        target.blend_shapes.push(
+                            BlendShape::new(blend_shape.name.clone())
+                                .target_scale(blend_shape.target_scale),
+                        );
+                        target.blend_shapes.len() - 1
+                    }
+                };
+
+                target.blend_shapes[shape].vertex_deltas.extend(
+                    blend_shape
+                        .vertex_deltas
+                        .into_iter()
+                        .map(|(index, delta)| (index + offset, delta)),
+                );
+            }
+        }
+    }
+
+    /// Splits meshes which use more than the given number of bones, so each mesh fits in a skinning palette.
+    pub fn split_mesh_by_bone_count(&mut self, max_bones: usize) {
+        if max_bones == 0 {
+            return;
+        }
+
+        let old_meshes = std::mem::take(&mut self.meshes);
+
+        for mesh in old_meshes {
+            let vertex_bones = |index: usize| {
+                mesh.vertices
+                    .vertex(index)
+                    .unique_weights()
+                    .into_iter()
+                    .filter(|(_, value)| *value > 0.0)
+                    .map(|(bone, _)| bone)
+            };
+
+            let mut mesh_bones: BTreeSet<WeightBoneId> = BTreeSet::new();
+
+            if mesh.vertices.maximum_influence() > 0 {
+                for v in 0..mesh.vertices.len() {
+                    mesh_bones.extend(vertex_bones(v));
+                }
+            }
+
+            if mesh_bones.len() <= max_bones {
+                self.meshes.push(mesh);
+                continue;
+            }
+
+            let mut chunks: Vec<Vec<usize>> = Vec::new();
+            let mut chunk_faces: Vec<usize> = Vec::new();
+            let mut chunk_bones: BTreeSet<WeightBoneId> = BTreeSet::new();
+
+            for (index, face) in mesh.faces.iter().enumerate() {
+                let mut face_bones: BTreeSet<WeightBoneId> = BTreeSet::new();
+
+                face_bones.extend(vertex_bones(face.i1 as usize));
+                face_bones.extend(vertex_bones(face.i2 as usize));
+                face_bones.extend(vertex_bones(face.i3 as usize));
+
+                let new_bones = face_bones.difference(&chunk_bones).count();
+
+                if chunk_bones.len() + new_bones > max_bones && !chunk_faces.is_empty() {
+                    chunks.push(std::mem::take(&mut chunk_faces));
+                    chunk_bones.clear();
+                }
+
+                chunk_bones.extend(face_bones);
+                chunk_faces.push(index);
+            }
+
+            if !chunk_faces.is_empty() {
+                chunks.push(chunk_faces);
+            }
+
+            for faces in chunks {
+                self.meshes.push(mesh.extract_faces(&faces));
+            }
+        }
+    }
+
+    /// Appends the bones, meshes, and materials of another model to this model.
+    ///
+    /// Fails if the combined skeleton has more bones than a vertex weight can reference.
+    pub fn append(&mut self, other: Model) -> Result<(), ModelError> {
+        // Static models don't reference any bones, so their weights are left alone.
+        let bone_offset = if other.skeleton.bones.is_empty() {
+            0
+        } else {
+            self.skeleton.bones.len()
+        };
+
+        if bone_offset + other.skeleton.bones.len() > WeightBoneId::MAX as usize + 1 {
+            return Err(ModelError::TooManyBones);
+        }
+
+        let material_offset = self.materials.len();
+
+        for mut bone in other.skeleton.bones {
+            if bone.parent > -1 {
+                bone.parent += bone_offset as i32;
+            }
+
+            self.skeleton.bones.push(bone);
+        }
+
+        for mut ik_handle in other.skeleton.ik_handles {
+            ik_handle.start_bone += bone_offset;
+            ik_handle.end_bone += bone_offset;
+            ik_handle.target_bone = ik_handle.target_bone.map(|x| x + bone_offset);
+            ik_handle.pole_vector_bone = ik_handle.pole_vector_bone.map(|x| x + bone_offset);
+            ik_handle.pole_bone = ik_handle.pole_bone.map(|x| x + bone_offset);
+
+            self.skeleton.ik_handles.push(ik_handle);
+        }
+
+        for mut constraint in other.skeleton.constraints {
+            constraint.constraint_bone += bone_offset;
+            constraint.target_bone += bone_offset;
+
+            self.skeleton.constraints.push(constraint);
+        }
+
+        for mut mesh in other.meshes {
+            mesh.material = mesh.material.map(|x| x + material_offset);
+
+            let maximum_influence = mesh.vertices.maximum_influence();
+
+            if bone_offset > 0 {
+                for v in 0..mesh.vertices.len() {
+                    let mut vertex = mesh.vertices.vertex_mut(v);
+
+                    for w in 0..maximum_influence {
+                        let bone = vertex.weight(w).bone as usize + bone_offset;
+                        let bone =
+                            WeightBoneId::try_from(bone).map_err(|_| ModelError::TooManyBones)?;
+
+                        vertex.set_weight_bone(w, bone);
+                    }
+                }
+            }
+
+            self.meshes.push(mesh);
+        }
+
+        self.materials.extend(other.materials);
+
+        Ok(())
+    }
+
     /// Gets the base texture for each material in this model.
     pub fn material_textures(&self) -> Vec<Option<MaterialTextureRef>> {
         let mut result = Vec::with_capacity(self.materials.len());
@@ -378,6 +648,14 @@ impl Model {
         result
     }
 
+    /// Rewrites hashed texture file names to their names in the database, images should be saved with `NameDatabase::resolve_file_name` to match.
+    pub fn resolve_texture_names(&mut self, names: &NameDatabase) -> usize {
+        self.materials
+            .iter_mut()
+            .map(|material| material.resolve_texture_names(names))
+            .sum()
+    }
+
     /// Calculates the bounding box for the given model.
     pub fn bounding_box(&self) -> Aabb {
         let mut min_x = f32::INFINITY;
@@ -421,19 +699,47 @@ impl Model {
                 model_file_type_xmodel_export::to_xmodel_export(path, self)
             }
             ModelFileType::Cast => model_file_type_cast::to_cast(path, self),
-            ModelFileType::Fbx => model_file_type_fbx::to_fbx(path, self),
+            ModelFileType::Fbx => model_file_type_fbx::to_fbx(path, self, false),
+            ModelFileType::FbxAscii => model_file_type_fbx::to_fbx(path, self, true),
             ModelFileType::Maya => model_file_type_maya::to_maya(path, self),
         }
     }
 
-    /// Validates the model has some form of valid data.
-    #[cfg(debug_assertions)]
-    pub fn validate(&self) {
-        self.skeleton.validate();
+    /// Saves the model like `save`, but validates it first so a corrupt file is never written.
+    ///
+    /// Fails with the problems found if any of them would produce a corrupt file.
+    pub fn save_validated<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ModelFileType,
+    ) -> Result<(), ModelError> {
+        let diagnostics = self.validate();
 
-        for mesh in &self.meshes {
-            mesh.validate(self.skeleton.bones.len());
+        if diagnostics.iter().any(|x| x.is_error()) {
+            return Err(ModelError::ValidationFailed(diagnostics));
+        }
+
+        self.save(path, file_type)
+    }
+
+    /// Validates the model has some form of valid data, returning any problems found.
+    ///
+    /// Only the first few problems of each kind are listed, followed by a summary of how many more were found.
+    pub fn validate(&self) -> Vec<ModelDiagnostic> {
+        let mut diagnostics = ModelDiagnostics::default();
+
+        self.skeleton.validate(&mut diagnostics);
+
+        for (index, mesh) in self.meshes.iter().enumerate() {
+            mesh.validate(
+                index,
+                self.skeleton.bones.len(),
+                self.materials.len(),
+                &mut diagnostics,
+            );
         }
+
+        diagnostics.finish()
     }
 }
 
@@ -0,0 +1,69 @@
+use porter_math::Angles;
+use porter_math::Axis;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+use crate::ModelUnits;
+
+/// A conversion applied to a model before exporting, so every format receives the same result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModelConversion {
+    /// The units to convert the model to, or `None` to keep the source units.
+    pub units: Option<ModelUnits>,
+    /// The up axis to convert the model to, or `None` to keep the source up axis.
+    pub up_axis: Option<Axis>,
+    /// An axis to mirror the model across, used to convert between left and right handed rigs.
+    pub mirror: Option<Axis>,
+}
+
+impl ModelConversion {
+    /// Constructs a new conversion which leaves the model unchanged.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the units to convert the model to.
+    pub fn units(mut self, units: Option<ModelUnits>) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Sets the up axis to convert the model to.
+    pub fn up_axis(mut self, up_axis: Option<Axis>) -> Self {
+        self.up_axis = up_axis;
+        self
+    }
+
+    /// Sets the axis to mirror the model across.
+    pub fn mirror(mut self, mirror: Option<Axis>) -> Self {
+        self.mirror = mirror;
+        self
+    }
+
+    /// Whether or not this conversion leaves the model unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.units.is_none() && self.up_axis.is_none() && self.mirror.is_none()
+    }
+}
+
+/// Returns the unit vector pointing along the given axis.
+fn axis_vector(axis: Axis) -> Vector3 {
+    match axis {
+        Axis::X => Vector3::new(1.0, 0.0, 0.0),
+        Axis::Y => Vector3::new(0.0, 1.0, 0.0),
+        Axis::Z => Vector3::new(0.0, 0.0, 1.0),
+    }
+}
+
+/// Returns the rotation around the origin that turns the given up axis into another one.
+pub fn up_axis_rotation(from: Axis, to: Axis) -> Quaternion {
+    if from == to {
+        return Quaternion::identity();
+    }
+
+    Quaternion::from_axis_rotation(
+        axis_vector(from).cross(axis_vector(to)),
+        90.0,
+        Angles::Degrees,
+    )
+}
@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The most problems of each kind listed when validating a model, the rest are counted in a summary.
+pub const MODEL_DIAGNOSTICS_PER_KIND_MAX: usize = 16;
+
+/// A problem found while validating a model.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModelDiagnostic {
+    /// A bone has a parent index outside of the skeleton.
+    InvalidBoneParent { bone: usize, parent: i32 },
+    /// A vertex position is NaN or infinite.
+    InvalidPosition { mesh: usize, vertex: usize },
+    /// A vertex normal isn't unit length.
+    UnnormalizedNormal {
+        mesh: usize,
+        vertex: usize,
+        length_squared: f32,
+    },
+    /// A vertex weight references a bone outside of the skeleton.
+    InvalidWeightBone {
+        mesh: usize,
+        vertex: usize,
+        bone: usize,
+    },
+    /// The weights of a vertex don't add up to 1.0.
+    UnnormalizedWeights {
+        mesh: usize,
+        vertex: usize,
+        total: f32,
+    },
+    /// A face references a vertex outside of the mesh.
+    InvalidFaceIndex { mesh: usize, face: usize },
+    /// A face uses the same vertex more than once.
+    DegenerateFace { mesh: usize, face: usize },
+    /// A mesh references a material outside of the model.
+    InvalidMaterial { mesh: usize, material: usize },
+    /// A blend shape references a vertex outside of the mesh.
+    InvalidBlendShapeIndex {
+        mesh: usize,
+        blend_shape: String,
+        vertex: usize,
+    },
+    /// More problems of one kind were found than are listed.
+    Omitted {
+        kind: &'static str,
+        count: usize,
+        error: bool,
+    },
+}
+
+impl ModelDiagnostic {
+    /// Whether or not this problem produces a corrupt file, instead of one that just looks wrong.
+    pub fn is_error(&self) -> bool {
+        match self {
+            Self::Omitted { error, .. } => *error,
+            _ => !matches!(
+                self,
+                Self::UnnormalizedNormal { .. }
+                    | Self::UnnormalizedWeights { .. }
+                    | Self::DegenerateFace { .. }
+            ),
+        }
+    }
+
+    /// The number of problems this diagnostic stands for, which is more than one for a summary of omitted problems.
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Omitted { count, .. } => *count,
+            _ => 1,
+        }
+    }
+
+    /// A short name for the kind of problem.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::InvalidBoneParent { .. } => "invalid bone parent",
+            Self::InvalidPosition { .. } => "invalid position",
+            Self::UnnormalizedNormal { .. } => "unnormalized normal",
+            Self::InvalidWeightBone { .. } => "invalid weight bone",
+            Self::UnnormalizedWeights { .. } => "unnormalized weights",
+            Self::InvalidFaceIndex { .. } => "invalid face index",
+            Self::DegenerateFace { .. } => "degenerate face",
+            Self::InvalidMaterial { .. } => "invalid material",
+            Self::InvalidBlendShapeIndex { .. } => "invalid blend shape index",
+            Self::Omitted { kind, .. } => kind,
+        }
+    }
+}
+
+/// Collects the problems found while validating a model, listing only the first few of each kind.
+#[derive(Debug, Default)]
+pub(crate) struct ModelDiagnostics {
+    diagnostics: Vec<ModelDiagnostic>,
+    counts: BTreeMap<&'static str, (usize, bool)>,
+}
+
+impl ModelDiagnostics {
+    /// Adds a problem, which is only counted once enough of its kind are listed.
+    pub fn push(&mut self, diagnostic: ModelDiagnostic) {
+        let (count, error) = self.counts.entry(diagnostic.kind()).or_insert((0, false));
+
+        *count += 1;
+
+        if *count > MODEL_DIAGNOSTICS_PER_KIND_MAX {
+            *error |= diagnostic.is_error();
+        } else {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Returns the listed problems, followed by a summary for each kind with problems omitted.
+    pub fn finish(mut self) -> Vec<ModelDiagnostic> {
+        for (kind, (count, error)) in self.counts {
+            if count > MODEL_DIAGNOSTICS_PER_KIND_MAX {
+                self.diagnostics.push(ModelDiagnostic::Omitted {
+                    kind,
+                    count: count - MODEL_DIAGNOSTICS_PER_KIND_MAX,
+                    error,
+                });
+            }
+        }
+
+        self.diagnostics
+    }
+}
+
+impl fmt::Display for ModelDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBoneParent { bone, parent } => {
+                write!(f, "Bone {} has an invalid parent {}", bone, parent)
+            }
+            Self::InvalidPosition { mesh, vertex } => {
+                write!(f, "Mesh {} vertex {} has an invalid position", mesh, vertex)
+            }
+            Self::UnnormalizedNormal {
+                mesh,
+                vertex,
+                length_squared,
+            } => write!(
+                f,
+                "Mesh {} vertex {} has a normal with a square length of {}",
+                mesh, vertex, length_squared
+            ),
+            Self::InvalidWeightBone { mesh, vertex, bone } => write!(
+                f,
+                "Mesh {} vertex {} is weighted to missing bone {}",
+                mesh, vertex, bone
+            ),
+            Self::UnnormalizedWeights {
+                mesh,
+                vertex,
+                total,
+            } => write!(
+                f,
+                "Mesh {} vertex {} has weights that add up to {}",
+                mesh, vertex, total
+            ),
+            Self::InvalidFaceIndex { mesh, face } => {
+                write!(f, "Mesh {} face {} has an invalid vertex index", mesh, face)
+            }
+            Self::DegenerateFace { mesh, face } => {
+                write!(f, "Mesh {} face {} is degenerate", mesh, face)
+            }
+            Self::InvalidMaterial { mesh, material } => {
+                write!(f, "Mesh {} uses missing material {}", mesh, material)
+            }
+            Self::InvalidBlendShapeIndex {
+                mesh,
+                blend_shape,
+                vertex,
+            } => write!(
+                f,
+                "Mesh {} blend shape {} has an invalid vertex index {}",
+                mesh, blend_shape, vertex
+            ),
+            Self::Omitted { kind, count, .. } => {
+                write!(f, "{} more {} problems were not listed", count, kind)
+            }
+        }
+    }
+}
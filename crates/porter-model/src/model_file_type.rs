@@ -11,4 +11,24 @@ pub enum ModelFileType {
     Cast,
     Maya,
     Fbx,
+    FbxAscii,
+}
+
+impl ModelFileType {
+    /// All of the supported model file types.
+    pub const ALL: [Self; 8] = [
+        Self::Obj,
+        Self::Smd,
+        Self::XnaLara,
+        Self::XModelExport,
+        Self::Cast,
+        Self::Maya,
+        Self::Fbx,
+        Self::FbxAscii,
+    ];
+
+    /// Whether or not the model file type can only be written to, models can't be loaded.
+    pub const fn is_write_only(&self) -> bool {
+        true
+    }
 }
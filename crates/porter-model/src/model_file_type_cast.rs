@@ -1,18 +1,20 @@
 use std::collections::HashMap;
-use std::collections::HashSet;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::Path;
 
 use porter_cast::CastFile;
 use porter_cast::CastId;
-use porter_cast::CastNode;
+use porter_cast::CastMaterialBuilder;
+use porter_cast::CastModelBuilder;
 use porter_cast::CastPropertyId;
 use porter_cast::CastPropertyValue;
 
 use porter_math::Axis;
+use porter_math::Vector4;
 
 use crate::ConstraintType;
+use crate::MaterialParameterValue;
 use crate::MaterialTextureRefUsage;
 use crate::Model;
 use crate::ModelError;
@@ -20,7 +22,8 @@ use crate::SkinningMethod;
 
 /// Writes a model in cast format to the given path.
 pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
-    let mut root = CastNode::root();
+    let mut file = CastFile::new();
+    let mut root = file.create_root();
 
     let meta_node = root.create(CastId::Metadata);
 
@@ -42,7 +45,11 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         .create_property(CastPropertyId::String, "up")
         .push(up_axis);
 
-    let model_node = root.create(CastId::Model);
+    for (key, value) in &model.metadata {
+        meta_node.create_metadata(key, value);
+    }
+
+    let mut model_node = CastModelBuilder::new().build(&mut root);
 
     if !model.skeleton.bones.is_empty() {
         let skeleton_node = model_node.create(CastId::Skeleton);
@@ -183,26 +190,9 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         HashMap::with_capacity(model.materials.len());
 
     for (material_index, material) in model.materials.iter().enumerate() {
-        let material_node = model_node.create(CastId::Material);
-
-        material_node
-            .create_property(CastPropertyId::String, "n")
-            .push(material.name.as_str());
-
-        material_node
-            .create_property(CastPropertyId::String, "t")
-            .push("pbr");
-
-        let mut used_slots: HashSet<String> = HashSet::new();
-
-        for i in 0..material.len() {
-            let texture = &material.textures[i];
-
-            let file = material_node.create(CastId::File);
-
-            file.create_property(CastPropertyId::String, "p")
-                .push(texture.file_name.as_str());
+        let mut builder = CastMaterialBuilder::new(material.name.as_str());
 
+        for (i, texture) in material.textures.iter().enumerate() {
             let slot = match texture.texture_usage {
                 MaterialTextureRefUsage::Albedo => String::from("albedo"),
                 MaterialTextureRefUsage::Diffuse => String::from("diffuse"),
@@ -220,21 +210,29 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 }
             };
 
-            let slot = if used_slots.contains(&slot) {
-                format!("extra{}", i)
-            } else {
-                used_slots.insert(slot.clone());
-                slot
-            };
+            builder = builder.texture(slot, texture.file_name.as_str());
+        }
 
-            let hash = CastPropertyValue::from(file);
+        for parameter in &material.parameters {
+            let name = parameter.param.name();
 
-            material_node
-                .create_property(CastPropertyId::Integer64, slot)
-                .push(hash);
+            builder = match &parameter.value {
+                MaterialParameterValue::String(value) => builder.property(name, value.as_str()),
+                MaterialParameterValue::Color { r, g, b, a } => {
+                    builder.property(name, Vector4::new(*r, *g, *b, *a))
+                }
+                MaterialParameterValue::Float(value) => builder.property(name, *value),
+                MaterialParameterValue::Bool(value) => builder.property(name, *value),
+                MaterialParameterValue::Vector2(value) => builder.property(name, *value),
+                MaterialParameterValue::Vector3(value) => builder.property(name, *value),
+                MaterialParameterValue::Vector4(value) => builder.property(name, *value),
+                MaterialParameterValue::BlendMode(value) => {
+                    builder.property(name, value.to_string())
+                }
+            };
         }
 
-        material_map.insert(material_index, CastPropertyValue::from(material_node));
+        material_map.insert(material_index, builder.build(&mut model_node));
     }
 
     let mut mesh_map: HashMap<usize, CastPropertyValue> =
@@ -429,9 +427,6 @@ pub fn to_cast<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     let writer = BufWriter::new(File::create(path.as_ref().with_extension("cast"))?);
 
-    let mut file = CastFile::new();
-
-    file.push(root);
     file.write(writer)?;
 
     Ok(())
@@ -13,13 +13,18 @@ use porter_fbx::FbxPropertyType;
 use porter_fbx::FbxPropertyValue;
 
 use porter_math::Angles;
+use porter_math::Axis;
 use porter_math::Matrix4x4;
 use porter_math::Vector3;
 
+use crate::MaterialParameter;
+use crate::MaterialParameterType;
+use crate::MaterialParameterValue;
 use crate::MaterialTextureRef;
 use crate::MaterialTextureRefUsage;
 use crate::Model;
 use crate::ModelError;
+use crate::Skeleton;
 
 /// Adds an object connection from->to.
 fn add_object_connection<F: Into<FbxPropertyValue>, T: Into<FbxPropertyValue>>(
@@ -183,8 +188,14 @@ fn initialize_texture_node(
     );
 }
 
-/// Adds basic properties to the model and skeleton root nodes.
-fn initialize_root_node(root_node: &mut FbxNode) {
+/// Adds basic properties to the model and skeleton root nodes, rotating the given up axis to fbx's y up.
+fn initialize_root_node(root_node: &mut FbxNode, up_axis: Axis) {
+    let pre_rotation = match up_axis {
+        Axis::X => Vector3::new(0.0, 0.0, 90.0),
+        Axis::Y => Vector3::zero(),
+        Axis::Z => Vector3::new(-90.0, 0.0, 0.0),
+    };
+
     root_node
         .create("Version")
         .create_property(FbxPropertyType::Integer32)
@@ -209,9 +220,13 @@ fn initialize_root_node(root_node: &mut FbxNode) {
             .push_string("");
         props
             .create_property(FbxPropertyType::Float64)
-            .push(-90.0f64);
-        props.create_property(FbxPropertyType::Float64).push(0.0f64);
-        props.create_property(FbxPropertyType::Float64).push(0.0f64);
+            .push(pre_rotation.x as f64);
+        props
+            .create_property(FbxPropertyType::Float64)
+            .push(pre_rotation.y as f64);
+        props
+            .create_property(FbxPropertyType::Float64)
+            .push(pre_rotation.z as f64);
     }
 
     {
@@ -289,282 +304,368 @@ fn initialize_root_node(root_node: &mut FbxNode) {
     }
 }
 
-/// Writes a model in fbx format to the given path.
-pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
-    let mut root = FbxDocument::new();
-    let root_hash = FbxPropertyValue::from(root.root_node());
+/// Adds a material parameter to the material properties, as a user property unless fbx has a matching property.
+fn initialize_material_parameter(properties: &mut FbxNode, parameter: &MaterialParameter) {
+    let base_color = matches!(parameter.param, MaterialParameterType::BaseColor);
+
+    let (name, flags) = match parameter.param {
+        MaterialParameterType::BaseColor => ("DiffuseColor", "A"),
+        MaterialParameterType::TwoSided => ("TwoSided", "A+U"),
+        MaterialParameterType::BlendMode => ("BlendMode", "A+U"),
+        MaterialParameterType::Custom(ref name) => (name.as_str(), "A+U"),
+    };
+
+    let (type_name, label) = match parameter.value {
+        MaterialParameterValue::String(_) | MaterialParameterValue::BlendMode(_) => ("KString", ""),
+        MaterialParameterValue::Color { .. } if base_color => ("Color", ""),
+        MaterialParameterValue::Color { .. } => ("ColorAndAlpha", ""),
+        MaterialParameterValue::Float(_) => ("double", "Number"),
+        MaterialParameterValue::Bool(_) => ("bool", ""),
+        MaterialParameterValue::Vector2(_) => ("Vector2D", "Vector2"),
+        MaterialParameterValue::Vector3(_) => ("Vector3D", "Vector"),
+        MaterialParameterValue::Vector4(_) => ("Vector4D", "Vector4"),
+    };
+
+    let props = properties.create("P");
+
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string(name);
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string(type_name);
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string(label);
+    props
+        .create_property(FbxPropertyType::String)
+        .push_string(flags);
+
+    let values: Vec<f32> = match &parameter.value {
+        MaterialParameterValue::String(value) => {
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string(value.as_str());
+            return;
+        }
+        MaterialParameterValue::BlendMode(value) => {
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string(value.to_string());
+            return;
+        }
+        MaterialParameterValue::Bool(value) => {
+            props
+                .create_property(FbxPropertyType::Integer32)
+                .push(*value as u32);
+            return;
+        }
+        MaterialParameterValue::Color { r, g, b, .. } if base_color => vec![*r, *g, *b],
+        MaterialParameterValue::Color { r, g, b, a } => vec![*r, *g, *b, *a],
+        MaterialParameterValue::Float(value) => vec![*value],
+        MaterialParameterValue::Vector2(value) => vec![value.x, value.y],
+        MaterialParameterValue::Vector3(value) => vec![value.x, value.y, value.z],
+        MaterialParameterValue::Vector4(value) => vec![value.x, value.y, value.z, value.w],
+    };
+
+    for value in values {
+        props
+            .create_property(FbxPropertyType::Float64)
+            .push(value as f64);
+    }
+}
 
+/// Adds the bones of the skeleton as joints under the document root, returning the joint hashes by bone index.
+pub(crate) fn initialize_skeleton(
+    root: &mut FbxDocument,
+    skeleton: &Skeleton,
+    up_axis: Axis,
+) -> HashMap<usize, FbxPropertyValue> {
     let mut joints_map: HashMap<usize, FbxPropertyValue> =
-        HashMap::with_capacity(model.skeleton.bones.len());
+        HashMap::with_capacity(skeleton.bones.len());
+
+    if skeleton.bones.is_empty() {
+        return joints_map;
+    }
 
-    if !model.skeleton.bones.is_empty() {
-        let joints = root.objects_node().create("Model");
+    let root_hash = FbxPropertyValue::from(root.root_node());
+
+    let joints = root.objects_node().create("Model");
 
-        joints.create_hash();
-        joints
+    joints.create_hash();
+    joints
+        .create_property(FbxPropertyType::String)
+        .push_string("Joints\u{0000}\u{0001}Model");
+    joints
+        .create_property(FbxPropertyType::String)
+        .push_string("Null");
+
+    initialize_root_node(joints, up_axis);
+
+    let joints_hash = FbxPropertyValue::from(joints);
+
+    add_object_connection(root.connections_node(), joints_hash, root_hash);
+
+    for (bone_index, bone) in skeleton.bones.iter().enumerate() {
+        let skeleton = root.objects_node().create("NodeAttribute");
+
+        skeleton.create_hash();
+        skeleton
             .create_property(FbxPropertyType::String)
-            .push_string("Joints\u{0000}\u{0001}Model");
-        joints
+            .push_string("\u{0000}\u{0001}NodeAttribute");
+        skeleton
             .create_property(FbxPropertyType::String)
-            .push_string("Null");
+            .push_string("LimbNode");
 
-        initialize_root_node(joints);
+        let properties = skeleton.create("Properties70").create("P");
 
-        let joints_hash = FbxPropertyValue::from(joints);
+        properties
+            .create_property(FbxPropertyType::String)
+            .push_string("Size");
+        properties
+            .create_property(FbxPropertyType::String)
+            .push_string("double");
+        properties
+            .create_property(FbxPropertyType::String)
+            .push_string("Number");
+        properties
+            .create_property(FbxPropertyType::String)
+            .push_string("");
+        properties
+            .create_property(FbxPropertyType::Float64)
+            .push(1000.0 / 30.0);
 
-        add_object_connection(root.connections_node(), joints_hash, root_hash);
+        skeleton
+            .create("TypeFlags")
+            .create_property(FbxPropertyType::String)
+            .push_string("Skeleton");
 
-        for (bone_index, bone) in model.skeleton.bones.iter().enumerate() {
-            let skeleton = root.objects_node().create("NodeAttribute");
+        let skeleton_hash = FbxPropertyValue::from(skeleton);
 
-            skeleton.create_hash();
-            skeleton
-                .create_property(FbxPropertyType::String)
-                .push_string("\u{0000}\u{0001}NodeAttribute");
-            skeleton
-                .create_property(FbxPropertyType::String)
-                .push_string("LimbNode");
+        let joint = root.objects_node().create("Model");
 
-            let properties = skeleton.create("Properties70").create("P");
+        joint.create_hash();
+        joint
+            .create_property(FbxPropertyType::String)
+            .push_string(format!(
+                "{}\u{0000}\u{0001}Model",
+                bone.name
+                    .as_deref()
+                    .unwrap_or(&format!("porter_bone_{}", bone_index))
+            ));
+        joint
+            .create_property(FbxPropertyType::String)
+            .push_string("LimbNode");
 
-            properties
-                .create_property(FbxPropertyType::String)
-                .push_string("Size");
-            properties
+        joint
+            .create("Version")
+            .create_property(FbxPropertyType::Integer32)
+            .push(232u32);
+
+        let properties = joint.create("Properties70");
+
+        {
+            let props = properties.create("P");
+
+            props
                 .create_property(FbxPropertyType::String)
-                .push_string("double");
-            properties
+                .push_string("RotationActive");
+            props
                 .create_property(FbxPropertyType::String)
-                .push_string("Number");
-            properties
+                .push_string("bool");
+            props
                 .create_property(FbxPropertyType::String)
                 .push_string("");
-            properties
-                .create_property(FbxPropertyType::Float64)
-                .push(1000.0 / 30.0);
-
-            skeleton
-                .create("TypeFlags")
+            props
                 .create_property(FbxPropertyType::String)
-                .push_string("Skeleton");
-
-            let skeleton_hash = FbxPropertyValue::from(skeleton);
+                .push_string("");
+            props.create_property(FbxPropertyType::Integer32).push(1u32);
+        }
 
-            let joint = root.objects_node().create("Model");
+        {
+            let props = properties.create("P");
 
-            joint.create_hash();
-            joint
+            props
                 .create_property(FbxPropertyType::String)
-                .push_string(format!(
-                    "{}\u{0000}\u{0001}Model",
-                    bone.name
-                        .as_deref()
-                        .unwrap_or(&format!("porter_bone_{}", bone_index))
-                ));
-            joint
+                .push_string("InheritType");
+            props
                 .create_property(FbxPropertyType::String)
-                .push_string("LimbNode");
-
-            joint
-                .create("Version")
-                .create_property(FbxPropertyType::Integer32)
-                .push(232u32);
-
-            let properties = joint.create("Properties70");
-
-            {
-                let props = properties.create("P");
+                .push_string("enum");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props.create_property(FbxPropertyType::Integer32).push(1u32);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("RotationActive");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("bool");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props.create_property(FbxPropertyType::Integer32).push(1u32);
-            }
+        {
+            let props = properties.create("P");
 
-            {
-                let props = properties.create("P");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("ScalingMax");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Vector3D");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Vector");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props.create_property(FbxPropertyType::Float64).push(0.0f64);
+            props.create_property(FbxPropertyType::Float64).push(0.0f64);
+            props.create_property(FbxPropertyType::Float64).push(0.0f64);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("InheritType");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("enum");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props.create_property(FbxPropertyType::Integer32).push(1u32);
-            }
+        {
+            let props = properties.create("P");
 
-            {
-                let props = properties.create("P");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("DefaultAttributeIndex");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("int");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Integer");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props.create_property(FbxPropertyType::Integer32).push(0u32);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("ScalingMax");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Vector3D");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Vector");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props.create_property(FbxPropertyType::Float64).push(0.0f64);
-                props.create_property(FbxPropertyType::Float64).push(0.0f64);
-                props.create_property(FbxPropertyType::Float64).push(0.0f64);
-            }
+        {
+            let props = properties.create("P");
+            let position = bone.local_position.unwrap_or_default();
 
-            {
-                let props = properties.create("P");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Translation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Translation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A");
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.x as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.y as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(position.z as f64);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("DefaultAttributeIndex");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("int");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Integer");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props.create_property(FbxPropertyType::Integer32).push(0u32);
-            }
+        {
+            let props = properties.create("P");
+            let rotation = bone
+                .local_rotation
+                .unwrap_or_default()
+                .to_euler(Angles::Degrees);
 
-            {
-                let props = properties.create("P");
-                let position = bone.local_position.unwrap_or_default();
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Rotation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Rotation");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A");
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.x as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.y as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(rotation.z as f64);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Translation");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Translation");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("A");
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(position.x as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(position.y as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(position.z as f64);
-            }
+        {
+            let props = properties.create("P");
+            let scale = bone.local_scale.unwrap_or(Vector3::one());
 
-            {
-                let props = properties.create("P");
-                let rotation = bone
-                    .local_rotation
-                    .unwrap_or_default()
-                    .to_euler(Angles::Degrees);
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Scaling");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Lcl Scaling");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A");
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(scale.x as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(scale.y as f64);
+            props
+                .create_property(FbxPropertyType::Float64)
+                .push(scale.z as f64);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Rotation");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Rotation");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("A");
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(rotation.x as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(rotation.y as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(rotation.z as f64);
-            }
+        {
+            let props = properties.create("P");
 
-            {
-                let props = properties.create("P");
-                let scale = bone.local_scale.unwrap_or(Vector3::one());
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("lockInfluenceWeights");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("Bool");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("");
+            props
+                .create_property(FbxPropertyType::String)
+                .push_string("A+U");
+            props.create_property(FbxPropertyType::Integer32).push(0u32);
+        }
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Scaling");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Lcl Scaling");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("A");
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(scale.x as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(scale.y as f64);
-                props
-                    .create_property(FbxPropertyType::Float64)
-                    .push(scale.z as f64);
-            }
+        let joint_hash = FbxPropertyValue::from(joint);
 
-            {
-                let props = properties.create("P");
+        add_object_connection(root.connections_node(), skeleton_hash, joint_hash);
 
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("lockInfluenceWeights");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("Bool");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("");
-                props
-                    .create_property(FbxPropertyType::String)
-                    .push_string("A+U");
-                props.create_property(FbxPropertyType::Integer32).push(0u32);
-            }
+        if bone.parent >= 0 {
+            add_object_connection(
+                root.connections_node(),
+                joint_hash,
+                joints_map[&(bone.parent as usize)],
+            );
+        } else {
+            add_object_connection(root.connections_node(), joint_hash, joints_hash);
+        }
 
-            let joint_hash = FbxPropertyValue::from(joint);
+        joints_map.insert(bone_index, joint_hash);
+    }
 
-            add_object_connection(root.connections_node(), skeleton_hash, joint_hash);
+    joints_map
+}
 
-            if bone.parent >= 0 {
-                add_object_connection(
-                    root.connections_node(),
-                    joint_hash,
-                    joints_map[&(bone.parent as usize)],
-                );
-            } else {
-                add_object_connection(root.connections_node(), joint_hash, joints_hash);
-            }
+/// Writes a model in fbx format to the given path, in either ascii or binary form.
+pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model, ascii: bool) -> Result<(), ModelError> {
+    let mut root = FbxDocument::new();
+    let root_hash = FbxPropertyValue::from(root.root_node());
 
-            joints_map.insert(bone_index, joint_hash);
-        }
-    }
+    let joints_map = initialize_skeleton(&mut root, &model.skeleton, model.up_axis);
 
     let mut material_map: HashMap<usize, FbxPropertyValue> = HashMap::new();
 
@@ -592,6 +693,14 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             .create_property(FbxPropertyType::Integer32)
             .push(0u32);
 
+        if !material.parameters.is_empty() {
+            let properties = material_node.create("Properties70");
+
+            for parameter in &material.parameters {
+                initialize_material_parameter(properties, parameter);
+            }
+        }
+
         let material_hash = FbxPropertyValue::from(material_node);
 
         material_map.insert(material_index, material_hash);
@@ -625,7 +734,7 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         .create_property(FbxPropertyType::String)
         .push_string("Null");
 
-    initialize_root_node(model_node);
+    initialize_root_node(model_node, model.up_axis);
 
     let model_hash = FbxPropertyValue::from(model_node);
 
@@ -807,7 +916,11 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             layer_uvs
                 .create("Name")
                 .create_property(FbxPropertyType::String)
-                .push_string(format!("map{}", i + 1));
+                .push_string(
+                    mesh.uv_layer_name(i)
+                        .map(String::from)
+                        .unwrap_or_else(|| format!("map{}", i + 1)),
+                );
             layer_uvs
                 .create("Version")
                 .create_property(FbxPropertyType::Integer32)
@@ -842,7 +955,11 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
             layer_color
                 .create("Name")
                 .create_property(FbxPropertyType::String)
-                .push_string(format!("colorSet{}", i));
+                .push_string(
+                    mesh.color_layer_name(i)
+                        .map(String::from)
+                        .unwrap_or_else(|| format!("colorSet{}", i)),
+                );
             layer_color
                 .create("Version")
                 .create_property(FbxPropertyType::Integer32)
@@ -1162,7 +1279,11 @@ pub fn to_fbx<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
 
     let writer = BufWriter::new(File::create(path.as_ref().with_extension("fbx"))?);
 
-    root.write(writer)?;
+    if ascii {
+        root.write_ascii(writer)?;
+    } else {
+        root.write(writer)?;
+    }
 
     Ok(())
 }
@@ -10,10 +10,23 @@ use porter_math::Vector3;
 
 use porter_utils::HashXXH64;
 
+use crate::Mesh;
 use crate::Model;
 use crate::ModelError;
 use crate::VertexColor;
 
+/// Gets the name of the uv set at the given index, using maya's default names.
+fn uv_set_name(mesh: &Mesh, index: usize) -> String {
+    mesh.uv_layer_name(index)
+        .map(String::from)
+        .unwrap_or_else(|| format!("map{}", index + 1))
+}
+
+/// Gets the name of the color set, using maya's default name.
+fn color_set_name(mesh: &Mesh) -> &str {
+    mesh.color_layer_name(0).unwrap_or("colorSet1")
+}
+
 /// Writes a model in maya format to the given path.
 pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
     let path = path.as_ref();
@@ -59,21 +72,21 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
                 writeln!(
                     maya,
                     concat!(
-                        "setAttr \".uvst[{}].uvsn\" -type \"string\" \"map{}\";\n",
+                        "setAttr \".uvst[{}].uvsn\" -type \"string\" \"{}\";\n",
                         "setAttr -s 1 \".uvst[0].uvsp[0]\" -type \"float2\"",
                     ),
                     i - 1,
-                    i
+                    uv_set_name(mesh, i - 1)
                 )?;
             } else {
                 writeln!(
                     maya,
                     concat!(
-                        "setAttr \".uvst[{}].uvsn\" -type \"string\" \"map{}\";\n",
+                        "setAttr \".uvst[{}].uvsn\" -type \"string\" \"{}\";\n",
                         "setAttr -s {} \".uvst[0].uvsp[0:{}]\" -type \"float2\"",
                     ),
                     i - 1,
-                    i,
+                    uv_set_name(mesh, i - 1),
                     mesh.vertices.len(),
                     mesh.vertices.len() - 1
                 )?;
@@ -91,10 +104,13 @@ pub fn to_maya<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError>
         write!(
             maya,
             concat!(
-                "setAttr \".cuvs\" -type \"string\" \"map1\";\nsetAttr \".dcol\" yes;\nsetAttr \".dcc\" -type \"string\" \"Ambient+Diffuse\";\nsetAttr \".ccls\" -type \"string\" \"colorSet1\";\nsetAttr \".clst[0].clsn\" -type \"string\" \"colorSet1\";\n",
+                "setAttr \".cuvs\" -type \"string\" \"{}\";\nsetAttr \".dcol\" yes;\nsetAttr \".dcc\" -type \"string\" \"Ambient+Diffuse\";\nsetAttr \".ccls\" -type \"string\" \"{}\";\nsetAttr \".clst[0].clsn\" -type \"string\" \"{}\";\n",
                 "setAttr -s {} \".clst[0].clsp\";\n",
                 "setAttr \".clst[0].clsp[0:{}]\"",
             ),
+            uv_set_name(mesh, 0),
+            color_set_name(mesh),
+            color_set_name(mesh),
             (mesh.faces.len() * 3),
             (mesh.faces.len() * 3) - 1
         )?;
@@ -5,11 +5,44 @@ use std::path::Path;
 
 use porter_math::normalize_array_f32;
 
+use crate::Material;
+use crate::MaterialBlendMode;
+use crate::MaterialTextureRef;
+use crate::MaterialTextureRefUsage;
 use crate::Model;
 use crate::ModelError;
 use crate::VertexColor;
 use crate::WeightBoneId;
 
+/// Picks the xna lara render group for a material, and the textures it expects in order.
+fn render_group(material: Option<&Material>) -> (u32, Vec<Option<&MaterialTextureRef>>) {
+    let Some(material) = material else {
+        return (5, vec![None]);
+    };
+
+    let find = |usage: MaterialTextureRefUsage| {
+        material
+            .textures
+            .iter()
+            .find(|x| x.texture_usage == usage && !x.file_name.is_empty())
+    };
+
+    let diffuse = material.base_color_texture();
+    let normal = find(MaterialTextureRefUsage::Normal);
+    let specular = find(MaterialTextureRefUsage::Specular);
+
+    let alpha = material.blend_mode() != MaterialBlendMode::Opaque;
+
+    match (normal, specular) {
+        (Some(normal), Some(specular)) => (
+            if alpha { 41 } else { 40 },
+            vec![diffuse, Some(normal), Some(specular)],
+        ),
+        (Some(normal), None) => (if alpha { 6 } else { 4 }, vec![diffuse, Some(normal)]),
+        _ => (if alpha { 7 } else { 5 }, vec![diffuse]),
+    }
+}
+
 /// Writes a model in xna lara format to the given path.
 pub fn to_xna_lara<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelError> {
     let mut xna = BufWriter::new(File::create(path.as_ref().with_extension("mesh.ascii"))?);
@@ -35,25 +68,22 @@ pub fn to_xna_lara<P: AsRef<Path>>(path: P, model: &Model) -> Result<(), ModelEr
     writeln!(xna, "{}", model.meshes.len())?;
 
     for (mesh_index, mesh) in model.meshes.iter().enumerate() {
+        let (render_group, textures) =
+            render_group(mesh.material.map(|index| &model.materials[index]));
+
         writeln!(
             xna,
-            "PorterMesh{}\n{}\n{}",
+            "{}_PorterMesh{}\n{}\n{}",
+            render_group,
             mesh_index,
             mesh.vertices.uv_layers(),
-            mesh.vertices.uv_layers()
+            textures.len()
         )?;
 
-        for i in 0..mesh.vertices.uv_layers() {
-            if let Some(material_index) = mesh.material {
-                let diffuse = model.materials[material_index].base_color_texture();
-
-                if let Some(diffuse) = diffuse {
-                    writeln!(xna, "{}\n{}", diffuse.file_name, i)?;
-                } else {
-                    writeln!(xna, "default_material\n{}", i)?;
-                }
-            } else {
-                writeln!(xna, "default_material\n{}", i)?;
+        for texture in textures {
+            match texture {
+                Some(texture) => writeln!(xna, "{}\n0", texture.file_name)?,
+                None => writeln!(xna, "default_material\n0")?,
             }
         }
 
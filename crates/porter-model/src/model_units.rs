@@ -0,0 +1,30 @@
+use bincode::Decode;
+use bincode::Encode;
+
+/// The units of measurement used by a model's positions.
+#[derive(Decode, Encode, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModelUnits {
+    #[default]
+    Centimeters,
+    Meters,
+    Inches,
+}
+
+impl ModelUnits {
+    /// All of the supported model units.
+    pub const ALL: [Self; 3] = [Self::Centimeters, Self::Meters, Self::Inches];
+
+    /// The number of centimeters in one unit.
+    pub const fn centimeters(self) -> f32 {
+        match self {
+            Self::Centimeters => 1.0,
+            Self::Meters => 100.0,
+            Self::Inches => 2.54,
+        }
+    }
+
+    /// The factor used to convert positions in these units to the given units.
+    pub fn scale_to(self, units: Self) -> f32 {
+        self.centimeters() / units.centimeters()
+    }
+}
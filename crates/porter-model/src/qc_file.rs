@@ -0,0 +1,111 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+
+use crate::ModelError;
+
+/// An animation sequence listed in a qc file.
+#[derive(Debug, Clone)]
+pub struct QcSequence {
+    /// The name of the sequence.
+    pub name: String,
+    /// The animation smd file, relative to the qc file.
+    pub file: String,
+    /// The framerate of the sequence.
+    pub framerate: f32,
+    /// Whether or not the sequence loops.
+    pub looping: bool,
+}
+
+/// A Source engine compile script stub, listing the body groups and sequences of an smd export.
+#[derive(Debug, Clone, Default)]
+pub struct QcFile {
+    /// The name of the compiled model.
+    pub model_name: String,
+    /// The body groups as name and reference smd file, relative to the qc file.
+    pub body_groups: Vec<(String, String)>,
+    /// The animation sequences.
+    pub sequences: Vec<QcSequence>,
+}
+
+impl QcFile {
+    /// Constructs a new qc file for the given model name.
+    pub fn new<N: Into<String>>(model_name: N) -> Self {
+        Self {
+            model_name: model_name.into(),
+            body_groups: Vec::new(),
+            sequences: Vec::new(),
+        }
+    }
+
+    /// Adds a body group using the given reference smd file.
+    pub fn body_group<N: Into<String>, F: Into<String>>(mut self, name: N, file: F) -> Self {
+        self.body_groups.push((name.into(), file.into()));
+        self
+    }
+
+    /// Adds a sequence using the given animation smd file.
+    pub fn sequence<N: Into<String>, F: Into<String>>(
+        mut self,
+        name: N,
+        file: F,
+        framerate: f32,
+        looping: bool,
+    ) -> Self {
+        self.sequences.push(QcSequence {
+            name: name.into(),
+            file: file.into(),
+            framerate,
+            looping,
+        });
+        self
+    }
+
+    /// Saves the qc file to the given path.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ModelError> {
+        let mut qc = BufWriter::new(File::create(path.as_ref().with_extension("qc"))?);
+
+        writeln!(
+            qc,
+            "// Exported by PorterLib\n// Please credit DTZxPorter for use of this asset!\n"
+        )?;
+        writeln!(qc, "$modelname \"{}.mdl\"", self.model_name)?;
+        writeln!(qc, "$cdmaterials \"models/{}/\"", self.model_name)?;
+
+        for (name, file) in &self.body_groups {
+            writeln!(
+                qc,
+                "\n$bodygroup \"{}\"\n{{\n\tstudio \"{}\"\n}}",
+                name,
+                file.replace('\\', "/")
+            )?;
+        }
+
+        writeln!(qc)?;
+
+        // Studiomdl requires at least one sequence, so the first reference is used as a static pose.
+        if self.sequences.is_empty() {
+            if let Some((_, file)) = self.body_groups.first() {
+                writeln!(
+                    qc,
+                    "$sequence \"idle\" \"{}\" fps 30",
+                    file.replace('\\', "/")
+                )?;
+            }
+        }
+
+        for sequence in &self.sequences {
+            writeln!(
+                qc,
+                "$sequence \"{}\" \"{}\" fps {}{}",
+                sequence.name,
+                sequence.file.replace('\\', "/"),
+                sequence.framerate,
+                if sequence.looping { " loop" } else { "" }
+            )?;
+        }
+
+        Ok(())
+    }
+}
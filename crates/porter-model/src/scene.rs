@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use porter_math::Axis;
+
+use porter_utils::SanitizeFilename;
+
+use crate::scene_file_type_cast;
+use crate::Model;
+use crate::ModelError;
+use crate::ModelFileType;
+use crate::SceneInstance;
+
+/// A 3d scene, such as a map or level, made of placed model instances.
+#[derive(Debug, Clone)]
+pub struct Scene {
+    /// A collection of models by name, placed by index from the instances.
+    pub models: Vec<(String, Model)>,
+    /// A collection of placements of the models.
+    pub instances: Vec<SceneInstance>,
+    /// The up axis for this scene.
+    pub up_axis: Axis,
+    /// Custom metadata, such as the source archive or game version, written to formats which support it.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl Scene {
+    /// Constructs a new instance of scene.
+    pub fn new() -> Self {
+        Self {
+            models: Vec::new(),
+            instances: Vec::new(),
+            up_axis: Axis::Z,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Constructs a new instance of scene with the given capacity.
+    pub fn with_capacity(models: usize, instances: usize) -> Self {
+        Self {
+            models: Vec::with_capacity(models),
+            instances: Vec::with_capacity(instances),
+            up_axis: Axis::Z,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a model to the scene with the given name, returning the index used to place it.
+    pub fn add_model<S: Into<String>>(&mut self, name: S, model: Model) -> usize {
+        let mut name = name.into().sanitized();
+
+        // Names are used as file names, so they must be unique.
+        if self.models.iter().any(|(x, _)| *x == name) {
+            let base = name;
+            let mut index = self.models.len();
+
+            loop {
+                name = format!("{}_{}", base, index);
+
+                if !self.models.iter().any(|(x, _)| *x == name) {
+                    break;
+                }
+
+                index += 1;
+            }
+        }
+
+        self.models.push((name, model));
+        self.models.len() - 1
+    }
+
+    /// Sets a custom metadata value, such as the source archive or game version.
+    pub fn set_metadata<K: Into<String>, V: Into<String>>(&mut self, key: K, value: V) {
+        self.metadata.insert(key.into(), value.into());
+    }
+
+    /// Places a model in the scene.
+    pub fn add_instance(&mut self, instance: SceneInstance) {
+        self.instances.push(instance);
+    }
+
+    /// Combines every instance into a single model, with each instance transformed into place.
+    ///
+    /// Models are converted to the scene's up axis, and the units of the first model, before they are placed.
+    ///
+    /// Fails if the combined skeleton has more bones than a vertex weight can reference.
+    pub fn flatten(&self) -> Result<Model, ModelError> {
+        let mut result = Model::new();
+
+        result.up_axis = self.up_axis;
+        result.metadata = self.metadata.clone();
+
+        if let Some((_, model)) = self.models.first() {
+            result.units = model.units;
+        }
+
+        for instance in &self.instances {
+            let Some((_, model)) = self.models.get(instance.model) else {
+                continue;
+            };
+
+            let mut model = model.clone();
+
+            // Instances are placed in the scene's space, so the model must be in it before it's transformed.
+            model.set_up_axis(self.up_axis);
+            model.set_units(result.units);
+            model.transform(&instance.matrix());
+
+            result.append(model)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Saves the scene to the given file path in the given model format.
+    ///
+    /// Cast scenes save each model once and reference it from every instance, other formats save the flattened scene.
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        file_type: ModelFileType,
+    ) -> Result<(), ModelError> {
+        match file_type {
+            ModelFileType::Cast => scene_file_type_cast::to_cast(path, self),
+            _ => self.flatten()?.save(path, file_type),
+        }
+    }
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,91 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use porter_cast::CastId;
+use porter_cast::CastPropertyId;
+use porter_cast::CastWriter;
+
+use porter_math::Axis;
+
+use crate::model_file_type_cast;
+use crate::ModelError;
+use crate::Scene;
+
+/// Writes a scene in cast format to the given path, with each model saved to a folder next to it.
+///
+/// The scene is streamed to disk, so large maps don't need every instance node in memory.
+pub fn to_cast<P: AsRef<Path>>(path: P, scene: &Scene) -> Result<(), ModelError> {
+    let path = path.as_ref().with_extension("cast");
+
+    let folder_name = format!(
+        "{}_models",
+        path.file_stem().unwrap_or_default().to_string_lossy()
+    );
+
+    let folder = path.with_file_name(&folder_name);
+
+    std::fs::create_dir_all(&folder)?;
+
+    let mut writer = CastWriter::new(BufWriter::new(File::create(path)?))?;
+
+    writer.begin_node(CastId::Root)?;
+    writer.begin_node(CastId::Metadata)?;
+    writer.write_property(CastPropertyId::String, "a", "DTZxPorter")?;
+    writer.write_property(CastPropertyId::String, "s", "Exported by PorterLib")?;
+
+    let up_axis = match scene.up_axis {
+        Axis::X => "x",
+        Axis::Y => "y",
+        Axis::Z => "z",
+    };
+
+    writer.write_property(CastPropertyId::String, "up", up_axis)?;
+
+    for (key, value) in &scene.metadata {
+        writer.write_metadata(key, value)?;
+    }
+
+    writer.end_node()?;
+
+    // Each model is referenced by a single file node, shared by all of its instances.
+    let mut files: Vec<u64> = Vec::with_capacity(scene.models.len());
+
+    for (name, model) in &scene.models {
+        let file_name = format!("{}.cast", name);
+
+        model_file_type_cast::to_cast(folder.join(&file_name), model)?;
+
+        // Instances reference the model relative to the scene, so the folder can be moved as a whole.
+        // The separator is always '/', so scenes exported on windows load on other platforms.
+        let relative = format!("{}/{}", folder_name, file_name);
+
+        files.push(writer.begin_node(CastId::File)?);
+
+        writer.write_property(CastPropertyId::String, "p", relative)?;
+        writer.end_node()?;
+    }
+
+    for instance in &scene.instances {
+        let Some(file) = files.get(instance.model) else {
+            continue;
+        };
+
+        writer.begin_node(CastId::Instance)?;
+
+        if let Some(name) = &instance.name {
+            writer.write_property(CastPropertyId::String, "n", name.as_str())?;
+        }
+
+        writer.write_property(CastPropertyId::Integer64, "rf", *file)?;
+        writer.write_property(CastPropertyId::Vector3, "p", instance.position)?;
+        writer.write_property(CastPropertyId::Vector4, "r", instance.rotation)?;
+        writer.write_property(CastPropertyId::Vector3, "s", instance.scale)?;
+        writer.end_node()?;
+    }
+
+    writer.end_node()?;
+    writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use porter_math::Matrix4x4;
+use porter_math::Quaternion;
+use porter_math::Vector3;
+
+/// A placement of a scene model, with its own transform.
+#[derive(Debug, Clone)]
+pub struct SceneInstance {
+    /// The name of this instance.
+    pub name: Option<String>,
+    /// The index of the model in the scene this instance places.
+    pub model: usize,
+    /// The position of this instance.
+    pub position: Vector3,
+    /// The rotation of this instance.
+    pub rotation: Quaternion,
+    /// The scale of this instance.
+    pub scale: Vector3,
+}
+
+impl SceneInstance {
+    /// Constructs a new instance of the given scene model at the origin.
+    pub fn new(model: usize) -> Self {
+        Self {
+            name: None,
+            model,
+            position: Vector3::zero(),
+            rotation: Quaternion::identity(),
+            scale: Vector3::one(),
+        }
+    }
+
+    /// Sets an optional name for this instance.
+    pub fn name<S: Into<String>>(mut self, name: Option<S>) -> Self {
+        self.name = name.map(|x| x.into());
+        self
+    }
+
+    /// Sets the position of this instance.
+    pub fn position(mut self, position: Vector3) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the rotation of this instance.
+    pub fn rotation(mut self, rotation: Quaternion) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the scale of this instance.
+    pub fn scale(mut self, scale: Vector3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Gets the transform matrix (T * R * S).
+    pub fn matrix(&self) -> Matrix4x4 {
+        Matrix4x4::create_position(self.position)
+            * Matrix4x4::create_rotation(self.rotation)
+            * Matrix4x4::create_scale(self.scale)
+    }
+}
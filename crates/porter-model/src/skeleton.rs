@@ -1,10 +1,20 @@
+use std::collections::HashMap;
+
+use porter_fbx::FbxDocument;
+use porter_fbx::FbxPropertyValue;
+
+use porter_math::Axis;
 use porter_math::Matrix4x4;
+use porter_math::Quaternion;
 use porter_math::Vector3;
 
+use crate::model_file_type_fbx;
 use crate::Bone;
 use crate::Constraint;
 use crate::ConstraintType;
 use crate::IKHandle;
+use crate::ModelDiagnostic;
+use crate::ModelDiagnostics;
 
 /// Represents a skeleton, or collection of bones for a model.
 #[derive(Debug, Clone, Default)]
@@ -126,6 +136,32 @@ impl Skeleton {
         }
     }
 
+    /// Mirrors the skeleton across the plane perpendicular to the given axis.
+    pub fn mirror(&mut self, axis: Axis) {
+        for bone in &mut self.bones {
+            bone.local_position = bone.local_position.map(|x| x.mirrored(axis));
+            bone.local_rotation = bone.local_rotation.map(|x| x.mirrored(axis));
+            bone.world_position = bone.world_position.map(|x| x.mirrored(axis));
+            bone.world_rotation = bone.world_rotation.map(|x| x.mirrored(axis));
+        }
+    }
+
+    /// Rotates the skeleton around the origin by the given rotation.
+    pub fn rotate(&mut self, rotation: Quaternion) {
+        let matrix = Matrix4x4::create_rotation(rotation);
+
+        for bone in &mut self.bones {
+            // Only root bones are relative to the origin, children stay relative to their parent.
+            if bone.parent < 0 {
+                bone.local_position = bone.local_position.map(|x| x.transform(&matrix));
+                bone.local_rotation = bone.local_rotation.map(|x| rotation * x);
+            }
+
+            bone.world_position = bone.world_position.map(|x| x.transform(&matrix));
+            bone.world_rotation = bone.world_rotation.map(|x| rotation * x);
+        }
+    }
+
     /// Transforms the skeleton by the given matrix.
     pub fn transform(&mut self, matrix: &Matrix4x4) {
         for bone in &mut self.bones {
@@ -252,18 +288,44 @@ impl Skeleton {
         })
     }
 
-    /// Validates the skeleton has some form of valid data.
-    #[cfg(debug_assertions)]
-    pub fn validate(&self) {
+    /// Renames bones using a table of source name to new name, such as the standard xna lara bone names, returns the number of bones renamed.
+    pub fn rename_bones(&mut self, names: &HashMap<String, String>) -> usize {
+        let mut renamed = 0;
+
+        for bone in &mut self.bones {
+            let Some(name) = bone.name.as_ref().and_then(|name| names.get(name)) else {
+                continue;
+            };
+
+            bone.name = Some(name.clone());
+            renamed += 1;
+        }
+
+        renamed
+    }
+
+    /// Adds the bones of this skeleton to the fbx document as joints, returning the joint hashes by bone index.
+    ///
+    /// Used to bind other objects, such as animation curves, to the same joints a model would export.
+    pub fn to_fbx(
+        &self,
+        document: &mut FbxDocument,
+        up_axis: Axis,
+    ) -> HashMap<usize, FbxPropertyValue> {
+        model_file_type_fbx::initialize_skeleton(document, self, up_axis)
+    }
+
+    /// Validates the skeleton has some form of valid data, adding any problems found to the diagnostics.
+    pub(crate) fn validate(&self, diagnostics: &mut ModelDiagnostics) {
         for (index, bone) in self.bones.iter().enumerate() {
             if bone.parent == -1 || (bone.parent > -1 && bone.parent < self.bones.len() as i32) {
                 continue;
             }
 
-            println!(
-                "Validate Error: Found bone with invalid parent: {} [{}] {:?}",
-                bone.parent, index, bone.name
-            );
+            diagnostics.push(ModelDiagnostic::InvalidBoneParent {
+                bone: index,
+                parent: bone.parent,
+            });
         }
     }
 }
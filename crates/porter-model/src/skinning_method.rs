@@ -1,5 +1,5 @@
 /// The skinning method to use when deforming a mesh.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SkinningMethod {
     /// Linear, the default skinning method.
     Linear,
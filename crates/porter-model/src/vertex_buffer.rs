@@ -82,6 +82,33 @@ impl VertexBuffer {
         self.maximum_influence
     }
 
+    /// Changes the maximum influence per vertex, keeping existing weights and zero filling new ones.
+    pub fn set_maximum_influence(&mut self, maximum_influence: usize) {
+        if maximum_influence == self.maximum_influence {
+            return;
+        }
+
+        let stride = self.stride();
+        let prefix = compute_stride(self.uv_layers, 0, 0);
+
+        let old_weights = std::mem::size_of::<VertexWeight>() * self.maximum_influence;
+        let new_weights = std::mem::size_of::<VertexWeight>() * maximum_influence;
+        let keep = old_weights.min(new_weights);
+
+        let mut buffer = Vec::with_capacity(
+            self.len() * compute_stride(self.uv_layers, maximum_influence, self.colors),
+        );
+
+        for vertex in self.buffer.chunks_exact(stride) {
+            buffer.extend_from_slice(&vertex[..prefix + keep]);
+            buffer.resize(buffer.len() + (new_weights - keep), 0);
+            buffer.extend_from_slice(&vertex[prefix + old_weights..]);
+        }
+
+        self.buffer = buffer;
+        self.maximum_influence = maximum_influence;
+    }
+
     /// Returns the number of color layers.
     pub fn colors(&self) -> usize {
         self.colors
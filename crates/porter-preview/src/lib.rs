@@ -1,22 +1,38 @@
 #![deny(unsafe_code)]
 
+mod preview_bone_label;
 mod preview_camera;
+mod preview_fly_direction;
 mod preview_key_state;
 mod preview_renderer;
+mod preview_shading;
+mod preview_view;
+mod preview_wireframe;
+mod preview_zoom;
+mod render_environment;
 mod render_image;
 mod render_material;
+mod render_material_sphere;
 mod render_material_texture;
 mod render_mesh;
 mod render_model;
 mod render_skeleton;
 mod render_type;
 
+pub use preview_bone_label::*;
+pub use preview_fly_direction::*;
 pub use preview_key_state::*;
 pub use preview_renderer::*;
+pub use preview_shading::*;
+pub use preview_view::*;
+pub use preview_wireframe::*;
+pub use preview_zoom::*;
 
 pub(crate) use preview_camera::*;
+pub(crate) use render_environment::*;
 pub(crate) use render_image::*;
 pub(crate) use render_material::*;
+pub(crate) use render_material_sphere::*;
 pub(crate) use render_material_texture::*;
 pub(crate) use render_mesh::*;
 pub(crate) use render_model::*;
@@ -0,0 +1,8 @@
+/// The name of a bone projected into the preview, relative to the top left.
+#[derive(Debug, Clone)]
+pub struct PreviewBoneLabel {
+    pub name: String,
+    pub x: f32,
+    pub y: f32,
+    pub hovered: bool,
+}
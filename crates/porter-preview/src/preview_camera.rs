@@ -7,10 +7,14 @@ use porter_math::Angles;
 use porter_math::Axis;
 use porter_math::Matrix4x4;
 use porter_math::Quaternion;
+use porter_math::Vector2;
 use porter_math::Vector3;
+use porter_math::Vector4;
 
 use porter_utils::AsByteSlice;
 
+use crate::PreviewShading;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 struct PreviewCameraUniform {
@@ -20,7 +24,7 @@ struct PreviewCameraUniform {
     projection_matrix: Matrix4x4,
     model_matrix: Matrix4x4,
     inverse_model_matrix: Matrix4x4,
-    default_shaded: u32,
+    shading: u32,
 }
 
 /// A 3d preview camera.
@@ -35,6 +39,9 @@ pub struct PreviewCamera {
     uniform_bind_group_layout: BindGroupLayout,
     uniform_bind_group: BindGroup,
     orthographic: Option<(f32, f32, f32)>,
+    orthographic_offset: Vector2,
+    orthographic_projection: bool,
+    shading: PreviewShading,
 }
 
 impl PreviewCamera {
@@ -57,7 +64,7 @@ impl PreviewCamera {
             projection_matrix: Matrix4x4::new(),
             model_matrix,
             inverse_model_matrix: model_matrix.inverse(),
-            default_shaded: 0,
+            shading: 0,
         };
 
         let uniform_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
@@ -102,6 +109,9 @@ impl PreviewCamera {
             uniform_bind_group_layout,
             uniform_bind_group,
             orthographic: None,
+            orthographic_offset: Vector2::zero(),
+            orthographic_projection: false,
+            shading: PreviewShading::Textured,
         }
     }
 
@@ -123,6 +133,26 @@ impl PreviewCamera {
     /// Sets whether or not the camera is in orthographic mode.
     pub fn set_orthographic(&mut self, orthographic: Option<(f32, f32, f32)>) {
         self.orthographic = orthographic;
+        self.orthographic_offset = Vector2::zero();
+    }
+
+    /// Moves the orthographic view by the given offset in screen space.
+    pub fn pan_orthographic(&mut self, x: f32, y: f32) {
+        self.orthographic_offset.x += x;
+        self.orthographic_offset.y += y;
+    }
+
+    /// Returns the screen position of the top left corner of the orthographic view, and it's scale.
+    pub fn orthographic_origin(&self, width: f32, height: f32) -> Option<(Vector2, f32)> {
+        let (o_width, o_height, o_scale) = self.orthographic?;
+
+        Some((
+            Vector2::new(
+                (width - (o_width * o_scale)) / 2.0 + self.orthographic_offset.x,
+                (height - (o_height * o_scale)) / 2.0 + self.orthographic_offset.y,
+            ),
+            o_scale,
+        ))
     }
 
     /// Sets the orthographic scale value.
@@ -132,33 +162,74 @@ impl PreviewCamera {
         }
     }
 
-    /// Toggles the default shaded camera view.
+    /// Projects a model space position to the screen, or none if it's behind the camera.
+    pub fn project(&self, position: Vector3, width: f32, height: f32) -> Option<Vector2> {
+        let clip = Vector4::new(position.x, position.y, position.z, 1.0).transform(
+            &(self.uniforms.projection_matrix
+                * self.uniforms.view_matrix
+                * self.uniforms.model_matrix),
+        );
+
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        Some(Vector2::new(
+            (clip.x / clip.w + 1.0) * 0.5 * width,
+            (1.0 - clip.y / clip.w) * 0.5 * height,
+        ))
+    }
+
+    /// Toggles between a perspective and orthographic projection for models.
+    pub fn toggle_projection(&mut self) {
+        self.orthographic_projection = !self.orthographic_projection;
+    }
+
+    /// Returns true if models are using an orthographic projection.
+    pub fn is_orthographic_projection(&self) -> bool {
+        self.orthographic_projection
+    }
+
+    /// Points the camera from the given theta/phi, keeping the target and radius.
+    pub fn set_view(&mut self, theta: f32, phi: f32) {
+        self.theta = theta;
+        self.phi = phi;
+        self.up = 1.0;
+    }
+
+    /// Cycles to the next shading mode.
     pub fn toggle_shaded(&mut self) {
-        self.uniforms.default_shaded = if self.uniforms.default_shaded == 1 {
-            0
-        } else {
-            1
-        };
+        self.shading = self.shading.next();
+        self.uniforms.shading = self.shading as u32;
+    }
+
+    /// Returns the current shading mode.
+    pub fn shading(&self) -> PreviewShading {
+        self.shading
     }
 
     /// Updates the current uniforms on the gpu.
     pub fn update(&mut self, instance: &GPUInstance, width: f32, height: f32, far_clip: f32) {
-        if let Some((o_width, o_height, o_scale)) = self.orthographic {
+        if let Some((origin, o_scale)) = self.orthographic_origin(width, height) {
             self.uniforms.projection_matrix =
                 Matrix4x4::orthographic(0.0, width, height, 0.0, -1.0, 1.0);
 
-            let center_x = (width - (o_width * o_scale)) / 2.0;
-            let center_y = (height - (o_height * o_scale)) / 2.0;
-
             self.uniforms.view_matrix =
-                Matrix4x4::create_position(Vector3::new(center_x, center_y, 0.0))
+                Matrix4x4::create_position(Vector3::new(origin.x, origin.y, 0.0))
                     * Matrix4x4::create_scale(Vector3::new(o_scale, o_scale, 0.0));
 
             self.uniforms.inverse_view_matrix = self.uniforms.view_matrix.inverse();
             self.uniforms.inverse_model_matrix = self.uniforms.model_matrix.inverse();
         } else {
-            self.uniforms.projection_matrix =
-                Matrix4x4::perspective_fov(65.0, width / height, 0.1, far_clip);
+            self.uniforms.projection_matrix = if self.orthographic_projection {
+                // Match the size of the perspective view at the target so toggling keeps the framing.
+                let top = self.radius * (0.5 * 65.0f32.to_radians()).tan();
+                let right = top * (width / height);
+
+                Matrix4x4::orthographic(-right, right, -top, top, -far_clip, far_clip)
+            } else {
+                Matrix4x4::perspective_fov(65.0, width / height, 0.1, far_clip)
+            };
             self.uniforms.view_matrix = Matrix4x4::look_at(
                 self.camera_position(),
                 self.uniforms.target,
@@ -239,6 +310,25 @@ impl PreviewCamera {
         self.uniforms.target += (right * x) + (up * y);
     }
 
+    /// Rotates the view around the camera position instead of the target.
+    pub fn look(&mut self, theta: f32, phi: f32) {
+        let position = self.camera_position();
+
+        self.rotate(theta, phi);
+
+        self.uniforms.target = position - self.to_cartesian();
+    }
+
+    /// Moves the camera and target along the view direction.
+    pub fn fly(&mut self, forward: f32, right: f32, up: f32) {
+        let look = (self.uniforms.target - self.camera_position()).normalized();
+        let world_up = Vector3::new(0.0, self.up, 0.0);
+
+        let right_axis = look.cross(world_up).normalized();
+
+        self.uniforms.target += (look * forward) + (right_axis * right) + (world_up * up);
+    }
+
     /// Returns the camera position.
     fn camera_position(&self) -> Vector3 {
         self.uniforms.target + self.to_cartesian()
@@ -0,0 +1,10 @@
+/// A direction to move the camera in while flying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewFlyDirection {
+    Forward,
+    Back,
+    Left,
+    Right,
+    Up,
+    Down,
+}
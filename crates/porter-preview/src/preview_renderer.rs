@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use wgpu::util::*;
 use wgpu::*;
 
@@ -11,22 +13,37 @@ use porter_math::Vector3;
 use porter_utils::AsAligned;
 use porter_utils::AsThisSlice;
 
+use porter_texture::format_to_block_dimensions;
+use porter_texture::is_format_compressed;
+use porter_texture::Image;
+use porter_texture::ImageFormat;
+use porter_texture::ImageToneMapping;
+use porter_texture::TextureError;
 use porter_texture::TextureExtensions;
 
+use crate::PreviewBoneLabel;
 use crate::PreviewCamera;
+use crate::PreviewFlyDirection;
 use crate::PreviewKeyState;
+use crate::PreviewView;
+use crate::PreviewWireframe;
+use crate::PreviewZoom;
 use crate::RenderType;
 use crate::ToRenderType;
 
 /// Renders 'preview' versions of models, animations, images, and materials.
 pub struct PreviewRenderer {
     instance: &'static GPUInstance,
-    wireframe: bool,
+    wireframe: PreviewWireframe,
     show_bones: bool,
+    show_bone_names: bool,
+    skeleton_only: bool,
     show_grid: bool,
     width: f32,
     height: f32,
     far_clip: f32,
+    tone_mapping: ImageToneMapping,
+    exposure: f32,
     output_texture: Texture,
     output_texture_view: TextureView,
     output_buffer: Buffer,
@@ -41,11 +58,27 @@ pub struct PreviewRenderer {
     render_name: Option<String>,
     camera: PreviewCamera,
     scale: u32,
+    pixel_probe: bool,
+    probe: Option<(u32, u32, [f32; 4])>,
+    hovered_bone: Option<usize>,
+    fly: bool,
+    fly_speed: f32,
+    fly_directions: Vec<PreviewFlyDirection>,
+    fly_last: Option<Instant>,
 }
 
 /// The minimum preview size.
 const MIN_SIZE: u32 = 256;
 
+/// The maximum zoom percentage for images.
+const MAX_SCALE: u32 = 1600;
+
+/// The distance in pixels from a bone for it to be hovered.
+const BONE_HOVER_DISTANCE: f32 = 12.0;
+
+/// The default fly camera speed in units per second.
+const FLY_SPEED: f32 = 50.0;
+
 /// The size of the grid.
 const GRID_SIZE: f32 = 120.0;
 /// The size of each subdivision.
@@ -248,12 +281,16 @@ impl PreviewRenderer {
 
         Self {
             instance,
-            wireframe: false,
+            wireframe: PreviewWireframe::Off,
             show_bones: true,
+            show_bone_names: false,
+            skeleton_only: false,
             show_grid: true,
             width: MIN_SIZE as f32,
             height: MIN_SIZE as f32,
             far_clip: 10000.0,
+            tone_mapping: ImageToneMapping::None,
+            exposure: 0.0,
             output_texture_view: output_texture.create_view(&Default::default()),
             output_texture,
             output_buffer,
@@ -268,6 +305,13 @@ impl PreviewRenderer {
             render_name: None,
             camera,
             scale: 100,
+            pixel_probe: false,
+            probe: None,
+            hovered_bone: None,
+            fly: false,
+            fly_speed: FLY_SPEED,
+            fly_directions: Vec::new(),
+            fly_last: None,
         }
     }
 
@@ -279,6 +323,8 @@ impl PreviewRenderer {
         match &render {
             RenderType::Model(_) => self.camera.set_orthographic(None),
             RenderType::Image(image) => {
+                image.set_tone_mapping(self.instance, self.tone_mapping, self.exposure);
+
                 let scale =
                     (self.width / image.width() as f32).min(self.height / image.height() as f32);
 
@@ -309,12 +355,17 @@ impl PreviewRenderer {
 
         self.render = Some(render);
         self.render_name = Some(name);
+        self.probe = None;
+        self.hovered_bone = None;
     }
 
     /// Clears the asset being previewed.
     pub fn clear_preview(&mut self) {
         self.render = None;
         self.render_name = None;
+        self.probe = None;
+        self.hovered_bone = None;
+        self.fly = false;
     }
 
     /// Resizes the renderer output.
@@ -353,25 +404,132 @@ impl PreviewRenderer {
         if let Some(RenderType::Material(material)) = &mut self.render {
             material.next();
 
-            let scale =
-                (self.width / material.width() as f32).min(self.height / material.height() as f32);
+            if material.is_sphere() {
+                return;
+            }
 
-            self.scale = 100.min((scale * 100.0) as u32);
+            self.fit_material();
+        }
+    }
+
+    /// Toggles between the material images and a sphere lit by the studio environment.
+    pub fn toggle_material_sphere(&mut self) {
+        let Some(RenderType::Material(material)) = &mut self.render else {
+            return;
+        };
 
-            self.camera.set_orthographic(Some((
-                material.width() as f32,
-                material.height() as f32,
-                self.scale as f32 / 100.0,
-            )));
+        material.toggle_sphere();
 
-            self.camera
-                .update(self.instance, self.width, self.height, self.far_clip);
+        if material.is_sphere() {
+            self.camera.set_orthographic(None);
+            self.reset_view();
+        } else {
+            self.fit_material();
         }
     }
 
-    /// Toggles the wireframe view.
+    /// Fits the current material image in the preview.
+    fn fit_material(&mut self) {
+        let Some(RenderType::Material(material)) = &self.render else {
+            return;
+        };
+
+        let scale =
+            (self.width / material.width() as f32).min(self.height / material.height() as f32);
+
+        self.scale = 100.min((scale * 100.0) as u32);
+
+        self.camera.set_orthographic(Some((
+            material.width() as f32,
+            material.height() as f32,
+            self.scale as f32 / 100.0,
+        )));
+
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+    }
+
+    /// Shows the next or previous cubemap face, or volume and array slice.
+    pub fn cycle_slice(&mut self, forward: bool) {
+        if let Some((slice, slices)) = self.slices() {
+            let slice = if forward {
+                (slice + 1) % slices
+            } else {
+                (slice + slices - 1) % slices
+            };
+
+            self.set_slice(slice);
+        }
+    }
+
+    /// Shows the given cubemap face, or volume and array slice.
+    pub fn set_slice(&mut self, slice: usize) {
+        if let Some(RenderType::Image(image)) = &mut self.render {
+            image.set_layer(slice);
+
+            self.fit_image();
+        }
+    }
+
+    /// Sets the tone mapping and exposure in stops used to show high dynamic range images.
+    pub fn set_tone_mapping(&mut self, tone_mapping: ImageToneMapping, exposure: f32) {
+        self.tone_mapping = tone_mapping;
+        self.exposure = exposure;
+
+        if let Some(RenderType::Image(image)) = &self.render {
+            image.set_tone_mapping(self.instance, tone_mapping, exposure);
+        }
+    }
+
+    /// Toggles the unfolded cross view for cubemaps.
+    pub fn toggle_cube_cross(&mut self) {
+        if let Some(RenderType::Image(image)) = &mut self.render {
+            image.toggle_cross();
+
+            self.fit_image();
+        }
+    }
+
+    /// Returns the current slice and the number of slices, when previewing an image with more than one.
+    pub fn slices(&self) -> Option<(usize, usize)> {
+        match &self.render {
+            Some(RenderType::Image(image)) if image.layers() > 1 => {
+                Some((image.layer(), image.layers()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Fits the image being previewed after it's displayed size changes.
+    fn fit_image(&mut self) {
+        let Some(RenderType::Image(image)) = &self.render else {
+            return;
+        };
+
+        let scale = (self.width / image.width() as f32).min(self.height / image.height() as f32);
+
+        self.scale = 100.min((scale * 100.0) as u32).max(1);
+
+        self.camera.set_orthographic(Some((
+            image.width() as f32,
+            image.height() as f32,
+            self.scale as f32 / 100.0,
+        )));
+
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+
+        self.probe = None;
+    }
+
+    /// Returns the zoom percentage when previewing an image or material.
+    pub fn zoom(&self) -> Option<u32> {
+        self.camera.is_orthographic().then_some(self.scale)
+    }
+
+    /// Cycles between the shaded, wireframe, and wireframe over shaded views.
     pub fn toggle_wireframe(&mut self) {
-        self.wireframe = !self.wireframe;
+        self.wireframe = self.wireframe.next();
     }
 
     /// Toggles the bone view.
@@ -379,12 +537,181 @@ impl PreviewRenderer {
         self.show_bones = !self.show_bones;
     }
 
+    /// Toggles the bone name labels.
+    pub fn toggle_bone_names(&mut self) {
+        self.show_bone_names = !self.show_bone_names;
+    }
+
+    /// Toggles only rendering the skeleton of a model.
+    pub fn toggle_skeleton_only(&mut self) {
+        self.skeleton_only = !self.skeleton_only;
+    }
+
+    /// Toggles the first person fly camera for models.
+    pub fn toggle_fly(&mut self) {
+        self.fly = !self.fly && !self.camera.is_orthographic();
+        self.fly_directions.clear();
+        self.fly_last = None;
+    }
+
+    /// Whether or not the fly camera is active.
+    pub fn is_flying(&self) -> bool {
+        self.fly
+    }
+
+    /// Starts or stops moving the fly camera in the given direction.
+    pub fn set_fly_direction(&mut self, direction: PreviewFlyDirection, active: bool) {
+        self.fly_directions
+            .retain(|existing| *existing != direction);
+
+        if active {
+            self.fly_directions.push(direction);
+        }
+    }
+
+    /// Moves the fly camera by the time elapsed since the last update.
+    pub fn fly_update(&mut self) {
+        let now = Instant::now();
+
+        // Cap the step so a stalled frame doesn't teleport the camera.
+        let elapsed = self
+            .fly_last
+            .replace(now)
+            .map(|last| (now - last).as_secs_f32().min(0.1))
+            .unwrap_or_default();
+
+        if !self.fly || self.fly_directions.is_empty() || elapsed <= 0.0 {
+            return;
+        }
+
+        let distance = self.fly_speed * elapsed;
+
+        let (mut forward, mut right, mut up) = (0.0, 0.0, 0.0);
+
+        for direction in &self.fly_directions {
+            match direction {
+                PreviewFlyDirection::Forward => forward += distance,
+                PreviewFlyDirection::Back => forward -= distance,
+                PreviewFlyDirection::Left => right -= distance,
+                PreviewFlyDirection::Right => right += distance,
+                PreviewFlyDirection::Up => up += distance,
+                PreviewFlyDirection::Down => up -= distance,
+            }
+        }
+
+        self.camera.fly(forward, right, up);
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+    }
+
     /// Toggles the grid view.
     pub fn toggle_grid(&mut self) {
         self.show_grid = !self.show_grid;
     }
 
-    /// Toggles the shaded view.
+    /// Toggles the pixel inspector for images.
+    pub fn toggle_pixel_probe(&mut self) {
+        self.pixel_probe = !self.pixel_probe;
+        self.probe = None;
+    }
+
+    /// Inspects the image pixel under the given position, relative to the top left of the preview.
+    pub fn probe_pixel(&mut self, x: f32, y: f32) {
+        self.probe = None;
+
+        if !self.pixel_probe || x < 0.0 || y < 0.0 || x >= self.width || y >= self.height {
+            return;
+        }
+
+        let Some(RenderType::Image(image)) = &mut self.render else {
+            return;
+        };
+
+        let Some((origin, scale)) = self.camera.orthographic_origin(self.width, self.height) else {
+            return;
+        };
+
+        if scale <= 0.0 {
+            return;
+        }
+
+        let pixel_x = ((x - origin.x) / scale).floor();
+        let pixel_y = ((y - origin.y) / scale).floor();
+
+        if pixel_x < 0.0 || pixel_y < 0.0 {
+            return;
+        }
+
+        let (pixel_x, pixel_y) = (pixel_x as u32, pixel_y as u32);
+
+        if let Some(color) = image.pixel(pixel_x, pixel_y) {
+            self.probe = Some((pixel_x, pixel_y, color));
+        }
+    }
+
+    /// Highlights the bone closest to the given position, relative to the top left of the preview.
+    pub fn hover_bone(&mut self, x: f32, y: f32) {
+        if x < 0.0 || y < 0.0 || x >= self.width || y >= self.height {
+            self.hovered_bone = None;
+            return;
+        }
+
+        self.hovered_bone = self
+            .projected_bones()
+            .map(|(index, _, position)| {
+                (
+                    index,
+                    ((position.x - x).powi(2) + (position.y - y).powi(2)).sqrt(),
+                )
+            })
+            .filter(|(_, distance)| *distance < BONE_HOVER_DISTANCE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _)| index);
+    }
+
+    /// Returns the bone labels to draw over the preview.
+    pub fn bone_labels(&self) -> Vec<PreviewBoneLabel> {
+        self.projected_bones()
+            .filter_map(|(index, name, position)| {
+                let hovered = self.hovered_bone == Some(index);
+
+                (self.show_bone_names || hovered).then(|| PreviewBoneLabel {
+                    name: name.to_string(),
+                    x: position.x,
+                    y: position.y,
+                    hovered,
+                })
+            })
+            .collect()
+    }
+
+    /// Projects each visible bone of the model being previewed to the screen.
+    fn projected_bones(&self) -> impl Iterator<Item = (usize, &str, Vector2)> + '_ {
+        let bones = match &self.render {
+            Some(RenderType::Model(model))
+                if self.show_bones || model.is_skeleton_only(self.skeleton_only) =>
+            {
+                model.skeleton().map(|skeleton| skeleton.bones.as_slice())
+            }
+            _ => None,
+        };
+
+        bones
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (name, position))| {
+                let position = self.camera.project(*position, self.width, self.height)?;
+
+                (position.x >= 0.0
+                    && position.y >= 0.0
+                    && position.x < self.width
+                    && position.y < self.height)
+                    .then_some((index, name.as_str(), position))
+            })
+    }
+
+    /// Cycles between the textured, shaded, normals, and matcap views.
     pub fn toggle_shaded(&mut self) {
         self.camera.toggle_shaded();
         self.camera
@@ -405,19 +732,92 @@ impl PreviewRenderer {
         }
     }
 
-    /// Performs a scrolling operation.
-    pub fn scroll_delta(&mut self, delta: f32) {
+    /// Points the camera at the model from a preset direction.
+    pub fn set_view(&mut self, view: PreviewView) {
         if self.camera.is_orthographic() {
+            return;
+        }
+
+        // Looking straight up or down is nudged slightly off axis, so the view has a valid up direction.
+        let (theta, phi) = match view {
+            PreviewView::Front => (0.5 * std::f32::consts::PI, 0.5 * std::f32::consts::PI),
+            PreviewView::Back => (-0.5 * std::f32::consts::PI, 0.5 * std::f32::consts::PI),
+            PreviewView::Left => (0.0, 0.5 * std::f32::consts::PI),
+            PreviewView::Right => (std::f32::consts::PI, 0.5 * std::f32::consts::PI),
+            PreviewView::Top => (0.5 * std::f32::consts::PI, 0.0001),
+            PreviewView::Bottom => (0.5 * std::f32::consts::PI, std::f32::consts::PI - 0.0001),
+        };
+
+        self.camera.set_view(theta, phi);
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+    }
+
+    /// Toggles between a perspective and orthographic projection for models.
+    pub fn toggle_projection(&mut self) {
+        self.camera.toggle_projection();
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+    }
+
+    /// Applies a zoom preset to the image or material being previewed.
+    pub fn set_zoom(&mut self, zoom: PreviewZoom) {
+        let (width, height) = match &self.render {
+            Some(RenderType::Image(image)) => (image.width() as f32, image.height() as f32),
+            Some(RenderType::Material(material)) => {
+                (material.width() as f32, material.height() as f32)
+            }
+            _ => return,
+        };
+
+        let scale = match zoom {
+            PreviewZoom::Actual => 1.0,
+            PreviewZoom::Fit => (self.width / width).min(self.height / height),
+            PreviewZoom::Fill => (self.width / width).max(self.height / height),
+        };
+
+        self.scale = ((scale * 100.0) as u32).clamp(1, MAX_SCALE);
+
+        self.camera
+            .set_orthographic(Some((width, height, self.scale as f32 / 100.0)));
+        self.camera
+            .update(self.instance, self.width, self.height, self.far_clip);
+    }
+
+    /// Performs a scrolling operation, images are zoomed towards the given position.
+    pub fn scroll_delta<P: Into<Vector2>>(&mut self, delta: f32, position: P) {
+        if let Some((origin, scale)) = self.camera.orthographic_origin(self.width, self.height) {
+            let position = position.into();
+
+            // Step proportionally so large zoom levels don't take forever to reach.
+            let step = (self.scale / 10).max(3);
+
             if delta > 0.0 {
-                self.scale = self.scale.wrapping_add(3);
+                self.scale = self.scale.saturating_add(step);
             } else {
-                self.scale = self.scale.wrapping_sub(3);
+                self.scale = self.scale.saturating_sub(step);
             }
 
-            self.scale = (self.scale as i32).clamp(0, 200) as u32;
+            self.scale = self.scale.clamp(1, MAX_SCALE);
 
-            self.camera
-                .set_orthographic_scale(self.scale as f32 / 100.0);
+            let new_scale = self.scale as f32 / 100.0;
+
+            self.camera.set_orthographic_scale(new_scale);
+
+            // Keep the point under the cursor in place after scaling.
+            if let Some((new_origin, _)) = self
+                .camera
+                .orthographic_origin(self.width, self.height)
+                .filter(|_| scale > 0.0)
+            {
+                let target_x = position.x - ((position.x - origin.x) / scale) * new_scale;
+                let target_y = position.y - ((position.y - origin.y) / scale) * new_scale;
+
+                self.camera
+                    .pan_orthographic(target_x - new_origin.x, target_y - new_origin.y);
+            }
+        } else if self.fly {
+            self.fly_speed = (self.fly_speed * 1.1f32.powf(delta.signum())).clamp(1.0, 5000.0);
         } else {
             self.camera.zoom(delta * 0.5);
         }
@@ -430,6 +830,26 @@ impl PreviewRenderer {
     pub fn mouse_move<D: Into<Vector2>>(&mut self, delta: D, key_state: PreviewKeyState) {
         let delta = delta.into();
 
+        if self.camera.is_orthographic() {
+            if key_state.left || key_state.middle {
+                self.camera.pan_orthographic(-delta.x, -delta.y);
+                self.camera
+                    .update(self.instance, self.width, self.height, self.far_clip);
+            }
+
+            return;
+        }
+
+        if self.fly {
+            if key_state.left || key_state.right {
+                self.camera.look(delta.x / 400.0, delta.y / 400.0);
+                self.camera
+                    .update(self.instance, self.width, self.height, self.far_clip);
+            }
+
+            return;
+        }
+
         if key_state.maya && !key_state.alt {
             return;
         }
@@ -490,20 +910,89 @@ impl PreviewRenderer {
                     (String::from("Verts"), model.vertex_count().to_string()),
                     (String::from("Tris"), model.face_count().to_string()),
                     (String::from("Bones"), model.bone_count().to_string()),
+                    (String::from("Shading"), self.camera.shading().to_string()),
+                    (String::from("Wireframe"), self.wireframe.to_string()),
+                    (
+                        String::from("Camera"),
+                        if self.fly {
+                            format!("Fly ({:.0}/s)", self.fly_speed)
+                        } else {
+                            String::from("Orbit")
+                        },
+                    ),
+                    (
+                        String::from("View"),
+                        String::from(if self.camera.is_orthographic_projection() {
+                            "Orthographic"
+                        } else {
+                            "Perspective"
+                        }),
+                    ),
+                    (
+                        String::from("Bone"),
+                        self.hovered_bone
+                            .zip(model.skeleton())
+                            .and_then(|(index, skeleton)| skeleton.bones.get(index))
+                            .map(|(name, _)| name.clone())
+                            .unwrap_or_else(|| String::from("N/A")),
+                    ),
                 ]
             }
             Some(RenderType::Image(image)) => {
-                vec![
+                let mut statistics = vec![
                     (
                         String::from("Name"),
                         self.render_name
                             .clone()
                             .unwrap_or_else(|| String::from("N/A")),
                     ),
-                    (String::from("Width"), image.width().to_string()),
-                    (String::from("Height"), image.height().to_string()),
+                    (String::from("Width"), image.frame_width().to_string()),
+                    (String::from("Height"), image.frame_height().to_string()),
                     (String::from("Scale"), format!("{}%", self.scale)),
-                ]
+                ];
+
+                if image.layers() > 1 {
+                    statistics.push((
+                        String::from(if image.is_cubemap() { "Face" } else { "Slice" }),
+                        image.layer_name(),
+                    ));
+                }
+
+                if let Some((x, y, color)) = self.probe {
+                    let format = image.format();
+
+                    statistics.push((String::from("Pixel"), format!("{}, {}", x, y)));
+                    statistics.push((
+                        String::from("RGBA"),
+                        color
+                            .map(|channel| {
+                                ((channel.clamp(0.0, 1.0) * 255.0).round() as u8).to_string()
+                            })
+                            .join(", "),
+                    ));
+                    statistics.push((
+                        String::from("Float"),
+                        color.map(|channel| format!("{:.4}", channel)).join(", "),
+                    ));
+
+                    if is_format_compressed(format) {
+                        let (block_width, block_height) = format_to_block_dimensions(format);
+
+                        statistics.push((
+                            String::from("Format"),
+                            format!(
+                                "{:?} (block {}, {})",
+                                format,
+                                x / block_width,
+                                y / block_height
+                            ),
+                        ));
+                    } else {
+                        statistics.push((String::from("Format"), format!("{:?}", format)));
+                    }
+                }
+
+                statistics
             }
             Some(RenderType::Material(material)) => {
                 vec![
@@ -525,12 +1014,59 @@ impl PreviewRenderer {
                     (String::from("Width"), material.width().to_string()),
                     (String::from("Height"), material.height().to_string()),
                     (String::from("Scale"), format!("{}%", self.scale)),
+                    (
+                        String::from("View"),
+                        String::from(if material.is_sphere() {
+                            "Sphere"
+                        } else {
+                            "Image"
+                        }),
+                    ),
                 ]
             }
             _ => vec![(String::from("Name"), String::from("N/A"))],
         }
     }
 
+    /// Returns the name of the asset being previewed.
+    pub fn render_name(&self) -> Option<&str> {
+        self.render_name.as_deref()
+    }
+
+    /// Renders the current view into an image.
+    pub fn capture(&self) -> Result<Image, TextureError> {
+        let (width, height, pixels) = self.render();
+
+        let mut image = Image::new(width, height, ImageFormat::R8G8B8A8Unorm)?;
+        let frame = image.create_frame()?;
+
+        let size = frame.buffer().len().min(pixels.len());
+
+        frame.buffer_mut()[..size].copy_from_slice(&pixels[..size]);
+
+        Ok(image)
+    }
+
+    /// Renders the given number of frames of a full rotation around the model being previewed.
+    pub fn capture_turntable(&mut self, frames: u32) -> Result<Vec<Image>, TextureError> {
+        if !matches!(self.render, Some(RenderType::Model(_))) || frames == 0 {
+            return Ok(Vec::new());
+        }
+
+        let step = std::f32::consts::TAU / frames as f32;
+        let mut result = Vec::with_capacity(frames as usize);
+
+        for _ in 0..frames {
+            result.push(self.capture()?);
+
+            self.camera.rotate(step, 0.0);
+            self.camera
+                .update(self.instance, self.width, self.height, self.far_clip);
+        }
+
+        Ok(result)
+    }
+
     // Get the rendered output.
     pub fn render(&self) -> (u32, u32, Vec<u8>) {
         let mut encoder = self
@@ -579,7 +1115,12 @@ impl PreviewRenderer {
             Some(RenderType::Model(model)) => {
                 draw_grid();
 
-                model.draw(&mut render_pass, self.show_bones, self.wireframe);
+                model.draw(
+                    &mut render_pass,
+                    self.show_bones,
+                    self.skeleton_only,
+                    self.wireframe,
+                );
             }
             Some(RenderType::Image(image)) => {
                 image.draw(&mut render_pass);
@@ -0,0 +1,37 @@
+use std::fmt;
+
+/// Shading modes for model previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewShading {
+    /// Lit with the material albedo and normal map.
+    Textured,
+    /// Lit with a default grey material.
+    Shaded,
+    /// Visualizes the world space normals.
+    Normals,
+    /// Lit with a clay matcap.
+    Matcap,
+}
+
+impl PreviewShading {
+    /// Returns the next shading mode in the cycle.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Textured => Self::Shaded,
+            Self::Shaded => Self::Normals,
+            Self::Normals => Self::Matcap,
+            Self::Matcap => Self::Textured,
+        }
+    }
+}
+
+impl fmt::Display for PreviewShading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreviewShading::Textured => write!(f, "Textured"),
+            PreviewShading::Shaded => write!(f, "Shaded"),
+            PreviewShading::Normals => write!(f, "Normals"),
+            PreviewShading::Matcap => write!(f, "Matcap"),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+/// Preset directions to view model previews from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewView {
+    /// Looking at the front of the model.
+    Front,
+    /// Looking at the back of the model.
+    Back,
+    /// Looking at the left side of the model.
+    Left,
+    /// Looking at the right side of the model.
+    Right,
+    /// Looking down at the top of the model.
+    Top,
+    /// Looking up at the bottom of the model.
+    Bottom,
+}
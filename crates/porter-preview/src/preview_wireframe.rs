@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Wireframe modes for model previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewWireframe {
+    /// Only the shaded mesh is drawn.
+    Off,
+    /// Only the wireframe is drawn.
+    Wireframe,
+    /// The wireframe is drawn over the shaded mesh.
+    Overlay,
+}
+
+impl PreviewWireframe {
+    /// Returns the next wireframe mode in the cycle.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Wireframe,
+            Self::Wireframe => Self::Overlay,
+            Self::Overlay => Self::Off,
+        }
+    }
+}
+
+impl fmt::Display for PreviewWireframe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PreviewWireframe::Off => write!(f, "Off"),
+            PreviewWireframe::Wireframe => write!(f, "Wireframe"),
+            PreviewWireframe::Overlay => write!(f, "Overlay"),
+        }
+    }
+}
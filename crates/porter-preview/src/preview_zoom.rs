@@ -0,0 +1,10 @@
+/// Zoom presets for image and material previews.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewZoom {
+    /// One image pixel per preview pixel.
+    Actual,
+    /// Scales the image to fit entirely inside of the preview.
+    Fit,
+    /// Scales the image to fill the entire preview, cropping the edges.
+    Fill,
+}
@@ -0,0 +1,312 @@
+use std::f32::consts::PI;
+use std::f32::consts::TAU;
+use std::sync::OnceLock;
+
+use wgpu::util::*;
+use wgpu::*;
+
+use porter_gpu::GPUInstance;
+use porter_math::Vector3;
+use porter_utils::AsThisSlice;
+
+/// The width of the environment map.
+const ENVIRONMENT_WIDTH: u32 = 64;
+/// The height of the environment map.
+const ENVIRONMENT_HEIGHT: u32 = 32;
+/// The number of prefiltered roughness levels, followed by one irradiance level.
+const ENVIRONMENT_LEVELS: u32 = 6;
+/// The size of the brdf lookup table.
+const BRDF_SIZE: u32 = 32;
+/// The number of samples used to integrate each brdf lookup table texel.
+const BRDF_SAMPLES: u32 = 128;
+
+/// The prefiltered environment and brdf lookup table, computed once.
+struct EnvironmentData {
+    environment: Vec<f32>,
+    brdf: Vec<f32>,
+}
+
+/// Global environment data.
+static ENVIRONMENT_DATA: OnceLock<EnvironmentData> = OnceLock::new();
+
+/// Image based lighting resources used to shade materials.
+pub struct RenderEnvironment {
+    bind_group: BindGroup,
+    bind_group_layout: BindGroupLayout,
+}
+
+impl RenderEnvironment {
+    /// Uploads the prefiltered studio environment and brdf lookup table.
+    pub fn new(instance: &GPUInstance) -> Self {
+        let data = ENVIRONMENT_DATA.get_or_init(|| EnvironmentData {
+            environment: prefilter_environment(),
+            brdf: integrate_brdf(),
+        });
+
+        let environment = instance.device().create_texture_with_data(
+            instance.queue(),
+            &TextureDescriptor {
+                label: None,
+                size: Extent3d {
+                    width: ENVIRONMENT_WIDTH,
+                    height: ENVIRONMENT_HEIGHT,
+                    depth_or_array_layers: ENVIRONMENT_LEVELS + 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Rgba32Float,
+                usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            TextureDataOrder::LayerMajor,
+            data.environment.as_slice().as_this_slice(),
+        );
+
+        let brdf = instance.device().create_texture_with_data(
+            instance.queue(),
+            &TextureDescriptor {
+                label: None,
+                size: Extent3d {
+                    width: BRDF_SIZE,
+                    height: BRDF_SIZE,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Rg32Float,
+                usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+                view_formats: &[],
+            },
+            TextureDataOrder::LayerMajor,
+            data.brdf.as_slice().as_this_slice(),
+        );
+
+        let environment_view = environment.create_view(&TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::D2Array),
+            ..Default::default()
+        });
+
+        let brdf_view = brdf.create_view(&Default::default());
+
+        let sampler = instance.device().create_sampler(&SamplerDescriptor {
+            address_mode_u: AddressMode::Repeat,
+            address_mode_v: AddressMode::ClampToEdge,
+            address_mode_w: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let bind_group_layout =
+            instance
+                .device()
+                .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                    label: None,
+                    entries: &[
+                        BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Texture {
+                                sample_type: TextureSampleType::Float { filterable: true },
+                                view_dimension: TextureViewDimension::D2Array,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Texture {
+                                sample_type: TextureSampleType::Float { filterable: true },
+                                view_dimension: TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
+                        BindGroupLayoutEntry {
+                            binding: 2,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                            count: None,
+                        },
+                    ],
+                });
+
+        let bind_group = instance.device().create_bind_group(&BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&environment_view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::TextureView(&brdf_view),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::Sampler(&sampler),
+                },
+            ],
+        });
+
+        Self {
+            bind_group,
+            bind_group_layout,
+        }
+    }
+
+    /// The bind group for this environment.
+    pub fn bind_group(&self) -> &BindGroup {
+        &self.bind_group
+    }
+
+    /// The bind group layout for this environment.
+    pub fn bind_group_layout(&self) -> &BindGroupLayout {
+        &self.bind_group_layout
+    }
+}
+
+/// Converts an equirectangular texel to a direction.
+fn texel_direction(x: u32, y: u32) -> Vector3 {
+    let phi = ((x as f32 + 0.5) / ENVIRONMENT_WIDTH as f32 - 0.5) * TAU;
+    let theta = ((y as f32 + 0.5) / ENVIRONMENT_HEIGHT as f32) * PI;
+
+    Vector3::new(
+        theta.sin() * phi.sin(),
+        theta.cos(),
+        -theta.sin() * phi.cos(),
+    )
+}
+
+/// A procedural studio environment with a warm key light and a cool fill light.
+fn studio_radiance(direction: Vector3) -> Vector3 {
+    let horizon = Vector3::new(0.55, 0.55, 0.6);
+
+    let mut radiance = if direction.y >= 0.0 {
+        horizon.lerp(Vector3::new(0.25, 0.32, 0.45), direction.y)
+    } else {
+        (horizon * 0.5).lerp(Vector3::new(0.08, 0.07, 0.06), -direction.y)
+    };
+
+    if direction.dot(Vector3::new(-0.5, 0.6, 0.6).normalized()) > 0.97 {
+        radiance += Vector3::new(12.0, 11.0, 10.0);
+    }
+
+    if direction.dot(Vector3::new(0.7, 0.3, 0.4).normalized()) > 0.96 {
+        radiance += Vector3::new(2.5, 2.8, 3.2);
+    }
+
+    radiance
+}
+
+/// Prefilters the environment for each roughness level with a ggx lobe, followed by the irradiance.
+fn prefilter_environment() -> Vec<f32> {
+    let texels: Vec<(Vector3, Vector3, f32)> = (0..ENVIRONMENT_HEIGHT)
+        .flat_map(|y| (0..ENVIRONMENT_WIDTH).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let direction = texel_direction(x, y);
+            let theta = ((y as f32 + 0.5) / ENVIRONMENT_HEIGHT as f32) * PI;
+            let solid_angle =
+                (TAU / ENVIRONMENT_WIDTH as f32) * (PI / ENVIRONMENT_HEIGHT as f32) * theta.sin();
+
+            (direction, studio_radiance(direction), solid_angle)
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(texels.len() * 4 * (ENVIRONMENT_LEVELS as usize + 1));
+
+    for level in 0..=ENVIRONMENT_LEVELS {
+        let roughness = level as f32 / (ENVIRONMENT_LEVELS - 1) as f32;
+        let alpha_squared = (roughness * roughness).powi(2);
+
+        for (normal, radiance, _) in &texels {
+            let (color, weight) = if level == 0 {
+                (*radiance, 1.0)
+            } else {
+                texels.iter().fold(
+                    (Vector3::zero(), 0.0),
+                    |(color, weight), (direction, radiance, solid_angle)| {
+                        let cosine = normal.dot(*direction);
+
+                        if cosine <= 0.0 {
+                            return (color, weight);
+                        }
+
+                        // The last level is the cosine weighted irradiance.
+                        let distribution = if level == ENVIRONMENT_LEVELS {
+                            1.0
+                        } else {
+                            let denominator = cosine * cosine * (alpha_squared - 1.0) + 1.0;
+
+                            alpha_squared / (PI * denominator * denominator)
+                        };
+
+                        let sample = distribution * cosine * solid_angle;
+
+                        (color + (*radiance * sample), weight + sample)
+                    },
+                )
+            };
+
+            let color = color / weight.max(f32::EPSILON);
+
+            result.extend([color.x, color.y, color.z, 1.0]);
+        }
+    }
+
+    result
+}
+
+/// Integrates the split sum brdf scale and bias for each view angle and roughness.
+fn integrate_brdf() -> Vec<f32> {
+    let mut result = Vec::with_capacity((BRDF_SIZE * BRDF_SIZE * 2) as usize);
+
+    for y in 0..BRDF_SIZE {
+        let roughness = (y as f32 + 0.5) / BRDF_SIZE as f32;
+        let alpha = roughness * roughness;
+        let k = alpha / 2.0;
+
+        for x in 0..BRDF_SIZE {
+            let n_dot_v = (x as f32 + 0.5) / BRDF_SIZE as f32;
+            let view = Vector3::new((1.0 - n_dot_v * n_dot_v).sqrt(), 0.0, n_dot_v);
+
+            let (mut scale, mut bias) = (0.0, 0.0);
+
+            for i in 0..BRDF_SAMPLES {
+                let u = i as f32 / BRDF_SAMPLES as f32;
+                let v = i.reverse_bits() as f32 / u32::MAX as f32;
+
+                let phi = TAU * u;
+                let cos_theta = ((1.0 - v) / (1.0 + (alpha * alpha - 1.0) * v)).sqrt();
+                let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+
+                let half = Vector3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta);
+                let light = (half * (2.0 * view.dot(half))) - view;
+
+                let n_dot_l = light.z;
+                let n_dot_h = half.z;
+                let v_dot_h = view.dot(half).max(0.0);
+
+                if n_dot_l <= 0.0 {
+                    continue;
+                }
+
+                let geometry =
+                    (n_dot_v / (n_dot_v * (1.0 - k) + k)) * (n_dot_l / (n_dot_l * (1.0 - k) + k));
+                let visibility = geometry * v_dot_h / (n_dot_h * n_dot_v);
+                let fresnel = (1.0 - v_dot_h).powi(5);
+
+                scale += (1.0 - fresnel) * visibility;
+                bias += fresnel * visibility;
+            }
+
+            result.extend([scale / BRDF_SAMPLES as f32, bias / BRDF_SAMPLES as f32]);
+        }
+    }
+
+    result
+}
@@ -5,17 +5,57 @@ use porter_gpu::GPUInstance;
 use porter_math::Vector2;
 use porter_math::Vector3;
 use porter_texture::format_to_wgpu;
+use porter_texture::is_format_hdr;
 use porter_texture::Image;
+use porter_texture::ImageConvertOptions;
+use porter_texture::ImageFormat;
+use porter_texture::ImageToneMapping;
 use porter_utils::AsByteSlice;
 use porter_utils::AsThisSlice;
 
-/// A 3d render image.
+/// The names of each cubemap face, in frame order.
+const CUBEMAP_FACES: [&str; 6] = ["+X", "-X", "+Y", "-Y", "+Z", "-Z"];
+
+/// The column and row of each cubemap face in the unfolded cross layout.
+const CUBEMAP_CROSS: [(u32, u32); 6] = [(2, 1), (0, 1), (1, 0), (1, 2), (1, 1), (3, 1)];
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct RenderImageUniform {
+    tone_mapping: u32,
+    exposure: f32,
+    padding: [u32; 2],
+}
+
+/// Utility to append a textured quad to the vertex buffer.
+fn push_quad(vertex_buffer: &mut Vec<u8>, left: f32, top: f32, right: f32, bottom: f32) {
+    vertex_buffer.extend_from_slice(Vector3::new(left, top, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(0.0, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector3::new(right, top, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(1.0, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector3::new(right, bottom, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(1.0, 1.0).as_byte_slice());
+
+    vertex_buffer.extend_from_slice(Vector3::new(left, top, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(0.0, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector3::new(right, bottom, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(1.0, 1.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector3::new(left, bottom, 0.0).as_byte_slice());
+    vertex_buffer.extend_from_slice(Vector2::new(0.0, 1.0).as_byte_slice());
+}
+
+/// A 3d render image, cubemaps and volume or array textures upload each frame as a layer.
 pub struct RenderImage {
-    bind_group: BindGroup,
+    bind_groups: Vec<BindGroup>,
+    uniform_buffer: Buffer,
     render_pipeline: RenderPipeline,
     vertex_buffer: Buffer,
     width: u32,
     height: u32,
+    layer: usize,
+    cross: bool,
+    source: Image,
+    pixels: Option<Vec<Vec<f32>>>,
 }
 
 impl RenderImage {
@@ -30,12 +70,17 @@ impl RenderImage {
             .as_ref()
             .unwrap_or(&TextureFormat::Rgba8Unorm);
 
+        let layers = image.frames().count().clamp(
+            1,
+            instance.device().limits().max_texture_array_layers as usize,
+        );
+
         let texture_desc = TextureDescriptor {
             label: None,
             size: Extent3d {
                 width: image.width(),
                 height: image.height(),
-                depth_or_array_layers: 1,
+                depth_or_array_layers: layers as u32,
             },
             mip_level_count: 1,
             sample_count: 1,
@@ -45,29 +90,47 @@ impl RenderImage {
             view_formats: &[],
         };
 
-        let texture = if let (Some(frame), Ok(_)) = (image.frames().next(), format_convert) {
+        let layer_size = image.frame_size_with_mipmaps(image.width(), image.height(), 1) as usize;
+
+        let texture = if image.frames().next().is_some() && format_convert.is_ok() {
+            let mut buffer = Vec::with_capacity(layer_size * layers);
+
+            for frame in image.frames().take(layers) {
+                buffer.extend_from_slice(&frame.buffer()[..layer_size.min(frame.buffer().len())]);
+                buffer.resize(buffer.len().next_multiple_of(layer_size), 0);
+            }
+
             instance.device().create_texture_with_data(
                 instance.queue(),
                 &texture_desc,
                 TextureDataOrder::LayerMajor,
-                frame.buffer(),
+                &buffer,
             )
         } else {
             instance.device().create_texture_with_data(
                 instance.queue(),
                 &texture_desc,
                 TextureDataOrder::LayerMajor,
-                &vec![0; image.width() as usize * image.height() as usize * 0x4],
+                &vec![0; image.width() as usize * image.height() as usize * 0x4 * layers],
             )
         };
 
-        let texture_view = texture.create_view(&Default::default());
-
         let texture_sampler = instance.device().create_sampler(&SamplerDescriptor {
             mag_filter: FilterMode::Linear,
             ..Default::default()
         });
 
+        let uniform_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: RenderImageUniform {
+                tone_mapping: 0,
+                exposure: 1.0,
+                padding: [0; 2],
+            }
+            .as_byte_slice(),
+            usage: BufferUsages::UNIFORM | BufferUsages::COPY_DST,
+        });
+
         let bind_group_layout =
             instance
                 .device()
@@ -90,23 +153,48 @@ impl RenderImage {
                             ty: BindingType::Sampler(SamplerBindingType::Filtering),
                             count: None,
                         },
+                        BindGroupLayoutEntry {
+                            binding: 7,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Buffer {
+                                ty: BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
                     ],
                 });
 
-        let bind_group = instance.device().create_bind_group(&BindGroupDescriptor {
-            label: None,
-            layout: &bind_group_layout,
-            entries: &[
-                BindGroupEntry {
-                    binding: 0,
-                    resource: BindingResource::TextureView(&texture_view),
-                },
-                BindGroupEntry {
-                    binding: 1,
-                    resource: BindingResource::Sampler(&texture_sampler),
-                },
-            ],
-        });
+        let bind_groups = (0..layers)
+            .map(|layer| {
+                let texture_view = texture.create_view(&TextureViewDescriptor {
+                    dimension: Some(TextureViewDimension::D2),
+                    base_array_layer: layer as u32,
+                    array_layer_count: Some(1),
+                    ..Default::default()
+                });
+
+                instance.device().create_bind_group(&BindGroupDescriptor {
+                    label: None,
+                    layout: &bind_group_layout,
+                    entries: &[
+                        BindGroupEntry {
+                            binding: 0,
+                            resource: BindingResource::TextureView(&texture_view),
+                        },
+                        BindGroupEntry {
+                            binding: 1,
+                            resource: BindingResource::Sampler(&texture_sampler),
+                        },
+                        BindGroupEntry {
+                            binding: 7,
+                            resource: uniform_buffer.as_entire_binding(),
+                        },
+                    ],
+                })
+            })
+            .collect();
 
         let render_pipeline_layout =
             instance
@@ -182,19 +270,16 @@ impl RenderImage {
         let width = image.width() as f32;
         let height = image.height() as f32;
 
-        vertex_buffer.extend_from_slice(Vector3::new(-1.0, -1.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(0.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector3::new(width, -1.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(1.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector3::new(width, height, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(1.0, 1.0).as_byte_slice());
+        push_quad(&mut vertex_buffer, -1.0, -1.0, width, height);
+
+        if image.is_cubemap() {
+            for (column, row) in CUBEMAP_CROSS {
+                let left = column as f32 * width;
+                let top = row as f32 * height;
 
-        vertex_buffer.extend_from_slice(Vector3::new(-1.0, -1.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(0.0, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector3::new(width, height, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(1.0, 1.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector3::new(-1.0, height, 0.0).as_byte_slice());
-        vertex_buffer.extend_from_slice(Vector2::new(0.0, 1.0).as_byte_slice());
+                push_quad(&mut vertex_buffer, left, top, left + width, top + height);
+            }
+        }
 
         let vertex_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
             label: None,
@@ -203,29 +288,178 @@ impl RenderImage {
         });
 
         Self {
-            bind_group,
+            bind_groups,
+            uniform_buffer,
             render_pipeline,
             vertex_buffer,
             width: image.width(),
             height: image.height(),
+            layer: 0,
+            cross: image.is_cubemap(),
+            source: image.clone(),
+            pixels: None,
         }
     }
 
-    /// Returns the width of the image.
+    /// Returns the displayed width of the image, which is the width of the cross for cubemaps.
     pub fn width(&self) -> u32 {
-        self.width
+        if self.cross {
+            self.width * 4
+        } else {
+            self.width
+        }
     }
 
-    /// Returns the height of the image.
+    /// Returns the displayed height of the image, which is the height of the cross for cubemaps.
     pub fn height(&self) -> u32 {
+        if self.cross {
+            self.height * 3
+        } else {
+            self.height
+        }
+    }
+
+    /// Returns the width of a single frame of the image.
+    pub fn frame_width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of a single frame of the image.
+    pub fn frame_height(&self) -> u32 {
         self.height
     }
 
+    /// Returns the number of layers, which are cubemap faces, or volume and array slices.
+    pub fn layers(&self) -> usize {
+        self.bind_groups.len()
+    }
+
+    /// Returns the layer being shown when not in the cross view.
+    pub fn layer(&self) -> usize {
+        self.layer
+    }
+
+    /// Sets the layer to show, switching cubemaps out of the cross view.
+    pub fn set_layer(&mut self, layer: usize) {
+        self.layer = layer.min(self.layers().saturating_sub(1));
+        self.cross = false;
+    }
+
+    /// Returns a description of the layer being shown.
+    pub fn layer_name(&self) -> String {
+        if self.source.is_cubemap() {
+            if self.cross {
+                String::from("Cross")
+            } else {
+                format!("{} ({} of 6)", CUBEMAP_FACES[self.layer], self.layer + 1)
+            }
+        } else {
+            format!("{} of {}", self.layer + 1, self.layers())
+        }
+    }
+
+    /// Whether or not the image is a cubemap.
+    pub fn is_cubemap(&self) -> bool {
+        self.source.is_cubemap()
+    }
+
+    /// Toggles the unfolded cross view for cubemaps.
+    pub fn toggle_cross(&mut self) {
+        self.cross = self.source.is_cubemap() && !self.cross;
+    }
+
+    /// Sets the exposure in stops and tone mapping used to show high dynamic range images.
+    pub fn set_tone_mapping(
+        &self,
+        instance: &GPUInstance,
+        tone_mapping: ImageToneMapping,
+        exposure: f32,
+    ) {
+        if !is_format_hdr(self.source.format()) {
+            return;
+        }
+
+        let uniform = RenderImageUniform {
+            tone_mapping: match tone_mapping {
+                ImageToneMapping::None => 0,
+                ImageToneMapping::Reinhard => 1,
+                ImageToneMapping::Aces => 2,
+            },
+            exposure: exposure.exp2(),
+            padding: [0; 2],
+        };
+
+        instance
+            .queue()
+            .write_buffer(&self.uniform_buffer, 0, uniform.as_byte_slice());
+    }
+
+    /// Returns the format of the source image.
+    pub fn format(&self) -> ImageFormat {
+        self.source.format()
+    }
+
+    /// Returns the color of the pixel at the given displayed coordinates, decoding the source image on first use.
+    pub fn pixel(&mut self, x: u32, y: u32) -> Option<[f32; 4]> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        let (layer, x, y) = if self.cross {
+            let position = (x / self.width, y / self.height);
+            let layer = CUBEMAP_CROSS.iter().position(|face| *face == position)?;
+
+            (layer, x % self.width, y % self.height)
+        } else {
+            (self.layer, x, y)
+        };
+
+        let pixels = self.pixels.get_or_insert_with(|| {
+            let mut image = self.source.clone();
+
+            // An empty buffer marks an image that can't be decoded, so we don't retry on every probe.
+            if image
+                .convert(ImageFormat::R32G32B32A32Float, ImageConvertOptions::new())
+                .is_err()
+            {
+                return Vec::new();
+            }
+
+            image
+                .frames()
+                .map(|frame| {
+                    frame
+                        .buffer()
+                        .chunks_exact(4)
+                        .map(|value| f32::from_le_bytes([value[0], value[1], value[2], value[3]]))
+                        .collect()
+                })
+                .collect()
+        });
+
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+
+        pixels
+            .get(layer)?
+            .get(offset..offset + 4)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+    }
+
     /// Draws the image using the given render pass.
     pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
         render_pass.set_pipeline(&self.render_pipeline);
-        render_pass.set_bind_group(1, &self.bind_group, &[]);
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
-        render_pass.draw(0..6, 0..1);
+
+        if self.cross {
+            for (face, bind_group) in self.bind_groups.iter().enumerate() {
+                let start = (face as u32 + 1) * 6;
+
+                render_pass.set_bind_group(1, bind_group, &[]);
+                render_pass.draw(start..start + 6, 0..1);
+            }
+        } else if let Some(bind_group) = self.bind_groups.get(self.layer) {
+            render_pass.set_bind_group(1, bind_group, &[]);
+            render_pass.draw(0..6, 0..1);
+        }
     }
 }
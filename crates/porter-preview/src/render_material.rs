@@ -5,11 +5,14 @@ use porter_model::MaterialTextureRefUsage;
 use porter_texture::Image;
 
 use crate::RenderImage;
+use crate::RenderMaterialSphere;
 
 /// A 3d render material.
 pub struct RenderMaterial {
     images: Vec<(RenderImage, MaterialTextureRefUsage)>,
     index: usize,
+    sphere: RenderMaterialSphere,
+    show_sphere: bool,
 }
 
 impl RenderMaterial {
@@ -30,6 +33,8 @@ impl RenderMaterial {
                 })
                 .collect(),
             index: 0,
+            sphere: RenderMaterialSphere::from_images(instance, bind_group_layouts, images),
+            show_sphere: false,
         }
     }
 
@@ -88,8 +93,23 @@ impl RenderMaterial {
         }
     }
 
+    /// Toggles between the current image and the lit material sphere.
+    pub fn toggle_sphere(&mut self) {
+        self.show_sphere = !self.show_sphere;
+    }
+
+    /// Whether or not the lit material sphere is shown.
+    pub fn is_sphere(&self) -> bool {
+        self.show_sphere
+    }
+
     /// Draws the material using the given render pass.
     pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+        if self.show_sphere {
+            self.sphere.draw(render_pass);
+            return;
+        }
+
         if self.images.is_empty() {
             return;
         }
@@ -0,0 +1,332 @@
+use std::f32::consts::PI;
+use std::f32::consts::TAU;
+
+use wgpu::util::*;
+use wgpu::*;
+
+use porter_gpu::GPUInstance;
+use porter_math::Vector2;
+use porter_math::Vector3;
+use porter_model::MaterialTextureRefUsage;
+use porter_texture::Image;
+use porter_utils::AsByteSlice;
+use porter_utils::AsThisSlice;
+
+use crate::create_texture_view;
+use crate::RenderEnvironment;
+
+/// The radius of the material sphere.
+const SPHERE_RADIUS: f32 = 40.0;
+/// The number of segments around the material sphere.
+const SPHERE_SEGMENTS: u32 = 64;
+/// The number of rings from the top to the bottom of the material sphere.
+const SPHERE_RINGS: u32 = 32;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct RenderMaterialSphereUniform {
+    gloss: u32,
+    padding: [u32; 3],
+}
+
+/// A sphere used to preview a material with image based lighting.
+pub struct RenderMaterialSphere {
+    render_pipeline: RenderPipeline,
+    vertex_buffer: Buffer,
+    face_buffer: Buffer,
+    face_count: u32,
+    bind_group: BindGroup,
+    environment: RenderEnvironment,
+}
+
+impl RenderMaterialSphere {
+    /// Constructs a new material sphere from the base color, normal, roughness, metalness, and occlusion images.
+    pub fn from_images(
+        instance: &GPUInstance,
+        bind_group_layouts: &[&BindGroupLayout],
+        images: &[(MaterialTextureRefUsage, Image)],
+    ) -> Self {
+        let find = |usage: MaterialTextureRefUsage| {
+            images
+                .iter()
+                .find(|(image_usage, _)| *image_usage == usage)
+                .map(|(_, image)| image)
+        };
+
+        let base_color = find(MaterialTextureRefUsage::Albedo)
+            .or_else(|| find(MaterialTextureRefUsage::Diffuse));
+        let roughness = find(MaterialTextureRefUsage::Roughness);
+        let gloss = find(MaterialTextureRefUsage::Gloss).filter(|_| roughness.is_none());
+
+        let texture_views = [
+            create_texture_view(instance, base_color, 0xFFA1A1A1),
+            create_texture_view(instance, find(MaterialTextureRefUsage::Normal), 0xFFFF8080),
+            create_texture_view(instance, roughness.or(gloss), 0xFF808080),
+            create_texture_view(
+                instance,
+                find(MaterialTextureRefUsage::Metalness),
+                0xFF000000,
+            ),
+            create_texture_view(
+                instance,
+                find(MaterialTextureRefUsage::AmbientOcclusion),
+                0xFFFFFFFF,
+            ),
+        ];
+
+        let uniforms = RenderMaterialSphereUniform {
+            gloss: gloss.is_some() as u32,
+            padding: [0; 3],
+        };
+
+        let uniform_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: uniforms.as_byte_slice(),
+            usage: BufferUsages::UNIFORM,
+        });
+
+        let texture_sampler = instance.device().create_sampler(&SamplerDescriptor {
+            address_mode_u: AddressMode::Repeat,
+            address_mode_v: AddressMode::Repeat,
+            address_mode_w: AddressMode::Repeat,
+            mag_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let texture_entry = |binding: u32| BindGroupLayoutEntry {
+            binding,
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: true },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+
+        let bind_group_layout =
+            instance
+                .device()
+                .create_bind_group_layout(&BindGroupLayoutDescriptor {
+                    label: None,
+                    entries: &[
+                        texture_entry(0),
+                        BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                            count: None,
+                        },
+                        texture_entry(2),
+                        texture_entry(3),
+                        texture_entry(4),
+                        texture_entry(5),
+                        BindGroupLayoutEntry {
+                            binding: 6,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Buffer {
+                                ty: BufferBindingType::Uniform,
+                                has_dynamic_offset: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                });
+
+        let bind_group = instance.device().create_bind_group(&BindGroupDescriptor {
+            label: None,
+            layout: &bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&texture_views[0]),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&texture_sampler),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(&texture_views[1]),
+                },
+                BindGroupEntry {
+                    binding: 3,
+                    resource: BindingResource::TextureView(&texture_views[2]),
+                },
+                BindGroupEntry {
+                    binding: 4,
+                    resource: BindingResource::TextureView(&texture_views[3]),
+                },
+                BindGroupEntry {
+                    binding: 5,
+                    resource: BindingResource::TextureView(&texture_views[4]),
+                },
+                BindGroupEntry {
+                    binding: 6,
+                    resource: uniform_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let environment = RenderEnvironment::new(instance);
+
+        let (vertices, faces) = create_sphere();
+
+        let vertex_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: vertices.as_slice().as_this_slice(),
+            usage: BufferUsages::VERTEX,
+        });
+
+        let face_buffer = instance.device().create_buffer_init(&BufferInitDescriptor {
+            label: None,
+            contents: faces.as_slice().as_this_slice(),
+            usage: BufferUsages::INDEX,
+        });
+
+        let render_pipeline_layout =
+            instance
+                .device()
+                .create_pipeline_layout(&PipelineLayoutDescriptor {
+                    label: None,
+                    bind_group_layouts: &[
+                        bind_group_layouts,
+                        &[&bind_group_layout, environment.bind_group_layout()],
+                    ]
+                    .concat(),
+                    push_constant_ranges: &[],
+                });
+
+        let stride = (std::mem::size_of::<Vector3>() * 2) + std::mem::size_of::<Vector2>();
+
+        let render_pipeline = instance
+            .device()
+            .create_render_pipeline(&RenderPipelineDescriptor {
+                label: None,
+                layout: Some(&render_pipeline_layout),
+                vertex: VertexState {
+                    module: instance.gpu_preview_shader(),
+                    entry_point: "vs_main",
+                    buffers: &[VertexBufferLayout {
+                        array_stride: stride as BufferAddress,
+                        step_mode: VertexStepMode::Vertex,
+                        attributes: &[
+                            VertexAttribute {
+                                offset: 0,
+                                shader_location: 0,
+                                format: VertexFormat::Float32x3,
+                            },
+                            VertexAttribute {
+                                offset: std::mem::size_of::<Vector3>() as BufferAddress,
+                                shader_location: 1,
+                                format: VertexFormat::Float32x3,
+                            },
+                            VertexAttribute {
+                                offset: (std::mem::size_of::<Vector3>() * 2) as BufferAddress,
+                                shader_location: 2,
+                                format: VertexFormat::Float32x2,
+                            },
+                        ],
+                    }],
+                },
+                primitive: PrimitiveState {
+                    topology: PrimitiveTopology::TriangleList,
+                    strip_index_format: None,
+                    front_face: FrontFace::Cw,
+                    cull_mode: None,
+                    unclipped_depth: false,
+                    polygon_mode: PolygonMode::Fill,
+                    conservative: false,
+                },
+                depth_stencil: Some(DepthStencilState {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: CompareFunction::Less,
+                    stencil: StencilState::default(),
+                    bias: DepthBiasState::default(),
+                }),
+                multisample: MultisampleState {
+                    count: 4,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                fragment: Some(FragmentState {
+                    module: instance.gpu_preview_shader(),
+                    entry_point: "fs_pbr_main",
+                    targets: &[Some(ColorTargetState {
+                        format: TextureFormat::Rgba8Unorm,
+                        blend: Some(BlendState::REPLACE),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                }),
+                multiview: None,
+            });
+
+        Self {
+            render_pipeline,
+            vertex_buffer,
+            face_buffer,
+            face_count: faces.len() as u32,
+            bind_group,
+            environment,
+        }
+    }
+
+    /// Draws the material sphere using the given render pass.
+    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>) {
+        render_pass.set_pipeline(&self.render_pipeline);
+        render_pass.set_bind_group(1, &self.bind_group, &[]);
+        render_pass.set_bind_group(2, self.environment.bind_group(), &[]);
+        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        render_pass.set_index_buffer(self.face_buffer.slice(..), IndexFormat::Uint32);
+        render_pass.draw_indexed(0..self.face_count, 0, 0..1);
+    }
+}
+
+/// Builds the vertices (position, normal, uv) and face indices of a uv sphere.
+fn create_sphere() -> (Vec<f32>, Vec<u32>) {
+    let mut vertices =
+        Vec::with_capacity(((SPHERE_SEGMENTS + 1) * (SPHERE_RINGS + 1) * 8) as usize);
+    let mut faces = Vec::with_capacity((SPHERE_SEGMENTS * SPHERE_RINGS * 6) as usize);
+
+    for ring in 0..=SPHERE_RINGS {
+        let v = ring as f32 / SPHERE_RINGS as f32;
+        let theta = v * PI;
+
+        for segment in 0..=SPHERE_SEGMENTS {
+            let u = segment as f32 / SPHERE_SEGMENTS as f32;
+            let phi = u * TAU;
+
+            let normal = Vector3::new(
+                theta.sin() * phi.sin(),
+                theta.cos(),
+                theta.sin() * phi.cos(),
+            );
+            let position = normal * SPHERE_RADIUS;
+
+            // The sphere is twice as wide as it is tall, so the texture repeats twice around it.
+            vertices.extend([
+                position.x,
+                position.y,
+                position.z,
+                normal.x,
+                normal.y,
+                normal.z,
+                u * 2.0,
+                v,
+            ]);
+        }
+    }
+
+    for ring in 0..SPHERE_RINGS {
+        for segment in 0..SPHERE_SEGMENTS {
+            let top = ring * (SPHERE_SEGMENTS + 1) + segment;
+            let bottom = top + SPHERE_SEGMENTS + 1;
+
+            faces.extend([top, bottom, top + 1, top + 1, bottom, bottom + 1]);
+        }
+    }
+
+    (vertices, faces)
+}
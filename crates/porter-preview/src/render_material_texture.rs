@@ -14,66 +14,80 @@ pub struct RenderMaterialTexture {
 }
 
 /// Utility to allocate the fallback image for a material texture.
-fn default_image() -> Image {
+fn default_image(color: u32) -> Image {
     let mut image = Image::new(4, 4, ImageFormat::R8G8B8A8Unorm).unwrap();
 
     image
         .create_frame()
         .unwrap()
         .buffer_mut()
-        .copy_from_slice([0xFFA1A1A1u32; 4 * 4].as_slice().as_this_slice());
+        .copy_from_slice([color; 4 * 4].as_slice().as_this_slice());
 
     image
 }
 
+/// Utility to upload an image, or the fallback color, to a texture view.
+pub(crate) fn create_texture_view(
+    instance: &GPUInstance,
+    image: Option<&Image>,
+    color: u32,
+) -> TextureView {
+    let default = image.is_none().then(|| default_image(color));
+    let image = image.or(default.as_ref()).unwrap();
+
+    let format_convert = format_to_wgpu(image.format());
+    let format = *format_convert
+        .as_ref()
+        .unwrap_or(&TextureFormat::Rgba8Unorm);
+
+    let texture_desc = TextureDescriptor {
+        label: None,
+        size: Extent3d {
+            width: image.width(),
+            height: image.height(),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format,
+        usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
+        view_formats: &[],
+    };
+
+    let texture = if let (Some(frame), Ok(_)) = (image.frames().next(), format_convert) {
+        instance.device().create_texture_with_data(
+            instance.queue(),
+            &texture_desc,
+            TextureDataOrder::LayerMajor,
+            frame.buffer(),
+        )
+    } else {
+        instance.device().create_texture_with_data(
+            instance.queue(),
+            &texture_desc,
+            TextureDataOrder::LayerMajor,
+            &vec![0; image.width() as usize * image.height() as usize * 0x4],
+        )
+    };
+
+    texture.create_view(&Default::default())
+}
+
 impl RenderMaterialTexture {
     /// Constructs a new render material texture from the given image, or defaults to a 4x4 grey square.
     pub fn from_image_default(instance: &GPUInstance, image: &Option<Image>) -> Self {
-        let mut default: Option<Image> = None;
-
-        if image.is_none() {
-            default = Some(default_image());
-        }
-
-        let image = image.as_ref().or(default.as_ref()).unwrap();
-
-        let format_convert = format_to_wgpu(image.format());
-        let format = *format_convert
-            .as_ref()
-            .unwrap_or(&TextureFormat::Rgba8Unorm);
+        Self::from_images_default(instance, image.as_ref(), None)
+    }
 
-        let texture_desc = TextureDescriptor {
-            label: None,
-            size: Extent3d {
-                width: image.width(),
-                height: image.height(),
-                depth_or_array_layers: 1,
-            },
-            mip_level_count: 1,
-            sample_count: 1,
-            dimension: TextureDimension::D2,
-            format,
-            usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
-            view_formats: &[],
-        };
-
-        let texture = if let (Some(frame), Ok(_)) = (image.frames().next(), format_convert) {
-            instance.device().create_texture_with_data(
-                instance.queue(),
-                &texture_desc,
-                TextureDataOrder::LayerMajor,
-                frame.buffer(),
-            )
-        } else {
-            instance.device().create_texture_with_data(
-                instance.queue(),
-                &texture_desc,
-                TextureDataOrder::LayerMajor,
-                &vec![0; image.width() as usize * image.height() as usize * 0x4],
-            )
-        };
-
-        let texture_view = texture.create_view(&Default::default());
+    /// Constructs a new render material texture from the given albedo and normal map, or defaults to a 4x4 grey square and a flat normal.
+    pub fn from_images_default(
+        instance: &GPUInstance,
+        image: Option<&Image>,
+        normal: Option<&Image>,
+    ) -> Self {
+        let texture_view = create_texture_view(instance, image, 0xFFA1A1A1);
+        let normal_view = create_texture_view(instance, normal, 0xFFFF8080);
 
         let texture_sampler = instance.device().create_sampler(&SamplerDescriptor {
             address_mode_u: AddressMode::Repeat,
@@ -105,6 +119,16 @@ impl RenderMaterialTexture {
                             ty: BindingType::Sampler(SamplerBindingType::Filtering),
                             count: None,
                         },
+                        BindGroupLayoutEntry {
+                            binding: 2,
+                            visibility: ShaderStages::FRAGMENT,
+                            ty: BindingType::Texture {
+                                sample_type: TextureSampleType::Float { filterable: true },
+                                view_dimension: TextureViewDimension::D2,
+                                multisampled: false,
+                            },
+                            count: None,
+                        },
                     ],
                 });
 
@@ -120,6 +144,10 @@ impl RenderMaterialTexture {
                     binding: 1,
                     resource: BindingResource::Sampler(&texture_sampler),
                 },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(&normal_view),
+                },
             ],
         });
 
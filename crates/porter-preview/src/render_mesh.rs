@@ -9,12 +9,14 @@ use porter_math::Vector3;
 use porter_model::Mesh;
 use porter_utils::AsThisSlice;
 
+use crate::PreviewWireframe;
 use crate::RenderMaterialTexture;
 
 /// A 3d render mesh.
 pub struct RenderMesh {
     render_pipeline: RenderPipeline,
     render_pipeline_wireframe: RenderPipeline,
+    render_pipeline_overlay: RenderPipeline,
     vertex_buffer: Buffer,
     pub(crate) vertex_count: usize,
     face_buffer: Buffer,
@@ -151,12 +153,45 @@ impl RenderMesh {
                         polygon_mode: PolygonMode::Line,
                         ..render_pipeline_desc.primitive
                     },
+                    ..render_pipeline_desc.clone()
+                });
+
+        // The overlay is biased towards the camera so the lines win the depth test against the shaded mesh.
+        let render_pipeline_overlay =
+            instance
+                .device()
+                .create_render_pipeline(&RenderPipelineDescriptor {
+                    primitive: PrimitiveState {
+                        polygon_mode: PolygonMode::Line,
+                        ..render_pipeline_desc.primitive
+                    },
+                    depth_stencil: Some(DepthStencilState {
+                        format: TextureFormat::Depth32Float,
+                        depth_write_enabled: false,
+                        depth_compare: CompareFunction::LessEqual,
+                        stencil: StencilState::default(),
+                        bias: DepthBiasState {
+                            constant: -2,
+                            slope_scale: -1.0,
+                            clamp: 0.0,
+                        },
+                    }),
+                    fragment: Some(FragmentState {
+                        module: instance.gpu_preview_shader(),
+                        entry_point: "fs_wireframe_main",
+                        targets: &[Some(ColorTargetState {
+                            format: TextureFormat::Rgba8Unorm,
+                            blend: Some(BlendState::ALPHA_BLENDING),
+                            write_mask: ColorWrites::ALL,
+                        })],
+                    }),
                     ..render_pipeline_desc
                 });
 
         Self {
             render_pipeline,
             render_pipeline_wireframe,
+            render_pipeline_overlay,
             vertex_buffer,
             vertex_count: mesh.vertices.len(),
             face_buffer,
@@ -166,8 +201,8 @@ impl RenderMesh {
     }
 
     /// Draws the mesh using the given render pass.
-    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, wireframe: bool) {
-        if wireframe {
+    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, wireframe: PreviewWireframe) {
+        if wireframe == PreviewWireframe::Wireframe {
             render_pass.set_pipeline(&self.render_pipeline_wireframe);
         } else {
             render_pass.set_pipeline(&self.render_pipeline);
@@ -177,5 +212,10 @@ impl RenderMesh {
         render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
         render_pass.set_index_buffer(self.face_buffer.slice(..), IndexFormat::Uint32);
         render_pass.draw_indexed(0..self.face_count as u32 * 3, 0, 0..1);
+
+        if wireframe == PreviewWireframe::Overlay {
+            render_pass.set_pipeline(&self.render_pipeline_overlay);
+            render_pass.draw_indexed(0..self.face_count as u32 * 3, 0, 0..1);
+        }
     }
 }
@@ -3,9 +3,11 @@ use std::sync::Arc;
 use wgpu::*;
 
 use porter_gpu::GPUInstance;
+use porter_model::MaterialTextureRefUsage;
 use porter_model::Model;
 use porter_texture::Image;
 
+use crate::PreviewWireframe;
 use crate::RenderMaterialTexture;
 use crate::RenderMesh;
 use crate::RenderSkeleton;
@@ -31,6 +33,45 @@ impl RenderModel {
             .map(Arc::new)
             .collect();
 
+        Self::from_material_textures(instance, bind_group_layouts, model, materials)
+    }
+
+    /// Constructs a new render model from the given model, using the albedo and normal map of each material.
+    pub fn from_model_materials(
+        instance: &GPUInstance,
+        bind_group_layouts: &[&BindGroupLayout],
+        model: &Model,
+        materials: &[Vec<(MaterialTextureRefUsage, Image)>],
+    ) -> Self {
+        let materials: Vec<Arc<_>> = materials
+            .iter()
+            .map(|images| {
+                RenderMaterialTexture::from_images_default(
+                    instance,
+                    find_image(
+                        images,
+                        &[
+                            MaterialTextureRefUsage::Albedo,
+                            MaterialTextureRefUsage::Diffuse,
+                        ],
+                    ),
+                    find_image(images, &[MaterialTextureRefUsage::Normal]),
+                )
+            })
+            .chain([RenderMaterialTexture::from_image_default(instance, &None)])
+            .map(Arc::new)
+            .collect();
+
+        Self::from_material_textures(instance, bind_group_layouts, model, materials)
+    }
+
+    /// Constructs a new render model from the given model and uploaded material textures.
+    fn from_material_textures(
+        instance: &GPUInstance,
+        bind_group_layouts: &[&BindGroupLayout],
+        model: &Model,
+        materials: Vec<Arc<RenderMaterialTexture>>,
+    ) -> Self {
         Self {
             meshes: model
                 .meshes
@@ -72,16 +113,49 @@ impl RenderModel {
             .unwrap_or_default()
     }
 
+    /// Returns the skeleton for this model, if it has bones.
+    pub fn skeleton(&self) -> Option<&RenderSkeleton> {
+        self.skeleton.as_ref()
+    }
+
+    /// Whether or not the model only has a skeleton, or the meshes are hidden.
+    pub fn is_skeleton_only(&self, skeleton_only: bool) -> bool {
+        self.skeleton.is_some() && (skeleton_only || self.meshes.is_empty())
+    }
+
     /// Draws the model using the given render pass.
-    pub fn draw<'a>(&'a self, render_pass: &mut RenderPass<'a>, show_bones: bool, wireframe: bool) {
-        for mesh in &self.meshes {
-            mesh.draw(render_pass, wireframe);
+    pub fn draw<'a>(
+        &'a self,
+        render_pass: &mut RenderPass<'a>,
+        show_bones: bool,
+        skeleton_only: bool,
+        wireframe: PreviewWireframe,
+    ) {
+        let skeleton_only = self.is_skeleton_only(skeleton_only);
+
+        if !skeleton_only {
+            for mesh in &self.meshes {
+                mesh.draw(render_pass, wireframe);
+            }
         }
 
-        if show_bones {
+        if show_bones || skeleton_only {
             if let Some(skeleton) = &self.skeleton {
                 skeleton.draw(render_pass);
             }
         }
     }
 }
+
+/// Finds the first image matching the usages, in order of preference.
+fn find_image<'a>(
+    images: &'a [(MaterialTextureRefUsage, Image)],
+    usages: &[MaterialTextureRefUsage],
+) -> Option<&'a Image> {
+    usages.iter().find_map(|usage| {
+        images
+            .iter()
+            .find(|(image_usage, _)| image_usage == usage)
+            .map(|(_, image)| image)
+    })
+}
@@ -11,6 +11,7 @@ pub struct RenderSkeleton {
     vertex_buffer: Buffer,
     render_pipeline: RenderPipeline,
     pub(crate) bone_count: usize,
+    pub(crate) bones: Vec<(String, Vector3)>,
 }
 
 impl RenderSkeleton {
@@ -106,6 +107,19 @@ impl RenderSkeleton {
             vertex_buffer,
             render_pipeline,
             bone_count: skeleton.bones.len(),
+            bones: skeleton
+                .bones
+                .iter()
+                .enumerate()
+                .map(|(index, bone)| {
+                    (
+                        bone.name
+                            .clone()
+                            .unwrap_or_else(|| format!("porter_bone_{}", index)),
+                        bone.world_position.unwrap_or_default(),
+                    )
+                })
+                .collect(),
         }
     }
 
@@ -40,6 +40,21 @@ impl ToRenderType for (Model, Vec<Option<Image>>) {
     }
 }
 
+impl ToRenderType for (Model, Vec<Vec<(MaterialTextureRefUsage, Image)>>) {
+    fn to_render_type(
+        &self,
+        instance: &GPUInstance,
+        bind_group_layouts: &[&BindGroupLayout],
+    ) -> RenderType {
+        RenderType::Model(RenderModel::from_model_materials(
+            instance,
+            bind_group_layouts,
+            &self.0,
+            &self.1,
+        ))
+    }
+}
+
 impl ToRenderType for Image {
     fn to_render_type(
         &self,
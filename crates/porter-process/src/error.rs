@@ -2,6 +2,7 @@
 pub enum ProcessError {
     NotFound,
     AccessDenied,
+    InvalidDump,
     IoError(std::io::Error),
     #[cfg(target_os = "windows")]
     NulErrorU16(widestring::error::NulError<u16>),
@@ -2,19 +2,25 @@
 
 mod error;
 mod process;
+mod process_cache;
+mod process_dump;
 mod process_handle;
 mod process_handle_platform;
 mod process_info;
 mod process_info_platform;
 mod process_pointer;
 mod process_reader;
+mod process_region;
 
 pub use error::*;
 pub use process::*;
+pub use process_dump::*;
 pub use process_handle::*;
 pub use process_pointer::*;
 pub use process_reader::*;
+pub use process_region::*;
 
+pub(crate) use process_cache::*;
 pub(crate) use process_handle_platform::*;
 pub(crate) use process_info::*;
 pub(crate) use process_info_platform::*;
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 use std::time::SystemTime;
 
 use crate::ProcessError;
@@ -9,6 +11,9 @@ use crate::ProcessInfo;
 use crate::ProcessInfoPlatform;
 use crate::ProcessReader;
 
+/// How often to check for a process when waiting for it to start.
+const WAIT_FOR_INTERVAL: Duration = Duration::from_millis(500);
+
 /// A process that exists on the local system.
 #[derive(Clone, PartialEq, Eq)]
 pub struct Process {
@@ -33,6 +38,48 @@ impl Process {
             .collect())
     }
 
+    /// Returns a list of processes with a main window that matches the given title, case sensitive.
+    #[cfg(target_os = "windows")]
+    pub fn get_processes_by_window_title<T: AsRef<str>>(
+        title: T,
+    ) -> Result<Vec<Self>, ProcessError> {
+        let pids = ProcessInfo::get_pids_by_window_title(title.as_ref())?;
+
+        if pids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(ProcessInfo::get_processes(pids)?
+            .into_iter()
+            .map(|info| Process { info })
+            .collect())
+    }
+
+    /// Waits for a process that matches the given name to start, and can be opened for reading.
+    ///
+    /// Returns [`ProcessError::NotFound`] if the process didn't start before the timeout.
+    pub fn wait_for<N: AsRef<str>>(name: N, timeout: Duration) -> Result<Self, ProcessError> {
+        let started = Instant::now();
+
+        loop {
+            let process = Self::get_processes_by_name(name.as_ref())?
+                .into_iter()
+                .find(|process| process.open_read().is_ok());
+
+            if let Some(process) = process {
+                return Ok(process);
+            }
+
+            let elapsed = started.elapsed();
+
+            if elapsed >= timeout {
+                return Err(ProcessError::NotFound);
+            }
+
+            std::thread::sleep(WAIT_FOR_INTERVAL.min(timeout - elapsed));
+        }
+    }
+
     /// Attempts to get a process by it's unique id.
     pub fn get_process_by_id<P: Into<u64>>(pid: P) -> Result<Self, ProcessError> {
         ProcessInfo::get_processes([pid.into()])?
@@ -56,6 +103,26 @@ impl Process {
             && process.started_at == self.started_at();
     }
 
+    /// Calls the given callback on a background thread once the process exits.
+    pub fn on_exit<F: FnOnce() + Send + 'static>(&self, callback: F) -> Result<(), ProcessError> {
+        let process = self.clone();
+
+        std::thread::Builder::new()
+            .name(String::from("porter-process-exit"))
+            .spawn(move || {
+                // The pid may be reused once the process exits, so make sure it's still the same process first.
+                if process.alive() && process.info.wait_for_exit().is_err() {
+                    while process.alive() {
+                        std::thread::sleep(WAIT_FOR_INTERVAL);
+                    }
+                }
+
+                callback();
+            })?;
+
+        Ok(())
+    }
+
     /// The name of the process executable without the extension.
     pub fn name(&self) -> &str {
         &self.info.name
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::ProcessError;
+use crate::ProcessHandle;
+use crate::ProcessHandlePlatform;
+
+/// A cached page of process memory.
+#[derive(Debug, Clone)]
+struct ProcessCachePage {
+    data: Box<[u8]>,
+    valid: usize,
+    used: u64,
+}
+
+/// A least recently used cache of process memory pages.
+#[derive(Debug, Clone)]
+pub struct ProcessCache {
+    page_size: u64,
+    capacity: usize,
+    tick: u64,
+    pages: HashMap<u64, ProcessCachePage>,
+}
+
+impl ProcessCache {
+    /// Constructs a new cache with the given page size in bytes, and maximum number of pages.
+    pub fn new(page_size: usize, capacity: usize) -> Self {
+        Self {
+            page_size: page_size.max(1) as u64,
+            capacity: capacity.max(1),
+            tick: 0,
+            pages: HashMap::with_capacity(capacity.max(1)),
+        }
+    }
+
+    /// Reads from the cache, loading any missing pages from the process.
+    pub fn read(
+        &mut self,
+        handle: &ProcessHandle,
+        offset: u64,
+        buf: &mut [u8],
+    ) -> Result<usize, ProcessError> {
+        let mut read = 0;
+
+        while read < buf.len() {
+            let address = offset + read as u64;
+            let page = address / self.page_size;
+            let position = (address % self.page_size) as usize;
+
+            if !self.pages.contains_key(&page) {
+                if let Err(error) = self.load(handle, page) {
+                    // Report the error only if nothing could be read, like an uncached read.
+                    if read == 0 {
+                        return Err(error);
+                    }
+
+                    break;
+                }
+            }
+
+            self.tick += 1;
+
+            let Some(cached) = self.pages.get_mut(&page) else {
+                break;
+            };
+
+            cached.used = self.tick;
+
+            if position >= cached.valid {
+                break;
+            }
+
+            let size = (cached.valid - position).min(buf.len() - read);
+
+            buf[read..read + size].copy_from_slice(&cached.data[position..position + size]);
+
+            read += size;
+
+            // A partially readable page ends the readable memory.
+            if cached.valid < cached.data.len() {
+                break;
+            }
+        }
+
+        Ok(read)
+    }
+
+    /// Loads the pages covering the given range into the cache with as few reads as possible.
+    pub fn prefetch(
+        &mut self,
+        handle: &ProcessHandle,
+        range: Range<u64>,
+    ) -> Result<(), ProcessError> {
+        if range.is_empty() {
+            return Ok(());
+        }
+
+        let first = range.start / self.page_size;
+        let last = (range.end - 1) / self.page_size;
+
+        // Only the most recently loaded pages would survive when the range is larger than the cache.
+        let first = first.max((last + 1).saturating_sub(self.capacity as u64));
+
+        let mut buffer = vec![0; ((last - first + 1) * self.page_size) as usize];
+
+        let read = handle
+            .read(first * self.page_size, &mut buffer)
+            .unwrap_or(0);
+
+        for (index, data) in buffer.chunks(self.page_size as usize).enumerate() {
+            let page = first + index as u64;
+            let valid = read
+                .saturating_sub(index * self.page_size as usize)
+                .min(data.len());
+
+            if valid == data.len() {
+                self.insert(page, data.into(), valid);
+            } else if !self.pages.contains_key(&page) {
+                // The bulk read stopped early, so the remaining pages are loaded one at a time.
+                let _ = self.load(handle, page);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes all pages from the cache.
+    pub fn clear(&mut self) {
+        self.pages.clear();
+    }
+
+    /// Loads a single page from the process.
+    fn load(&mut self, handle: &ProcessHandle, page: u64) -> Result<(), ProcessError> {
+        let mut data = vec![0; self.page_size as usize].into_boxed_slice();
+
+        let valid = handle.read(page * self.page_size, &mut data)?;
+
+        if valid == 0 {
+            return Err(ProcessError::AccessDenied);
+        }
+
+        self.insert(page, data, valid);
+
+        Ok(())
+    }
+
+    /// Inserts a page, evicting the least recently used page when full.
+    fn insert(&mut self, page: u64, data: Box<[u8]>, valid: usize) {
+        if self.pages.len() >= self.capacity && !self.pages.contains_key(&page) {
+            let oldest = self
+                .pages
+                .iter()
+                .min_by_key(|(_, cached)| cached.used)
+                .map(|(page, _)| *page);
+
+            if let Some(oldest) = oldest {
+                self.pages.remove(&oldest);
+            }
+        }
+
+        self.tick += 1;
+
+        self.pages.insert(
+            page,
+            ProcessCachePage {
+                data,
+                valid,
+                used: self.tick,
+            },
+        );
+    }
+}
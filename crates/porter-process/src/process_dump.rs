@@ -0,0 +1,160 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+
+use porter_utils::StructReadExt;
+
+use crate::ProcessError;
+use crate::ProcessRegion;
+
+/// Magic of a process dump file 'PDMP'.
+pub(crate) const PROCESS_DUMP_MAGIC: u32 = 0x504D4450;
+/// Current version of the process dump format.
+pub(crate) const PROCESS_DUMP_VERSION: u32 = 0x1;
+/// Regions are stored aligned to this size in the dump file.
+pub(crate) const PROCESS_DUMP_PAGE_SIZE: u64 = 0x1000;
+
+/// The header of a process dump file.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ProcessDumpHeader {
+    pub magic: u32,
+    pub version: u32,
+    pub page_size: u32,
+    pub reserved: u32,
+    pub base_address: u64,
+    pub main_module_size: u64,
+    pub index_offset: u64,
+    pub index_count: u64,
+}
+
+/// An entry in the index of a process dump file, which maps a region to its data.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ProcessDumpEntry {
+    pub address: u64,
+    pub size: u64,
+    pub offset: u64,
+}
+
+/// A snapshot of a process memory, which can be read like the process it was dumped from.
+#[derive(Debug)]
+pub struct ProcessDump {
+    offset: u64,
+    header: ProcessDumpHeader,
+    index: Vec<ProcessDumpEntry>,
+    file: BufReader<File>,
+}
+
+impl ProcessDump {
+    /// Opens a process dump file created with [`crate::ProcessReader::dump`].
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ProcessError> {
+        let file = File::open(path)?;
+        let length = file.metadata()?.len();
+
+        let mut file = BufReader::new(file);
+
+        let header: ProcessDumpHeader = file.read_struct()?;
+
+        if header.magic != PROCESS_DUMP_MAGIC || header.version != PROCESS_DUMP_VERSION {
+            return Err(ProcessError::InvalidDump);
+        }
+
+        let index_size = header
+            .index_count
+            .checked_mul(std::mem::size_of::<ProcessDumpEntry>() as u64)
+            .and_then(|size| size.checked_add(header.index_offset))
+            .ok_or(ProcessError::InvalidDump)?;
+
+        if index_size > length {
+            return Err(ProcessError::InvalidDump);
+        }
+
+        file.seek(SeekFrom::Start(header.index_offset))?;
+
+        let mut index: Vec<ProcessDumpEntry> = Vec::with_capacity(header.index_count as usize);
+
+        for _ in 0..header.index_count {
+            index.push(file.read_struct()?);
+        }
+
+        index.sort_by_key(|entry| entry.address);
+
+        Ok(Self {
+            offset: 0,
+            header,
+            index,
+            file,
+        })
+    }
+
+    /// Gets the base address of the process when it was dumped.
+    pub fn base_address(&self) -> u64 {
+        self.header.base_address
+    }
+
+    /// Gets the size of the main module in bytes when it was dumped.
+    pub fn main_module_size(&self) -> u64 {
+        self.header.main_module_size
+    }
+
+    /// Gets the memory regions stored in the dump, sorted by address.
+    pub fn regions(&self) -> Vec<ProcessRegion> {
+        self.index
+            .iter()
+            .map(|entry| ProcessRegion::new(entry.address, entry.size))
+            .collect()
+    }
+
+    /// Finds the index entry containing the given address.
+    fn entry(&self, address: u64) -> Option<&ProcessDumpEntry> {
+        let index = self
+            .index
+            .partition_point(|entry| entry.address <= address)
+            .checked_sub(1)?;
+
+        let entry = &self.index[index];
+
+        (address - entry.address < entry.size).then_some(entry)
+    }
+}
+
+impl std::io::Read for ProcessDump {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // Memory that wasn't dumped reads nothing, just like memory that isn't mapped in the process.
+        let Some(entry) = self.entry(self.offset).copied() else {
+            return Ok(0);
+        };
+
+        let position = self.offset - entry.address;
+        let size = (buf.len() as u64).min(entry.size - position) as usize;
+
+        self.file.seek(SeekFrom::Start(entry.offset + position))?;
+
+        let read = self.file.read(&mut buf[..size])?;
+
+        self.offset += read as u64;
+
+        Ok(read)
+    }
+}
+
+impl std::io::Seek for ProcessDump {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            std::io::SeekFrom::Current(offset) => {
+                self.offset = (self.offset as i64).wrapping_add(offset) as u64;
+            }
+            std::io::SeekFrom::End(offset) => {
+                self.offset = (i64::MAX).wrapping_add(offset) as u64;
+            }
+            std::io::SeekFrom::Start(offset) => {
+                self.offset = offset;
+            }
+        }
+
+        Ok(self.offset)
+    }
+}
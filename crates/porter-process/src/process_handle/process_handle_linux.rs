@@ -1,8 +1,16 @@
+use std::io::Read;
+
 use libc::*;
 
+use procfs::process::MMPermissions;
+use procfs::process::MMapPath;
+use procfs::process::MemoryMap;
+use procfs::process::Process;
+
 use crate::ProcessError;
 use crate::ProcessHandle;
 use crate::ProcessHandlePlatform;
+use crate::ProcessRegion;
 
 impl ProcessHandlePlatform for ProcessHandle {
     fn open_process(pid: u64, _: bool, _: bool) -> Result<Self, ProcessError> {
@@ -43,14 +51,59 @@ impl ProcessHandlePlatform for ProcessHandle {
     }
 
     fn base_address(&self) -> Result<u64, ProcessError> {
-        unimplemented!()
+        self.main_module_maps()?
+            .first()
+            .map(|map| map.address.0)
+            .ok_or(ProcessError::NotFound)
     }
 
     fn main_module_size(&self) -> Result<u64, ProcessError> {
-        unimplemented!()
+        let maps = self.main_module_maps()?;
+
+        match (maps.first(), maps.last()) {
+            (Some(first), Some(last)) => Ok(last.address.1 - first.address.0),
+            _ => Err(ProcessError::NotFound),
+        }
+    }
+
+    fn is_64bit(&self) -> Result<bool, ProcessError> {
+        let mut ident = [0u8; 5];
+
+        std::fs::File::open(Process::new(self.handle)?.exe()?)?.read_exact(&mut ident)?;
+
+        // The elf class in the identification bytes is 1 for 32 bit, and 2 for 64 bit.
+        match ident {
+            [0x7F, b'E', b'L', b'F', 1] => Ok(false),
+            [0x7F, b'E', b'L', b'F', 2] => Ok(true),
+            _ => Err(ProcessError::NotFound),
+        }
+    }
+
+    fn regions(&self) -> Result<Vec<ProcessRegion>, ProcessError> {
+        let maps = Process::new(self.handle)?.maps()?;
+
+        Ok(maps
+            .into_iter()
+            .filter(|map| map.perms.contains(MMPermissions::READ))
+            .map(|map| ProcessRegion::new(map.address.0, map.address.1 - map.address.0))
+            .collect())
     }
 
     fn close(&mut self) {
         // Nothing, there is no open handle on linux, just the pid.
     }
 }
+
+impl ProcessHandle {
+    /// Gets the mappings of the main executable, sorted by address.
+    fn main_module_maps(&self) -> Result<Vec<MemoryMap>, ProcessError> {
+        let process = Process::new(self.handle)?;
+        let exe = process.exe()?;
+
+        Ok(process
+            .maps()?
+            .into_iter()
+            .filter(|map| matches!(&map.pathname, MMapPath::Path(path) if *path == exe))
+            .collect())
+    }
+}
@@ -4,10 +4,13 @@ use mach2::mach_port::mach_port_deallocate;
 use mach2::task;
 use mach2::task_info::*;
 use mach2::vm::*;
+use mach2::vm_prot::VM_PROT_READ;
+use mach2::vm_region::*;
 
 use crate::ProcessError;
 use crate::ProcessHandle;
 use crate::ProcessHandlePlatform;
+use crate::ProcessRegion;
 
 #[repr(C)]
 #[allow(non_camel_case_types)]
@@ -110,6 +113,48 @@ impl ProcessHandlePlatform for ProcessHandle {
         unimplemented!()
     }
 
+    fn is_64bit(&self) -> Result<bool, ProcessError> {
+        // 32 bit processes are no longer supported on macos, and rosetta translated processes are 64 bit.
+        Ok(true)
+    }
+
+    fn regions(&self) -> Result<Vec<ProcessRegion>, ProcessError> {
+        let mut regions = Vec::new();
+        let mut address: mach_vm_address_t = 0;
+
+        loop {
+            let mut size: mach_vm_size_t = 0;
+            let mut info: vm_region_basic_info_64 = unsafe { std::mem::zeroed() };
+            let mut count = vm_region_basic_info_64::count();
+            let mut object_name: mach_port_t = 0;
+
+            let result = unsafe {
+                mach_vm_region(
+                    self.handle,
+                    &mut address as *mut mach_vm_address_t,
+                    &mut size as *mut mach_vm_size_t,
+                    VM_REGION_BASIC_INFO_64,
+                    &mut info as *mut vm_region_basic_info_64 as vm_region_info_t,
+                    &mut count as *mut mach_msg_type_number_t,
+                    &mut object_name as *mut mach_port_t,
+                )
+            };
+
+            // The end of the address space was reached.
+            if result != KERN_SUCCESS {
+                break;
+            }
+
+            if info.protection & VM_PROT_READ != 0 {
+                regions.push(ProcessRegion::new(address, size));
+            }
+
+            address = address.saturating_add(size);
+        }
+
+        Ok(regions)
+    }
+
     fn close(&mut self) {
         unsafe { mach_port_deallocate(mach_task_self(), self.handle) };
     }
@@ -2,12 +2,14 @@ use std::ffi::c_void;
 
 use windows_sys::Win32::Foundation::*;
 use windows_sys::Win32::System::Diagnostics::Debug::*;
+use windows_sys::Win32::System::Memory::*;
 use windows_sys::Win32::System::ProcessStatus::*;
 use windows_sys::Win32::System::Threading::*;
 
 use crate::ProcessError;
 use crate::ProcessHandle;
 use crate::ProcessHandlePlatform;
+use crate::ProcessRegion;
 
 impl ProcessHandlePlatform for ProcessHandle {
     fn open_process(pid: u64, read: bool, write: bool) -> Result<Self, ProcessError> {
@@ -137,6 +139,83 @@ impl ProcessHandlePlatform for ProcessHandle {
         Ok(module_info.SizeOfImage as u64)
     }
 
+    fn is_64bit(&self) -> Result<bool, ProcessError> {
+        let mut wow64: BOOL = FALSE;
+
+        let result = unsafe { IsWow64Process(self.handle, &mut wow64) };
+
+        if result == 0 {
+            match unsafe { GetLastError() } {
+                ERROR_INVALID_PARAMETER => return Err(ProcessError::NotFound),
+                ERROR_ACCESS_DENIED => return Err(ProcessError::AccessDenied),
+                _ => return Err(std::io::Error::last_os_error().into()),
+            }
+        }
+
+        // Processes running under wow64 are 32 bit, everything else matches the native width of the system.
+        if wow64 != FALSE {
+            return Ok(false);
+        }
+
+        if cfg!(target_pointer_width = "64") {
+            return Ok(true);
+        }
+
+        let mut current_wow64: BOOL = FALSE;
+
+        unsafe { IsWow64Process(GetCurrentProcess(), &mut current_wow64) };
+
+        Ok(current_wow64 != FALSE)
+    }
+
+    fn regions(&self) -> Result<Vec<ProcessRegion>, ProcessError> {
+        let mut regions = Vec::new();
+        let mut address: usize = 0;
+
+        loop {
+            let mut info: MEMORY_BASIC_INFORMATION = unsafe { std::mem::zeroed() };
+
+            let result = unsafe {
+                VirtualQueryEx(
+                    self.handle,
+                    address as *const c_void,
+                    &mut info,
+                    std::mem::size_of_val(&info),
+                )
+            };
+
+            // The end of the address space was reached.
+            if result == 0 {
+                break;
+            }
+
+            let readable = PAGE_READONLY
+                | PAGE_READWRITE
+                | PAGE_WRITECOPY
+                | PAGE_EXECUTE_READ
+                | PAGE_EXECUTE_READWRITE
+                | PAGE_EXECUTE_WRITECOPY;
+
+            if info.State == MEM_COMMIT
+                && info.Protect & readable != 0
+                && info.Protect & (PAGE_GUARD | PAGE_NOACCESS) == 0
+            {
+                regions.push(ProcessRegion::new(
+                    info.BaseAddress as u64,
+                    info.RegionSize as u64,
+                ));
+            }
+
+            let Some(next) = (info.BaseAddress as usize).checked_add(info.RegionSize) else {
+                break;
+            };
+
+            address = next;
+        }
+
+        Ok(regions)
+    }
+
     fn close(&mut self) {
         unsafe { CloseHandle(self.handle) };
     }
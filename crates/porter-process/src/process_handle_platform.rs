@@ -1,4 +1,5 @@
 use crate::ProcessError;
+use crate::ProcessRegion;
 
 /// Shared platform process handle trait.
 pub trait ProcessHandlePlatform
@@ -13,6 +14,10 @@ where
     fn base_address(&self) -> Result<u64, ProcessError>;
     /// Gets the size of the main module in bytes.
     fn main_module_size(&self) -> Result<u64, ProcessError>;
+    /// Whether or not the process is running as a 64 bit process.
+    fn is_64bit(&self) -> Result<bool, ProcessError>;
+    /// Gets the committed, readable memory regions of the process, sorted by address.
+    fn regions(&self) -> Result<Vec<ProcessRegion>, ProcessError>;
     /// Closes the handle of the process.
     fn close(&mut self);
 }
@@ -63,4 +63,37 @@ impl ProcessInfoPlatform for ProcessInfo {
     fn get_path(&self) -> Option<PathBuf> {
         self.path.clone()
     }
+
+    fn wait_for_exit(&self) -> Result<(), ProcessError> {
+        let pidfd = unsafe { syscall(SYS_pidfd_open, self.pid as pid_t, 0) } as c_int;
+
+        if pidfd < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        let mut poll_fd = pollfd {
+            fd: pidfd,
+            events: POLLIN,
+            revents: 0,
+        };
+
+        // The pidfd becomes readable once the process exits.
+        let result = loop {
+            let result = unsafe { poll(&mut poll_fd, 1, -1) };
+
+            if result >= 0
+                || std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted
+            {
+                break result;
+            }
+        };
+
+        unsafe { close(pidfd) };
+
+        if result < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        Ok(())
+    }
 }
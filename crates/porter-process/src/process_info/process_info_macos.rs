@@ -236,4 +236,42 @@ impl ProcessInfoPlatform for ProcessInfo {
     fn get_path(&self) -> Option<PathBuf> {
         self.path.clone()
     }
+
+    fn wait_for_exit(&self) -> Result<(), ProcessError> {
+        let queue = unsafe { kqueue() };
+
+        if queue < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        let change = kevent {
+            ident: self.pid as uintptr_t,
+            filter: EVFILT_PROC,
+            flags: EV_ADD | EV_ONESHOT,
+            fflags: NOTE_EXIT,
+            data: 0,
+            udata: std::ptr::null_mut(),
+        };
+
+        let mut event: kevent = unsafe { std::mem::zeroed() };
+
+        // Registers for the exit event and blocks until it's delivered.
+        let result = loop {
+            let result = unsafe { kevent(queue, &change, 1, &mut event, 1, std::ptr::null()) };
+
+            if result >= 0
+                || std::io::Error::last_os_error().kind() != std::io::ErrorKind::Interrupted
+            {
+                break result;
+            }
+        };
+
+        unsafe { close(queue) };
+
+        if result < 0 || event.flags & EV_ERROR != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        Ok(())
+    }
 }
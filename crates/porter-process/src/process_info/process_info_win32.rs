@@ -10,6 +10,7 @@ use windows_sys::Wdk::System::SystemInformation::*;
 use windows_sys::Win32::Foundation::*;
 use windows_sys::Win32::System::Threading::*;
 use windows_sys::Win32::System::WindowsProgramming::*;
+use windows_sys::Win32::UI::WindowsAndMessaging::*;
 
 use widestring::U16CStr;
 
@@ -36,6 +37,64 @@ fn create_time_to_sys_time(create_time: u64) -> SystemTime {
     SystemTime::UNIX_EPOCH + Duration::new(seconds, nanoseconds)
 }
 
+/// Collects the process id and title of each visible top level window.
+unsafe extern "system" fn enum_windows_callback(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    // SAFETY: The parameter is the window list passed to EnumWindows, which is alive for the duration of the call.
+    let windows = unsafe { &mut *(lparam as *mut Vec<(u64, String)>) };
+
+    // Only unowned visible windows are considered the main window of a process.
+    if unsafe { IsWindowVisible(hwnd) } == FALSE || unsafe { GetWindow(hwnd, GW_OWNER) } != 0 {
+        return TRUE;
+    }
+
+    let mut buffer: [u16; 512] = [0; 512];
+
+    let length = unsafe { GetWindowTextW(hwnd, buffer.as_mut_ptr(), buffer.len() as i32) };
+
+    if length <= 0 {
+        return TRUE;
+    }
+
+    let mut pid: u32 = 0;
+
+    unsafe { GetWindowThreadProcessId(hwnd, &mut pid) };
+
+    windows.push((
+        pid as u64,
+        String::from_utf16_lossy(&buffer[..length as usize]),
+    ));
+
+    TRUE
+}
+
+impl ProcessInfo {
+    /// Gets the process ids of processes with a main window matching the given title, case sensitive.
+    pub fn get_pids_by_window_title(title: &str) -> Result<Vec<u64>, ProcessError> {
+        let mut windows: Vec<(u64, String)> = Vec::new();
+
+        let result = unsafe {
+            EnumWindows(
+                Some(enum_windows_callback),
+                &mut windows as *mut Vec<(u64, String)> as LPARAM,
+            )
+        };
+
+        if result == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        let mut pids: Vec<u64> = windows
+            .into_iter()
+            .filter(|(_, window_title)| window_title == title)
+            .map(|(pid, _)| pid)
+            .collect();
+
+        pids.dedup();
+
+        Ok(pids)
+    }
+}
+
 impl ProcessInfoPlatform for ProcessInfo {
     fn get_processes<F: AsRef<[u64]>>(filter: F) -> Result<Vec<Self>, ProcessError> {
         let filter = filter.as_ref();
@@ -155,4 +214,26 @@ impl ProcessInfoPlatform for ProcessInfo {
             Some(PathBuf::from(string))
         }
     }
+
+    fn wait_for_exit(&self) -> Result<(), ProcessError> {
+        let handle: HANDLE = unsafe { OpenProcess(PROCESS_SYNCHRONIZE, FALSE, self.pid as u32) };
+
+        if handle == 0 {
+            match unsafe { GetLastError() } {
+                ERROR_INVALID_PARAMETER => return Err(ProcessError::NotFound),
+                ERROR_ACCESS_DENIED => return Err(ProcessError::AccessDenied),
+                _ => return Err(std::io::Error::last_os_error().into()),
+            }
+        }
+
+        let result = unsafe { WaitForSingleObject(handle, INFINITE) };
+
+        unsafe { CloseHandle(handle) };
+
+        if result == WAIT_FAILED {
+            return Err(std::io::Error::last_os_error().into());
+        }
+
+        Ok(())
+    }
 }
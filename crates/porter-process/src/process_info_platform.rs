@@ -11,4 +11,6 @@ where
     fn get_processes<F: AsRef<[u64]>>(filter: F) -> Result<Vec<Self>, ProcessError>;
     /// Gets the full path of the process if available.
     fn get_path(&self) -> Option<PathBuf>;
+    /// Blocks until the process exits.
+    fn wait_for_exit(&self) -> Result<(), ProcessError>;
 }
@@ -19,6 +19,34 @@ where
     _phantom: PhantomData<T>,
 }
 
+impl<S, T> ProcessPointer<S, T>
+where
+    S: Copy + 'static,
+    T: Copy + 'static,
+{
+    /// Constructs a new pointer to the given address.
+    pub const fn new(address: S) -> Self {
+        Self {
+            inner: address,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// The address this pointer points to.
+    pub const fn address(&self) -> S {
+        self.inner
+    }
+}
+
+impl<T> From<ProcessPointer<u32, T>> for ProcessPointer<u64, T>
+where
+    T: Copy + 'static,
+{
+    fn from(value: ProcessPointer<u32, T>) -> Self {
+        Self::new(value.inner as u64)
+    }
+}
+
 impl ProcessPointer<u64, &str> {
     /// Reads a null terminated string.
     pub fn read_string<R: Read + Seek>(&self, reader: &mut R) -> Result<String, ProcessError> {
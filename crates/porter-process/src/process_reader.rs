@@ -1,20 +1,117 @@
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::ops::Range;
 use std::sync::Arc;
 
+use porter_utils::StructReadExt;
+use porter_utils::StructWriteExt;
+
+use crate::ProcessCache;
+use crate::ProcessDumpEntry;
+use crate::ProcessDumpHeader;
 use crate::ProcessError;
 use crate::ProcessHandle;
 use crate::ProcessHandlePlatform;
+use crate::ProcessPointer;
+use crate::ProcessRegion;
+use crate::PROCESS_DUMP_MAGIC;
+use crate::PROCESS_DUMP_PAGE_SIZE;
+use crate::PROCESS_DUMP_VERSION;
+
+/// The size of each read when dumping a region.
+const DUMP_CHUNK_SIZE: usize = 0x10000;
 
 /// An open process for reading.
 #[derive(Debug, Clone)]
 pub struct ProcessReader {
     offset: u64,
     handle: Arc<ProcessHandle>,
+    cache: Option<ProcessCache>,
+    is_64bit: bool,
 }
 
 impl ProcessReader {
     /// Constructs a new process reader from the given handle.
     pub(crate) fn from_handle(handle: Arc<ProcessHandle>) -> Self {
-        Self { offset: 0, handle }
+        let is_64bit = handle
+            .is_64bit()
+            .unwrap_or(cfg!(target_pointer_width = "64"));
+
+        Self {
+            offset: 0,
+            handle,
+            cache: None,
+            is_64bit,
+        }
+    }
+
+    /// Caches reads in pages of the given size in bytes, keeping up to the given number of pages.
+    ///
+    /// Cached memory isn't refreshed when the process changes it, use [`ProcessReader::invalidate_cache`] as needed.
+    pub fn with_cache(mut self, page_size: usize, pages: usize) -> Self {
+        self.cache = Some(ProcessCache::new(page_size, pages));
+        self
+    }
+
+    /// Loads the given range into the cache ahead of time, does nothing without a cache.
+    pub fn prefetch(&mut self, range: Range<u64>) -> Result<(), ProcessError> {
+        match &mut self.cache {
+            Some(cache) => cache.prefetch(&self.handle, range),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes all cached memory, so the next reads come from the process.
+    pub fn invalidate_cache(&mut self) {
+        if let Some(cache) = &mut self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Whether or not the process is running as a 64 bit process.
+    pub fn is_64bit(&self) -> bool {
+        self.is_64bit
+    }
+
+    /// The size of a pointer in the process in bytes.
+    pub fn pointer_size(&self) -> usize {
+        if self.is_64bit {
+            8
+        } else {
+            4
+        }
+    }
+
+    /// Reads a 32 bit pointer at the given address.
+    pub fn read_ptr32(&mut self, address: u64) -> Result<u64, ProcessError> {
+        self.seek(SeekFrom::Start(address))?;
+
+        Ok(self.read_struct::<u32>()? as u64)
+    }
+
+    /// Reads a 64 bit pointer at the given address.
+    pub fn read_ptr64(&mut self, address: u64) -> Result<u64, ProcessError> {
+        self.seek(SeekFrom::Start(address))?;
+
+        Ok(self.read_struct::<u64>()?)
+    }
+
+    /// Reads a pointer the size of the process pointer width at the given address.
+    pub fn read_ptr(&mut self, address: u64) -> Result<u64, ProcessError> {
+        if self.is_64bit {
+            self.read_ptr64(address)
+        } else {
+            self.read_ptr32(address)
+        }
+    }
+
+    /// Reads a pointer to [`T`] the size of the process pointer width at the given address.
+    pub fn read_pointer<T: Copy + 'static>(
+        &mut self,
+        address: u64,
+    ) -> Result<ProcessPointer<u64, T>, ProcessError> {
+        self.read_ptr(address).map(ProcessPointer::new)
     }
 
     /// Gets the base address from the process.
@@ -26,11 +123,118 @@ impl ProcessReader {
     pub fn main_module_size(&self) -> Result<u64, ProcessError> {
         self.handle.main_module_size()
     }
+
+    /// Gets the committed, readable memory regions of the process, sorted by address.
+    pub fn regions(&self) -> Result<Vec<ProcessRegion>, ProcessError> {
+        self.handle.regions()
+    }
+
+    /// Writes the memory in the given range to the writer, returning the number of bytes that could be read.
+    ///
+    /// Pages that can't be read are written as zeros, so the output always matches the size of the range.
+    pub fn dump_region<W: Write>(
+        &self,
+        range: Range<u64>,
+        writer: &mut W,
+    ) -> Result<u64, ProcessError> {
+        let mut buffer = vec![0; DUMP_CHUNK_SIZE];
+        let mut address = range.start;
+        let mut total_read: u64 = 0;
+
+        while address < range.end {
+            let size = (range.end - address).min(DUMP_CHUNK_SIZE as u64) as usize;
+            let chunk = &mut buffer[..size];
+
+            let mut read = self.handle.read(address, chunk).unwrap_or(0).min(size);
+
+            total_read += read as u64;
+
+            // A short read stops at the first unreadable page, so retry the rest one page at a time.
+            while read < size {
+                let page_end =
+                    ((address + read as u64) / PROCESS_DUMP_PAGE_SIZE + 1) * PROCESS_DUMP_PAGE_SIZE;
+                let page_end = ((page_end - address) as usize).min(size);
+
+                let page = &mut chunk[read..page_end];
+                let page_read = self
+                    .handle
+                    .read(address + read as u64, page)
+                    .unwrap_or(0)
+                    .min(page.len());
+
+                page[page_read..].fill(0);
+
+                total_read += page_read as u64;
+                read = page_end;
+            }
+
+            writer.write_all(chunk)?;
+
+            address += size as u64;
+        }
+
+        Ok(total_read)
+    }
+
+    /// Dumps all committed, readable memory of the process to the writer, which can be opened later with [`crate::ProcessDump`].
+    ///
+    /// Returns the number of bytes that could be read.
+    pub fn dump<W: Write + Seek>(&self, writer: &mut W) -> Result<u64, ProcessError> {
+        let start = writer.stream_position()?;
+
+        let mut header = ProcessDumpHeader {
+            magic: PROCESS_DUMP_MAGIC,
+            version: PROCESS_DUMP_VERSION,
+            page_size: PROCESS_DUMP_PAGE_SIZE as u32,
+            base_address: self.base_address().unwrap_or_default(),
+            main_module_size: self.main_module_size().unwrap_or_default(),
+            ..Default::default()
+        };
+
+        writer.write_struct(header)?;
+
+        let mut index: Vec<ProcessDumpEntry> = Vec::new();
+        let mut total_read: u64 = 0;
+
+        for region in self.regions()? {
+            // Each region starts on a page boundary, so the data can be memory mapped.
+            let position = writer.stream_position()? - start;
+            let padding = position.next_multiple_of(PROCESS_DUMP_PAGE_SIZE) - position;
+
+            writer.write_all(&vec![0; padding as usize])?;
+
+            index.push(ProcessDumpEntry {
+                address: region.address,
+                size: region.size,
+                offset: position + padding,
+            });
+
+            total_read += self.dump_region(region.range(), writer)?;
+        }
+
+        header.index_offset = writer.stream_position()? - start;
+        header.index_count = index.len() as u64;
+
+        for entry in index {
+            writer.write_struct(entry)?;
+        }
+
+        let end = writer.stream_position()?;
+
+        writer.seek(SeekFrom::Start(start))?;
+        writer.write_struct(header)?;
+        writer.seek(SeekFrom::Start(end))?;
+
+        Ok(total_read)
+    }
 }
 
 impl std::io::Read for ProcessReader {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        let read = self.handle.read(self.offset, buf)?;
+        let read = match &mut self.cache {
+            Some(cache) => cache.read(&self.handle, self.offset, buf)?,
+            None => self.handle.read(self.offset, buf)?,
+        };
 
         self.offset += read as u64;
 
@@ -0,0 +1,22 @@
+use std::ops::Range;
+
+/// A committed, readable region of memory in a process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessRegion {
+    /// The start address of the region.
+    pub address: u64,
+    /// The size of the region in bytes.
+    pub size: u64,
+}
+
+impl ProcessRegion {
+    /// Constructs a new process region.
+    pub const fn new(address: u64, size: u64) -> Self {
+        Self { address, size }
+    }
+
+    /// The address range covered by this region.
+    pub const fn range(&self) -> Range<u64> {
+        self.address..self.address + self.size
+    }
+}
@@ -0,0 +1,17 @@
+/// The index format of a ps2 palettized texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClutIndexFormat {
+    /// 4 bit indices into a 16 color table, the low nibble is the first pixel.
+    Psmt4,
+    /// 8 bit indices into a 256 color table, stored with the ps2's csm1 swizzle.
+    Psmt8,
+}
+
+/// The color format of the entries in a ps2 color lookup table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClutColorFormat {
+    /// 32 bit rgba colors, where an alpha of 0x80 is opaque.
+    Psmct32,
+    /// 16 bit colors with 5 bits of red, green, blue, and a 1 bit alpha.
+    Psmct16,
+}
@@ -12,6 +12,7 @@ pub enum TextureError {
     FrameAllocationFailed,
     ContainerFormatInvalid(ImageFormat, ImageFileType),
     ContainerInvalid(ImageFileType),
+    ContainerReadOnly(ImageFileType),
     ConversionError,
     InvalidOperation,
     IoError(std::io::Error),
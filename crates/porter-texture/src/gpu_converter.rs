@@ -8,9 +8,12 @@ use porter_utils::AsAligned;
 use porter_utils::AsByteSlice;
 
 use porter_gpu::gpu_instance;
+use porter_gpu::try_gpu_instance;
 use porter_gpu::GPUInstance;
 
+use crate::ImageChannel;
 use crate::ImageConvertOptions;
+use crate::ImageNormalMapOptions;
 use crate::TextureError;
 use crate::TextureExtensions;
 
@@ -20,6 +23,26 @@ struct GPUOptionsUniform {
     input_unorm: u32,
     output_unorm: u32,
     invert_y: u32,
+    padding: u32,
+    remap: [u32; 4],
+    invert: [u32; 4],
+}
+
+/// Whether or not a gpu is available, and supports converting between the given formats.
+pub fn is_gpu_converter_supported(
+    input_format: TextureFormat,
+    output_format: TextureFormat,
+) -> bool {
+    let Some(instance) = try_gpu_instance() else {
+        return false;
+    };
+
+    !instance.is_lost()
+        && instance.supports_format(input_format, TextureUsages::TEXTURE_BINDING)
+        && instance.supports_format(
+            output_format,
+            TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+        )
 }
 
 /// Converts textures from one format to another (uncompressed only).
@@ -80,9 +103,29 @@ impl GPUConverter {
         let uniforms = GPUOptionsUniform {
             input_unorm: self.input_format.is_unorm() as u32,
             output_unorm: self.output_format.is_unorm() as u32,
-            invert_y: (matches!(self.options, ImageConvertOptions::ReconstructZInvertY)
-                || matches!(self.options, ImageConvertOptions::AutoReconstructZInvertY))
-                as u32,
+            invert_y: matches!(
+                self.options.normal_map,
+                ImageNormalMapOptions::ReconstructZInvertY
+                    | ImageNormalMapOptions::AutoReconstructZInvertY
+            ) as u32,
+            padding: 0,
+            remap: self
+                .options
+                .channel_remap
+                .channels
+                .map(|channel| match channel {
+                    ImageChannel::Red => 0,
+                    ImageChannel::Green => 1,
+                    ImageChannel::Blue => 2,
+                    ImageChannel::Alpha => 3,
+                    ImageChannel::Zero => 4,
+                    ImageChannel::One => 5,
+                }),
+            invert: self
+                .options
+                .channel_remap
+                .invert
+                .map(|invert| invert as u32),
         };
 
         self.instance
@@ -191,13 +234,13 @@ impl GPUConverter {
                     push_constant_ranges: &[],
                 });
 
-        let fragment_entry = match self.options {
-            ImageConvertOptions::None => "fs_main",
-            ImageConvertOptions::ReconstructZ | ImageConvertOptions::ReconstructZInvertY => {
+        let fragment_entry = match self.options.normal_map {
+            ImageNormalMapOptions::None => "fs_main",
+            ImageNormalMapOptions::ReconstructZ | ImageNormalMapOptions::ReconstructZInvertY => {
                 "fs_rz_main"
             }
-            ImageConvertOptions::AutoReconstructZ
-            | ImageConvertOptions::AutoReconstructZInvertY => {
+            ImageNormalMapOptions::AutoReconstructZ
+            | ImageNormalMapOptions::AutoReconstructZInvertY => {
                 if matches!(self.input_format, TextureFormat::Bc5RgUnorm) {
                     "fs_rz_main"
                 } else {
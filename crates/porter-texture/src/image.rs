@@ -1,29 +1,55 @@
 use wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
 
 use porter_utils::AsAligned;
+use porter_utils::SanitizeFilename;
 
 use porter_math::Rect;
 
 use crate::format_to_bpp;
 use crate::format_to_buffer_size;
+use crate::format_to_srgb;
+use crate::format_to_unorm;
 use crate::format_to_wgpu;
 use crate::image_file_type_dds;
+use crate::image_file_type_gtf;
+use crate::image_file_type_ktx;
 use crate::image_file_type_png;
 use crate::image_file_type_tga;
 use crate::image_file_type_tiff;
+use crate::image_file_type_xpr;
 use crate::is_format_compressed;
+use crate::is_format_hdr;
 use crate::is_format_requires_unpack;
+use crate::is_format_software_quantized;
+use crate::is_format_srgb;
 use crate::is_format_swizzled;
+use crate::is_gpu_converter_supported;
+use crate::software_convert_image;
+use crate::software_decode_clut;
+use crate::software_deswizzle_frame;
+use crate::software_quantize_image;
 use crate::software_swizzle_image;
+use crate::software_swizzled_frame_size;
+use crate::software_tone_map_image;
 use crate::software_unpack_image;
+use crate::ClutColorFormat;
+use crate::ClutIndexFormat;
 use crate::Frame;
 use crate::GPUConverter;
+use crate::ImageAtlasRegion;
+use crate::ImageColorSpace;
+use crate::ImageConvertDepth;
 use crate::ImageConvertOptions;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageLayout;
+use crate::ImageToneMapping;
+use crate::SwizzlePattern;
 use crate::TextureError;
 use crate::TextureExtensions;
 
+use std::collections::HashSet;
+use std::ffi::OsString;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::BufWriter;
@@ -31,6 +57,7 @@ use std::io::Read;
 use std::io::Seek;
 use std::io::Write;
 use std::path::Path;
+use std::path::PathBuf;
 use std::slice::Iter;
 use std::slice::IterMut;
 
@@ -41,6 +68,8 @@ pub struct Image {
     height: u32,
     mipmaps: u32,
     format: ImageFormat,
+    layout: Option<ImageLayout>,
+    color_space: Option<ImageColorSpace>,
     frames: Vec<Frame>,
 }
 
@@ -60,6 +89,8 @@ impl Image {
             height,
             mipmaps: 1,
             format,
+            layout: None,
+            color_space: None,
             frames: Vec::new(),
         })
     }
@@ -88,17 +119,68 @@ impl Image {
             height,
             mipmaps,
             format,
+            layout: None,
+            color_space: None,
             frames: Vec::new(),
         })
     }
 
+    /// Creates a new r8g8b8a8 image from a ps2 palettized texture and it's color lookup table.
+    pub fn from_clut(
+        width: u32,
+        height: u32,
+        index_format: ClutIndexFormat,
+        indices: &[u8],
+        color_format: ClutColorFormat,
+        clut: &[u8],
+    ) -> Result<Self, TextureError> {
+        let mut image = Self::new(width, height, ImageFormat::R8G8B8A8Unorm)?;
+
+        let frame = image.create_frame()?;
+
+        software_decode_clut(
+            index_format,
+            indices,
+            color_format,
+            clut,
+            frame.buffer_mut(),
+        )?;
+
+        Ok(image)
+    }
+
     /// Converts all frames of the image to the specified format.
+    ///
+    /// Encoded values are kept between low dynamic range formats, and only srgb images are linearized when converting to high dynamic range.
     pub fn convert(
         &mut self,
         format: ImageFormat,
         options: ImageConvertOptions,
     ) -> Result<(), TextureError> {
-        if self.format == format {
+        let color_space = self.color_space();
+        let source_hdr = is_format_hdr(self.format);
+
+        self.convert_frames(format, options, color_space)?;
+
+        self.color_space = match (source_hdr, is_format_hdr(format)) {
+            (false, false) => color_space,
+            (false, true) => Some(ImageColorSpace::Linear),
+            (true, _) => None,
+        };
+
+        Ok(())
+    }
+
+    /// Converts all frames of the image to the specified format, treating the source values as the given color space.
+    fn convert_frames(
+        &mut self,
+        format: ImageFormat,
+        options: ImageConvertOptions,
+        color_space: Option<ImageColorSpace>,
+    ) -> Result<(), TextureError> {
+        let remap = !options.channel_remap.is_identity();
+
+        if self.format == format && !remap {
             return Ok(());
         }
 
@@ -106,10 +188,32 @@ impl Image {
             return Err(TextureError::UnsupportedImageFormat(format));
         }
 
+        let tone_map = options.tone_mapping != ImageToneMapping::None || options.exposure != 0.0;
+
+        if (matches!(options.depth, ImageConvertDepth::Float) || tone_map)
+            && is_format_hdr(self.format)
+            && !is_format_hdr(format)
+        {
+            self.convert(
+                ImageFormat::R32G32B32A32Float,
+                options.depth(ImageConvertDepth::Target),
+            )?;
+
+            if tone_map {
+                software_tone_map_image(self, options.tone_mapping, options.exposure)?;
+            }
+
+            if is_format_software_quantized(format) {
+                return software_quantize_image(self, format);
+            }
+
+            return self.convert(format, ImageConvertOptions::new());
+        }
+
         if is_format_requires_unpack(self.format) {
             software_unpack_image(self)?;
 
-            if self.format == format {
+            if self.format == format && !remap {
                 return Ok(());
             }
         }
@@ -119,18 +223,34 @@ impl Image {
 
             self.format = format;
 
-            return Ok(());
+            if !remap {
+                return Ok(());
+            }
         }
 
-        let source_format = format_to_wgpu(self.format)?;
-        let target_format = format_to_wgpu(format)?;
+        // Only the srgb flag differs between the working formats, which changes the values the converter reads and writes.
+        let (source, target) = match (is_format_hdr(self.format), is_format_hdr(format)) {
+            (false, false) => (format_to_unorm(self.format), format_to_unorm(format)),
+            (false, true) if color_space == Some(ImageColorSpace::Srgb) => {
+                (format_to_srgb(self.format), format)
+            }
+            (false, true) => (format_to_unorm(self.format), format),
+            (true, _) => (self.format, format),
+        };
 
-        self.mipmaps = 1;
+        let source_format = format_to_wgpu(source)?;
+        let target_format = format_to_wgpu(target)?;
+
+        if !is_gpu_converter_supported(source_format, target_format) {
+            return self.convert_software(source, format, target, options);
+        }
 
         let width = self.width;
         let height = self.height;
 
-        for frame in self.frames_mut() {
+        let mut buffers = Vec::new();
+
+        for frame in self.frames() {
             let block_dims = target_format.block_dimensions();
 
             let bytes_per_row = target_format.bytes_per_row(width) as usize;
@@ -147,7 +267,15 @@ impl Image {
             let mut converter = GPUConverter::new(width, height, source_format, target_format);
 
             converter.set_options(options);
-            converter.convert(frame.buffer(), &mut buffer)?;
+
+            // The device may be lost mid conversion, the frames are untouched so finish in software.
+            if let Err(error) = converter.convert(frame.buffer(), &mut buffer) {
+                if is_gpu_converter_supported(source_format, target_format) {
+                    return Err(error);
+                }
+
+                return self.convert_software(source, format, target, options);
+            }
 
             let truncated_size = target_format.buffer_size(width, height) as usize;
 
@@ -163,11 +291,40 @@ impl Image {
                 }
 
                 buffer.resize(truncated_size, 0);
-
-                frame.replace_buffer(buffer);
-            } else {
-                frame.replace_buffer(buffer);
             }
+
+            buffers.push(buffer);
+        }
+
+        self.mipmaps = 1;
+
+        for (frame, buffer) in self.frames_mut().zip(buffers) {
+            frame.replace_buffer(buffer);
+        }
+
+        self.format = format;
+
+        Ok(())
+    }
+
+    /// Converts the frames in software, reading them as the source working format and writing the target working format.
+    fn convert_software(
+        &mut self,
+        source: ImageFormat,
+        format: ImageFormat,
+        target: ImageFormat,
+        options: ImageConvertOptions,
+    ) -> Result<(), TextureError> {
+        let original = self.format;
+
+        log::debug!("Converting {:?} to {:?} in software", source, target);
+
+        self.format = source;
+
+        if let Err(error) = software_convert_image(self, target, options) {
+            self.format = original;
+
+            return Err(error);
         }
 
         self.format = format;
@@ -283,6 +440,92 @@ impl Image {
         Ok(())
     }
 
+    /// Splits the first frame of the image into a new image for each rect, clamped to the image bounds.
+    pub fn split(&self, rects: &[Rect]) -> Result<Vec<Self>, TextureError> {
+        if is_format_compressed(self.format) {
+            let mut unpacked = self.clone();
+
+            unpacked.convert(ImageFormat::R8G8B8A8Unorm, ImageConvertOptions::new())?;
+
+            return unpacked.split(rects);
+        }
+
+        let bounds = Rect::new(0, 0, self.width, self.height);
+
+        // Copying requires matching frame counts, so only the first frame is used as the source.
+        let source = Self {
+            layout: None,
+            frames: self.frames.iter().take(1).cloned().collect(),
+            ..*self
+        };
+
+        let mut result = Vec::with_capacity(rects.len());
+
+        for rect in rects {
+            let rect = rect
+                .intersection(&bounds)
+                .ok_or(TextureError::InvalidImageSize(rect.width, rect.height))?;
+
+            let mut image = Self::new(rect.width, rect.height, self.format)?;
+
+            image.color_space = self.color_space;
+            image.create_frame()?;
+            image.copy_rect(&source, rect, 0, 0)?;
+
+            result.push(image);
+        }
+
+        Ok(result)
+    }
+
+    /// Splits the image into each atlas region, saving each one to the directory with a sanitized, unique name.
+    pub fn save_atlas<P: AsRef<Path>>(
+        &self,
+        directory: P,
+        regions: &[ImageAtlasRegion],
+        file_type: ImageFileType,
+    ) -> Result<Vec<PathBuf>, TextureError> {
+        let rects: Vec<Rect> = regions.iter().map(|region| region.rect).collect();
+        let images = self.split(&rects)?;
+
+        let mut names: HashSet<String> = HashSet::new();
+        let mut paths = Vec::with_capacity(images.len());
+
+        for (index, (region, mut image)) in regions.iter().zip(images).enumerate() {
+            let mut name = region.name.sanitized();
+
+            if name.is_empty() {
+                name = format!("region_{}", index);
+            }
+
+            let mut unique = name.clone();
+            let mut suffix = 1;
+
+            while !names.insert(unique.to_lowercase()) {
+                unique = format!("{}_{}", name, suffix);
+                suffix += 1;
+            }
+
+            // Names often contain dots, so the extension is appended instead of replaced.
+            let mut file_name = OsString::from(unique);
+
+            file_name.push(".");
+            file_name.push(file_type);
+
+            let path = directory.as_ref().join(file_name);
+
+            image.convert(
+                image.format_for_file_type(file_type),
+                ImageConvertOptions::new(),
+            )?;
+            image.save(&path, file_type)?;
+
+            paths.push(path);
+        }
+
+        Ok(paths)
+    }
+
     /// Calculates the optimal image format required to save this image to the given file type.
     pub fn format_for_file_type(&self, file_type: ImageFileType) -> ImageFormat {
         match file_type {
@@ -290,6 +533,21 @@ impl Image {
             ImageFileType::Png => image_file_type_png::pick_format(self.format),
             ImageFileType::Tiff => image_file_type_tiff::pick_format(self.format),
             ImageFileType::Tga => image_file_type_tga::pick_format(self.format),
+            ImageFileType::Ktx | ImageFileType::Xpr | ImageFileType::Gtf => self.format,
+        }
+    }
+
+    /// Calculates the optimal image format required to save this image to the given file type and options.
+    pub fn format_for_file_type_with_options(
+        &self,
+        file_type: ImageFileType,
+        options: ImageConvertOptions,
+    ) -> ImageFormat {
+        match (file_type, options.depth) {
+            (ImageFileType::Tiff, ImageConvertDepth::Float) => {
+                image_file_type_tiff::pick_format_float(self.format)
+            }
+            _ => self.format_for_file_type(file_type),
         }
     }
 
@@ -311,6 +569,9 @@ impl Image {
             ImageFileType::Png => image_file_type_png::from_png(input),
             ImageFileType::Tiff => image_file_type_tiff::from_tiff(input),
             ImageFileType::Tga => image_file_type_tga::from_tga(input),
+            ImageFileType::Ktx => image_file_type_ktx::from_ktx(input),
+            ImageFileType::Xpr => image_file_type_xpr::from_xpr(input),
+            ImageFileType::Gtf => image_file_type_gtf::from_gtf(input),
         }
     }
 
@@ -341,6 +602,9 @@ impl Image {
             ImageFileType::Png => image_file_type_png::to_png(self, output),
             ImageFileType::Tiff => image_file_type_tiff::to_tiff(self, output),
             ImageFileType::Tga => image_file_type_tga::to_tga(self, output),
+            ImageFileType::Ktx | ImageFileType::Xpr | ImageFileType::Gtf => {
+                Err(TextureError::ContainerReadOnly(file_type))
+            }
         }
     }
 
@@ -382,6 +646,34 @@ impl Image {
             .ok_or(TextureError::FrameAllocationFailed)
     }
 
+    /// Returns the size of a frame stored in the given console swizzle pattern, including any padding between frames.
+    pub fn swizzled_frame_size(&self, pattern: SwizzlePattern) -> Result<u32, TextureError> {
+        software_swizzled_frame_size(self.format, self.width, self.height, self.mipmaps, pattern)
+    }
+
+    /// Allocates and creates a new frame, deswizzling every mipmap from the buffer stored in the given console swizzle pattern.
+    pub fn create_frame_from_swizzled(
+        &mut self,
+        buffer: &[u8],
+        pattern: SwizzlePattern,
+    ) -> Result<&mut Frame, TextureError> {
+        let (width, height, mipmaps, format) = (self.width, self.height, self.mipmaps, self.format);
+
+        let frame = self.create_frame()?;
+
+        software_deswizzle_frame(
+            format,
+            width,
+            height,
+            mipmaps,
+            pattern,
+            buffer,
+            frame.buffer_mut(),
+        )?;
+
+        Ok(frame)
+    }
+
     /// Returns the base width of the image, all frames must be <= this width.
     pub fn width(&self) -> u32 {
         self.width
@@ -417,8 +709,36 @@ impl Image {
         self.frames.iter_mut()
     }
 
-    /// Image is considered a cubemap if it has exactly 6 frames.
+    /// Returns how the frames are arranged, when not set an image with exactly 6 frames is a cubemap.
+    pub fn layout(&self) -> ImageLayout {
+        match self.layout {
+            Some(layout) => layout,
+            None if self.frames.len() == 6 => ImageLayout::Cubemap,
+            None => ImageLayout::Texture,
+        }
+    }
+
+    /// Sets how the frames are arranged.
+    pub fn set_layout(&mut self, layout: ImageLayout) {
+        self.layout = Some(layout);
+    }
+
+    /// How the color values are encoded, srgb formats are srgb unless set, and `None` is unknown.
+    pub fn color_space(&self) -> Option<ImageColorSpace> {
+        match self.color_space {
+            Some(color_space) => Some(color_space),
+            None if is_format_srgb(self.format) => Some(ImageColorSpace::Srgb),
+            None => None,
+        }
+    }
+
+    /// Sets how the color values are encoded, which is written as metadata when saving.
+    pub fn set_color_space(&mut self, color_space: ImageColorSpace) {
+        self.color_space = Some(color_space);
+    }
+
+    /// Image is considered a cubemap if it has exactly 6 frames laid out as cubemap faces.
     pub fn is_cubemap(&self) -> bool {
-        self.frames.len() == 6
+        self.frames.len() == 6 && self.layout() == ImageLayout::Cubemap
     }
 }
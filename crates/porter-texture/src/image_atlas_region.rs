@@ -0,0 +1,18 @@
+use porter_math::Rect;
+
+/// A named region of a texture atlas, such as a sprite from ui metadata.
+#[derive(Debug, Clone)]
+pub struct ImageAtlasRegion {
+    pub name: String,
+    pub rect: Rect,
+}
+
+impl ImageAtlasRegion {
+    /// Constructs a new atlas region with the given name and rect.
+    pub fn new<N: Into<String>>(name: N, rect: Rect) -> Self {
+        Self {
+            name: name.into(),
+            rect,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+/// How the color values of an image are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageColorSpace {
+    /// Values are gamma encoded with the srgb transfer function, such as albedo.
+    Srgb,
+    /// Values are stored as is, such as normal maps, masks, and high dynamic range data.
+    Linear,
+}
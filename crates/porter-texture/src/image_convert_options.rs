@@ -1,5 +1,9 @@
-#[derive(Default, Clone, Copy)]
-pub enum ImageConvertOptions {
+use bincode::Decode;
+use bincode::Encode;
+
+/// Normal map processing to apply when converting an image.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageNormalMapOptions {
     /// Do nothing to modify the image.
     #[default]
     None,
@@ -12,3 +16,185 @@ pub enum ImageConvertOptions {
     /// Only reconstruct the Z channel and invert the Y channel of the image when the format is Bc5Unorm.
     AutoReconstructZInvertY,
 }
+
+/// The working color depth used while converting an image.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageConvertDepth {
+    /// Convert directly to the target format.
+    #[default]
+    Target,
+    /// Keep high dynamic range sources in a float working format, only quantizing at the final step.
+    Float,
+}
+
+/// Tone mapping applied when converting high dynamic range images to low dynamic range formats.
+#[derive(Decode, Encode, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImageToneMapping {
+    /// Clamp the color to the low dynamic range.
+    #[default]
+    None,
+    /// The reinhard operator, which compresses highlights evenly.
+    Reinhard,
+    /// The aces filmic curve, which keeps more contrast in the mid tones.
+    Aces,
+}
+
+/// A channel of an image, or a constant, used as the source when remapping channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageChannel {
+    Red,
+    Green,
+    Blue,
+    Alpha,
+    Zero,
+    One,
+}
+
+/// Remaps each output channel from a source channel, optionally inverting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageChannelRemap {
+    /// The source of each output channel, in rgba order.
+    pub channels: [ImageChannel; 4],
+    /// Whether or not each output channel is inverted (one minus the value), in rgba order.
+    pub invert: [bool; 4],
+}
+
+impl ImageChannelRemap {
+    /// Constructs a new channel remap that keeps every channel as is.
+    pub const fn new() -> Self {
+        Self {
+            channels: [
+                ImageChannel::Red,
+                ImageChannel::Green,
+                ImageChannel::Blue,
+                ImageChannel::Alpha,
+            ],
+            invert: [false; 4],
+        }
+    }
+
+    /// Sets the source of the red output channel.
+    pub const fn red(mut self, source: ImageChannel) -> Self {
+        self.channels[0] = source;
+        self
+    }
+
+    /// Sets the source of the green output channel.
+    pub const fn green(mut self, source: ImageChannel) -> Self {
+        self.channels[1] = source;
+        self
+    }
+
+    /// Sets the source of the blue output channel.
+    pub const fn blue(mut self, source: ImageChannel) -> Self {
+        self.channels[2] = source;
+        self
+    }
+
+    /// Sets the source of the alpha output channel.
+    pub const fn alpha(mut self, source: ImageChannel) -> Self {
+        self.channels[3] = source;
+        self
+    }
+
+    /// Inverts the given output channel, constants are ignored.
+    pub const fn invert(mut self, channel: ImageChannel) -> Self {
+        match channel {
+            ImageChannel::Red => self.invert[0] = true,
+            ImageChannel::Green => self.invert[1] = true,
+            ImageChannel::Blue => self.invert[2] = true,
+            ImageChannel::Alpha => self.invert[3] = true,
+            ImageChannel::Zero | ImageChannel::One => {}
+        }
+        self
+    }
+
+    /// Whether or not this remap leaves every channel as is.
+    pub const fn is_identity(&self) -> bool {
+        matches!(
+            self.channels,
+            [
+                ImageChannel::Red,
+                ImageChannel::Green,
+                ImageChannel::Blue,
+                ImageChannel::Alpha
+            ]
+        ) && matches!(self.invert, [false, false, false, false])
+    }
+}
+
+impl Default for ImageChannelRemap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Options used when converting an image from one format to another.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ImageConvertOptions {
+    /// The normal map processing to apply.
+    pub normal_map: ImageNormalMapOptions,
+    /// The working color depth of the conversion.
+    pub depth: ImageConvertDepth,
+    /// The channel remap applied to each pixel.
+    pub channel_remap: ImageChannelRemap,
+    /// The tone mapping applied when converting high dynamic range images to low dynamic range formats.
+    pub tone_mapping: ImageToneMapping,
+    /// The exposure in stops applied before tone mapping.
+    pub exposure: f32,
+}
+
+impl ImageConvertOptions {
+    /// Constructs a new instance of image convert options.
+    pub const fn new() -> Self {
+        Self {
+            normal_map: ImageNormalMapOptions::None,
+            depth: ImageConvertDepth::Target,
+            channel_remap: ImageChannelRemap::new(),
+            tone_mapping: ImageToneMapping::None,
+            exposure: 0.0,
+        }
+    }
+
+    /// Sets the normal map processing.
+    pub const fn normal_map(mut self, normal_map: ImageNormalMapOptions) -> Self {
+        self.normal_map = normal_map;
+        self
+    }
+
+    /// Sets the working color depth.
+    pub const fn depth(mut self, depth: ImageConvertDepth) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Sets the channel remap.
+    pub const fn channel_remap(mut self, channel_remap: ImageChannelRemap) -> Self {
+        self.channel_remap = channel_remap;
+        self
+    }
+
+    /// Sets the tone mapping.
+    pub const fn tone_mapping(mut self, tone_mapping: ImageToneMapping) -> Self {
+        self.tone_mapping = tone_mapping;
+        self
+    }
+
+    /// Sets the exposure in stops.
+    pub const fn exposure(mut self, exposure: f32) -> Self {
+        self.exposure = exposure;
+        self
+    }
+}
+
+impl From<ImageNormalMapOptions> for ImageConvertOptions {
+    fn from(value: ImageNormalMapOptions) -> Self {
+        Self::new().normal_map(value)
+    }
+}
+
+impl From<ImageChannelRemap> for ImageConvertOptions {
+    fn from(value: ImageChannelRemap) -> Self {
+        Self::new().channel_remap(value)
+    }
+}
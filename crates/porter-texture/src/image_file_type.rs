@@ -10,6 +10,27 @@ pub enum ImageFileType {
     Png,
     Tiff,
     Tga,
+    Ktx,
+    Xpr,
+    Gtf,
+}
+
+impl ImageFileType {
+    /// All of the supported image file types.
+    pub const ALL: [Self; 7] = [
+        Self::Dds,
+        Self::Png,
+        Self::Tiff,
+        Self::Tga,
+        Self::Ktx,
+        Self::Xpr,
+        Self::Gtf,
+    ];
+
+    /// Whether or not the image file type can only be read from.
+    pub const fn is_read_only(&self) -> bool {
+        matches!(self, Self::Ktx | Self::Xpr | Self::Gtf)
+    }
 }
 
 impl AsRef<OsStr> for ImageFileType {
@@ -19,6 +40,9 @@ impl AsRef<OsStr> for ImageFileType {
             ImageFileType::Png => OsStr::new("png"),
             ImageFileType::Tiff => OsStr::new("tiff"),
             ImageFileType::Tga => OsStr::new("tga"),
+            ImageFileType::Ktx => OsStr::new("ktx"),
+            ImageFileType::Xpr => OsStr::new("xpr"),
+            ImageFileType::Gtf => OsStr::new("gtf"),
         }
     }
 }
@@ -7,10 +7,13 @@ use porter_utils::StructWriteExt;
 
 use crate::format_to_bpp;
 use crate::format_to_srgb;
+use crate::format_to_unorm;
 use crate::is_format_compressed;
 use crate::Image;
+use crate::ImageColorSpace;
 use crate::ImageFileType;
 use crate::ImageFormat;
+use crate::ImageLayout;
 use crate::TextureError;
 
 const DDS_FOURCC: u32 = 0x00000004;
@@ -22,14 +25,17 @@ const DDS_HEADER_FLAGS_TEXTURE: u32 = 0x00001007;
 const DDS_HEADER_FLAGS_PITCH: u32 = 0x00000008;
 const DDS_HEADER_FLAGS_LINEARSIZE: u32 = 0x00080000;
 const DDS_HEADER_FLAGS_MIPMAP: u32 = 0x20000;
+const DDS_HEADER_FLAGS_VOLUME: u32 = 0x00800000;
 
 const DDS_SURFACE_FLAGS_TEXTURE: u32 = 0x00001000;
 const DDS_SURFACE_FLAGS_CUBEMAP: u32 = 0x00000008;
 const DDS_SURFACE_FLAGS_MIPMAP: u32 = 0x400008;
 
 const DDS_CUBEMAP_ALLFACES: u32 = 0x0000FE00;
+const DDS_FLAGS_VOLUME: u32 = 0x00200000;
 
 const DDS_TEX_DIMENSION_TEXTURE2D: u32 = 0x3;
+const DDS_TEX_DIMENSION_TEXTURE3D: u32 = 0x4;
 const DDS_TEX_MISC_TEXTURECUBE: u32 = 0x4;
 
 /// Utility macro used to create a FourCC code.
@@ -149,7 +155,7 @@ fn compute_pitch_slice(format: ImageFormat, width: u32, height: u32) -> (u32, u3
 fn format_to_pf_dx10(
     format: ImageFormat,
     array_size: u32,
-    is_cubemap: bool,
+    layout: ImageLayout,
 ) -> (DdsPixelFormat, Option<DdsHeaderDx10>) {
     let dx10_fallback = || {
         let pixel_format = DdsPixelFormat {
@@ -165,8 +171,12 @@ fn format_to_pf_dx10(
 
         let header_dx10 = DdsHeaderDx10 {
             dxgi_format: format as u32,
-            resource_dimension: DDS_TEX_DIMENSION_TEXTURE2D,
-            misc_flag: if is_cubemap {
+            resource_dimension: if layout == ImageLayout::Volume {
+                DDS_TEX_DIMENSION_TEXTURE3D
+            } else {
+                DDS_TEX_DIMENSION_TEXTURE2D
+            },
+            misc_flag: if layout == ImageLayout::Cubemap {
                 DDS_TEX_MISC_TEXTURECUBE
             } else {
                 0
@@ -178,7 +188,8 @@ fn format_to_pf_dx10(
         (pixel_format, Some(header_dx10))
     };
 
-    if array_size > 1 && !is_cubemap {
+    // Arrays of textures and cubemaps can only be described by the dx10 header.
+    if array_size > 1 {
         return dx10_fallback();
     }
 
@@ -270,14 +281,26 @@ fn format_to_pf_dx10(
 fn format_to_dds(image: &Image) -> (DdsHeader, Option<DdsHeaderDx10>) {
     let mut caps: u32 = DDS_SURFACE_FLAGS_TEXTURE;
     let mut flags: u32 = DDS_HEADER_FLAGS_TEXTURE;
+    let mut caps2: u32 = 0;
 
-    let is_cubemap = image.is_cubemap();
+    let layout = image.layout();
+    let frames = image.frames().len() as u32;
 
-    let caps2 = if is_cubemap {
-        caps |= DDS_SURFACE_FLAGS_CUBEMAP;
-        DDS_CUBEMAP_ALLFACES
-    } else {
-        0
+    let (depth, array_size) = match layout {
+        ImageLayout::Texture => (1, frames),
+        ImageLayout::Cubemap => {
+            caps |= DDS_SURFACE_FLAGS_CUBEMAP;
+            caps2 |= DDS_CUBEMAP_ALLFACES;
+
+            (1, frames / 6)
+        }
+        ImageLayout::Volume => {
+            caps |= DDS_SURFACE_FLAGS_CUBEMAP;
+            caps2 |= DDS_FLAGS_VOLUME;
+            flags |= DDS_HEADER_FLAGS_VOLUME;
+
+            (frames, 1)
+        }
     };
 
     let mip_map_count = image.mipmaps();
@@ -297,8 +320,14 @@ fn format_to_dds(image: &Image) -> (DdsHeader, Option<DdsHeaderDx10>) {
         pitch
     };
 
-    let (pixel_format, header_dx10) =
-        format_to_pf_dx10(image.format(), image.frames().len() as u32, is_cubemap);
+    // The srgb dxgi formats are the only colorspace metadata dds has.
+    let format = match image.color_space() {
+        Some(ImageColorSpace::Srgb) => format_to_srgb(image.format()),
+        Some(ImageColorSpace::Linear) => format_to_unorm(image.format()),
+        None => image.format(),
+    };
+
+    let (pixel_format, header_dx10) = format_to_pf_dx10(format, array_size, layout);
 
     let header = DdsHeader {
         size: std::mem::size_of::<DdsHeader>() as u32,
@@ -306,7 +335,7 @@ fn format_to_dds(image: &Image) -> (DdsHeader, Option<DdsHeaderDx10>) {
         height: image.height(),
         width: image.width(),
         pitch_or_linear_size,
-        depth: 1,
+        depth,
         mip_map_count,
         reserved1: [0; 11],
         pixel_format,
@@ -320,6 +349,25 @@ fn format_to_dds(image: &Image) -> (DdsHeader, Option<DdsHeaderDx10>) {
     (header, header_dx10)
 }
 
+/// Calculates the offset and size of each mip level in a frame.
+fn mip_ranges(image: &Image) -> Vec<(usize, usize)> {
+    (0..image.mipmaps())
+        .map(|mip| {
+            let start = image.frame_size_with_mipmaps(image.width(), image.height(), mip);
+            let end = image.frame_size_with_mipmaps(image.width(), image.height(), mip + 1);
+
+            (start as usize, (end - start) as usize)
+        })
+        .collect()
+}
+
+/// Calculates which depth slice each slice of a volume mip level is taken from.
+fn volume_mip_slices(depth: usize, mip: usize) -> impl Iterator<Item = usize> {
+    let mip_depth = (depth >> mip).max(1);
+
+    (0..mip_depth).map(move |slice| slice * depth / mip_depth)
+}
+
 /// Creates a proper image format from the dds pixel format.
 fn dds_to_format(pixel_format: &DdsPixelFormat) -> Result<ImageFormat, TextureError> {
     if (pixel_format.flags & DDS_FOURCC) > 0 {
@@ -380,6 +428,8 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
     match format {
         ImageFormat::B8G8R8Unorm => ImageFormat::R8G8B8A8Unorm,
         ImageFormat::A8R8G8B8Unorm => ImageFormat::R8G8B8A8Unorm,
+        ImageFormat::Pvrtc2bppUnorm => ImageFormat::R8G8B8A8Unorm,
+        ImageFormat::Pvrtc4bppUnorm => ImageFormat::R8G8B8A8Unorm,
         _ => format,
     }
 }
@@ -396,8 +446,19 @@ pub fn to_dds<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
         output.write_struct(header_dx10)?;
     }
 
-    for frame in image.frames() {
-        output.write_all(frame.buffer())?;
+    if image.layout() == ImageLayout::Volume {
+        // Volumes are stored one mip level at a time, and each level halves the depth.
+        let frames: Vec<_> = image.frames().collect();
+
+        for (mip, (offset, size)) in mip_ranges(image).into_iter().enumerate() {
+            for slice in volume_mip_slices(frames.len(), mip) {
+                output.write_all(&frames[slice].buffer()[offset..offset + size])?;
+            }
+        }
+    } else {
+        for frame in image.frames() {
+            output.write_all(frame.buffer())?;
+        }
     }
 
     Ok(())
@@ -413,17 +474,27 @@ pub fn from_dds<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
 
     let header: DdsHeader = input.read_struct()?;
 
-    let mut frames = if header.caps2 & DDS_CUBEMAP_ALLFACES == DDS_CUBEMAP_ALLFACES {
-        6
+    let mut layout = if header.caps2 & DDS_CUBEMAP_ALLFACES == DDS_CUBEMAP_ALLFACES {
+        ImageLayout::Cubemap
+    } else if header.caps2 & DDS_FLAGS_VOLUME == DDS_FLAGS_VOLUME {
+        ImageLayout::Volume
     } else {
-        1
+        ImageLayout::Texture
     };
 
+    let mut array_size = 1;
+
     let mut format: ImageFormat =
         if header.pixel_format.four_cc == make_four_cc!('D', 'X', '1', '0') {
             let header_dx10: DdsHeaderDx10 = input.read_struct()?;
 
-            frames = frames.max(header_dx10.array_size);
+            if header_dx10.misc_flag & DDS_TEX_MISC_TEXTURECUBE == DDS_TEX_MISC_TEXTURECUBE {
+                layout = ImageLayout::Cubemap;
+            } else if header_dx10.resource_dimension == DDS_TEX_DIMENSION_TEXTURE3D {
+                layout = ImageLayout::Volume;
+            }
+
+            array_size = header_dx10.array_size.max(1);
 
             ImageFormat::try_from(header_dx10.dxgi_format)?
         } else {
@@ -443,10 +514,50 @@ pub fn from_dds<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
         format,
     )?;
 
-    for _ in 0..frames {
-        let frame = image.create_frame()?;
+    image.set_layout(layout);
+
+    match layout {
+        ImageLayout::Texture | ImageLayout::Cubemap => {
+            let frames = if layout == ImageLayout::Cubemap {
+                array_size * 6
+            } else {
+                array_size
+            };
+
+            for _ in 0..frames {
+                let frame = image.create_frame()?;
+
+                input.read_exact(frame.buffer_mut())?;
+            }
+        }
+        ImageLayout::Volume => {
+            let depth = header.depth.max(1) as usize;
 
-        input.read_exact(frame.buffer_mut())?;
+            for _ in 0..depth {
+                image.create_frame()?;
+            }
+
+            let mips = mip_ranges(&image);
+            let mut frames: Vec<_> = image.frames_mut().collect();
+
+            // Smaller mip levels have fewer slices, so each slice is shared with the slices it covers.
+            for (mip, (offset, size)) in mips.into_iter().enumerate() {
+                let slices: Vec<usize> = volume_mip_slices(depth, mip).collect();
+
+                for (index, slice) in slices.iter().enumerate() {
+                    input.read_exact(&mut frames[*slice].buffer_mut()[offset..offset + size])?;
+
+                    let next = slices.get(index + 1).copied().unwrap_or(depth);
+
+                    for covered in slice + 1..next {
+                        let (source, target) = frames.split_at_mut(covered);
+
+                        target[0].buffer_mut()[offset..offset + size]
+                            .copy_from_slice(&source[*slice].buffer()[offset..offset + size]);
+                    }
+                }
+            }
+        }
     }
 
     Ok(image)
@@ -0,0 +1,192 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use porter_utils::StructReadExt;
+
+use crate::format_to_bpp;
+use crate::format_to_buffer_size;
+use crate::is_format_compressed;
+use crate::morton_index;
+use crate::Image;
+use crate::ImageFileType;
+use crate::ImageFormat;
+use crate::TextureError;
+
+/// The texture is stored linear instead of swizzled.
+const GCM_TEXTURE_LN: u8 = 0x20;
+/// The texture uses unnormalized coordinates.
+const GCM_TEXTURE_UN: u8 = 0x40;
+/// Cubemap faces are aligned to this many bytes when swizzled.
+const GCM_CUBEMAP_ALIGNMENT: u64 = 0x80;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct GtfHeader {
+    version: u32,
+    size: u32,
+    texture_count: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct GtfTextureAttribute {
+    id: u32,
+    offset: u32,
+    size: u32,
+    texture: GcmTexture,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct GcmTexture {
+    format: u8,
+    mipmaps: u8,
+    dimension: u8,
+    cubemap: u8,
+    remap: u32,
+    width: u16,
+    height: u16,
+    depth: u16,
+    location: u8,
+    padding: u8,
+    pitch: u32,
+    offset: u32,
+}
+
+/// Converts a gcm texture format to an image format, and the size of each element to swap.
+const fn gcm_to_format(format: u8) -> Result<(ImageFormat, usize), TextureError> {
+    Ok(match format & !(GCM_TEXTURE_LN | GCM_TEXTURE_UN) {
+        0x81 => (ImageFormat::R8Unorm, 1),
+        0x82 => (ImageFormat::B5G5R5A1Unorm, 2),
+        0x83 => (ImageFormat::B4G4R4A4Unorm, 2),
+        0x84 => (ImageFormat::B5G6R5Unorm, 2),
+        0x85 => (ImageFormat::A8R8G8B8Unorm, 1),
+        0x86 => (ImageFormat::Bc1Unorm, 1),
+        0x87 => (ImageFormat::Bc2Unorm, 1),
+        0x88 => (ImageFormat::Bc3Unorm, 1),
+        0x8B => (ImageFormat::R8G8Unorm, 1),
+        0x92 => (ImageFormat::R16Unorm, 2),
+        0x93 => (ImageFormat::R16G16Unorm, 2),
+        0x9A => (ImageFormat::R16G16B16A16Float, 2),
+        0x9B => (ImageFormat::R32G32B32A32Float, 4),
+        0x9C => (ImageFormat::R32Float, 4),
+        0x9E => (ImageFormat::A8R8G8B8Unorm, 1),
+        0x9F => (ImageFormat::R16G16Float, 2),
+        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Gtf)),
+    })
+}
+
+/// Reads a gtf file from the input stream to an image.
+///
+/// Only the first texture in the file is read.
+pub fn from_gtf<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
+    let header: GtfHeader = input.read_struct()?;
+
+    if u32::from_be(header.texture_count) == 0 {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Gtf));
+    }
+
+    let attribute: GtfTextureAttribute = input.read_struct()?;
+    let texture = attribute.texture;
+
+    if u16::from_be(texture.depth) > 1 {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Gtf));
+    }
+
+    let (format, swap_size) = gcm_to_format(texture.format)?;
+
+    let linear = texture.format & GCM_TEXTURE_LN == GCM_TEXTURE_LN;
+    let compressed = is_format_compressed(format);
+    let frames = if texture.cubemap > 0 { 6 } else { 1 };
+    let pitch = u32::from_be(texture.pitch) as usize;
+
+    let mut image = Image::with_mipmaps(
+        u16::from_be(texture.width) as u32,
+        u16::from_be(texture.height) as u32,
+        (texture.mipmaps as u32).max(1),
+        format,
+    )?;
+
+    let bytes_per_pixel = (format_to_bpp(format) / 8) as usize;
+    let (width, height, mipmaps) = (image.width(), image.height(), image.mipmaps());
+
+    if linear && !compressed && pitch > 0 && pitch < width as usize * bytes_per_pixel {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Gtf));
+    }
+
+    // Swizzled textures must be a power of two, otherwise the morton index would go past the mip.
+    if !linear && !compressed && (!width.is_power_of_two() || !height.is_power_of_two()) {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Gtf));
+    }
+
+    input.seek(SeekFrom::Start(u32::from_be(attribute.offset) as u64))?;
+
+    for _ in 0..frames {
+        let mut buffer = Vec::new();
+
+        let mut mip_offset: usize = 0;
+        let mut mip_width = width;
+        let mut mip_height = height;
+
+        let frame = image.create_frame()?.buffer_mut();
+
+        for _ in 0..mipmaps {
+            let mip_size = format_to_buffer_size(format, mip_width, mip_height) as usize;
+            let mip = &mut frame[mip_offset..mip_offset + mip_size];
+
+            if compressed {
+                input.read_exact(mip)?;
+            } else if linear && pitch > 0 {
+                let row_size = mip_width as usize * bytes_per_pixel;
+
+                buffer.resize(pitch * mip_height as usize, 0);
+
+                input.read_exact(&mut buffer)?;
+
+                for (dst, src) in mip
+                    .chunks_exact_mut(row_size)
+                    .zip(buffer.chunks_exact(pitch))
+                {
+                    dst.copy_from_slice(&src[0..row_size]);
+                }
+            } else if linear {
+                input.read_exact(mip)?;
+            } else {
+                buffer.resize(mip_size, 0);
+
+                input.read_exact(&mut buffer)?;
+
+                for y in 0..mip_height {
+                    for x in 0..mip_width {
+                        let source =
+                            morton_index(x, y, mip_width, mip_height) as usize * bytes_per_pixel;
+                        let dest = (y * mip_width + x) as usize * bytes_per_pixel;
+
+                        mip[dest..dest + bytes_per_pixel]
+                            .copy_from_slice(&buffer[source..source + bytes_per_pixel]);
+                    }
+                }
+            }
+
+            if swap_size > 1 {
+                for chunk in mip.chunks_exact_mut(swap_size) {
+                    chunk.reverse();
+                }
+            }
+
+            mip_offset += mip_size;
+            mip_width = (mip_width / 2).max(1);
+            mip_height = (mip_height / 2).max(1);
+        }
+
+        if frames > 1 && !linear {
+            let position = input.stream_position()?;
+            let aligned = (position + (GCM_CUBEMAP_ALIGNMENT - 1)) & !(GCM_CUBEMAP_ALIGNMENT - 1);
+
+            input.seek(SeekFrom::Start(aligned))?;
+        }
+    }
+
+    Ok(image)
+}
@@ -0,0 +1,231 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use porter_utils::StructReadExt;
+
+use crate::format_to_block_dimensions;
+use crate::format_to_bpp;
+use crate::format_to_buffer_size;
+use crate::is_format_compressed;
+use crate::Image;
+use crate::ImageFileType;
+use crate::ImageFormat;
+use crate::TextureError;
+
+/// The ktx1 file identifier.
+const KTX_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x31, 0x31, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+/// The endianness marker when the file matches the host.
+const KTX_ENDIAN_NATIVE: u32 = 0x04030201;
+/// The endianness marker when the file is swapped.
+const KTX_ENDIAN_SWAPPED: u32 = 0x01020304;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct KtxHeader {
+    gl_type: u32,
+    gl_type_size: u32,
+    gl_format: u32,
+    gl_internal_format: u32,
+    gl_base_internal_format: u32,
+    pixel_width: u32,
+    pixel_height: u32,
+    pixel_depth: u32,
+    number_of_array_elements: u32,
+    number_of_faces: u32,
+    number_of_mipmap_levels: u32,
+    bytes_of_key_value_data: u32,
+}
+
+impl KtxHeader {
+    /// Swaps the byte order of every field in the header.
+    fn swap_bytes(self) -> Self {
+        Self {
+            gl_type: self.gl_type.swap_bytes(),
+            gl_type_size: self.gl_type_size.swap_bytes(),
+            gl_format: self.gl_format.swap_bytes(),
+            gl_internal_format: self.gl_internal_format.swap_bytes(),
+            gl_base_internal_format: self.gl_base_internal_format.swap_bytes(),
+            pixel_width: self.pixel_width.swap_bytes(),
+            pixel_height: self.pixel_height.swap_bytes(),
+            pixel_depth: self.pixel_depth.swap_bytes(),
+            number_of_array_elements: self.number_of_array_elements.swap_bytes(),
+            number_of_faces: self.number_of_faces.swap_bytes(),
+            number_of_mipmap_levels: self.number_of_mipmap_levels.swap_bytes(),
+            bytes_of_key_value_data: self.bytes_of_key_value_data.swap_bytes(),
+        }
+    }
+}
+
+/// Converts a ktx gl format to an image format.
+const fn ktx_to_format(header: &KtxHeader) -> Result<ImageFormat, TextureError> {
+    Ok(match header.gl_internal_format {
+        // Sized uncompressed formats.
+        0x8229 => ImageFormat::R8Unorm,
+        0x822B => ImageFormat::R8G8Unorm,
+        0x8051 => ImageFormat::R8G8B8Unorm,
+        0x8058 => ImageFormat::R8G8B8A8Unorm,
+        0x8C43 => ImageFormat::R8G8B8A8UnormSrgb,
+        0x822A => ImageFormat::R16Unorm,
+        0x822C => ImageFormat::R16G16Unorm,
+        0x805B => ImageFormat::R16G16B16A16Unorm,
+        0x822D => ImageFormat::R16Float,
+        0x822F => ImageFormat::R16G16Float,
+        0x881A => ImageFormat::R16G16B16A16Float,
+        0x822E => ImageFormat::R32Float,
+        0x8230 => ImageFormat::R32G32Float,
+        0x8815 => ImageFormat::R32G32B32Float,
+        0x8814 => ImageFormat::R32G32B32A32Float,
+        0x8C3A => ImageFormat::R11G11B10Float,
+        0x8059 => ImageFormat::R10G10B10A2Unorm,
+
+        // S3TC compressed formats.
+        0x83F0 | 0x83F1 => ImageFormat::Bc1Unorm,
+        0x83F2 => ImageFormat::Bc2Unorm,
+        0x83F3 => ImageFormat::Bc3Unorm,
+        0x8C4C | 0x8C4D => ImageFormat::Bc1UnormSrgb,
+        0x8C4E => ImageFormat::Bc2UnormSrgb,
+        0x8C4F => ImageFormat::Bc3UnormSrgb,
+
+        // RGTC compressed formats.
+        0x8DBB => ImageFormat::Bc4Unorm,
+        0x8DBC => ImageFormat::Bc4Snorm,
+        0x8DBD => ImageFormat::Bc5Unorm,
+        0x8DBE => ImageFormat::Bc5Snorm,
+
+        // BPTC compressed formats.
+        0x8E8C => ImageFormat::Bc7Unorm,
+        0x8E8D => ImageFormat::Bc7UnormSrgb,
+        0x8E8E => ImageFormat::Bc6HSf16,
+        0x8E8F => ImageFormat::Bc6HUf16,
+
+        // Unsized formats, only valid with unsigned bytes.
+        0x1903 if header.gl_type == 0x1401 => ImageFormat::R8Unorm,
+        0x1907 if header.gl_type == 0x1401 => ImageFormat::R8G8B8Unorm,
+        0x1908 if header.gl_type == 0x1401 => ImageFormat::R8G8B8A8Unorm,
+        0x80E0 if header.gl_type == 0x1401 => ImageFormat::B8G8R8Unorm,
+        0x80E1 if header.gl_type == 0x1401 => ImageFormat::B8G8R8A8Unorm,
+
+        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Ktx)),
+    })
+}
+
+/// Reads a ktx file from the input stream to an image.
+pub fn from_ktx<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
+    let mut identifier = [0u8; 12];
+
+    input.read_exact(&mut identifier)?;
+
+    if identifier != KTX_IDENTIFIER {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Ktx));
+    }
+
+    let endianness: u32 = input.read_struct()?;
+
+    let swapped = match endianness {
+        KTX_ENDIAN_NATIVE => false,
+        KTX_ENDIAN_SWAPPED => true,
+        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Ktx)),
+    };
+
+    let mut header: KtxHeader = input.read_struct()?;
+
+    if swapped {
+        header = header.swap_bytes();
+    }
+
+    if header.pixel_depth > 1 {
+        return Err(TextureError::ContainerInvalid(ImageFileType::Ktx));
+    }
+
+    let format = ktx_to_format(&header)?;
+
+    let faces = header.number_of_faces.max(1);
+    let layers = header.number_of_array_elements.max(1);
+    let mipmaps = header.number_of_mipmap_levels.max(1);
+
+    input.seek(SeekFrom::Current(header.bytes_of_key_value_data as i64))?;
+
+    let mut image = Image::with_mipmaps(
+        header.pixel_width,
+        header.pixel_height.max(1),
+        mipmaps,
+        format,
+    )?;
+
+    for _ in 0..(faces * layers) {
+        image.create_frame()?;
+    }
+
+    let compressed = is_format_compressed(format);
+    let bytes_per_pixel = format_to_bpp(format) / 8;
+    let (_, block_height) = format_to_block_dimensions(format);
+
+    let mut mip_offset: usize = 0;
+    let mut mip_width = image.width();
+    let mut mip_height = image.height();
+
+    for _ in 0..mipmaps {
+        let mut image_size: u32 = input.read_struct()?;
+
+        if swapped {
+            image_size = image_size.swap_bytes();
+        }
+
+        let mip_size = format_to_buffer_size(format, mip_width, mip_height) as usize;
+
+        // Rows of uncompressed data are aligned to 4 bytes.
+        let (row_size, row_count) = if compressed {
+            let blocks_high = mip_height.div_ceil(block_height) as usize;
+
+            (mip_size / blocks_high, blocks_high)
+        } else {
+            ((mip_width * bytes_per_pixel) as usize, mip_height as usize)
+        };
+
+        let row_pitch = (row_size + 3) & !3;
+
+        let mut buffer = vec![0u8; row_pitch * row_count];
+
+        for frame in image.frames_mut() {
+            input.read_exact(&mut buffer)?;
+
+            if swapped && header.gl_type_size > 1 {
+                for chunk in buffer.chunks_exact_mut(header.gl_type_size as usize) {
+                    chunk.reverse();
+                }
+            }
+
+            let frame = &mut frame.buffer_mut()[mip_offset..mip_offset + mip_size];
+
+            for (dst, src) in frame
+                .chunks_exact_mut(row_size)
+                .zip(buffer.chunks_exact(row_pitch))
+            {
+                dst.copy_from_slice(&src[0..row_size]);
+            }
+
+            // Cubemap faces are aligned to 4 bytes.
+            let padding = (4 - (buffer.len() % 4)) % 4;
+
+            if faces == 6 && layers == 1 && padding > 0 {
+                input.seek(SeekFrom::Current(padding as i64))?;
+            }
+        }
+
+        // Each mip level is aligned to 4 bytes.
+        let padding = (4 - (image_size % 4)) % 4;
+
+        if padding > 0 {
+            input.seek(SeekFrom::Current(padding as i64))?;
+        }
+
+        mip_offset += mip_size;
+        mip_width = (mip_width / 2).max(1);
+        mip_height = (mip_height / 2).max(1);
+    }
+
+    Ok(image)
+}
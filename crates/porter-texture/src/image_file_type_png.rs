@@ -7,12 +7,14 @@ use png::ColorType;
 use png::Compression;
 use png::Decoder;
 use png::Encoder;
+use png::ScaledFloat;
 use png::SrgbRenderingIntent;
 use png::Transformations;
 
 use crate::format_to_srgb;
 use crate::is_format_srgb;
 use crate::Image;
+use crate::ImageColorSpace;
 use crate::ImageFileType;
 use crate::ImageFormat;
 use crate::TextureError;
@@ -23,16 +25,16 @@ const MAXIMUM_PNG_FRAMES: usize = 6;
 const MAXIMUM_PNG_BUFFER: usize = 16 * 1024;
 
 /// Converts an image format to a png specification.
-const fn format_to_png(format: ImageFormat) -> Result<(ColorType, BitDepth, bool), TextureError> {
+const fn format_to_png(format: ImageFormat) -> Result<(ColorType, BitDepth), TextureError> {
     Ok(match format {
-        ImageFormat::R1Unorm => (ColorType::Grayscale, BitDepth::One, false),
-        ImageFormat::R8Unorm => (ColorType::Grayscale, BitDepth::Eight, false),
-        ImageFormat::R16Unorm => (ColorType::Grayscale, BitDepth::Sixteen, false),
-        ImageFormat::R8G8Unorm => (ColorType::GrayscaleAlpha, BitDepth::Eight, false),
-        ImageFormat::R16G16Unorm => (ColorType::GrayscaleAlpha, BitDepth::Sixteen, false),
-        ImageFormat::R8G8B8A8Unorm => (ColorType::Rgba, BitDepth::Eight, false),
-        ImageFormat::R8G8B8A8UnormSrgb => (ColorType::Rgba, BitDepth::Eight, true),
-        ImageFormat::R16G16B16A16Unorm => (ColorType::Rgba, BitDepth::Sixteen, false),
+        ImageFormat::R1Unorm => (ColorType::Grayscale, BitDepth::One),
+        ImageFormat::R8Unorm => (ColorType::Grayscale, BitDepth::Eight),
+        ImageFormat::R16Unorm => (ColorType::Grayscale, BitDepth::Sixteen),
+        ImageFormat::R8G8Unorm => (ColorType::GrayscaleAlpha, BitDepth::Eight),
+        ImageFormat::R16G16Unorm => (ColorType::GrayscaleAlpha, BitDepth::Sixteen),
+        ImageFormat::R8G8B8A8Unorm => (ColorType::Rgba, BitDepth::Eight),
+        ImageFormat::R8G8B8A8UnormSrgb => (ColorType::Rgba, BitDepth::Eight),
+        ImageFormat::R16G16B16A16Unorm => (ColorType::Rgba, BitDepth::Sixteen),
         _ => {
             return Err(TextureError::ContainerFormatInvalid(
                 format,
@@ -115,7 +117,7 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
 
 /// Writes an image to a png file to the output stream.
 pub fn to_png<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), TextureError> {
-    let (color_type, bit_depth, is_srgb) = format_to_png(image.format())?;
+    let (color_type, bit_depth) = format_to_png(image.format())?;
 
     let frames = image.frames().len();
     let height = image.height() * frames.min(MAXIMUM_PNG_FRAMES) as u32;
@@ -127,8 +129,10 @@ pub fn to_png<O: Write + Seek>(image: &Image, output: &mut O) -> Result<(), Text
     encoder.set_color(color_type);
     encoder.set_depth(bit_depth);
 
-    if is_srgb {
-        encoder.set_srgb(SrgbRenderingIntent::Perceptual);
+    match image.color_space() {
+        Some(ImageColorSpace::Srgb) => encoder.set_source_srgb(SrgbRenderingIntent::Perceptual),
+        Some(ImageColorSpace::Linear) => encoder.set_source_gamma(ScaledFloat::new(1.0)),
+        None => {}
     }
 
     encoder.add_text_chunk("Author".into(), "DTZxPorter".into())?;
@@ -173,7 +177,17 @@ pub fn from_png<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
         format = format_to_srgb(format);
     }
 
+    let linear = decoder.info().srgb.is_none()
+        && decoder
+            .info()
+            .gama_chunk
+            .is_some_and(|gamma| gamma == ScaledFloat::new(1.0));
+
     let mut image = Image::new(decoder.info().width, decoder.info().height, format)?;
+
+    if linear {
+        image.set_color_space(ImageColorSpace::Linear);
+    }
     let frame = image.create_frame()?;
 
     decoder.next_frame(frame.buffer_mut())?;
@@ -16,8 +16,11 @@ use tiff::ColorType;
 
 use porter_utils::AsThisSlice;
 
+use crate::format_to_srgb;
+use crate::is_format_hdr;
 use crate::is_format_srgb;
 use crate::Image;
+use crate::ImageColorSpace;
 use crate::ImageFileType;
 use crate::ImageFormat;
 use crate::TextureError;
@@ -326,10 +329,22 @@ pub const fn pick_format(format: ImageFormat) -> ImageFormat {
     }
 }
 
+/// Picks the proper format required to save the input format to a tiff file type, keeping hdr in float.
+pub const fn pick_format_float(format: ImageFormat) -> ImageFormat {
+    if is_format_hdr(format) {
+        ImageFormat::R32G32B32A32Float
+    } else {
+        pick_format(format)
+    }
+}
+
 /// Writes an image to a tiff file to the output stream.
 pub fn to_tiff<O: Write + Seek>(image: &Image, mut output: &mut O) -> Result<(), TextureError> {
     let mut encoder = TiffEncoder::new(&mut output)?;
 
+    // Tiff has no srgb flag, srgb images embed an srgb icc profile instead.
+    let srgb = image.color_space() == Some(ImageColorSpace::Srgb);
+
     for frame in image.frames() {
         let size = image.frame_size_with_mipmaps(image.width(), image.height(), 1);
 
@@ -341,13 +356,16 @@ pub fn to_tiff<O: Write + Seek>(image: &Image, mut output: &mut O) -> Result<(),
                 write_image_data!(encoder, frame, image, size, colortype::Gray16, false)
             }
             ImageFormat::R8G8B8A8Unorm => {
-                write_image_data!(encoder, frame, image, size, colortype::RGBA8, false)
+                write_image_data!(encoder, frame, image, size, colortype::RGBA8, srgb)
             }
             ImageFormat::R8G8B8A8UnormSrgb => {
-                write_image_data!(encoder, frame, image, size, colortype::RGBA8, true)
+                write_image_data!(encoder, frame, image, size, colortype::RGBA8, srgb)
             }
             ImageFormat::R16G16B16A16Unorm => {
-                write_image_data!(encoder, frame, image, size, colortype::RGBA16, false)
+                write_image_data!(encoder, frame, image, size, colortype::RGBA16, srgb)
+            }
+            ImageFormat::R32G32B32A32Float => {
+                write_image_data!(encoder, frame, image, size, colortype::RGBA32Float, false)
             }
             _ => {
                 return Err(TextureError::ContainerFormatInvalid(
@@ -366,7 +384,11 @@ pub fn from_tiff<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
     let mut decoder = Decoder::new(input)?;
 
     let dimensions = decoder.dimensions()?;
-    let format = tiff_to_format(decoder.colortype()?)?;
+    let mut format = tiff_to_format(decoder.colortype()?)?;
+
+    if decoder.find_tag(Tag::Unknown(0x8773))?.is_some() {
+        format = format_to_srgb(format);
+    }
 
     let buffer = decoder.read_image()?;
 
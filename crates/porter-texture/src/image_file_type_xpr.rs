@@ -0,0 +1,334 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+use porter_utils::StructReadExt;
+
+use crate::format_to_block_dimensions;
+use crate::format_to_block_size;
+use crate::format_to_bpp;
+use crate::format_to_buffer_size;
+use crate::is_format_compressed;
+use crate::Image;
+use crate::ImageFileType;
+use crate::ImageFormat;
+use crate::TextureError;
+
+/// The xpr2 file magic, big endian.
+const XPR2_MAGIC: u32 = 0x58505232;
+/// The 2d texture resource type, big endian.
+const XPR2_RESOURCE_TX2D: u32 = 0x54583244;
+/// The cube texture resource type, big endian.
+const XPR2_RESOURCE_TXCM: u32 = 0x5458434D;
+/// The size of the xpr2 file header which resource offsets are relative to.
+const XPR2_HEADER_SIZE: u64 = 0xC;
+
+/// Texture data is not swapped.
+const XENOS_ENDIAN_NONE: u32 = 0;
+/// Texture data is swapped in 16bit words.
+const XENOS_ENDIAN_8IN16: u32 = 1;
+/// Texture data is swapped in 32bit words.
+const XENOS_ENDIAN_8IN32: u32 = 2;
+/// Texture data is swapped in 16bit halves of 32bit words.
+const XENOS_ENDIAN_16IN32: u32 = 3;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Xpr2Header {
+    magic: u32,
+    header_size: u32,
+    data_size: u32,
+    resource_count: u32,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct Xpr2Resource {
+    resource_type: u32,
+    offset: u32,
+    size: u32,
+    name_offset: u32,
+}
+
+/// A d3d base texture as stored in xbox 360 resources, all fields are big endian.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct XenosTextureHeader {
+    common: u32,
+    reference_count: u32,
+    fence: u32,
+    read_fence: u32,
+    identifier: u32,
+    base_flush: u32,
+    mip_flush: u32,
+    fetch_constant: [u32; 6],
+}
+
+/// The decoded parts of a xenos texture fetch constant.
+#[derive(Debug, Clone, Copy)]
+struct XenosTexture {
+    tiled: bool,
+    format: u32,
+    endian: u32,
+    width: u32,
+    height: u32,
+}
+
+impl XenosTextureHeader {
+    /// Decodes the texture fetch constant.
+    fn decode(&self) -> XenosTexture {
+        let dword0 = u32::from_be(self.fetch_constant[0]);
+        let dword1 = u32::from_be(self.fetch_constant[1]);
+        let dword2 = u32::from_be(self.fetch_constant[2]);
+
+        XenosTexture {
+            tiled: (dword0 >> 31) & 0x1 == 0x1,
+            format: dword1 & 0x3F,
+            endian: (dword1 >> 6) & 0x3,
+            width: (dword2 & 0x1FFF) + 1,
+            height: ((dword2 >> 13) & 0x1FFF) + 1,
+        }
+    }
+}
+
+/// Converts a xenos gpu format to an image format.
+const fn xenos_to_format(format: u32) -> Result<ImageFormat, TextureError> {
+    Ok(match format {
+        0x2 => ImageFormat::R8Unorm,
+        0x3 => ImageFormat::B5G5R5A1Unorm,
+        0x4 => ImageFormat::B5G6R5Unorm,
+        0x6 => ImageFormat::B8G8R8A8Unorm,
+        0x7 => ImageFormat::R10G10B10A2Unorm,
+        0xA => ImageFormat::R8G8Unorm,
+        0xF => ImageFormat::B4G4R4A4Unorm,
+        0x12 => ImageFormat::Bc1Unorm,
+        0x13 => ImageFormat::Bc2Unorm,
+        0x14 => ImageFormat::Bc3Unorm,
+        0x18 => ImageFormat::R16Unorm,
+        0x19 => ImageFormat::R16G16Unorm,
+        0x1A => ImageFormat::R16G16B16A16Unorm,
+        0x1E => ImageFormat::R16Float,
+        0x1F => ImageFormat::R16G16Float,
+        0x20 => ImageFormat::R16G16B16A16Float,
+        0x24 => ImageFormat::R32Float,
+        0x25 => ImageFormat::R32G32Float,
+        0x26 => ImageFormat::R32G32B32A32Float,
+        0x31 => ImageFormat::Bc5Unorm,
+        0x3B => ImageFormat::Bc4Unorm,
+        _ => return Err(TextureError::ContainerInvalid(ImageFileType::Xpr)),
+    })
+}
+
+/// Undoes the xenos endian swap on the given buffer.
+fn xenos_swap_endian(buffer: &mut [u8], endian: u32) {
+    match endian {
+        XENOS_ENDIAN_8IN16 => {
+            for chunk in buffer.chunks_exact_mut(2) {
+                chunk.swap(0, 1);
+            }
+        }
+        XENOS_ENDIAN_8IN32 => {
+            for chunk in buffer.chunks_exact_mut(4) {
+                chunk.reverse();
+            }
+        }
+        XENOS_ENDIAN_16IN32 => {
+            for chunk in buffer.chunks_exact_mut(4) {
+                chunk.swap(0, 2);
+                chunk.swap(1, 3);
+            }
+        }
+        _ => {
+            debug_assert!(endian == XENOS_ENDIAN_NONE);
+        }
+    }
+}
+
+/// Computes the log2 of the bytes per block for tiling.
+const fn xenos_log_bpp(texel_pitch: u32) -> u32 {
+    (texel_pitch >> 2) + ((texel_pitch >> 1) >> (texel_pitch >> 2))
+}
+
+/// Computes the untiled x coordinate of the given tiled block offset.
+const fn xenos_tiled_x(offset: u32, width: u32, texel_pitch: u32) -> u32 {
+    let aligned_width = (width + 31) & !31;
+    let log_bpp = xenos_log_bpp(texel_pitch);
+
+    let offset_b = offset << log_bpp;
+    let offset_t = ((offset_b & !4095) >> 3) + ((offset_b & 1792) >> 2) + (offset_b & 63);
+    let offset_m = offset_t >> (7 + log_bpp);
+
+    let macro_x = (offset_m % (aligned_width >> 5)) << 2;
+    let tile = (((offset_t >> (5 + log_bpp)) & 2) + (offset_b >> 6)) & 3;
+    let macro_offset = (macro_x + tile) << 3;
+    let micro = ((((offset_t >> 1) & !15) + (offset_t & 15)) & ((texel_pitch << 3) - 1)) >> log_bpp;
+
+    macro_offset + micro
+}
+
+/// Computes the untiled y coordinate of the given tiled block offset.
+const fn xenos_tiled_y(offset: u32, width: u32, texel_pitch: u32) -> u32 {
+    let aligned_width = (width + 31) & !31;
+    let log_bpp = xenos_log_bpp(texel_pitch);
+
+    let offset_b = offset << log_bpp;
+    let offset_t = ((offset_b & !4095) >> 3) + ((offset_b & 1792) >> 2) + (offset_b & 63);
+    let offset_m = offset_t >> (7 + log_bpp);
+
+    let macro_y = (offset_m / (aligned_width >> 5)) << 2;
+    let tile = ((offset_t >> (6 + log_bpp)) & 1) + ((offset_b & 2048) >> 10);
+    let macro_offset = (macro_y + tile) << 3;
+    let micro = (((offset_t & (((texel_pitch << 6) - 1) & !31)) + ((offset_t & 15) << 1))
+        >> (3 + log_bpp))
+        & !1;
+
+    macro_offset + micro + ((offset_t & 16) >> 4)
+}
+
+/// Untiles a xenos tiled surface into a linear surface.
+fn xenos_untile(
+    source: &[u8],
+    dest: &mut [u8],
+    blocks_wide: u32,
+    blocks_high: u32,
+    texel_pitch: u32,
+) {
+    let aligned_wide = (blocks_wide + 31) & !31;
+    let aligned_high = (blocks_high + 31) & !31;
+
+    for y in 0..aligned_high {
+        for x in 0..aligned_wide {
+            let block = y * aligned_wide + x;
+
+            let dest_x = xenos_tiled_x(block, aligned_wide, texel_pitch);
+            let dest_y = xenos_tiled_y(block, aligned_wide, texel_pitch);
+
+            if dest_x >= blocks_wide || dest_y >= blocks_high {
+                continue;
+            }
+
+            let source_offset = (block * texel_pitch) as usize;
+            let dest_offset = ((dest_y * blocks_wide + dest_x) * texel_pitch) as usize;
+
+            if source_offset + texel_pitch as usize > source.len() {
+                continue;
+            }
+
+            dest[dest_offset..dest_offset + texel_pitch as usize]
+                .copy_from_slice(&source[source_offset..source_offset + texel_pitch as usize]);
+        }
+    }
+}
+
+/// Reads the base level of a xenos texture, whose data starts at the given offset.
+fn read_xenos_texture<I: Read + Seek>(
+    input: &mut I,
+    header: &XenosTextureHeader,
+    data_offset: u64,
+    frames: u32,
+) -> Result<Image, TextureError> {
+    let texture = header.decode();
+    let format = xenos_to_format(texture.format)?;
+
+    let mut image = Image::new(texture.width, texture.height, format)?;
+
+    let (block_width, block_height) = format_to_block_dimensions(format);
+    let texel_pitch = if is_format_compressed(format) {
+        format_to_block_size(format)
+    } else {
+        format_to_bpp(format) / 8
+    };
+
+    let blocks_wide = texture.width.div_ceil(block_width);
+    let blocks_high = texture.height.div_ceil(block_height);
+
+    // Tiled surfaces are stored padded to 32x32 blocks, linear ones are padded to 256 byte rows.
+    let (surface_wide, surface_high) = if texture.tiled {
+        ((blocks_wide + 31) & !31, (blocks_high + 31) & !31)
+    } else {
+        (
+            ((blocks_wide * texel_pitch + 255) & !255) / texel_pitch,
+            blocks_high,
+        )
+    };
+
+    let surface_size = (surface_wide * surface_high * texel_pitch) as usize;
+    let frame_size = format_to_buffer_size(format, texture.width, texture.height) as usize;
+
+    input.seek(SeekFrom::Start(data_offset))?;
+
+    let mut surface = vec![0u8; surface_size];
+
+    for _ in 0..frames {
+        input.read_exact(&mut surface)?;
+
+        xenos_swap_endian(&mut surface, texture.endian);
+
+        let frame = image.create_frame()?;
+        let buffer = &mut frame.buffer_mut()[0..frame_size];
+
+        if texture.tiled {
+            xenos_untile(&surface, buffer, blocks_wide, blocks_high, texel_pitch);
+        } else {
+            let row_size = (blocks_wide * texel_pitch) as usize;
+            let row_pitch = (surface_wide * texel_pitch) as usize;
+
+            for (dst, src) in buffer
+                .chunks_exact_mut(row_size)
+                .zip(surface.chunks_exact(row_pitch))
+            {
+                dst.copy_from_slice(&src[0..row_size]);
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+/// Reads a raw xbox 360 texture header followed by its data from the input stream to an image.
+///
+/// This is the layout used by most bundle formats which embed the d3d texture header directly.
+pub fn from_xbox360_texture<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
+    let header: XenosTextureHeader = input.read_struct()?;
+    let data_offset = input.stream_position()?;
+
+    read_xenos_texture(input, &header, data_offset, 1)
+}
+
+/// Reads a xpr file from the input stream to an image.
+///
+/// Only the first texture resource, and its base mip level is read.
+pub fn from_xpr<I: Read + Seek>(input: &mut I) -> Result<Image, TextureError> {
+    let header: Xpr2Header = input.read_struct()?;
+
+    if u32::from_be(header.magic) != XPR2_MAGIC {
+        input.seek(SeekFrom::Start(0))?;
+
+        return from_xbox360_texture(input);
+    }
+
+    let header_size = u32::from_be(header.header_size) as u64;
+
+    for _ in 0..u32::from_be(header.resource_count) {
+        let resource: Xpr2Resource = input.read_struct()?;
+
+        let frames = match u32::from_be(resource.resource_type) {
+            XPR2_RESOURCE_TX2D => 1,
+            XPR2_RESOURCE_TXCM => 6,
+            _ => continue,
+        };
+
+        input.seek(SeekFrom::Start(
+            XPR2_HEADER_SIZE + u32::from_be(resource.offset) as u64,
+        ))?;
+
+        let texture: XenosTextureHeader = input.read_struct()?;
+        let base_address = (u32::from_be(texture.fetch_constant[1]) >> 12) << 12;
+
+        let data_offset = XPR2_HEADER_SIZE + header_size + base_address as u64;
+
+        return read_xenos_texture(input, &texture, data_offset, frames);
+    }
+
+    Err(TextureError::ContainerInvalid(ImageFileType::Xpr))
+}
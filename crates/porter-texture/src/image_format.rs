@@ -1,5 +1,6 @@
 use wgpu::TextureFormat;
 
+use crate::pvrtc_buffer_size;
 use crate::TextureError;
 
 /// Image formats, matches DXGI_FORMAT from DirectX.
@@ -133,6 +134,8 @@ pub enum ImageFormat {
     R8G8B8Unorm = 0x400,
     B8G8R8Unorm,
     A8R8G8B8Unorm,
+    Pvrtc2bppUnorm,
+    Pvrtc4bppUnorm,
 }
 
 /// Gets whether or not an image format is palettized.
@@ -185,6 +188,25 @@ pub const fn is_format_srgb(format: ImageFormat) -> bool {
     )
 }
 
+/// Gets whether or not an image format is high dynamic range.
+pub const fn is_format_hdr(format: ImageFormat) -> bool {
+    matches!(
+        format,
+        ImageFormat::R32G32B32A32Float
+            | ImageFormat::R32G32B32Float
+            | ImageFormat::R16G16B16A16Float
+            | ImageFormat::R32G32Float
+            | ImageFormat::R11G11B10Float
+            | ImageFormat::R16G16Float
+            | ImageFormat::R32Float
+            | ImageFormat::R16Float
+            | ImageFormat::R9G9B9E5Sharedexp
+            | ImageFormat::Bc6HTypeless
+            | ImageFormat::Bc6HUf16
+            | ImageFormat::Bc6HSf16
+    )
+}
+
 /// Gets whether or not an image format is software convertable.
 pub const fn is_format_requires_unpack(format: ImageFormat) -> bool {
     matches!(
@@ -196,6 +218,8 @@ pub const fn is_format_requires_unpack(format: ImageFormat) -> bool {
             | ImageFormat::R32G32B32Float
             | ImageFormat::R32G32B32Uint
             | ImageFormat::R32G32B32Sint
+            | ImageFormat::Pvrtc2bppUnorm
+            | ImageFormat::Pvrtc4bppUnorm
     )
 }
 
@@ -280,7 +304,11 @@ pub const fn format_to_block_size(format: ImageFormat) -> u32 {
 
 /// Calculates the buffer size for an image in the format, with the given width and height.
 pub const fn format_to_buffer_size(format: ImageFormat, width: u32, height: u32) -> u32 {
-    if is_format_compressed(format) {
+    if matches!(format, ImageFormat::Pvrtc2bppUnorm) {
+        pvrtc_buffer_size(width, height, true)
+    } else if matches!(format, ImageFormat::Pvrtc4bppUnorm) {
+        pvrtc_buffer_size(width, height, false)
+    } else if is_format_compressed(format) {
         let block_size = format_to_block_size(format);
         let block_dimensions = format_to_block_dimensions(format);
 
@@ -405,6 +433,20 @@ pub const fn format_to_srgb(format: ImageFormat) -> ImageFormat {
     }
 }
 
+/// Converts an srgb to an unsigned image format.
+pub const fn format_to_unorm(format: ImageFormat) -> ImageFormat {
+    match format {
+        ImageFormat::R8G8B8A8UnormSrgb => ImageFormat::R8G8B8A8Unorm,
+        ImageFormat::Bc1UnormSrgb => ImageFormat::Bc1Unorm,
+        ImageFormat::Bc2UnormSrgb => ImageFormat::Bc2Unorm,
+        ImageFormat::Bc3UnormSrgb => ImageFormat::Bc3Unorm,
+        ImageFormat::B8G8R8A8UnormSrgb => ImageFormat::B8G8R8A8Unorm,
+        ImageFormat::B8G8R8X8UnormSrgb => ImageFormat::B8G8R8X8Unorm,
+        ImageFormat::Bc7UnormSrgb => ImageFormat::Bc7Unorm,
+        _ => format,
+    }
+}
+
 /// Gets an image formats `bits` per pixel.
 pub const fn format_to_bpp(format: ImageFormat) -> u32 {
     match format {
@@ -414,13 +456,17 @@ pub const fn format_to_bpp(format: ImageFormat) -> u32 {
         // 1 bit per pixel
         ImageFormat::R1Unorm => 1,
 
+        // 2 bits per pixel
+        ImageFormat::Pvrtc2bppUnorm => 2,
+
         // 4 bits per pixel
         ImageFormat::Bc1Typeless
         | ImageFormat::Bc1Unorm
         | ImageFormat::Bc1UnormSrgb
         | ImageFormat::Bc4Typeless
         | ImageFormat::Bc4Unorm
-        | ImageFormat::Bc4Snorm => 4,
+        | ImageFormat::Bc4Snorm
+        | ImageFormat::Pvrtc4bppUnorm => 4,
 
         // 8 bits per pixel
         ImageFormat::R8Typeless
@@ -0,0 +1,10 @@
+/// How the frames of an image are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageLayout {
+    /// Each frame is a 2d texture, more than one frame is a texture array.
+    Texture,
+    /// Every six frames are the faces of a cubemap (+x, -x, +y, -y, +z, -z), more than six is a cubemap array.
+    Cubemap,
+    /// Each frame is a depth slice of a volume texture.
+    Volume,
+}
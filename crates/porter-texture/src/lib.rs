@@ -1,30 +1,53 @@
 #![deny(unsafe_code)]
 
+mod clut_format;
 mod error;
 mod frame;
 mod gpu_converter;
 mod image;
+mod image_atlas_region;
+mod image_color_space;
 mod image_convert_options;
 mod image_file_type;
 mod image_format;
+mod image_layout;
+mod software_clut;
+mod software_convert;
+mod software_deswizzle;
+mod software_pvrtc;
+mod software_quantize;
 mod software_swizzle;
 mod software_unpack;
+mod swizzle_pattern;
 mod texture_extension;
 
 pub(crate) mod image_file_type_dds;
+pub(crate) mod image_file_type_gtf;
+pub(crate) mod image_file_type_ktx;
 pub(crate) mod image_file_type_png;
 pub(crate) mod image_file_type_tga;
 pub(crate) mod image_file_type_tiff;
+pub(crate) mod image_file_type_xpr;
 
+pub use clut_format::*;
 pub use error::*;
 pub use frame::*;
 
 pub use image::*;
+pub use image_atlas_region::*;
+pub use image_color_space::*;
 pub use image_convert_options::*;
 pub use image_file_type::*;
 pub use image_format::*;
+pub use image_layout::*;
+pub use swizzle_pattern::*;
 pub use texture_extension::*;
 
 pub(crate) use gpu_converter::*;
+pub(crate) use software_clut::*;
+pub(crate) use software_convert::*;
+pub(crate) use software_deswizzle::*;
+pub(crate) use software_pvrtc::*;
+pub(crate) use software_quantize::*;
 pub(crate) use software_swizzle::*;
 pub(crate) use software_unpack::*;
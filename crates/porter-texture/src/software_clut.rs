@@ -0,0 +1,86 @@
+use crate::ClutColorFormat;
+use crate::ClutIndexFormat;
+use crate::TextureError;
+
+/// Unswizzles a csm1 color table index, which swaps the second and third group of 8 colors in every 32.
+const fn clut_csm1_index(index: usize) -> usize {
+    (index & !0x18) | ((index & 0x08) << 1) | ((index & 0x10) >> 1)
+}
+
+/// Reads a color from the color table, converting it to r8g8b8a8.
+fn clut_color(clut: &[u8], index: usize, format: ClutColorFormat) -> Result<[u8; 4], TextureError> {
+    match format {
+        ClutColorFormat::Psmct32 => {
+            let color = clut
+                .get(index * 4..index * 4 + 4)
+                .ok_or(TextureError::ConversionError)?;
+
+            // The ps2 treats 0x80 as fully opaque.
+            let alpha = ((color[3] as u32 * 255 + 64) / 128).min(255) as u8;
+
+            Ok([color[0], color[1], color[2], alpha])
+        }
+        ClutColorFormat::Psmct16 => {
+            let color = clut
+                .get(index * 2..index * 2 + 2)
+                .ok_or(TextureError::ConversionError)?;
+
+            let color = u16::from_le_bytes([color[0], color[1]]);
+
+            let expand = |value: u16| ((value << 3) | (value >> 2)) as u8;
+
+            Ok([
+                expand(color & 0x1F),
+                expand((color >> 5) & 0x1F),
+                expand((color >> 10) & 0x1F),
+                if color & 0x8000 != 0 { 0xFF } else { 0x00 },
+            ])
+        }
+    }
+}
+
+/// Decodes a ps2 palettized texture with it's color table to r8g8b8a8 pixels.
+pub fn software_decode_clut(
+    index_format: ClutIndexFormat,
+    indices: &[u8],
+    color_format: ClutColorFormat,
+    clut: &[u8],
+    output: &mut [u8],
+) -> Result<(), TextureError> {
+    let colors = match index_format {
+        ClutIndexFormat::Psmt4 => 16,
+        ClutIndexFormat::Psmt8 => 256,
+    };
+
+    let mut palette = [[0u8; 4]; 256];
+
+    for (index, color) in palette.iter_mut().enumerate().take(colors) {
+        let entry = match index_format {
+            ClutIndexFormat::Psmt4 => index,
+            ClutIndexFormat::Psmt8 => clut_csm1_index(index),
+        };
+
+        *color = clut_color(clut, entry, color_format)?;
+    }
+
+    for (pixel, color) in output.chunks_exact_mut(4).enumerate() {
+        let index = match index_format {
+            ClutIndexFormat::Psmt4 => {
+                let packed = *indices
+                    .get(pixel / 2)
+                    .ok_or(TextureError::ConversionError)?;
+
+                if pixel & 1 == 0 {
+                    packed & 0xF
+                } else {
+                    packed >> 4
+                }
+            }
+            ClutIndexFormat::Psmt8 => *indices.get(pixel).ok_or(TextureError::ConversionError)?,
+        };
+
+        color.copy_from_slice(&palette[index as usize]);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use crate::format_to_bpp;
+use crate::is_format_software_quantized;
+use crate::software_quantize_image;
+use crate::Image;
+use crate::ImageChannel;
+use crate::ImageConvertOptions;
+use crate::ImageFormat;
+use crate::ImageNormalMapOptions;
+use crate::TextureError;
+
+/// Converts an srgb color component to linear.
+#[inline(always)]
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a half precision float to a float.
+#[inline(always)]
+fn half_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((bits >> 10) & 0x1F) as i32;
+    let mantissa = (bits & 0x3FF) as f32;
+
+    match exponent {
+        0 => sign * mantissa * (-24.0f32).exp2(),
+        31 if mantissa == 0.0 => sign * f32::INFINITY,
+        31 => f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * ((exponent - 15) as f32).exp2(),
+    }
+}
+
+/// Reads a little endian component of the given size at the index.
+#[inline(always)]
+fn read_component<const N: usize>(pixel: &[u8], index: usize) -> [u8; N] {
+    let mut bytes = [0; N];
+
+    bytes.copy_from_slice(&pixel[index * N..(index + 1) * N]);
+    bytes
+}
+
+/// Decodes a single pixel to a linear float rgba color, missing channels are sampled the same as the gpu.
+fn decode_pixel(format: ImageFormat, pixel: &[u8]) -> [f32; 4] {
+    let unorm8 = |index: usize| pixel[index] as f32 / 255.0;
+    let snorm8 = |index: usize| (pixel[index] as i8 as f32 / 127.0).max(-1.0);
+    let unorm16 = |index: usize| u16::from_le_bytes(read_component(pixel, index)) as f32 / 65535.0;
+    let half = |index: usize| half_to_f32(u16::from_le_bytes(read_component(pixel, index)));
+    let float = |index: usize| f32::from_le_bytes(read_component(pixel, index));
+
+    match format {
+        ImageFormat::R8Unorm => [unorm8(0), 0.0, 0.0, 1.0],
+        ImageFormat::R8Snorm => [snorm8(0), 0.0, 0.0, 1.0],
+        ImageFormat::A8Unorm => [0.0, 0.0, 0.0, unorm8(0)],
+        ImageFormat::R8G8Unorm => [unorm8(0), unorm8(1), 0.0, 1.0],
+        ImageFormat::R8G8Snorm => [snorm8(0), snorm8(1), 0.0, 1.0],
+        ImageFormat::R8G8B8A8Unorm => [unorm8(0), unorm8(1), unorm8(2), unorm8(3)],
+        ImageFormat::R8G8B8A8Snorm => [snorm8(0), snorm8(1), snorm8(2), snorm8(3)],
+        ImageFormat::R8G8B8A8UnormSrgb => [
+            srgb_to_linear(unorm8(0)),
+            srgb_to_linear(unorm8(1)),
+            srgb_to_linear(unorm8(2)),
+            unorm8(3),
+        ],
+        ImageFormat::B8G8R8A8Unorm => [unorm8(2), unorm8(1), unorm8(0), unorm8(3)],
+        ImageFormat::B8G8R8A8UnormSrgb => [
+            srgb_to_linear(unorm8(2)),
+            srgb_to_linear(unorm8(1)),
+            srgb_to_linear(unorm8(0)),
+            unorm8(3),
+        ],
+        ImageFormat::R10G10B10A2Unorm => {
+            let value = u32::from_le_bytes(read_component(pixel, 0));
+
+            [
+                (value & 0x3FF) as f32 / 1023.0,
+                ((value >> 10) & 0x3FF) as f32 / 1023.0,
+                ((value >> 20) & 0x3FF) as f32 / 1023.0,
+                (value >> 30) as f32 / 3.0,
+            ]
+        }
+        ImageFormat::R16Unorm => [unorm16(0), 0.0, 0.0, 1.0],
+        ImageFormat::R16G16Unorm => [unorm16(0), unorm16(1), 0.0, 1.0],
+        ImageFormat::R16G16B16A16Unorm => [unorm16(0), unorm16(1), unorm16(2), unorm16(3)],
+        ImageFormat::R16Float => [half(0), 0.0, 0.0, 1.0],
+        ImageFormat::R16G16Float => [half(0), half(1), 0.0, 1.0],
+        ImageFormat::R16G16B16A16Float => [half(0), half(1), half(2), half(3)],
+        ImageFormat::R32Float => [float(0), 0.0, 0.0, 1.0],
+        ImageFormat::R32G32Float => [float(0), float(1), 0.0, 1.0],
+        ImageFormat::R32G32B32A32Float => [float(0), float(1), float(2), float(3)],
+        _ => [0.0, 0.0, 0.0, 1.0],
+    }
+}
+
+/// Gets whether or not the source format can be converted in software.
+pub const fn is_format_software_converted(format: ImageFormat) -> bool {
+    matches!(
+        format,
+        ImageFormat::R8Unorm
+            | ImageFormat::R8Snorm
+            | ImageFormat::A8Unorm
+            | ImageFormat::R8G8Unorm
+            | ImageFormat::R8G8Snorm
+            | ImageFormat::R8G8B8A8Unorm
+            | ImageFormat::R8G8B8A8Snorm
+            | ImageFormat::R8G8B8A8UnormSrgb
+            | ImageFormat::B8G8R8A8Unorm
+            | ImageFormat::B8G8R8A8UnormSrgb
+            | ImageFormat::R10G10B10A2Unorm
+            | ImageFormat::R16Unorm
+            | ImageFormat::R16G16Unorm
+            | ImageFormat::R16G16B16A16Unorm
+            | ImageFormat::R16Float
+            | ImageFormat::R16G16Float
+            | ImageFormat::R16G16B16A16Float
+            | ImageFormat::R32Float
+            | ImageFormat::R32G32Float
+            | ImageFormat::R32G32B32A32Float
+    )
+}
+
+/// Applies the normal map reconstruction and channel remap, the same as the gpu converter shader.
+fn process_pixel(
+    mut color: [f32; 4],
+    options: &ImageConvertOptions,
+    input_unorm: bool,
+    output_unorm: bool,
+    reconstruct_z: bool,
+) -> [f32; 4] {
+    if reconstruct_z {
+        let (mut x, mut y) = (color[0], color[1]);
+
+        if input_unorm {
+            x = x * 2.0 - 1.0;
+            y = y * 2.0 - 1.0;
+        }
+
+        let z = (1.0 - (x * x + y * y).clamp(0.0, 1.0)).sqrt();
+        let length = (x * x + y * y + z * z).sqrt().max(f32::EPSILON);
+
+        let (x, mut y, z) = (x / length, y / length, z / length);
+
+        if matches!(
+            options.normal_map,
+            ImageNormalMapOptions::ReconstructZInvertY
+                | ImageNormalMapOptions::AutoReconstructZInvertY
+        ) {
+            y = -y;
+        }
+
+        color = if output_unorm {
+            [x * 0.5 + 0.5, y * 0.5 + 0.5, z * 0.5 + 0.5, 1.0]
+        } else {
+            [x, y, z, 1.0]
+        };
+    }
+
+    let remap = options.channel_remap;
+
+    let mut result = [0.0; 4];
+
+    for (index, value) in result.iter_mut().enumerate() {
+        *value = match remap.channels[index] {
+            ImageChannel::Red => color[0],
+            ImageChannel::Green => color[1],
+            ImageChannel::Blue => color[2],
+            ImageChannel::Alpha => color[3],
+            ImageChannel::Zero => 0.0,
+            ImageChannel::One => 1.0,
+        };
+
+        if remap.invert[index] {
+            *value = 1.0 - *value;
+        }
+    }
+
+    result
+}
+
+/// Utility method to convert an uncompressed image to a float working format, or a quantized target, without the gpu.
+pub fn software_convert_image(
+    image: &mut Image,
+    format: ImageFormat,
+    options: ImageConvertOptions,
+) -> Result<(), TextureError> {
+    if !is_format_software_converted(image.format()) {
+        return Err(TextureError::UnsupportedImageFormat(image.format()));
+    }
+
+    if !(format == ImageFormat::R32G32B32A32Float || is_format_software_quantized(format)) {
+        return Err(TextureError::UnsupportedImageFormat(format));
+    }
+
+    let source_format = image.format();
+    let bytes_per_pixel = (format_to_bpp(source_format) / 8) as usize;
+    let pixels = image.width() as usize * image.height() as usize;
+
+    let input_unorm = !matches!(
+        source_format,
+        ImageFormat::R8Snorm
+            | ImageFormat::R8G8Snorm
+            | ImageFormat::R8G8B8A8Snorm
+            | ImageFormat::R16Float
+            | ImageFormat::R16G16Float
+            | ImageFormat::R16G16B16A16Float
+            | ImageFormat::R32Float
+            | ImageFormat::R32G32Float
+            | ImageFormat::R32G32B32A32Float
+    );
+
+    // The quantized targets are always unorm.
+    let output_unorm = format != ImageFormat::R32G32B32A32Float;
+
+    // Only bc5 sources are automatically reconstructed, and those can't be decoded in software.
+    let reconstruct_z = matches!(
+        options.normal_map,
+        ImageNormalMapOptions::ReconstructZ | ImageNormalMapOptions::ReconstructZInvertY
+    );
+
+    let mut result = Image::new(
+        image.width(),
+        image.height(),
+        ImageFormat::R32G32B32A32Float,
+    )?;
+
+    for frame in image.frames() {
+        let buffer = frame.buffer().get(..pixels * bytes_per_pixel).ok_or(
+            TextureError::InvalidFrameSize(
+                (pixels * bytes_per_pixel) as u32,
+                frame.buffer().len() as u32,
+            ),
+        )?;
+
+        let new_frame = result.create_frame()?;
+
+        for (pixel, output) in buffer
+            .chunks_exact(bytes_per_pixel)
+            .zip(new_frame.buffer_mut().chunks_exact_mut(16))
+        {
+            let color = process_pixel(
+                decode_pixel(source_format, pixel),
+                &options,
+                input_unorm,
+                output_unorm,
+                reconstruct_z,
+            );
+
+            for (component, bytes) in color.iter().zip(output.chunks_exact_mut(4)) {
+                bytes.copy_from_slice(&component.to_le_bytes());
+            }
+        }
+    }
+
+    result.set_layout(image.layout());
+
+    *image = result;
+
+    if format == ImageFormat::R32G32B32A32Float {
+        return Ok(());
+    }
+
+    software_quantize_image(image, format)
+}
@@ -0,0 +1,299 @@
+use crate::format_to_block_dimensions;
+use crate::format_to_block_size;
+use crate::format_to_bpp;
+use crate::is_format_compressed;
+use crate::ImageFormat;
+use crate::SwizzlePattern;
+use crate::TextureError;
+
+/// The size of a tegra gob in bytes.
+const TEGRA_GOB_SIZE: u32 = 512;
+/// The width of a tegra gob in bytes.
+const TEGRA_GOB_WIDTH: u32 = 64;
+/// The height of a tegra gob in rows.
+const TEGRA_GOB_HEIGHT: u32 = 8;
+/// The largest block height in gobs the tegra supports.
+const TEGRA_MAX_BLOCK_HEIGHT: u32 = 32;
+/// The width and height of a ps4 tile in pixels or blocks.
+const PS4_TILE_SIZE: u32 = 8;
+
+/// Calculates the morton index of the pixel or block at x, y in a power of two surface.
+pub const fn morton_index(mut x: u32, mut y: u32, mut width: u32, mut height: u32) -> u32 {
+    let mut index = 0;
+    let mut shift = 0;
+
+    while width > 1 || height > 1 {
+        if width > 1 {
+            index |= (x & 1) << shift;
+            x >>= 1;
+            width >>= 1;
+            shift += 1;
+        }
+
+        if height > 1 {
+            index |= (y & 1) << shift;
+            y >>= 1;
+            height >>= 1;
+            shift += 1;
+        }
+    }
+
+    index
+}
+
+/// Calculates the offset of the byte at x, y in a tegra block linear surface.
+const fn tegra_offset(x: u32, y: u32, width_in_gobs: u32, block_height: u32) -> usize {
+    let block_size = (TEGRA_GOB_SIZE * block_height) as usize;
+    let block_rows = TEGRA_GOB_HEIGHT * block_height;
+
+    let gob = (y / block_rows) as usize * block_size * width_in_gobs as usize
+        + (x / TEGRA_GOB_WIDTH) as usize * block_size
+        + ((y % block_rows) / TEGRA_GOB_HEIGHT * TEGRA_GOB_SIZE) as usize;
+
+    let x = x % TEGRA_GOB_WIDTH;
+    let y = y % TEGRA_GOB_HEIGHT;
+
+    gob + ((x / 32) * 256 + (y / 2) * 64 + ((x % 32) / 16) * 32 + (y % 2) * 16 + (x % 16)) as usize
+}
+
+/// Calculates the block height in gobs for mip 0 from the height in pixels or blocks.
+const fn tegra_block_height(height: u32) -> u32 {
+    let block_height = height.div_ceil(TEGRA_GOB_HEIGHT).next_power_of_two();
+
+    if block_height > 16 {
+        16
+    } else {
+        block_height
+    }
+}
+
+/// Shrinks the block height for a mip, the hardware halves it until the mip is taller than half a block.
+const fn tegra_mip_block_height(height: u32, mut block_height: u32) -> u32 {
+    while block_height > 1 && height <= (block_height / 2) * TEGRA_GOB_HEIGHT {
+        block_height /= 2;
+    }
+
+    block_height
+}
+
+/// Validates the pattern and calculates the tegra block height in gobs for mip 0.
+fn pattern_block_height(pattern: SwizzlePattern, height: u32) -> Result<u32, TextureError> {
+    match pattern {
+        SwizzlePattern::Tegra {
+            block_height: Some(block_height),
+        } => {
+            if !block_height.is_power_of_two() || block_height > TEGRA_MAX_BLOCK_HEIGHT {
+                return Err(TextureError::InvalidOperation);
+            }
+
+            Ok(block_height)
+        }
+        SwizzlePattern::Tegra { block_height: None } => Ok(tegra_block_height(height)),
+        _ => Ok(1),
+    }
+}
+
+/// The layout of a single swizzled mip, in pixels or blocks.
+struct SwizzledMip {
+    pattern: SwizzlePattern,
+    width: u32,
+    height: u32,
+    bytes_per_element: usize,
+    block_height: u32,
+}
+
+impl SwizzledMip {
+    /// Calculates the layout of a mip with the given dimensions in pixels.
+    fn new(
+        format: ImageFormat,
+        pattern: SwizzlePattern,
+        width: u32,
+        height: u32,
+        block_height: u32,
+    ) -> Result<Self, TextureError> {
+        let (bytes_per_element, width, height) = if is_format_compressed(format) {
+            let (block_width, block_rows) = format_to_block_dimensions(format);
+
+            (
+                format_to_block_size(format) as usize,
+                width.div_ceil(block_width),
+                height.div_ceil(block_rows),
+            )
+        } else {
+            let bits_per_pixel = format_to_bpp(format);
+
+            if bits_per_pixel < 8 || bits_per_pixel & 7 != 0 {
+                return Err(TextureError::UnsupportedImageFormat(format));
+            }
+
+            ((bits_per_pixel / 8) as usize, width, height)
+        };
+
+        // Tegra gobs are made of 16 byte runs, which only evenly hold power of two elements.
+        if matches!(pattern, SwizzlePattern::Tegra { .. })
+            && !(bytes_per_element.is_power_of_two() && bytes_per_element <= 16)
+        {
+            return Err(TextureError::UnsupportedImageFormat(format));
+        }
+
+        Ok(Self {
+            pattern,
+            width,
+            height,
+            bytes_per_element,
+            block_height: tegra_mip_block_height(height, block_height),
+        })
+    }
+
+    /// The number of bytes this mip takes up when swizzled.
+    fn size(&self) -> usize {
+        let elements = match self.pattern {
+            SwizzlePattern::Linear => self.width as usize * self.height as usize,
+            SwizzlePattern::Morton => {
+                self.width.next_power_of_two() as usize * self.height.next_power_of_two() as usize
+            }
+            SwizzlePattern::Ps4 => {
+                self.width.next_multiple_of(PS4_TILE_SIZE) as usize
+                    * self.height.next_multiple_of(PS4_TILE_SIZE) as usize
+            }
+            SwizzlePattern::Tegra { .. } => {
+                let width_in_gobs = (self.width as usize * self.bytes_per_element)
+                    .div_ceil(TEGRA_GOB_WIDTH as usize);
+                let height = self
+                    .height
+                    .next_multiple_of(TEGRA_GOB_HEIGHT * self.block_height);
+
+                return width_in_gobs * TEGRA_GOB_WIDTH as usize * height as usize;
+            }
+        };
+
+        elements * self.bytes_per_element
+    }
+
+    /// The offset of the pixel or block at x, y in the swizzled mip.
+    fn offset(&self, x: u32, y: u32) -> usize {
+        match self.pattern {
+            SwizzlePattern::Linear => {
+                (y as usize * self.width as usize + x as usize) * self.bytes_per_element
+            }
+            SwizzlePattern::Morton => {
+                morton_index(
+                    x,
+                    y,
+                    self.width.next_power_of_two(),
+                    self.height.next_power_of_two(),
+                ) as usize
+                    * self.bytes_per_element
+            }
+            SwizzlePattern::Ps4 => {
+                let tiles_per_row = self.width.div_ceil(PS4_TILE_SIZE);
+                let tile = (y / PS4_TILE_SIZE) * tiles_per_row + (x / PS4_TILE_SIZE);
+                let index = morton_index(
+                    x % PS4_TILE_SIZE,
+                    y % PS4_TILE_SIZE,
+                    PS4_TILE_SIZE,
+                    PS4_TILE_SIZE,
+                );
+
+                (tile as usize * (PS4_TILE_SIZE * PS4_TILE_SIZE) as usize + index as usize)
+                    * self.bytes_per_element
+            }
+            SwizzlePattern::Tegra { .. } => {
+                let width_in_gobs = (self.width as usize * self.bytes_per_element)
+                    .div_ceil(TEGRA_GOB_WIDTH as usize);
+
+                tegra_offset(
+                    x * self.bytes_per_element as u32,
+                    y,
+                    width_in_gobs as u32,
+                    self.block_height,
+                )
+            }
+        }
+    }
+}
+
+/// Calculates the size of a swizzled frame, including the padding between frames in an array.
+pub fn software_swizzled_frame_size(
+    format: ImageFormat,
+    width: u32,
+    height: u32,
+    mipmaps: u32,
+    pattern: SwizzlePattern,
+) -> Result<u32, TextureError> {
+    let block_height = pattern_block_height(pattern, height)?;
+
+    let mut size = 0;
+
+    for mip in 0..mipmaps {
+        let mip_width = (width >> mip).max(1);
+        let mip_height = (height >> mip).max(1);
+
+        size += SwizzledMip::new(format, pattern, mip_width, mip_height, block_height)?.size();
+    }
+
+    // Tegra array layers start on a block boundary of the first mip.
+    if matches!(pattern, SwizzlePattern::Tegra { .. }) && mipmaps > 0 {
+        let mip = SwizzledMip::new(format, pattern, width, height, block_height)?;
+
+        size = size.next_multiple_of((TEGRA_GOB_SIZE * mip.block_height) as usize);
+    }
+
+    u32::try_from(size).map_err(|_| TextureError::FrameAllocationFailed)
+}
+
+/// Deswizzles every mip of a frame from the given pattern into linear rows of pixels or blocks.
+pub fn software_deswizzle_frame(
+    format: ImageFormat,
+    width: u32,
+    height: u32,
+    mipmaps: u32,
+    pattern: SwizzlePattern,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<(), TextureError> {
+    let block_height = pattern_block_height(pattern, height)?;
+    let output_len = output.len();
+
+    let mut input_offset = 0;
+    let mut output_offset = 0;
+
+    for mip in 0..mipmaps {
+        let mip_width = (width >> mip).max(1);
+        let mip_height = (height >> mip).max(1);
+
+        let layout = SwizzledMip::new(format, pattern, mip_width, mip_height, block_height)?;
+
+        let input_size = layout.size();
+        let output_size = layout.width as usize * layout.height as usize * layout.bytes_per_element;
+
+        let source = input.get(input_offset..input_offset + input_size).ok_or(
+            TextureError::InvalidFrameSize((input_offset + input_size) as u32, input.len() as u32),
+        )?;
+
+        let dest = output
+            .get_mut(output_offset..output_offset + output_size)
+            .ok_or(TextureError::InvalidFrameSize(
+                (output_offset + output_size) as u32,
+                output_len as u32,
+            ))?;
+
+        let bytes_per_element = layout.bytes_per_element;
+
+        for (y, row) in dest
+            .chunks_exact_mut(layout.width as usize * bytes_per_element)
+            .enumerate()
+        {
+            for (x, element) in row.chunks_exact_mut(bytes_per_element).enumerate() {
+                let offset = layout.offset(x as u32, y as u32);
+
+                element.copy_from_slice(&source[offset..offset + bytes_per_element]);
+            }
+        }
+
+        input_offset += input_size;
+        output_offset += output_size;
+    }
+
+    Ok(())
+}
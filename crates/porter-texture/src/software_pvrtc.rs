@@ -0,0 +1,334 @@
+/// The height of a pvrtc word in pixels.
+const PVRTC_WORD_HEIGHT: usize = 4;
+/// The modulation weights for the two bit modulation values, out of 8.
+const PVRTC_MODULATION_WEIGHTS: [i32; 4] = [0, 3, 5, 8];
+
+/// A color in a pvrtc word, with 5 bit rgb and 4 bit alpha.
+#[derive(Clone, Copy)]
+struct PvrtcColor([i32; 4]);
+
+/// A single 64 bit pvrtc word, covering one 4x4 or 8x4 block.
+#[derive(Clone, Copy)]
+struct PvrtcWord {
+    modulation: u32,
+    color: u32,
+}
+
+impl PvrtcWord {
+    /// Reads the word at the given index.
+    fn read(input: &[u8], index: usize) -> Self {
+        let offset = index * 8;
+        let word = input.get(offset..offset + 8).unwrap_or(&[0; 8]);
+
+        Self {
+            modulation: u32::from_le_bytes([word[0], word[1], word[2], word[3]]),
+            color: u32::from_le_bytes([word[4], word[5], word[6], word[7]]),
+        }
+    }
+
+    /// The first color of the word, stored in the lower half.
+    fn color_a(&self) -> PvrtcColor {
+        let color = self.color;
+
+        if color & 0x8000 != 0 {
+            PvrtcColor([
+                ((color & 0x7C00) >> 10) as i32,
+                ((color & 0x3E0) >> 5) as i32,
+                ((color & 0x1E) | ((color & 0x1E) >> 4)) as i32,
+                0xF,
+            ])
+        } else {
+            PvrtcColor([
+                (((color & 0xF00) >> 7) | ((color & 0xF00) >> 11)) as i32,
+                (((color & 0xF0) >> 3) | ((color & 0xF0) >> 7)) as i32,
+                (((color & 0xE) << 1) | ((color & 0xE) >> 2)) as i32,
+                ((color & 0x7000) >> 11) as i32,
+            ])
+        }
+    }
+
+    /// The second color of the word, stored in the upper half.
+    fn color_b(&self) -> PvrtcColor {
+        let color = self.color;
+
+        if color & 0x8000_0000 != 0 {
+            PvrtcColor([
+                ((color & 0x7C00_0000) >> 26) as i32,
+                ((color & 0x3E0_0000) >> 21) as i32,
+                ((color & 0x1F_0000) >> 16) as i32,
+                0xF,
+            ])
+        } else {
+            PvrtcColor([
+                (((color & 0xF00_0000) >> 23) | ((color & 0xF00_0000) >> 27)) as i32,
+                (((color & 0xF0_0000) >> 19) | ((color & 0xF0_0000) >> 23)) as i32,
+                (((color & 0xF_0000) >> 15) | ((color & 0xF_0000) >> 19)) as i32,
+                ((color & 0x7000_0000) >> 27) as i32,
+            ])
+        }
+    }
+}
+
+/// The modulation values and modes for a 2x2 group of words.
+struct PvrtcModulation {
+    values: [[i32; 16]; 8],
+    modes: [[u32; 16]; 8],
+}
+
+impl PvrtcModulation {
+    /// Unpacks the modulation of a word at the given pixel offset in the group.
+    fn unpack(&mut self, word: PvrtcWord, offset_x: usize, offset_y: usize, word_width: usize) {
+        let mut mode = word.color & 1;
+        let mut bits = word.modulation;
+
+        if word_width == 8 {
+            if mode != 0 {
+                // Interpolated mode stores every other pixel, the first bit picks the direction.
+                if bits & 1 != 0 {
+                    mode = if bits & (1 << 20) != 0 { 3 } else { 2 };
+
+                    if bits & (1 << 21) != 0 {
+                        bits |= 1 << 20;
+                    } else {
+                        bits &= !(1 << 20);
+                    }
+                }
+
+                if bits & 2 != 0 {
+                    bits |= 1;
+                } else {
+                    bits &= !1;
+                }
+
+                for y in 0..PVRTC_WORD_HEIGHT {
+                    for x in 0..word_width {
+                        self.modes[y + offset_y][x + offset_x] = mode;
+
+                        if (x ^ y) & 1 == 0 {
+                            self.values[y + offset_y][x + offset_x] = (bits & 3) as i32;
+                            bits >>= 2;
+                        }
+                    }
+                }
+            } else {
+                for y in 0..PVRTC_WORD_HEIGHT {
+                    for x in 0..word_width {
+                        self.modes[y + offset_y][x + offset_x] = mode;
+                        self.values[y + offset_y][x + offset_x] = if bits & 1 != 0 { 3 } else { 0 };
+
+                        bits >>= 1;
+                    }
+                }
+            }
+        } else {
+            for y in 0..PVRTC_WORD_HEIGHT {
+                for x in 0..word_width {
+                    // Values over 10 flag punch through alpha.
+                    let value = match (mode, bits & 3) {
+                        (0, value) => PVRTC_MODULATION_WEIGHTS[value as usize],
+                        (_, 1) => 4,
+                        (_, 2) => 14,
+                        (_, 3) => 8,
+                        _ => 0,
+                    };
+
+                    self.modes[y + offset_y][x + offset_x] = mode;
+                    self.values[y + offset_y][x + offset_x] = value;
+
+                    bits >>= 2;
+                }
+            }
+        }
+    }
+
+    /// Gets the modulation weight at the given pixel in the group.
+    fn value(&self, x: usize, y: usize, word_width: usize) -> i32 {
+        if word_width != 8 {
+            return self.values[y][x];
+        }
+
+        let weight = |x: usize, y: usize| PVRTC_MODULATION_WEIGHTS[self.values[y][x] as usize];
+
+        match self.modes[y][x] {
+            0 => weight(x, y),
+            _ if (x ^ y) & 1 == 0 => weight(x, y),
+            1 => {
+                (weight(x, y - 1) + weight(x, y + 1) + weight(x - 1, y) + weight(x + 1, y) + 2) / 4
+            }
+            2 => (weight(x - 1, y) + weight(x + 1, y) + 1) / 2,
+            _ => (weight(x, y - 1) + weight(x, y + 1) + 1) / 2,
+        }
+    }
+}
+
+/// Calculates the index of a word, words are twiddled with the smaller dimension interleaved.
+fn pvrtc_word_index(x: usize, y: usize, words_x: usize, words_y: usize) -> usize {
+    let min_dimension = words_x.min(words_y);
+
+    let mut index = 0;
+    let mut bit = 1;
+    let mut shift = 0;
+
+    while bit < min_dimension {
+        if x & bit != 0 {
+            index |= 1 << (2 * shift);
+        }
+
+        if y & bit != 0 {
+            index |= 1 << (2 * shift + 1);
+        }
+
+        bit <<= 1;
+        shift += 1;
+    }
+
+    let remainder = if words_x < words_y { y } else { x };
+
+    index | ((remainder >> shift) << (2 * shift))
+}
+
+/// Bilinearly upscales the colors of a 2x2 group of words to the pixels between their centers.
+#[allow(clippy::needless_range_loop)]
+fn pvrtc_interpolate(colors: [PvrtcColor; 4], word_width: usize, pixels: &mut [[i32; 4]; 32]) {
+    let [p, q, r, s] = colors;
+
+    for channel in 0..4 {
+        let q_minus_p = q.0[channel] - p.0[channel];
+        let s_minus_r = s.0[channel] - r.0[channel];
+
+        let mut top = p.0[channel] * word_width as i32;
+        let mut bottom = r.0[channel] * word_width as i32;
+
+        for x in 0..word_width {
+            let mut result = 4 * top;
+            let step = bottom - top;
+
+            for y in 0..PVRTC_WORD_HEIGHT {
+                // Expand the 5 bit color or 4 bit alpha to 8 bits.
+                pixels[y * word_width + x][channel] = match (word_width, channel) {
+                    (8, 3) => (result >> 5) + (result >> 1),
+                    (8, _) => (result >> 7) + (result >> 2),
+                    (_, 3) => (result >> 4) + result,
+                    (_, _) => (result >> 6) + (result >> 1),
+                };
+
+                result += step;
+            }
+
+            top += q_minus_p;
+            bottom += s_minus_r;
+        }
+    }
+}
+
+/// Calculates the size of a pvrtc surface, which is padded to a power of two and at least 2x2 words.
+pub const fn pvrtc_buffer_size(width: u32, height: u32, two_bpp: bool) -> u32 {
+    let word_width = if two_bpp { 8 } else { 4 };
+
+    let mut words_x = width.next_power_of_two() / word_width;
+    let mut words_y = height.next_power_of_two() / PVRTC_WORD_HEIGHT as u32;
+
+    if words_x < 2 {
+        words_x = 2;
+    }
+
+    if words_y < 2 {
+        words_y = 2;
+    }
+
+    words_x * words_y * 8
+}
+
+/// Decodes a pvrtc 2bpp or 4bpp surface to r8g8b8a8 pixels.
+pub fn software_decode_pvrtc(
+    input: &[u8],
+    output: &mut [u8],
+    width: u32,
+    height: u32,
+    two_bpp: bool,
+) {
+    let word_width = if two_bpp { 8 } else { 4 };
+
+    let words_x = (width.next_power_of_two() as usize / word_width).max(2);
+    let words_y = (height.next_power_of_two() as usize / PVRTC_WORD_HEIGHT).max(2);
+
+    let padded_width = words_x * word_width;
+    let padded_height = words_y * PVRTC_WORD_HEIGHT;
+
+    let mut modulation = PvrtcModulation {
+        values: [[0; 16]; 8],
+        modes: [[0; 16]; 8],
+    };
+
+    let mut colors_a = [[0; 4]; 32];
+    let mut colors_b = [[0; 4]; 32];
+
+    for word_y in 0..words_y {
+        for word_x in 0..words_x {
+            let next_x = (word_x + 1) % words_x;
+            let next_y = (word_y + 1) % words_y;
+
+            let words = [
+                (word_x, word_y),
+                (next_x, word_y),
+                (word_x, next_y),
+                (next_x, next_y),
+            ]
+            .map(|(x, y)| PvrtcWord::read(input, pvrtc_word_index(x, y, words_x, words_y)));
+
+            for (index, word) in words.iter().enumerate() {
+                modulation.unpack(
+                    *word,
+                    (index & 1) * word_width,
+                    (index >> 1) * PVRTC_WORD_HEIGHT,
+                    word_width,
+                );
+            }
+
+            pvrtc_interpolate(words.map(|word| word.color_a()), word_width, &mut colors_a);
+            pvrtc_interpolate(words.map(|word| word.color_b()), word_width, &mut colors_b);
+
+            // The decoded pixels start at the center of the first word and wrap around the edges.
+            for y in 0..PVRTC_WORD_HEIGHT {
+                let pixel_y =
+                    (word_y * PVRTC_WORD_HEIGHT + y + PVRTC_WORD_HEIGHT / 2) % padded_height;
+
+                if pixel_y >= height as usize {
+                    continue;
+                }
+
+                for x in 0..word_width {
+                    let pixel_x = (word_x * word_width + x + word_width / 2) % padded_width;
+
+                    if pixel_x >= width as usize {
+                        continue;
+                    }
+
+                    let mut weight =
+                        modulation.value(x + word_width / 2, y + PVRTC_WORD_HEIGHT / 2, word_width);
+
+                    let punch_through = weight > 10;
+
+                    if punch_through {
+                        weight -= 10;
+                    }
+
+                    let a = colors_a[y * word_width + x];
+                    let b = colors_b[y * word_width + x];
+
+                    let offset = (pixel_y * width as usize + pixel_x) * 4;
+
+                    for channel in 0..4 {
+                        output[offset + channel] =
+                            ((a[channel] * (8 - weight) + b[channel] * weight) / 8).clamp(0, 255)
+                                as u8;
+                    }
+
+                    if punch_through {
+                        output[offset + 3] = 0;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::Image;
+use crate::ImageFormat;
+use crate::ImageToneMapping;
+use crate::TextureError;
+
+/// Converts a linear color component to srgb.
+#[inline(always)]
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Maps a linear high dynamic range color component to the low dynamic range.
+#[inline(always)]
+fn tone_map(value: f32, tone_mapping: ImageToneMapping) -> f32 {
+    let value = value.max(0.0);
+
+    match tone_mapping {
+        ImageToneMapping::None => value,
+        ImageToneMapping::Reinhard => value / (1.0 + value),
+        ImageToneMapping::Aces => {
+            // Narkowicz's fit of the aces filmic curve.
+            (value * (2.51 * value + 0.03)) / (value * (2.43 * value + 0.59) + 0.14)
+        }
+    }
+}
+
+/// Quantizes a float component to an 8bit unorm value.
+#[inline(always)]
+fn quantize_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+/// Quantizes a float component to a 16bit unorm value.
+#[inline(always)]
+fn quantize_u16(value: f32) -> [u8; 2] {
+    ((value.clamp(0.0, 1.0) * 65535.0 + 0.5) as u16).to_le_bytes()
+}
+
+/// Gets whether or not the target format can be quantized to in software.
+pub const fn is_format_software_quantized(format: ImageFormat) -> bool {
+    matches!(
+        format,
+        ImageFormat::R8Unorm
+            | ImageFormat::R8G8Unorm
+            | ImageFormat::R8G8B8A8Unorm
+            | ImageFormat::R8G8B8A8UnormSrgb
+            | ImageFormat::B8G8R8A8Unorm
+            | ImageFormat::B8G8R8A8UnormSrgb
+            | ImageFormat::R16Unorm
+            | ImageFormat::R16G16Unorm
+            | ImageFormat::R16G16B16A16Unorm
+    )
+}
+
+/// Utility method to apply exposure and tone mapping to the color of a float working image.
+pub fn software_tone_map_image(
+    image: &mut Image,
+    tone_mapping: ImageToneMapping,
+    exposure: f32,
+) -> Result<(), TextureError> {
+    if image.format() != ImageFormat::R32G32B32A32Float {
+        return Err(TextureError::ConversionError);
+    }
+
+    let scale = exposure.exp2();
+
+    for frame in image.frames_mut() {
+        for pixel in frame.buffer_mut().chunks_exact_mut(16) {
+            for bytes in pixel[..12].chunks_exact_mut(4) {
+                let value = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+
+                bytes.copy_from_slice(&tone_map(value * scale, tone_mapping).to_le_bytes());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Utility method to quantize a float working image to the target format.
+pub fn software_quantize_image(image: &mut Image, format: ImageFormat) -> Result<(), TextureError> {
+    if image.format() != ImageFormat::R32G32B32A32Float || !is_format_software_quantized(format) {
+        return Err(TextureError::ConversionError);
+    }
+
+    let mut result = Image::new(image.width(), image.height(), format)?;
+
+    for frame in image.frames() {
+        let new_frame = result.create_frame()?;
+        let buffer = new_frame.buffer_mut();
+
+        let mut offset = 0;
+
+        for pixel in frame.buffer().chunks_exact(16) {
+            let mut color = [0.0f32; 4];
+
+            for (component, bytes) in color.iter_mut().zip(pixel.chunks_exact(4)) {
+                *component = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            }
+
+            let [r, g, b, a] = color;
+
+            match format {
+                ImageFormat::R8Unorm => {
+                    buffer[offset] = quantize_u8(r);
+                    offset += 1;
+                }
+                ImageFormat::R8G8Unorm => {
+                    buffer[offset..offset + 2].copy_from_slice(&[quantize_u8(r), quantize_u8(g)]);
+                    offset += 2;
+                }
+                ImageFormat::R8G8B8A8Unorm => {
+                    buffer[offset..offset + 4].copy_from_slice(&[
+                        quantize_u8(r),
+                        quantize_u8(g),
+                        quantize_u8(b),
+                        quantize_u8(a),
+                    ]);
+                    offset += 4;
+                }
+                ImageFormat::R8G8B8A8UnormSrgb => {
+                    buffer[offset..offset + 4].copy_from_slice(&[
+                        quantize_u8(linear_to_srgb(r)),
+                        quantize_u8(linear_to_srgb(g)),
+                        quantize_u8(linear_to_srgb(b)),
+                        quantize_u8(a),
+                    ]);
+                    offset += 4;
+                }
+                ImageFormat::B8G8R8A8Unorm => {
+                    buffer[offset..offset + 4].copy_from_slice(&[
+                        quantize_u8(b),
+                        quantize_u8(g),
+                        quantize_u8(r),
+                        quantize_u8(a),
+                    ]);
+                    offset += 4;
+                }
+                ImageFormat::B8G8R8A8UnormSrgb => {
+                    buffer[offset..offset + 4].copy_from_slice(&[
+                        quantize_u8(linear_to_srgb(b)),
+                        quantize_u8(linear_to_srgb(g)),
+                        quantize_u8(linear_to_srgb(r)),
+                        quantize_u8(a),
+                    ]);
+                    offset += 4;
+                }
+                ImageFormat::R16Unorm => {
+                    buffer[offset..offset + 2].copy_from_slice(&quantize_u16(r));
+                    offset += 2;
+                }
+                ImageFormat::R16G16Unorm => {
+                    buffer[offset..offset + 2].copy_from_slice(&quantize_u16(r));
+                    buffer[offset + 2..offset + 4].copy_from_slice(&quantize_u16(g));
+                    offset += 4;
+                }
+                ImageFormat::R16G16B16A16Unorm => {
+                    for (index, component) in [r, g, b, a].into_iter().enumerate() {
+                        buffer[offset + (index * 2)..offset + (index * 2) + 2]
+                            .copy_from_slice(&quantize_u16(component));
+                    }
+                    offset += 8;
+                }
+                _ => return Err(TextureError::ConversionError),
+            }
+        }
+    }
+
+    result.set_layout(image.layout());
+
+    *image = result;
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ use std::io::Write;
 use porter_utils::StructReadExt;
 use porter_utils::StructWriteExt;
 
+use crate::format_to_buffer_size;
+use crate::software_decode_pvrtc;
 use crate::Image;
 use crate::ImageFormat;
 use crate::TextureError;
@@ -28,6 +30,55 @@ fn unpack_extra_component(
         }
     }
 
+    result.set_layout(image.layout());
+
+    *image = result;
+
+    Ok(())
+}
+
+/// Utility method for decoding every mipmap of a pvrtc image to r8g8b8a8.
+fn unpack_pvrtc(image: &mut Image, two_bpp: bool) -> Result<(), TextureError> {
+    let mut result = Image::with_mipmaps(
+        image.width(),
+        image.height(),
+        image.mipmaps(),
+        ImageFormat::R8G8B8A8Unorm,
+    )?;
+
+    for frame in image.frames() {
+        let new_frame = result.create_frame()?;
+
+        let mut input_offset = 0;
+        let mut output_offset = 0;
+
+        for mip in 0..image.mipmaps() {
+            let width = (image.width() >> mip).max(1);
+            let height = (image.height() >> mip).max(1);
+
+            let input_size = format_to_buffer_size(image.format(), width, height) as usize;
+            let output_size = width as usize * height as usize * 4;
+
+            let input = frame
+                .buffer()
+                .get(input_offset..input_offset + input_size)
+                .ok_or(TextureError::ConversionError)?;
+
+            software_decode_pvrtc(
+                input,
+                &mut new_frame.buffer_mut()[output_offset..output_offset + output_size],
+                width,
+                height,
+                two_bpp,
+            );
+
+            input_offset += input_size;
+            output_offset += output_size;
+        }
+    }
+
+    result.set_layout(image.layout());
+
     *image = result;
 
     Ok(())
@@ -69,6 +120,8 @@ pub fn software_unpack_image(image: &mut Image) -> Result<(), TextureError> {
                 }
             }
 
+            result.set_layout(image.layout());
+
             *image = result;
         }
         ImageFormat::R8G8B8Unorm => {
@@ -109,6 +162,12 @@ pub fn software_unpack_image(image: &mut Image) -> Result<(), TextureError> {
                 &[0x80, 0x80, 0x80, 0x80],
             )?;
         }
+        ImageFormat::Pvrtc2bppUnorm => {
+            unpack_pvrtc(image, true)?;
+        }
+        ImageFormat::Pvrtc4bppUnorm => {
+            unpack_pvrtc(image, false)?;
+        }
         _ => return Err(TextureError::ConversionError),
     }
 
@@ -0,0 +1,15 @@
+/// The memory layout used by a console to store the pixels or blocks of a texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwizzlePattern {
+    /// Rows of pixels or blocks, no swizzling.
+    Linear,
+    /// Morton (z-order) across the whole surface, padded to a power of two, used by the ps3 and psvita.
+    Morton,
+    /// Morton (z-order) inside of 8x8 tiles of pixels or blocks, with the tiles in rows, used by the ps4 and ps5.
+    Ps4,
+    /// Tegra x1 block linear, used by the switch.
+    ///
+    /// The block height is the number of 512 byte gobs stacked in each block of mip 0, when `None` it's computed from the height.
+    /// Smaller mips automatically shrink the block height to match the hardware.
+    Tegra { block_height: Option<u32> },
+}
@@ -1,14 +1,31 @@
 #![deny(unsafe_code)]
 
+use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::Once;
 use std::thread::JoinHandle;
 
 use rayon::Scope;
+use rayon::ThreadPool;
+
+mod pipeline;
+mod task_scope;
+mod thread_priority;
+
+pub use pipeline::*;
+pub use task_scope::*;
+pub use thread_priority::*;
 
 pub use rayon::iter::IndexedParallelIterator;
 pub use rayon::iter::IntoParallelIterator;
 pub use rayon::iter::ParallelIterator;
 
+/// The dedicated export thread pool, and the number of threads and priority it was built with.
+static EXPORT_POOL: Mutex<Option<(usize, ThreadPriority, Arc<ThreadPool>)>> = Mutex::new(None);
+
+/// The dedicated io thread pool, and the number of threads it was built with.
+static IO_POOL: Mutex<Option<(usize, Arc<ThreadPool>)>> = Mutex::new(None);
+
 /// Used to run an error callback when a thread panics.
 struct OnError<E>
 where
@@ -116,3 +133,104 @@ pub fn initialize_thread_pool() {
         debug_assert!(result.is_ok());
     })
 }
+
+/// Returns the number of logical cores available, the maximum useful number of export threads.
+pub fn available_threads() -> usize {
+    num_cpus::get()
+}
+
+/// Sets the number of export threads and their priority, or zero threads at normal priority to export on the global thread pool.
+pub fn set_export_threads(threads: usize, priority: ThreadPriority) {
+    let Ok(mut pool) = EXPORT_POOL.lock() else {
+        return;
+    };
+
+    if threads == 0 && priority == ThreadPriority::Normal {
+        *pool = None;
+        return;
+    }
+
+    let threads = if threads == 0 {
+        num_cpus::get_physical().max(4)
+    } else {
+        threads
+    };
+
+    if pool
+        .as_ref()
+        .is_some_and(|(count, current, _)| *count == threads && *current == priority)
+    {
+        return;
+    }
+
+    // An export already running keeps the previous pool alive until it finishes.
+    *pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|index| format!("porter-export[{}]", index))
+        .start_handler(move |_| {
+            set_current_thread_priority(priority);
+        })
+        .build()
+        .ok()
+        .map(|result| (threads, priority, Arc::new(result)));
+}
+
+/// Spawns the closure on the export thread pool, parallel work inside of it is limited to the export threads.
+pub fn spawn_export<F>(func: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let pool = EXPORT_POOL
+        .lock()
+        .ok()
+        .and_then(|pool| pool.as_ref().map(|(_, _, pool)| pool.clone()));
+
+    match pool {
+        Some(pool) => pool.spawn(func),
+        None => rayon::spawn(func),
+    }
+}
+
+/// Sets the number of io threads, or zero to run io on the same pool as compute.
+///
+/// A separate pool keeps tasks blocked on slow disk reads or processes from starving compute, such as texture decoding.
+pub fn set_io_threads(threads: usize) {
+    let Ok(mut pool) = IO_POOL.lock() else {
+        return;
+    };
+
+    if threads == 0 {
+        *pool = None;
+        return;
+    }
+
+    if pool.as_ref().is_some_and(|(count, _)| *count == threads) {
+        return;
+    }
+
+    // Io already running keeps the previous pool alive until it finishes.
+    *pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .thread_name(|index| format!("porter-io[{}]", index))
+        .build()
+        .ok()
+        .map(|result| (threads, Arc::new(result)));
+}
+
+/// Spawns the closure on the io thread pool, used for work which blocks on file or process io.
+///
+/// Without io threads, the closure is spawned on the global thread pool.
+pub fn spawn_io<F>(func: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let pool = IO_POOL
+        .lock()
+        .ok()
+        .and_then(|pool| pool.as_ref().map(|(_, pool)| pool.clone()));
+
+    match pool {
+        Some(pool) => pool.spawn(func),
+        None => rayon::spawn(func),
+    }
+}
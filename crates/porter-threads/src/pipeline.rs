@@ -0,0 +1,106 @@
+use std::sync::mpsc::sync_channel;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+
+use crate::spawn_thread;
+
+/// A bounded producer-consumer pipeline, used to overlap stages such as reading, converting, and writing assets.
+///
+/// Each stage runs on its own threads, and waits when the next stage has `capacity` items queued.
+/// Stages block while waiting, so they run on dedicated threads instead of the thread pool. Items may finish out of order.
+pub struct Pipeline<T: Send + 'static> {
+    receiver: Receiver<T>,
+    capacity: usize,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl<T: Send + 'static> Pipeline<T> {
+    /// Constructs a new pipeline which feeds the items to the first stage, queueing at most `capacity` items per stage.
+    pub fn new<I>(items: I, capacity: usize) -> Self
+    where
+        I: IntoIterator<Item = T> + Send + 'static,
+    {
+        let capacity = capacity.max(1);
+        let (sender, receiver) = sync_channel(capacity);
+
+        let thread = spawn_thread(move || {
+            for item in items {
+                if sender.send(item).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            receiver,
+            capacity,
+            threads: vec![thread],
+        }
+    }
+
+    /// Adds a stage which runs the closure on every item using the given number of threads.
+    pub fn stage<U, F>(mut self, threads: usize, func: F) -> Pipeline<U>
+    where
+        U: Send + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        let (sender, receiver) = sync_channel(self.capacity);
+
+        let input = Arc::new(Mutex::new(self.receiver));
+        let func = Arc::new(func);
+
+        for _ in 0..threads.max(1) {
+            let input = input.clone();
+            let sender = sender.clone();
+            let func = func.clone();
+
+            self.threads.push(spawn_thread(move || loop {
+                // The lock is only held while waiting for the next item, not while processing it.
+                let item = match input.lock() {
+                    Ok(input) => input.recv(),
+                    Err(_) => break,
+                };
+
+                let Ok(item) = item else {
+                    break;
+                };
+
+                if sender.send(func(item)).is_err() {
+                    break;
+                }
+            }));
+        }
+
+        Pipeline {
+            receiver,
+            capacity: self.capacity,
+            threads: self.threads,
+        }
+    }
+
+    /// Runs the closure on every item leaving the last stage on the calling thread, waiting for every stage to finish.
+    ///
+    /// A panic in any stage is resumed on the calling thread.
+    pub fn for_each<F: FnMut(T)>(self, mut func: F) {
+        for item in self.receiver {
+            func(item);
+        }
+
+        for thread in self.threads {
+            if let Err(e) = thread.join() {
+                std::panic::resume_unwind(e);
+            }
+        }
+    }
+
+    /// Collects every item leaving the last stage, waiting for every stage to finish.
+    pub fn collect(self) -> Vec<T> {
+        let mut result = Vec::new();
+
+        self.for_each(|item| result.push(item));
+
+        result
+    }
+}
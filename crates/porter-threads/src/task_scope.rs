@@ -0,0 +1,121 @@
+use rayon::iter::IndexedParallelIterator;
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelIterator;
+use rayon::Scope;
+
+use porter_utils::AtomicCancel;
+use porter_utils::AtomicProgress;
+
+/// Runs parallel tasks which share a canceller and progress, such as loading or exporting assets.
+///
+/// Tasks started after cancellation are skipped, and every finished task counts towards the progress.
+#[derive(Clone, Default)]
+pub struct TaskScope {
+    cancel: AtomicCancel,
+    progress: AtomicProgress,
+}
+
+/// Spawns tasks inside of a task scope, see `TaskScope::scope`.
+pub struct TaskSpawner<'a, 'scope> {
+    scope: &'a Scope<'scope>,
+    task_scope: TaskScope,
+}
+
+impl TaskScope {
+    /// Constructs a new task scope with the given canceller and progress.
+    pub fn new(cancel: AtomicCancel, progress: AtomicProgress) -> Self {
+        Self { cancel, progress }
+    }
+
+    /// Gets the canceller of this scope.
+    pub fn cancel_token(&self) -> &AtomicCancel {
+        &self.cancel
+    }
+
+    /// Gets the progress of this scope.
+    pub fn progress(&self) -> &AtomicProgress {
+        &self.progress
+    }
+
+    /// Signals that the remaining tasks should stop.
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Whether or not the tasks were cancelled, cheap enough to check inside of a task.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Resets the progress to the new total.
+    pub fn reset(&self, total: usize) {
+        self.progress.reset(total);
+    }
+
+    /// Reports that a task has finished.
+    pub fn complete(&self) {
+        self.progress.increment();
+    }
+
+    /// Runs the closure for every item in parallel, resetting the progress to the number of items.
+    ///
+    /// Returns false if the tasks were cancelled before every item finished.
+    pub fn for_each<I, F>(&self, items: I, func: F) -> bool
+    where
+        I: IntoParallelIterator,
+        I::Iter: IndexedParallelIterator,
+        F: Fn(&TaskScope, I::Item) + Sync + Send,
+    {
+        let items = items.into_par_iter();
+
+        self.reset(items.len());
+
+        items.for_each(|item| {
+            if self.is_cancelled() {
+                return;
+            }
+
+            func(self, item);
+
+            self.complete();
+        });
+
+        !self.is_cancelled()
+    }
+
+    /// Calls a closure that can spawn tasks, which are allowed to reference variables outside of the closure.
+    ///
+    /// The progress isn't reset, so the total should be set with `reset` before spawning.
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&TaskSpawner<'_, 'scope>) -> R + Send,
+        R: Send,
+    {
+        rayon::scope(|scope| {
+            op(&TaskSpawner {
+                scope,
+                task_scope: self.clone(),
+            })
+        })
+    }
+}
+
+impl<'scope> TaskSpawner<'_, 'scope> {
+    /// Spawns a task, which is skipped if the scope was cancelled before it started.
+    pub fn spawn<F>(&self, func: F)
+    where
+        F: FnOnce(&TaskScope) + Send + 'scope,
+    {
+        let task_scope = self.task_scope.clone();
+
+        self.scope.spawn(move |_| {
+            if task_scope.is_cancelled() {
+                return;
+            }
+
+            func(&task_scope);
+
+            task_scope.complete();
+        });
+    }
+}
@@ -0,0 +1,77 @@
+use bincode::Decode;
+use bincode::Encode;
+
+/// The operating system scheduling priority of a thread.
+#[derive(Decode, Encode, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadPriority {
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Below normal cpu priority.
+    Low,
+    /// The lowest cpu priority, and idle io priority where supported.
+    Background,
+}
+
+/// Sets the priority of the calling thread, returns whether or not the priority was changed.
+///
+/// Some platforms don't allow raising the priority again once lowered, so this should be called when a thread starts.
+pub fn set_current_thread_priority(priority: ThreadPriority) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        use windows_sys::Win32::System::Threading::*;
+
+        let priority = match priority {
+            ThreadPriority::Normal => THREAD_PRIORITY_NORMAL,
+            ThreadPriority::Low => THREAD_PRIORITY_BELOW_NORMAL,
+            ThreadPriority::Background => THREAD_MODE_BACKGROUND_BEGIN,
+        };
+
+        // SAFETY: The pseudo handle of the current thread is always valid, and doesn't need to be closed.
+        #[allow(unsafe_code)]
+        let result = unsafe { SetThreadPriority(GetCurrentThread(), priority) };
+
+        result != 0
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let class = match priority {
+            ThreadPriority::Normal => libc::qos_class_t::QOS_CLASS_DEFAULT,
+            ThreadPriority::Low => libc::qos_class_t::QOS_CLASS_UTILITY,
+            ThreadPriority::Background => libc::qos_class_t::QOS_CLASS_BACKGROUND,
+        };
+
+        // SAFETY: Only changes the quality of service of the calling thread.
+        #[allow(unsafe_code)]
+        let result = unsafe { libc::pthread_set_qos_class_self_np(class, 0) };
+
+        result == 0
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let nice = match priority {
+            ThreadPriority::Normal => 0,
+            ThreadPriority::Low => 10,
+            ThreadPriority::Background => 19,
+        };
+
+        // SAFETY: Gettid has no preconditions, and always succeeds.
+        #[allow(unsafe_code)]
+        let thread = unsafe { libc::gettid() };
+
+        // SAFETY: On linux the nice value is per thread, so this only changes the calling thread.
+        #[allow(unsafe_code)]
+        let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, thread as libc::id_t, nice) };
+
+        result == 0
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+    {
+        let _ = priority;
+
+        false
+    }
+}
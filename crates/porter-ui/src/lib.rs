@@ -1,23 +1,37 @@
+mod porter_asset_loader;
 mod porter_asset_manager;
 mod porter_asset_status;
+mod porter_audio_waveform;
+mod porter_bone_labels;
 mod porter_color_palette;
+mod porter_context_menu;
 mod porter_divider;
 mod porter_executor;
+mod porter_export_collision;
+mod porter_export_database;
+mod porter_export_history;
+mod porter_export_hook;
+mod porter_export_journal;
+mod porter_format_support;
 mod porter_main;
 mod porter_main_about;
 mod porter_main_builder;
 mod porter_main_column;
 mod porter_main_commands;
 mod porter_main_events;
+mod porter_main_history;
 mod porter_main_settings;
 mod porter_overlay;
 mod porter_preview_asset;
 mod porter_search;
+mod porter_session;
 mod porter_settings;
 mod porter_splash;
 mod porter_strings;
+mod porter_support_bundle;
 mod porter_text;
 mod porter_theme;
+mod porter_thumbnail_cache;
 mod porter_ui;
 mod porter_viewport;
 mod porter_windows;
@@ -28,9 +42,13 @@ mod porter_icon_windows;
 pub mod porter_easing;
 pub mod porter_spinner;
 
+pub use porter_asset_loader::*;
 pub use porter_asset_manager::*;
 pub use porter_asset_status::*;
 pub use porter_color_palette::*;
+pub use porter_export_collision::*;
+pub use porter_export_hook::*;
+pub use porter_format_support::*;
 pub use porter_main_builder::*;
 pub use porter_main_column::*;
 pub use porter_preview_asset::*;
@@ -40,15 +58,24 @@ pub use porter_ui::*;
 
 pub use iced::Color;
 
+pub(crate) use porter_audio_waveform::*;
+pub(crate) use porter_bone_labels::*;
+pub(crate) use porter_context_menu::*;
 pub(crate) use porter_divider::*;
 pub(crate) use porter_executor::*;
+pub(crate) use porter_export_database::*;
+pub(crate) use porter_export_history::*;
+pub(crate) use porter_export_journal::*;
 
 pub(crate) use porter_main::*;
 pub(crate) use porter_overlay::*;
+pub(crate) use porter_session::*;
 pub(crate) use porter_splash::*;
 pub(crate) use porter_strings::*;
+pub(crate) use porter_support_bundle::*;
 pub(crate) use porter_text::*;
 pub(crate) use porter_theme::*;
+pub(crate) use porter_thumbnail_cache::*;
 pub(crate) use porter_viewport::*;
 pub(crate) use porter_windows::*;
 
@@ -57,9 +84,13 @@ pub(crate) use porter_icon_windows::*;
 
 use std::backtrace::Backtrace;
 use std::path::Path;
+use std::path::PathBuf;
 
 use directories::ProjectDirs;
 
+/// The key used to encrypt crash files.
+const CRASH_KEY: &str = "asakujaku";
+
 /// Encrypts a string using the given key.
 fn xor_encrypt<K: AsRef<[u8]>>(input: String, key: K) -> Vec<u8> {
     let key = key.as_ref();
@@ -90,11 +121,62 @@ pub fn install_panic_hook(name: &'static str, version: &'static str) {
             let backtrace = Backtrace::force_capture();
             let error = format!("{} {:?} ({})", error, backtrace, version);
 
-            let _ = std::fs::write(target.clone(), xor_encrypt(error, "asakujaku"));
+            log::error!("{}", error);
+            log::logger().flush();
+
+            let _ = std::fs::write(target.clone(), xor_encrypt(error, CRASH_KEY));
         }));
     }
 }
 
+/// Decrypts a buffer encrypted with the given key.
+fn xor_decrypt<K: AsRef<[u8]>>(input: Vec<u8>, key: K) -> String {
+    let mut buffer = input;
+
+    for (byte, key) in buffer.iter_mut().zip(key.as_ref().iter().cycle()) {
+        *byte ^= key;
+    }
+
+    String::from_utf8_lossy(&buffer).into_owned()
+}
+
+/// Returns the path of the crash file, or the last crash file which was already reported to the user.
+fn crash_report_path(name: &str, last: bool) -> Option<PathBuf> {
+    let project_directory = ProjectDirs::from("com", "DTZxPorter", "GameTools")?;
+
+    let file_name = if last {
+        format!("{}_last", name.to_lowercase())
+    } else {
+        name.to_lowercase()
+    };
+
+    Some(
+        project_directory
+            .config_dir()
+            .join(file_name)
+            .with_extension("crash"),
+    )
+}
+
+/// Reads and decrypts the crash report, or the last crash report which was already reported to the user.
+pub(crate) fn read_crash_report(name: &str, last: bool) -> Option<String> {
+    let path = crash_report_path(name, last)?;
+
+    std::fs::read(path)
+        .ok()
+        .map(|buffer| xor_decrypt(buffer, CRASH_KEY))
+}
+
+/// Moves the crash report to the last crash report, so the user is only told about it once.
+pub(crate) fn archive_crash_report(name: &str) {
+    if let (Some(source), Some(target)) = (
+        crash_report_path(name, false),
+        crash_report_path(name, true),
+    ) {
+        let _ = std::fs::rename(source, target);
+    }
+}
+
 /// Method to open a url in the user's default browser.
 pub fn open_url<U: AsRef<str>>(url: U) {
     let url = url.as_ref();
@@ -192,3 +274,79 @@ pub fn open_folder<F: AsRef<Path>>(folder: F) {
         debug_assert!(result.is_ok());
     }
 }
+
+/// Returns the available free space in bytes for the volume containing the given path.
+pub fn available_space<P: AsRef<Path>>(path: P) -> Option<u64> {
+    let path = path.as_ref();
+    let path = path.ancestors().find(|path| path.exists())?;
+
+    #[cfg(target_os = "windows")]
+    {
+        use widestring::U16CString;
+
+        use windows_sys::Win32::Storage::FileSystem::*;
+
+        let path = U16CString::from_os_str(path.as_os_str()).ok()?;
+        let mut available: u64 = 0;
+
+        // SAFETY: The pointer to path lives as long as the call does, and is checked that it's a valid string,
+        // the available pointer is a valid u64 which lives as long as the call does.
+        let result = unsafe {
+            GetDiskFreeSpaceExW(
+                path.as_ptr(),
+                &mut available,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+
+        if result == 0 {
+            None
+        } else {
+            Some(available)
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        use std::ffi::CString;
+        use std::mem::MaybeUninit;
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+        let mut stat: MaybeUninit<libc::statvfs> = MaybeUninit::uninit();
+
+        // SAFETY: The pointer to path lives as long as the call does, and is checked that it's a valid string,
+        // the stat pointer is valid for writes and lives as long as the call does.
+        let result = unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) };
+
+        if result != 0 {
+            return None;
+        }
+
+        // SAFETY: The call succeeded, so the stat structure was initialized.
+        let stat = unsafe { stat.assume_init() };
+
+        #[allow(clippy::unnecessary_cast)]
+        let available = stat.f_bavail as u64 * stat.f_frsize as u64;
+
+        Some(available)
+    }
+}
+
+/// Formats a count with thousands separators.
+pub(crate) fn format_count(count: usize) -> String {
+    let digits = count.to_string();
+    let offset = digits.len() % 3;
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && index % 3 == offset {
+            result.push(',');
+        }
+
+        result.push(digit);
+    }
+
+    result
+}
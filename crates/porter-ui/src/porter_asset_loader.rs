@@ -0,0 +1,46 @@
+use iced::futures::channel::mpsc::UnboundedSender;
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use crate::Message;
+
+/// Used by asset managers to publish assets to the ui while loading continues.
+#[derive(Clone)]
+pub struct PorterAssetLoader {
+    channel: Arc<Option<UnboundedSender<Message>>>,
+    published: Arc<AtomicUsize>,
+}
+
+impl PorterAssetLoader {
+    /// Constructs a new instance of the asset loader.
+    pub(crate) fn new(channel: Option<UnboundedSender<Message>>) -> Self {
+        Self {
+            channel: Arc::new(channel),
+            published: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Publishes a batch of assets, which the asset manager must already include in its length.
+    ///
+    /// The asset list updates live with the running count, so batches should be large enough to not flood the ui.
+    pub fn publish(&self, batch_len: usize) {
+        if batch_len == 0 {
+            return;
+        }
+
+        let published = self.published.fetch_add(batch_len, Ordering::Relaxed) + batch_len;
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::LoadPublished(published));
+
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    /// Returns the total number of assets published so far.
+    pub fn published(&self) -> usize {
+        self.published.load(Ordering::Relaxed)
+    }
+}
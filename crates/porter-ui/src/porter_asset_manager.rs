@@ -2,10 +2,25 @@ use std::path::PathBuf;
 
 use iced::Color;
 
+use porter_process::Process;
+
+use crate::PorterAssetLoader;
 use crate::PorterSearch;
 use crate::PorterSettings;
 use crate::PorterUI;
 
+/// Metadata for a single asset, used to summarize a selection of assets.
+#[derive(Debug, Clone, Default)]
+pub struct PorterAssetMetadata {
+    /// The type of the asset.
+    pub asset_type: Option<String>,
+    /// The size in bytes of the asset in the game, used to estimate the export size.
+    pub size: Option<u64>,
+}
+
+/// How much larger assets are estimated to be once exported, since block compressed images and packed vertex data grow when converted.
+const EXPORT_SIZE_FACTOR: u64 = 4;
+
 /// A unified asset trait used to normalize the information across games.
 pub trait PorterAssetManager: Send + Sync + 'static {
     /// Returns the asset info in the form of the columns to render.
@@ -25,6 +40,34 @@ pub trait PorterAssetManager: Send + Sync + 'static {
     /// Searches for assets, or resets the asset list when empty.
     fn search_assets(&self, search: Option<PorterSearch>);
 
+    /// The number of renderable assets per asset type, computed after loading and searching, shown as badges in the search bar.
+    fn asset_type_counts(&self) -> Vec<(String, usize)> {
+        Vec::new()
+    }
+
+    /// The type of the asset at the given row index, used to group assets when sampling.
+    fn asset_type(&self, _row_index: usize) -> Option<String> {
+        None
+    }
+
+    /// The metadata of the asset at the given row index, used to summarize the selection.
+    fn asset_metadata(&self, row_index: usize) -> PorterAssetMetadata {
+        PorterAssetMetadata {
+            asset_type: self.asset_type(row_index),
+            size: None,
+        }
+    }
+
+    /// The hash of the asset at the given row index formatted for display, used to copy the hash.
+    fn asset_hash(&self, _row_index: usize) -> Option<String> {
+        None
+    }
+
+    /// A small rgba8 thumbnail as (width, height, pixels) of the asset at the given row index, shown in the thumbnail column.
+    fn asset_thumbnail(&self, _row_index: usize) -> Option<(u32, u32, Vec<u8>)> {
+        None
+    }
+
     /// Whether or not load files is supported.
     fn supports_load_files(&self) -> bool;
 
@@ -37,12 +80,53 @@ pub trait PorterAssetManager: Send + Sync + 'static {
     /// Loads a game's memory in async.
     fn on_load_game(&self, settings: PorterSettings) -> Result<(), String>;
 
-    /// Exports a game's assets in async.
+    /// Loads one or more given file in async, publishing batches of assets with the loader as they're added.
+    ///
+    /// Managers which can list assets before loading finishes should override this, the default loads everything at once.
+    fn on_load_files_streamed(
+        &self,
+        settings: PorterSettings,
+        files: Vec<PathBuf>,
+        _loader: PorterAssetLoader,
+    ) -> Result<(), String> {
+        self.on_load_files(settings, files)
+    }
+
+    /// Loads a game's memory in async, publishing batches of assets with the loader as they're added.
+    ///
+    /// Managers which can list assets before loading finishes should override this, the default loads everything at once.
+    fn on_load_game_streamed(
+        &self,
+        settings: PorterSettings,
+        _loader: PorterAssetLoader,
+    ) -> Result<(), String> {
+        self.on_load_game(settings)
+    }
+
+    /// The process the game was loaded from, used to detect when the game exits.
+    fn loaded_process(&self) -> Option<Process> {
+        None
+    }
+
+    /// Estimates the size in bytes required to export the given assets, used to check for free space before exporting.
+    ///
+    /// Defaults to a multiple of the size of each asset in the game, or none when no asset has a known size.
+    fn export_size(&self, _settings: &PorterSettings, assets: &[usize]) -> Option<u64> {
+        assets
+            .iter()
+            .filter_map(|index| self.asset_metadata(*index).size)
+            .map(|size| size.saturating_mul(EXPORT_SIZE_FACTOR))
+            .reduce(u64::saturating_add)
+    }
+
+    /// Exports a game's assets in async, when the ui is a dry run no files should be written.
+    ///
+    /// The assets can be exported with `ui.scope().for_each`, which stops when the user cancels the export.
     fn on_export(&self, settings: PorterSettings, assets: Vec<usize>, ui: PorterUI);
 
     /// Loads a game's asset for previewing.
     fn on_preview(&self, settings: PorterSettings, asset: usize, request_id: u64, ui: PorterUI);
 
-    /// Cancels an active export.
+    /// Cancels an active export, the export scope of the ui is cancelled before this is called.
     fn cancel_export(&self);
 }
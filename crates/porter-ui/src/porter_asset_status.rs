@@ -72,6 +72,18 @@ impl PorterAssetStatus {
         self.status.load(Ordering::Relaxed) == 4
     }
 
+    /// Asset was skipped because the exported file already exists.
+    pub fn skipped() -> Self {
+        Self {
+            status: AtomicUsize::new(5),
+        }
+    }
+
+    /// Whether or not the status is skipped.
+    pub fn is_skipped(&self) -> bool {
+        self.status.load(Ordering::Relaxed) == 5
+    }
+
     /// Sets the status.
     pub fn set(&self, status: Self) {
         self.status
@@ -86,6 +98,7 @@ impl PorterAssetStatus {
             2 => Color::from_rgb8(212, 175, 55),
             3 => Color::from_rgb8(236, 52, 202),
             4 => Color::from_rgb8(144, 122, 214),
+            5 => Color::from_rgb8(150, 150, 150),
             _ => unreachable!(),
         }
     }
@@ -99,6 +112,7 @@ impl fmt::Display for PorterAssetStatus {
             2 => write!(f, "Error"),
             3 => write!(f, "Placeholder"),
             4 => write!(f, "Exporting..."),
+            5 => write!(f, "Skipped"),
             _ => unreachable!(),
         }
     }
@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use iced::widget::canvas::event;
+use iced::widget::canvas::Event;
+use iced::widget::canvas::Frame;
+use iced::widget::canvas::Path;
+use iced::widget::canvas::Program;
+
+use iced::mouse;
+use iced::Color;
+use iced::Point;
+use iced::Rectangle;
+use iced::Size;
+
+use porter_audio::AudioWaveform;
+
+use crate::Message;
+
+/// A canvas renderer for the audio preview waveform, with a playhead that seeks on click and an optional loop region.
+pub struct PorterAudioWaveform<'a> {
+    pub waveform: Option<&'a AudioWaveform>,
+    pub position: f32,
+    pub loop_region: Option<(f32, f32)>,
+}
+
+impl<'a> PorterAudioWaveform<'a> {
+    /// Converts the cursor position to a seek position.
+    fn seek(&self, bounds: Rectangle, cursor: mouse::Cursor) -> Option<Message> {
+        let position = cursor.position_in(bounds)?;
+
+        Some(Message::PreviewAudioSeek(
+            (position.x / bounds.width).clamp(0.0, 1.0),
+        ))
+    }
+}
+
+impl<'a> Program<Message> for PorterAudioWaveform<'a> {
+    type State = bool;
+
+    fn update(
+        &self,
+        state: &mut Self::State,
+        event: Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (event::Status, Option<Message>) {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if cursor.is_over(bounds) =>
+            {
+                *state = true;
+
+                (event::Status::Captured, self.seek(bounds, cursor))
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if *state => {
+                (event::Status::Captured, self.seek(bounds, cursor))
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if *state => {
+                *state = false;
+
+                (event::Status::Captured, None)
+            }
+            _ => (event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<iced::widget::canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        let center = bounds.height / 2.0;
+        let scale = bounds.height * 0.45;
+        let playhead = self.position * bounds.width;
+
+        frame.fill_rectangle(
+            Point::new(0.0, center),
+            Size::new(bounds.width, 1.0),
+            Color::from_rgb8(0x3A, 0x3A, 0x3A),
+        );
+
+        if let Some((start, end)) = self.loop_region {
+            let start = start.clamp(0.0, 1.0) * bounds.width;
+            let end = end.clamp(0.0, 1.0) * bounds.width;
+
+            frame.fill_rectangle(
+                Point::new(start, 0.0),
+                Size::new((end - start).max(1.0), bounds.height),
+                Color::from_rgba8(0x27, 0x9B, 0xD4, 0.15),
+            );
+
+            for marker in [start, end] {
+                frame.fill_rectangle(
+                    Point::new(marker - 0.5, 0.0),
+                    Size::new(1.0, bounds.height),
+                    Color::from_rgb8(0x27, 0x9B, 0xD4),
+                );
+            }
+        }
+
+        if let Some(peaks) = self.waveform.map(|waveform| waveform.peaks()) {
+            let columns = bounds.width.max(1.0) as usize;
+
+            for column in 0..columns {
+                let start = column * peaks.len() / columns;
+                let end = ((column + 1) * peaks.len() / columns).max(start + 1);
+
+                let Some((min, max)) = peaks.get(start..end.min(peaks.len())).map(|peaks| {
+                    peaks.iter().fold((0.0f32, 0.0f32), |(min, max), peak| {
+                        (min.min(peak.0), max.max(peak.1))
+                    })
+                }) else {
+                    continue;
+                };
+
+                let x = column as f32 * bounds.width / columns as f32;
+
+                let color = if x < playhead {
+                    Color::from_rgb8(0x27, 0x9B, 0xD4)
+                } else {
+                    Color::from_rgb8(0x8A, 0x8A, 0x8A)
+                };
+
+                frame.fill_rectangle(
+                    Point::new(x, center - max * scale),
+                    Size::new(1.0, ((max - min) * scale).max(1.0)),
+                    color,
+                );
+            }
+        }
+
+        frame.fill(
+            &Path::rectangle(
+                Point::new(playhead - 1.0, 0.0),
+                Size::new(2.0, bounds.height),
+            ),
+            Color::WHITE,
+        );
+
+        vec![frame.into_geometry()]
+    }
+
+    fn mouse_interaction(
+        &self,
+        _state: &Self::State,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> mouse::Interaction {
+        if cursor.is_over(bounds) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+}
+
+/// Formats an audio position as minutes, seconds, and milliseconds.
+pub(crate) fn format_audio_time(time: Duration) -> String {
+    format!(
+        "{}:{:02}.{:03}",
+        time.as_secs() / 60,
+        time.as_secs() % 60,
+        time.subsec_millis()
+    )
+}
+
+/// Formats a frame of audio at the given sample rate as minutes, seconds, and milliseconds.
+pub(crate) fn format_audio_frame_time(frame: u64, sample_rate: u32) -> String {
+    format_audio_time(Duration::from_secs_f64(
+        frame as f64 / sample_rate.max(1) as f64,
+    ))
+}
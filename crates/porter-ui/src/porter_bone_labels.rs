@@ -0,0 +1,66 @@
+use iced::widget::canvas::Frame;
+use iced::widget::canvas::Path;
+use iced::widget::canvas::Program;
+use iced::widget::canvas::Text;
+
+use iced::alignment::Vertical;
+use iced::Color;
+use iced::Point;
+
+use porter_preview::PreviewBoneLabel;
+
+/// The size of the bone name text.
+const LABEL_SIZE: f32 = 12.0;
+
+/// A canvas renderer for the bone names over the previewer.
+pub struct PorterBoneLabels(pub Vec<PreviewBoneLabel>);
+
+impl<Message> Program<Message> for PorterBoneLabels {
+    type State = ();
+
+    fn draw(
+        &self,
+        _state: &Self::State,
+        renderer: &iced::Renderer,
+        _theme: &iced::Theme,
+        bounds: iced::Rectangle,
+        _cursor: iced::advanced::mouse::Cursor,
+    ) -> Vec<iced::widget::canvas::Geometry> {
+        let mut frame = Frame::new(renderer, bounds.size());
+
+        // Draw the hovered bone last so that it's always on top.
+        let labels = self
+            .0
+            .iter()
+            .filter(|label| !label.hovered)
+            .chain(self.0.iter().filter(|label| label.hovered));
+
+        for label in labels {
+            let color = if label.hovered {
+                Color::from_rgb8(0xEC, 0x34, 0xCA)
+            } else {
+                Color::WHITE
+            };
+
+            frame.fill(
+                &Path::circle(Point::new(label.x, label.y), 3.0),
+                Color::from_rgb8(0x27, 0x9B, 0xD4),
+            );
+
+            if label.hovered {
+                frame.fill(&Path::circle(Point::new(label.x, label.y), 4.0), color);
+            }
+
+            frame.fill_text(Text {
+                content: label.name.clone(),
+                position: Point::new(label.x + 6.0, label.y),
+                color,
+                size: LABEL_SIZE.into(),
+                vertical_alignment: Vertical::Center,
+                ..Default::default()
+            });
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
@@ -0,0 +1,289 @@
+use iced::advanced::*;
+use iced::keyboard;
+use iced::keyboard::key::Named;
+use iced::Renderer;
+use iced::Vector;
+use iced::{event, mouse, Event, Length, Point, Rectangle};
+use iced::{Element, Size};
+
+/// Shows the menu element at the given position ontop of the base element, dismissing it when clicking outside of it.
+pub fn porter_context_menu<'a, Message>(
+    base: impl Into<Element<'a, Message>>,
+    menu: Option<(Point, Element<'a, Message>)>,
+    on_dismiss: Message,
+) -> Element<'a, Message>
+where
+    Message: Clone + 'a,
+{
+    PorterContextMenu {
+        base: base.into(),
+        menu,
+        on_dismiss,
+    }
+    .into()
+}
+
+struct PorterContextMenu<'a, Message, Theme> {
+    base: Element<'a, Message, Theme>,
+    menu: Option<(Point, Element<'a, Message, Theme>)>,
+    on_dismiss: Message,
+}
+
+impl<'a, Message, Theme> Widget<Message, Theme, Renderer> for PorterContextMenu<'a, Message, Theme>
+where
+    Message: Clone,
+{
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(
+        &self,
+        tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.base
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        )
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        match &self.menu {
+            Some((_, menu)) => vec![widget::Tree::new(&self.base), widget::Tree::new(menu)],
+            None => vec![widget::Tree::new(&self.base)],
+        }
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        match &self.menu {
+            Some((_, menu)) => tree.diff_children(&[&self.base, menu]),
+            None => tree.diff_children(&[&self.base]),
+        }
+    }
+
+    fn operate(
+        &self,
+        tree: &mut iced::advanced::widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.base
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.base.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.base.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let (base_tree, menu_tree) = tree.children.split_at_mut(1);
+
+        match (&mut self.menu, menu_tree.first_mut()) {
+            (Some((position, menu)), Some(menu_tree)) => {
+                Some(overlay::Element::new(Box::new(PorterContextMenuChild {
+                    position: *position,
+                    content: menu,
+                    tree: menu_tree,
+                    on_dismiss: self.on_dismiss.clone(),
+                })))
+            }
+            _ => {
+                self.base
+                    .as_widget_mut()
+                    .overlay(&mut base_tree[0], layout, renderer, translation)
+            }
+        }
+    }
+}
+
+impl<'a, Message, Theme: 'a> From<PorterContextMenu<'a, Message, Theme>>
+    for Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+{
+    fn from(context_menu: PorterContextMenu<'a, Message, Theme>) -> Self {
+        Element::new(context_menu)
+    }
+}
+
+struct PorterContextMenuChild<'a, 'b, Message, Theme> {
+    position: Point,
+    content: &'b mut Element<'a, Message, Theme>,
+    tree: &'b mut widget::Tree,
+    on_dismiss: Message,
+}
+
+impl<'a, 'b, Message, Theme> overlay::Overlay<Message, Theme, Renderer>
+    for PorterContextMenuChild<'a, 'b, Message, Theme>
+where
+    Message: Clone,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: iced::Size) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, bounds);
+
+        let node = self
+            .content
+            .as_widget()
+            .layout(self.tree, renderer, &limits);
+
+        let size = node.size();
+
+        // Keep the menu inside of the window when opened near the edges.
+        let x = self.position.x.min(bounds.width - size.width).max(0.0);
+        let y = self.position.y.min(bounds.height - size.height).max(0.0);
+
+        node.move_to(Point::new(x, y))
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.content.as_widget().draw(
+            self.tree,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            &layout.bounds(),
+        );
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn widget::Operation<Message>,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(self.tree, layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match &event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if !cursor.is_over(layout.bounds()) => {
+                shell.publish(self.on_dismiss.clone());
+
+                return event::Status::Ignored;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(Named::Escape),
+                ..
+            }) => {
+                shell.publish(self.on_dismiss.clone());
+
+                return event::Status::Captured;
+            }
+            _ => {
+                // Handled by the menu.
+            }
+        }
+
+        self.content.as_widget_mut().on_event(
+            self.tree,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            &layout.bounds(),
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> iced::advanced::mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(self.tree, layout, cursor, viewport, renderer)
+    }
+
+    fn is_over(&self, layout: Layout<'_>, _renderer: &Renderer, cursor_position: Point) -> bool {
+        layout.bounds().contains(cursor_position)
+    }
+}
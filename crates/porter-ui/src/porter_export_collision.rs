@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+use bincode::Decode;
+use bincode::Encode;
+
+/// The maximum suffix tried when renaming a file that already exists.
+const RENAME_MAX_SUFFIX: u32 = 9999;
+
+/// What happens when an exported file already exists.
+#[derive(Debug, Decode, Encode, Clone, Copy, PartialEq, Eq)]
+pub enum PorterExportCollision {
+    /// Replaces the existing file.
+    Overwrite,
+    /// Keeps the existing file and skips the export.
+    Skip,
+    /// Exports to a new file with an incrementing suffix.
+    Rename,
+}
+
+impl PorterExportCollision {
+    /// Resolves the path a file should be exported to, or `None` if the export should be skipped.
+    ///
+    /// Resolved paths are claimed, so files exported in parallel never resolve to the same path before they're written.
+    /// Fails when every renamed path is taken.
+    pub fn resolve<P: AsRef<Path>>(
+        &self,
+        path: P,
+        claimed: &mut HashSet<PathBuf>,
+    ) -> Result<Option<PathBuf>, Error> {
+        let path = path.as_ref();
+
+        let result = match self {
+            Self::Overwrite => return Ok(Some(path.to_path_buf())),
+            _ if !is_taken(path, claimed) => path.to_path_buf(),
+            Self::Skip => return Ok(None),
+            Self::Rename => {
+                let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                let extension = path
+                    .extension()
+                    .map(|extension| extension.to_string_lossy());
+
+                (1..=RENAME_MAX_SUFFIX)
+                    .map(|suffix| {
+                        let name = match &extension {
+                            Some(extension) => format!("{}_{}.{}", stem, suffix, extension),
+                            None => format!("{}_{}", stem, suffix),
+                        };
+
+                        path.with_file_name(name)
+                    })
+                    .find(|path| !is_taken(path, claimed))
+                    .ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::AlreadyExists,
+                            format!(
+                                "Every renamed path up to {} is taken: {}",
+                                RENAME_MAX_SUFFIX,
+                                path.display()
+                            ),
+                        )
+                    })?
+            }
+        };
+
+        claimed.insert(result.clone());
+
+        Ok(Some(result))
+    }
+}
+
+/// Whether or not the path exists, or was already claimed by another export.
+fn is_taken(path: &Path, claimed: &HashSet<PathBuf>) -> bool {
+    claimed.contains(path) || path.exists()
+}
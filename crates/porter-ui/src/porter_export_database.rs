@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use bincode::Decode;
+use bincode::Encode;
+
+use directories::ProjectDirs;
+
+/// The identity of an exported asset, used to skip unchanged assets on the next export.
+#[derive(Debug, Decode, Encode, Clone, PartialEq, Eq)]
+struct PorterExportRecord {
+    hash: String,
+    fingerprint: u64,
+}
+
+/// A persisted database of exported assets, keyed by name.
+#[derive(Debug, Decode, Encode, Clone, Default)]
+pub struct PorterExportDatabase {
+    assets: BTreeMap<String, PorterExportRecord>,
+}
+
+impl PorterExportDatabase {
+    /// Loads the export database from the disk for the given tool, or returns an empty one.
+    pub fn load<S: Into<String>>(name: S) -> PorterExportDatabase {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return Default::default();
+        };
+
+        std::fs::read(
+            project_directory
+                .config_dir()
+                .join(format!("{}_exports", name.into().to_lowercase()))
+                .with_extension("dat"),
+        )
+        .map_or(Default::default(), |buffer| {
+            let config = bincode::config::standard();
+
+            bincode::decode_from_slice(&buffer, config)
+                .unwrap_or_default()
+                .0
+        })
+    }
+
+    /// Saves the export database to the disk for the given tool.
+    pub fn save<S: Into<String>>(&self, name: S) {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return;
+        };
+
+        let config = bincode::config::standard();
+
+        let Ok(result) = bincode::encode_to_vec(self, config) else {
+            return;
+        };
+
+        let dirs = std::fs::create_dir_all(project_directory.config_dir());
+
+        debug_assert!(dirs.is_ok());
+
+        let result = std::fs::write(
+            project_directory
+                .config_dir()
+                .join(format!("{}_exports", name.into().to_lowercase()))
+                .with_extension("dat"),
+            result,
+        );
+
+        debug_assert!(result.is_ok());
+    }
+
+    /// Whether or not the asset was already exported with the same hash and settings fingerprint.
+    pub fn is_exported(&self, name: &str, hash: &str, fingerprint: u64) -> bool {
+        self.assets
+            .get(name)
+            .is_some_and(|record| record.hash == hash && record.fingerprint == fingerprint)
+    }
+
+    /// Records an exported asset, replacing any previous export of the same name.
+    pub fn insert(&mut self, name: String, hash: String, fingerprint: u64) {
+        self.assets
+            .insert(name, PorterExportRecord { hash, fingerprint });
+    }
+
+    /// The number of exported assets recorded.
+    pub fn len(&self) -> usize {
+        self.assets.len()
+    }
+
+    /// Removes every exported asset, so the next export processes everything.
+    pub fn clear(&mut self) {
+        self.assets.clear();
+    }
+}
@@ -0,0 +1,227 @@
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use bincode::Decode;
+use bincode::Encode;
+
+use directories::ProjectDirs;
+
+use crate::PorterExportFormat;
+use crate::PorterSettings;
+
+/// The maximum number of export runs kept in the history.
+const EXPORT_HISTORY_MAX: usize = 50;
+/// The maximum number of asset names kept for each export run.
+pub const EXPORT_HISTORY_ASSETS_MAX: usize = 1000;
+/// The layout version of the history, which must be bumped whenever the entries change.
+const EXPORT_HISTORY_VERSION: u32 = 2;
+
+/// The latest save of the history, so an older save finishing last doesn't overwrite it.
+static EXPORT_HISTORY_SAVE: AtomicU64 = AtomicU64::new(0);
+/// The last save written to disk.
+static EXPORT_HISTORY_WRITTEN: Mutex<u64> = Mutex::new(0);
+
+/// A single export run in the export history.
+#[derive(Debug, Decode, Encode, Clone)]
+pub struct PorterExportHistoryEntry {
+    pub(crate) timestamp: u64,
+    pub(crate) summary: String,
+    pub(crate) profile: Option<String>,
+    pub(crate) formats: Vec<String>,
+    pub(crate) output_directory: PathBuf,
+    pub(crate) assets: Vec<String>,
+    pub(crate) completed: u64,
+    pub(crate) total: u64,
+    pub(crate) cancelled: bool,
+    pub(crate) skipped: u64,
+}
+
+impl PorterExportHistoryEntry {
+    /// Constructs a new history entry for an export of `total` assets that is starting now, keeping at most `EXPORT_HISTORY_ASSETS_MAX` names.
+    pub fn new(
+        summary: String,
+        mut assets: Vec<String>,
+        total: usize,
+        settings: &PorterSettings,
+    ) -> Self {
+        let formats: Vec<String> = settings
+            .model_file_types()
+            .into_iter()
+            .map(|format| PorterExportFormat::Model(format).name())
+            .chain([PorterExportFormat::Image(settings.image_file_type()).name()])
+            .chain(
+                settings
+                    .anim_file_types()
+                    .into_iter()
+                    .map(|format| PorterExportFormat::Animation(format).name()),
+            )
+            .chain(
+                settings
+                    .audio_file_types()
+                    .into_iter()
+                    .map(|format| PorterExportFormat::Audio(format).name()),
+            )
+            .map(String::from)
+            .collect();
+
+        assets.truncate(EXPORT_HISTORY_ASSETS_MAX);
+
+        Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs())
+                .unwrap_or_default(),
+            summary,
+            profile: settings.active_profile().map(String::from),
+            formats,
+            output_directory: settings.output_directory(),
+            total: total as u64,
+            assets,
+            completed: 0,
+            cancelled: false,
+            skipped: 0,
+        }
+    }
+
+    /// Formats how long ago the export ran.
+    pub fn elapsed(&self) -> String {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        let elapsed = now.saturating_sub(self.timestamp);
+
+        let (value, unit) = match elapsed {
+            0..=59 => return String::from("Just now"),
+            60..=3599 => (elapsed / 60, "minute"),
+            3600..=86399 => (elapsed / 3600, "hour"),
+            _ => (elapsed / 86400, "day"),
+        };
+
+        if value == 1 {
+            format!("1 {} ago", unit)
+        } else {
+            format!("{} {}s ago", value, unit)
+        }
+    }
+
+    /// Formats the result of the export.
+    pub fn result(&self) -> String {
+        let result = if self.cancelled {
+            format!(
+                "Cancelled after {} of {} assets",
+                self.completed, self.total
+            )
+        } else {
+            format!("Exported {} of {} assets", self.completed, self.total)
+        };
+
+        if self.skipped > 0 {
+            format!("{} ({} files skipped)", result, self.skipped)
+        } else {
+            result
+        }
+    }
+}
+
+/// A persisted history of export runs, newest first.
+#[derive(Debug, Decode, Encode, Clone)]
+pub struct PorterExportHistory {
+    version: u32,
+    entries: Vec<PorterExportHistoryEntry>,
+}
+
+impl Default for PorterExportHistory {
+    fn default() -> Self {
+        Self {
+            version: EXPORT_HISTORY_VERSION,
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl PorterExportHistory {
+    /// Loads the export history from the disk for the given tool, or returns an empty one.
+    pub fn load<S: Into<String>>(name: S) -> PorterExportHistory {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return Default::default();
+        };
+
+        std::fs::read(
+            project_directory
+                .config_dir()
+                .join(format!("{}_history", name.into().to_lowercase()))
+                .with_extension("dat"),
+        )
+        .map_or(Default::default(), |buffer| {
+            let config = bincode::config::standard();
+
+            bincode::decode_from_slice::<PorterExportHistory, _>(&buffer, config)
+                .ok()
+                .filter(|(history, _)| history.version == EXPORT_HISTORY_VERSION)
+                .unwrap_or_default()
+                .0
+        })
+    }
+
+    /// Saves the export history to the disk for the given tool, writing the file in the background.
+    pub fn save<S: Into<String>>(&self, name: S) {
+        let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") else {
+            return;
+        };
+
+        let config = bincode::config::standard();
+
+        let Ok(result) = bincode::encode_to_vec(self, config) else {
+            return;
+        };
+
+        let path = project_directory
+            .config_dir()
+            .join(format!("{}_history", name.into().to_lowercase()))
+            .with_extension("dat");
+
+        let save = EXPORT_HISTORY_SAVE.fetch_add(1, Ordering::Relaxed) + 1;
+
+        porter_threads::spawn_io(move || {
+            let Ok(mut written) = EXPORT_HISTORY_WRITTEN.lock() else {
+                return;
+            };
+
+            if *written > save {
+                return;
+            }
+
+            let dirs = std::fs::create_dir_all(project_directory.config_dir());
+
+            debug_assert!(dirs.is_ok());
+
+            let result = std::fs::write(path, result);
+
+            debug_assert!(result.is_ok());
+
+            *written = save;
+        });
+    }
+
+    /// Returns the export runs, newest first.
+    pub fn entries(&self) -> &[PorterExportHistoryEntry] {
+        &self.entries
+    }
+
+    /// Adds an export run, removing the oldest ones past the limit.
+    pub fn push(&mut self, entry: PorterExportHistoryEntry) {
+        self.entries.insert(0, entry);
+        self.entries.truncate(EXPORT_HISTORY_MAX);
+    }
+
+    /// Removes every export run.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
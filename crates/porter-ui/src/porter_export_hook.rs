@@ -0,0 +1,269 @@
+use std::fmt;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Stdio;
+use std::time::Duration;
+use std::time::Instant;
+
+use bincode::Decode;
+use bincode::Encode;
+
+/// How often to check if the hook process has exited.
+const HOOK_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// When the post export hook is invoked.
+#[derive(Debug, Decode, Encode, Clone, Copy, PartialEq, Eq)]
+pub enum PorterExportHookMode {
+    /// Runs the command once for every exported file.
+    PerFile,
+    /// Runs the command once after the export finishes, with every exported file.
+    PerBatch,
+}
+
+/// Errors that occur when running the post export hook.
+#[derive(Debug)]
+pub enum PorterExportHookError {
+    /// The command template was empty or had unbalanced quotes.
+    InvalidCommand,
+    /// The command could not be started.
+    SpawnFailed(String, io::Error),
+    /// The command did not exit before the timeout and was killed.
+    TimedOut(String),
+    /// The command exited with a non-zero status and the captured error output.
+    Failed(String, Option<i32>, String),
+}
+
+impl fmt::Display for PorterExportHookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCommand => write!(f, "The post export command is invalid"),
+            Self::SpawnFailed(program, error) => {
+                write!(f, "Failed to run '{}': {}", program, error)
+            }
+            Self::TimedOut(program) => write!(f, "'{}' timed out and was killed", program),
+            Self::Failed(program, Some(code), output) => {
+                write!(f, "'{}' exited with code {}: {}", program, code, output)
+            }
+            Self::Failed(program, None, output) => {
+                write!(f, "'{}' was terminated: {}", program, output)
+            }
+        }
+    }
+}
+
+/// A command invoked after assets are exported, with placeholders for the exported paths and types.
+///
+/// Supported placeholders are `{path}`, `{dir}`, `{name}`, `{ext}`, and `{type}` per file, `{files}` and `{count}` per batch, and `{output}` for both.
+#[derive(Debug, Clone)]
+pub struct PorterExportHook {
+    command: String,
+    mode: PorterExportHookMode,
+    timeout: Duration,
+    output_directory: PathBuf,
+}
+
+impl PorterExportHook {
+    /// Constructs a new post export hook for the given command template and output directory.
+    pub fn new<C: Into<String>>(command: C, output_directory: PathBuf) -> Self {
+        Self {
+            command: command.into(),
+            mode: PorterExportHookMode::PerFile,
+            timeout: Duration::from_secs(60),
+            output_directory,
+        }
+    }
+
+    /// Sets when the hook is invoked.
+    pub fn mode(mut self, mode: PorterExportHookMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets the maximum time a single invocation can take before it's killed.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether or not the hook runs once for every exported file.
+    pub fn is_per_file(&self) -> bool {
+        self.mode == PorterExportHookMode::PerFile
+    }
+
+    /// Runs the hook for a single exported file.
+    pub fn run_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        asset_type: &str,
+    ) -> Result<(), PorterExportHookError> {
+        let path = path.as_ref();
+
+        let arguments = self.arguments(|placeholder| match placeholder {
+            "path" => Some(vec![path.to_string_lossy().to_string()]),
+            "dir" => Some(vec![path
+                .parent()
+                .map(|parent| parent.to_string_lossy().to_string())
+                .unwrap_or_default()]),
+            "name" => Some(vec![path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default()]),
+            "ext" => Some(vec![path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_string())
+                .unwrap_or_default()]),
+            "type" => Some(vec![asset_type.to_owned()]),
+            _ => None,
+        })?;
+
+        self.execute(arguments)
+    }
+
+    /// Runs the hook once for a batch of exported files.
+    pub fn run_batch(&self, files: &[PathBuf]) -> Result<(), PorterExportHookError> {
+        let arguments = self.arguments(|placeholder| match placeholder {
+            "files" => Some(
+                files
+                    .iter()
+                    .map(|file| file.to_string_lossy().to_string())
+                    .collect(),
+            ),
+            "count" => Some(vec![files.len().to_string()]),
+            _ => None,
+        })?;
+
+        self.execute(arguments)
+    }
+
+    /// Splits the command template into arguments, substituting placeholders.
+    fn arguments<F: Fn(&str) -> Option<Vec<String>>>(
+        &self,
+        substitute: F,
+    ) -> Result<Vec<String>, PorterExportHookError> {
+        let output = self.output_directory.to_string_lossy().to_string();
+        let mut arguments = Vec::new();
+
+        for token in tokenize(&self.command)? {
+            // A placeholder on its own can expand to multiple arguments, such as '{files}'.
+            if let Some(placeholder) = token.strip_prefix('{').and_then(|x| x.strip_suffix('}')) {
+                if let Some(values) = substitute(placeholder) {
+                    arguments.extend(values);
+                    continue;
+                }
+            }
+
+            let mut argument = token.replace("{output}", &output);
+
+            for placeholder in ["path", "dir", "name", "ext", "type", "files", "count"] {
+                let pattern = format!("{{{}}}", placeholder);
+
+                if argument.contains(&pattern) {
+                    let values = substitute(placeholder).unwrap_or_default();
+
+                    argument = argument.replace(&pattern, &values.join(" "));
+                }
+            }
+
+            arguments.push(argument);
+        }
+
+        if arguments.is_empty() {
+            return Err(PorterExportHookError::InvalidCommand);
+        }
+
+        Ok(arguments)
+    }
+
+    /// Runs the command, waiting up to the timeout and capturing the error output.
+    fn execute(&self, arguments: Vec<String>) -> Result<(), PorterExportHookError> {
+        let program = arguments[0].clone();
+
+        let mut child = Command::new(&program)
+            .args(&arguments[1..])
+            .current_dir(&self.output_directory)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|error| PorterExportHookError::SpawnFailed(program.clone(), error))?;
+
+        // Read the error output on another thread so a full pipe can't stall the process.
+        let stderr = child.stderr.take().map(|mut stderr| {
+            std::thread::spawn(move || {
+                let mut output = String::new();
+                let _ = stderr.read_to_string(&mut output);
+                output
+            })
+        });
+
+        let start = Instant::now();
+
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break Some(status),
+                Ok(None) if start.elapsed() >= self.timeout => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+
+                    break None;
+                }
+                Ok(None) => std::thread::sleep(HOOK_POLL_INTERVAL),
+                Err(error) => return Err(PorterExportHookError::SpawnFailed(program, error)),
+            }
+        };
+
+        let output = stderr
+            .and_then(|stderr| stderr.join().ok())
+            .unwrap_or_default();
+
+        match status {
+            Some(status) if status.success() => Ok(()),
+            Some(status) => Err(PorterExportHookError::Failed(
+                program,
+                status.code(),
+                output.trim().to_owned(),
+            )),
+            None => Err(PorterExportHookError::TimedOut(program)),
+        }
+    }
+}
+
+/// Splits a command into tokens by whitespace, keeping double quoted text together.
+fn tokenize(command: &str) -> Result<Vec<String>, PorterExportHookError> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut quoted = false;
+    let mut has_token = false;
+
+    for character in command.chars() {
+        match character {
+            '"' => {
+                quoted = !quoted;
+                has_token = true;
+            }
+            character if character.is_whitespace() && !quoted => {
+                if has_token {
+                    tokens.push(std::mem::take(&mut token));
+                    has_token = false;
+                }
+            }
+            character => {
+                token.push(character);
+                has_token = true;
+            }
+        }
+    }
+
+    if quoted {
+        return Err(PorterExportHookError::InvalidCommand);
+    }
+
+    if has_token {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
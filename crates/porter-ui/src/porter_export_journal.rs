@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use directories::ProjectDirs;
+
+/// The prefix of the line recording what the export was loaded from.
+const JOURNAL_SOURCE: &str = "#source ";
+/// The prefix of the line recording the fingerprint of the export settings.
+const JOURNAL_SETTINGS: &str = "#settings ";
+/// The prefix of a line recording an asset queued for export.
+const JOURNAL_QUEUED: char = '+';
+/// The prefix of a line recording an asset that finished exporting.
+const JOURNAL_COMPLETED: char = '-';
+
+/// Identifies an asset in the journal, by its row index and hash, since names aren't unique.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PorterExportJournalAsset {
+    pub index: usize,
+    pub hash: Option<String>,
+}
+
+impl PorterExportJournalAsset {
+    /// Formats the asset as a single journal line, without the prefix.
+    fn to_line(&self) -> String {
+        match &self.hash {
+            Some(hash) => format!("{} {}", self.index, hash.replace(['\r', '\n'], " ")),
+            None => self.index.to_string(),
+        }
+    }
+
+    /// Parses an asset from a journal line, without the prefix.
+    fn from_line(line: &str) -> Option<Self> {
+        let (index, hash) = match line.split_once(' ') {
+            Some((index, hash)) => (index, Some(String::from(hash))),
+            None => (line, None),
+        };
+
+        Some(Self {
+            index: index.parse().ok()?,
+            hash,
+        })
+    }
+}
+
+/// An append only journal of the active export, used to resume the remaining assets after a crash.
+#[derive(Debug)]
+pub struct PorterExportJournal {
+    path: Option<PathBuf>,
+    file: Option<File>,
+}
+
+impl PorterExportJournal {
+    /// Constructs a new journal for the given tool.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        let path = ProjectDirs::from("com", "DTZxPorter", "GameTools").map(|project_directory| {
+            project_directory
+                .config_dir()
+                .join(format!("{}_journal", name.into().to_lowercase()))
+                .with_extension("txt")
+        });
+
+        Self { path, file: None }
+    }
+
+    /// Starts a new journal with the source, settings fingerprint, and assets being exported.
+    pub fn begin(&mut self, source: &str, fingerprint: u64, assets: &[PorterExportJournalAsset]) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        if let Some(parent) = path.parent() {
+            let dirs = std::fs::create_dir_all(parent);
+
+            debug_assert!(dirs.is_ok());
+        }
+
+        self.file = File::create(path).ok();
+
+        self.write(format!(
+            "{}{}\n{}{}\n",
+            JOURNAL_SOURCE,
+            source.replace(['\r', '\n'], " "),
+            JOURNAL_SETTINGS,
+            fingerprint
+        ));
+        self.append(JOURNAL_QUEUED, assets);
+    }
+
+    /// Records the assets that finished exporting.
+    pub fn complete(&mut self, assets: &[PorterExportJournalAsset]) {
+        self.append(JOURNAL_COMPLETED, assets);
+    }
+
+    /// Removes the journal once the export finished, or the remaining assets were discarded.
+    pub fn finish(&mut self) {
+        self.file = None;
+
+        if let Some(path) = &self.path {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    /// Returns the assets that didn't finish exporting in the previous journal, in export order.
+    ///
+    /// Journals from another source, or exported with other settings, are ignored until they match.
+    pub fn remaining(&self, source: &str, fingerprint: u64) -> Vec<PorterExportJournalAsset> {
+        let Some(journal) = self
+            .path
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+        else {
+            return Vec::new();
+        };
+
+        let mut lines = journal.lines();
+
+        let journal_source = lines
+            .next()
+            .and_then(|line| line.strip_prefix(JOURNAL_SOURCE));
+        let journal_fingerprint = lines
+            .next()
+            .and_then(|line| line.strip_prefix(JOURNAL_SETTINGS))
+            .and_then(|line| line.parse::<u64>().ok());
+
+        if journal_source != Some(source.replace(['\r', '\n'], " ").as_str())
+            || journal_fingerprint != Some(fingerprint)
+        {
+            return Vec::new();
+        }
+
+        let completed: HashSet<PorterExportJournalAsset> = lines
+            .clone()
+            .filter_map(|line| line.strip_prefix(JOURNAL_COMPLETED))
+            .filter_map(PorterExportJournalAsset::from_line)
+            .collect();
+
+        lines
+            .filter_map(|line| line.strip_prefix(JOURNAL_QUEUED))
+            .filter_map(PorterExportJournalAsset::from_line)
+            .filter(|asset| !completed.contains(asset))
+            .collect()
+    }
+
+    /// Appends one line per asset.
+    fn append(&mut self, prefix: char, assets: &[PorterExportJournalAsset]) {
+        let mut buffer = String::new();
+
+        for asset in assets {
+            buffer.push(prefix);
+            buffer.push_str(&asset.to_line());
+            buffer.push('\n');
+        }
+
+        self.write(buffer);
+    }
+
+    /// Writes to the journal, flushing so the journal survives the process being killed.
+    fn write(&mut self, buffer: String) {
+        let Some(file) = &mut self.file else {
+            return;
+        };
+
+        let result = file.write_all(buffer.as_bytes()).and_then(|_| file.flush());
+
+        debug_assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,235 @@
+use porter_animation::AnimationFileType;
+use porter_audio::AudioCodec;
+use porter_audio::AudioFileType;
+use porter_audio::FFMPEG_ENABLED;
+use porter_model::ModelFileType;
+use porter_texture::ImageFileType;
+use porter_utils::CompressionMethod;
+
+/// A single entry in the format support matrix.
+#[derive(Debug, Clone)]
+pub struct PorterFormatSupport {
+    pub category: &'static str,
+    pub name: &'static str,
+    pub read: bool,
+    pub write: bool,
+}
+
+impl PorterFormatSupport {
+    /// Constructs a new format support entry.
+    const fn new(category: &'static str, name: &'static str, read: bool, write: bool) -> Self {
+        Self {
+            category,
+            name,
+            read,
+            write,
+        }
+    }
+
+    /// A short description of the supported operations.
+    pub const fn capability(&self) -> &'static str {
+        match (self.read, self.write) {
+            (true, true) => "Read/Write",
+            (true, false) => "Read",
+            (false, true) => "Write",
+            (false, false) => "Disabled",
+        }
+    }
+}
+
+/// A single export format, used to export assets to a format other than the configured ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PorterExportFormat {
+    Image(ImageFileType),
+    Model(ModelFileType),
+    Animation(AnimationFileType),
+    Audio(AudioFileType),
+}
+
+impl PorterExportFormat {
+    /// The display name of the format.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Image(ImageFileType::Dds) => "DDS",
+            Self::Image(ImageFileType::Png) => "PNG",
+            Self::Image(ImageFileType::Tiff) => "TIFF",
+            Self::Image(ImageFileType::Tga) => "TGA",
+            Self::Image(ImageFileType::Ktx) => "KTX",
+            Self::Image(ImageFileType::Xpr) => "XPR",
+            Self::Image(ImageFileType::Gtf) => "GTF",
+            Self::Model(ModelFileType::Obj) => "OBJ",
+            Self::Model(ModelFileType::Smd) => "Valve SMD",
+            Self::Model(ModelFileType::XnaLara) => "XNALara",
+            Self::Model(ModelFileType::XModelExport) => "XModel Export",
+            Self::Model(ModelFileType::Cast) => "Cast",
+            Self::Model(ModelFileType::Maya) => "Autodesk Maya",
+            Self::Model(ModelFileType::Fbx) => "Autodesk FBX",
+            Self::Model(ModelFileType::FbxAscii) => "Autodesk FBX (ASCII)",
+            Self::Animation(AnimationFileType::SEAnim) => "SEAnim",
+            Self::Animation(AnimationFileType::Cast) => "Cast",
+            Self::Animation(AnimationFileType::Fbx) => "Autodesk FBX",
+            Self::Animation(AnimationFileType::Smd) => "Valve SMD",
+            Self::Audio(AudioFileType::Wav) => "WAV",
+            Self::Audio(AudioFileType::Flac) => "FLAC",
+        }
+    }
+
+    /// Every format assets can be exported to, SEAnim export has been removed.
+    pub fn all() -> Vec<Self> {
+        let mut result = Vec::new();
+
+        result.extend(
+            ImageFileType::ALL
+                .into_iter()
+                .filter(|file_type| !file_type.is_read_only())
+                .map(Self::Image),
+        );
+        result.extend(ModelFileType::ALL.into_iter().map(Self::Model));
+        result.extend(
+            AnimationFileType::ALL
+                .into_iter()
+                .filter(|file_type| *file_type != AnimationFileType::SEAnim)
+                .map(Self::Animation),
+        );
+        result.extend(AudioFileType::ALL.into_iter().map(Self::Audio));
+
+        result
+    }
+
+    /// The formats relevant to the given asset type, or every format when the type is unknown.
+    pub fn for_asset_type(asset_type: Option<&str>) -> Vec<Self> {
+        let asset_type = asset_type.unwrap_or_default().to_lowercase();
+
+        let filter: fn(&Self) -> bool = if asset_type.contains("model") {
+            |format| matches!(format, Self::Model(_))
+        } else if asset_type.contains("image")
+            || asset_type.contains("texture")
+            || asset_type.contains("material")
+        {
+            |format| matches!(format, Self::Image(_))
+        } else if asset_type.contains("anim") {
+            |format| matches!(format, Self::Animation(_))
+        } else if asset_type.contains("sound") || asset_type.contains("audio") {
+            |format| matches!(format, Self::Audio(_))
+        } else {
+            |_| true
+        };
+
+        Self::all().into_iter().filter(filter).collect()
+    }
+}
+
+/// Enumerates every supported format in this build, formats are writable when assets can be exported to them.
+pub fn format_support() -> Vec<PorterFormatSupport> {
+    let mut result = Vec::new();
+
+    let exports = PorterExportFormat::all();
+
+    for file_type in ImageFileType::ALL {
+        let format = PorterExportFormat::Image(file_type);
+
+        result.push(PorterFormatSupport::new(
+            "Images",
+            format.name(),
+            true,
+            exports.contains(&format),
+        ));
+    }
+
+    for file_type in ModelFileType::ALL {
+        let format = PorterExportFormat::Model(file_type);
+
+        result.push(PorterFormatSupport::new(
+            "Models",
+            format.name(),
+            !file_type.is_write_only(),
+            exports.contains(&format),
+        ));
+    }
+
+    for file_type in AnimationFileType::ALL {
+        let format = PorterExportFormat::Animation(file_type);
+
+        result.push(PorterFormatSupport::new(
+            "Animations",
+            format.name(),
+            !file_type.is_write_only(),
+            exports.contains(&format),
+        ));
+    }
+
+    for file_type in AudioFileType::ALL {
+        let format = PorterExportFormat::Audio(file_type);
+
+        result.push(PorterFormatSupport::new(
+            "Sounds",
+            format.name(),
+            !file_type.is_write_only(),
+            exports.contains(&format),
+        ));
+    }
+
+    for codec in AudioCodec::ALL {
+        let name = match codec {
+            AudioCodec::ImaAdpcm => "IMA ADPCM",
+            AudioCodec::XboxAdpcm => "Xbox ADPCM",
+            AudioCodec::PsAdpcm => "PS-ADPCM",
+        };
+
+        result.push(PorterFormatSupport::new(
+            "Audio Codecs",
+            name,
+            codec.is_enabled(),
+            false,
+        ));
+    }
+
+    result.push(PorterFormatSupport::new(
+        "Audio Codecs",
+        "FFmpeg",
+        FFMPEG_ENABLED,
+        false,
+    ));
+
+    for method in CompressionMethod::ALL {
+        let name = match method {
+            CompressionMethod::Lz4 => "LZ4",
+            CompressionMethod::Lz4Frame => "LZ4 Frame",
+            CompressionMethod::Zstd => "Zstandard",
+            CompressionMethod::Zlib => "Zlib",
+            CompressionMethod::Deflate => "Deflate",
+            CompressionMethod::Oodle => "Oodle",
+        };
+
+        result.push(PorterFormatSupport::new(
+            "Compression",
+            name,
+            method.is_enabled(),
+            false,
+        ));
+    }
+
+    result
+}
+
+/// Formats the format support matrix grouped by category, one category per line.
+pub fn format_support_summary() -> Vec<String> {
+    let mut result: Vec<(&'static str, Vec<String>)> = Vec::new();
+
+    for support in format_support() {
+        let entry = format!("{} ({})", support.name, support.capability());
+
+        match result
+            .iter_mut()
+            .find(|(category, _)| *category == support.category)
+        {
+            Some((_, entries)) => entries.push(entry),
+            None => result.push((support.category, vec![entry])),
+        }
+    }
+
+    result
+        .into_iter()
+        .map(|(category, entries)| format!("{}: {}", category, entries.join(", ")))
+        .collect()
+}
@@ -1,4 +1,5 @@
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::ops::Add;
 use std::ops::Range;
 use std::path::PathBuf;
@@ -18,16 +19,21 @@ use iced::keyboard::Modifiers;
 
 use iced::widget::button;
 use iced::widget::canvas;
+use iced::widget::checkbox;
 use iced::widget::column;
 use iced::widget::container;
 use iced::widget::image;
 use iced::widget::mouse_area;
+use iced::widget::pick_list;
 use iced::widget::progress_bar;
 use iced::widget::row;
 use iced::widget::scrollable;
+use iced::widget::slider;
 use iced::widget::text;
 use iced::widget::text_input;
+use iced::widget::tooltip;
 use iced::widget::vertical_space;
+use iced::widget::Space;
 
 use iced::multi_window::Application;
 use iced::Alignment;
@@ -41,35 +47,68 @@ use iced::Rectangle;
 use iced::Size;
 use iced::Theme;
 
+use porter_audio::Audio;
+use porter_audio::AudioWaveform;
+
+use porter_console::JsonOutput;
+use porter_console::PorterLogger;
+
 use porter_preview::PreviewRenderer;
+use porter_preview::PreviewZoom;
+
+use porter_threads::TaskScope;
 
+use porter_utils::AsHumanDuration;
 use porter_utils::OptionExt;
 use porter_utils::StringCaseExt;
 
+use crate::apply_column_layout;
+use crate::format_audio_frame_time;
+use crate::format_audio_time;
+use crate::format_count;
+use crate::porter_context_menu;
 use crate::porter_overlay;
 use crate::porter_spinner;
 use crate::porter_splash_settings;
+use crate::porter_text_width;
+use crate::read_crash_report;
+use crate::session_title;
+use crate::ColumnTruncation;
 use crate::ImageNormalMapProcessing;
 use crate::PorterAssetManager;
+use crate::PorterAudioWaveform;
 use crate::PorterBackgroundStyle;
+use crate::PorterBoneLabels;
 use crate::PorterButtonStyle;
 use crate::PorterColumnHeader;
+use crate::PorterContextMenuButtonStyle;
+use crate::PorterContextMenuStyle;
 use crate::PorterDivider;
 use crate::PorterDividerStyle;
 use crate::PorterExecutor;
+use crate::PorterExportDatabase;
+use crate::PorterExportFormat;
+use crate::PorterExportHistory;
+use crate::PorterExportHistoryEntry;
+use crate::PorterExportJournal;
+use crate::PorterExportJournalAsset;
 use crate::PorterHeaderBackgroundStyle;
 use crate::PorterLabelStyle;
 use crate::PorterLinkStyle;
 use crate::PorterMainBuilder;
 use crate::PorterMainColumn;
 use crate::PorterOverlayBackgroundStyle;
+use crate::PorterPickListStyle;
 use crate::PorterPreviewAsset;
 use crate::PorterPreviewButtonStyle;
 use crate::PorterPreviewStyle;
 use crate::PorterProgressStyle;
 use crate::PorterRowStyle;
 use crate::PorterScrollStyle;
+use crate::PorterSession;
+use crate::PorterSessionFactory;
 use crate::PorterSettings;
+use crate::PorterSliderStyle;
 use crate::PorterSpinnerStyle;
 use crate::PorterSplash;
 use crate::PorterSplashBackgroundStyle;
@@ -78,8 +117,11 @@ use crate::PorterSwitchButtonBackgroundStyle;
 use crate::PorterSwitchButtonStyle;
 use crate::PorterText;
 use crate::PorterTextInputStyle;
+use crate::PorterThumbnailCache;
 use crate::PorterTitleFont;
 use crate::PorterViewport;
+use crate::PorterWindowState;
+use crate::MAIN_WINDOW_MIN_SIZE;
 use crate::PORTER_COPYRIGHT;
 use crate::PORTER_DISCLAIMER;
 use crate::PORTER_SITE_URL;
@@ -97,22 +139,75 @@ pub const COLUMN_MIN: f32 = 50.0;
 /// The maximum width of a column.
 pub const COLUMN_MAX: f32 = 1000.0;
 
+/// The smallest width of the preview pane.
+pub const PREVIEW_WIDTH_MIN: f32 = 250.0;
+/// The smallest width of the asset list when the preview pane is resized.
+pub const LIST_WIDTH_MIN: f32 = 400.0;
+
 /// The maximum number of assets before search isn't realtime.
 pub const SEARCH_REALTIME_MAX: usize = 250000;
 
+/// The default size of text.
+pub const TEXT_SIZE: f32 = if cfg!(target_os = "windows") {
+    14.0
+} else {
+    16.0
+};
+
+/// The size of a thumbnail in the asset list.
+pub const THUMBNAIL_SIZE: f32 = 24.0;
+/// The maximum number of thumbnails kept in memory.
+pub const THUMBNAIL_CACHE_SIZE: usize = 1024;
+/// The number of threads used to load thumbnails, which overlap reading and decoding with sending to the ui.
+pub const THUMBNAIL_THREADS: usize = 4;
+
+/// The color of a column header while it's being dragged.
+pub const COLUMN_DRAG_COLOR: Color = Color::from_rgb(0.153, 0.608, 0.831);
+
+/// The background color of search matches in the name column.
+pub const SEARCH_HIGHLIGHT_COLOR: Color = Color {
+    r: 0.88,
+    g: 0.63,
+    b: 0.13,
+    a: 0.45,
+};
+
 /// Time in which a double click is registered.
 pub const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(250);
 
+/// The maximum number of characters of the crash report shown in the dialog.
+pub const CRASH_REPORT_PREVIEW_MAX: usize = 4000;
+
+/// The number of frames rendered for a turntable capture.
+pub const TURNTABLE_FRAMES: u32 = 36;
+
 /// A list of preview controls to render over the previewer.
 pub const PREVIEW_CONTROLS: &[(&str, &str)] = &[
     ("Toggle Bones:", "[B]"),
-    ("Toggle Wireframe:", "[W]"),
-    ("Toggle Shaded:", "[M]"),
+    ("Bone Names:", "[L]"),
+    ("Skeleton Only:", "[K]"),
+    ("Cycle Wireframe:", "[W]"),
+    ("Cycle Shading:", "[M]"),
     ("Toggle Grid:", "[G]"),
     ("Reset View:", "[R]"),
     ("Cycle Image:", "[N]"),
+    ("Material Sphere:", "[O]"),
+    ("Cycle Slice:", "[[] []]"),
+    ("Cubemap Cross:", "[X]"),
+    ("Zoom 1:1/Fit/Fill:", "[1] [2] [3]"),
+    ("Front/Right/Top:", "[1] [3] [7] [Ctrl] Opposite"),
+    ("Perspective/Ortho:", "[5]"),
+    ("Fly Camera:", "[F] [WASD] [Q] [E] [RMB] Look"),
+    ("Always On Top:", "[T]"),
+    ("Pixel Inspector:", "[I]"),
+    ("Pan Image:", "[LMB] Drag"),
+    ("Screenshot:", "[F12]"),
+    ("Turntable:", "[Shift] [F12]"),
 ];
 
+/// The number of peaks computed for the audio preview waveform.
+pub const AUDIO_WAVEFORM_PEAKS: usize = 4096;
+
 /// Main window of the porter ui application.
 pub struct PorterMain {
     pub(crate) name: &'static str,
@@ -120,10 +215,16 @@ pub struct PorterMain {
     pub(crate) description: &'static str,
     pub(crate) item_range: Range<usize>,
     pub(crate) item_selection: BTreeSet<usize>,
+    pub(crate) selection_status: Option<String>,
+    pub(crate) selection_status_id: u64,
+    pub(crate) item_cursor: Option<usize>,
+    pub(crate) item_anchor: Option<usize>,
     pub(crate) asset_manager: Arc<dyn PorterAssetManager>,
     pub(crate) file_filters: Vec<(String, Vec<String>)>,
     pub(crate) multi_file: bool,
     pub(crate) preview_enabled: bool,
+    pub(crate) thumbnails_enabled: bool,
+    pub(crate) thumbnails: PorterThumbnailCache,
     pub(crate) animations_enabled: bool,
     pub(crate) materials_enabled: bool,
     pub(crate) sounds_enabled: bool,
@@ -133,14 +234,25 @@ pub struct PorterMain {
     pub(crate) normal_map_converter: bool,
     pub(crate) row_press: Option<usize>,
     pub(crate) row_press_last: Instant,
+    pub(crate) context_menu: Option<(usize, Point)>,
+    pub(crate) context_menu_formats: bool,
+    pub(crate) column_menu: Option<(Option<usize>, Point)>,
+    pub(crate) column_press: Option<usize>,
     pub(crate) loading: bool,
+    pub(crate) loading_published: usize,
     pub(crate) exporting: bool,
     pub(crate) show_settings: bool,
     pub(crate) show_about: bool,
+    pub(crate) show_history: bool,
     pub(crate) export_progress: u32,
+    pub(crate) export_completed: usize,
+    pub(crate) export_total: usize,
+    pub(crate) export_skipped: usize,
+    pub(crate) export_remaining: Option<Duration>,
     pub(crate) keyboard_modifiers: Modifiers,
     pub(crate) search_id: text_input::Id,
     pub(crate) search_value: String,
+    pub(crate) search_terms: Vec<String>,
     pub(crate) scroll_id: scrollable::Id,
     pub(crate) scroll_header_id: scrollable::Id,
     pub(crate) scroll_container_id: container::Id,
@@ -150,17 +262,44 @@ pub struct PorterMain {
     pub(crate) previewer_container_id: container::Id,
     pub(crate) preview_viewport_size: Rectangle,
     pub(crate) preview_request_id: u64,
+    pub(crate) preview_always_on_top: bool,
+    pub(crate) preview_audio: Option<(String, Audio)>,
+    pub(crate) preview_audio_waveform: Option<AudioWaveform>,
+    pub(crate) preview_audio_position: f32,
+    pub(crate) preview_audio_loop: bool,
+    pub(crate) window_state: PorterWindowState,
+    pub(crate) window_size: Size,
+    pub(crate) window_restore_size: Size,
+    pub(crate) window_position: Option<Point>,
     pub(crate) mouse_position: Point,
     pub(crate) mouse_button: Option<iced::mouse::Button>,
     pub(crate) columns: Vec<PorterMainColumn>,
     pub(crate) channel: Option<UnboundedSender<Message>>,
     pub(crate) last_load: Option<Vec<PathBuf>>,
+    pub(crate) game_process: Option<u64>,
+    pub(crate) game_exited: bool,
+    pub(crate) session_factory: Option<PorterSessionFactory>,
+    pub(crate) sessions: Vec<Option<PorterSession>>,
+    pub(crate) session_active: usize,
     pub(crate) file_dropped: Vec<PathBuf>,
     pub(crate) reload_required: bool,
     pub(crate) settings: PorterSettings,
+    pub(crate) gpu_adapters: Vec<String>,
+    pub(crate) profile_name: String,
     pub(crate) splash_id: Option<iced::window::Id>,
     pub(crate) splash_animation: f32,
+    pub(crate) crash_report_available: bool,
     pub(crate) export_cancel: bool,
+    pub(crate) export_scope: TaskScope,
+    pub(crate) dry_run: bool,
+    pub(crate) sample_count: u32,
+    pub(crate) sample_report: Option<String>,
+    pub(crate) export_history: PorterExportHistory,
+    pub(crate) export_history_pending: Option<PorterExportHistoryEntry>,
+    pub(crate) export_database: PorterExportDatabase,
+    pub(crate) export_database_pending: Option<u64>,
+    pub(crate) export_journal: PorterExportJournal,
+    pub(crate) export_journal_assets: HashMap<usize, PorterExportJournalAsset>,
 }
 
 /// Messages for the porter ui application.
@@ -172,17 +311,41 @@ pub enum Message {
     ScrollResize(Option<Rectangle>),
     Preview(Option<PorterPreviewAsset>, u64),
     PreviewResize(Option<Rectangle>),
+    PreviewDrag(f32),
+    PreviewDragEnd,
+    WindowMaximized(bool),
     ClosePreview,
+    PreviewZoom(PreviewZoom),
+    PreviewSlice(usize),
+    PreviewFly,
+    PreviewAudioWaveform(u64, AudioWaveform),
+    PreviewAudioSeek(f32),
+    TogglePreviewAudioLoop,
+    TogglePreviewAlwaysOnTop,
     CloseSplash(()),
     UpdateSplash(f32),
     Sync(bool, u32),
+    SyncProgress(bool, u32, usize, usize, Option<Duration>),
     RowPress(usize),
     RowRelease(usize),
+    RowContextMenu(usize),
+    CloseContextMenu,
+    ContextMenuExportAs(bool),
+    ExportAs(PorterExportFormat),
+    CopyAssetName(usize),
+    CopyAssetHash(usize),
+    PreviewAsset(usize),
+    ShowExportFolder,
     LoadFile,
     LoadFileDropped,
     LoadFiles(Vec<PathBuf>),
     LoadGame,
+    LoadPublished(usize),
     LoadResult(Result<(), String>),
+    GameExited(u64),
+    NewSession,
+    SelectSession(usize),
+    CloseSession(usize),
     SearchInput(String),
     SearchClear,
     SearchSubmit,
@@ -191,15 +354,44 @@ pub enum Message {
     Website,
     ToggleAbout,
     ToggleSettings,
+    ToggleHistory,
+    OpenHistoryFolder(usize),
+    ExportHistory(usize),
+    ClearHistory,
+    ClearExportDatabase,
     ExportSelected,
     ExportAll,
+    ExportSpaceWarning(Vec<usize>, PorterSettings, u64, u64),
+    ExportForced(Vec<usize>, PorterSettings),
+    ExportSample,
+    SampleCount(u32),
     SaveSettings(PorterSettings),
+    ProfileNameInput(String),
+    ToggleDryRun(bool),
     OpenConfigFolder,
+    CreateSupportBundle,
+    ExportHookFailed(Vec<String>),
+    ExportValidationFailed(Vec<String>),
+    ExportSkipped(usize),
+    ExportCompleted(Vec<usize>),
+    ExportResume(Vec<usize>),
+    ExportResumeDiscard,
+    ViewCrashReport,
+    CopyCrashReport(String),
+    SaveSupportBundle(PathBuf),
     PickExportFolder,
     OpenExportFolder,
     SaveExportFolder(PathBuf),
     ColumnDrag(usize, f32),
     ColumnDragEnd(usize),
+    ColumnPress(usize),
+    ColumnRelease(usize),
+    ColumnContextMenu(Option<usize>),
+    ToggleColumnTruncation(usize),
+    ToggleColumn(usize),
+    ResetColumns,
+    Thumbnail(u64, usize, Option<image::Handle>),
+    SelectionStatus(u64, String),
     Noop,
 }
 
@@ -236,6 +428,31 @@ impl Application for PorterMain {
             settings.set_image_normal_map_processing(ImageNormalMapProcessing::None);
         }
 
+        // Third party crates only log warnings, so the log stays focused on the tool itself.
+        PorterLogger::new(flags.name)
+            .target_level("porter", settings.log_level())
+            .console(cfg!(debug_assertions))
+            .install();
+
+        if let Some(output) = JsonOutput::from_args() {
+            if let Err(error) = porter_console::set_json_output(Some(output)) {
+                log::warn!("Failed to enable json output: {}", error);
+            }
+        }
+
+        log::info!("{} v{} started", flags.name, flags.version);
+
+        porter_gpu::set_gpu_adapter(settings.gpu_adapter());
+
+        let mut gpu_adapters: Vec<String> = porter_gpu::gpu_adapters()
+            .into_iter()
+            .map(|adapter| adapter.name)
+            .collect();
+
+        gpu_adapters.dedup();
+
+        let window_size = settings.window_state().size(MAIN_WINDOW_MIN_SIZE);
+
         let (splash_id, splash_command) = iced::window::spawn(porter_splash_settings());
 
         (
@@ -245,10 +462,16 @@ impl Application for PorterMain {
                 description: flags.description,
                 item_range: 0..0,
                 item_selection: BTreeSet::new(),
+                selection_status: None,
+                selection_status_id: 0,
+                item_cursor: None,
+                item_anchor: None,
                 asset_manager: flags.asset_manager,
                 file_filters: flags.file_filters,
                 multi_file: flags.multi_file,
                 preview_enabled: flags.preview,
+                thumbnails_enabled: flags.thumbnails,
+                thumbnails: PorterThumbnailCache::new(THUMBNAIL_CACHE_SIZE),
                 animations_enabled: flags.animations_enabled,
                 materials_enabled: flags.materials_enabled,
                 sounds_enabled: flags.sounds_enabled,
@@ -258,14 +481,25 @@ impl Application for PorterMain {
                 normal_map_converter: flags.normal_map_converter,
                 row_press: None,
                 row_press_last: Instant::now(),
+                context_menu: None,
+                context_menu_formats: false,
+                column_menu: None,
+                column_press: None,
                 loading: false,
+                loading_published: 0,
                 exporting: false,
                 show_settings: false,
                 show_about: false,
+                show_history: false,
                 export_progress: 0,
+                export_completed: 0,
+                export_total: 0,
+                export_skipped: 0,
+                export_remaining: None,
                 keyboard_modifiers: Modifiers::empty(),
                 search_id: text_input::Id::unique(),
                 search_value: String::new(),
+                search_terms: Vec::new(),
                 scroll_id: scrollable::Id::unique(),
                 scroll_header_id: scrollable::Id::unique(),
                 scroll_container_id: container::Id::unique(),
@@ -275,17 +509,47 @@ impl Application for PorterMain {
                 previewer_container_id: container::Id::unique(),
                 preview_viewport_size: Rectangle::with_size(Size::ZERO),
                 preview_request_id: 0,
+                preview_always_on_top: false,
+                preview_audio: None,
+                preview_audio_waveform: None,
+                preview_audio_position: 0.0,
+                preview_audio_loop: true,
+                window_state: settings.window_state(),
+                window_size: Size::new(
+                    window_size.width * 100.0 / settings.ui_scale() as f32,
+                    window_size.height * 100.0 / settings.ui_scale() as f32,
+                ),
+                window_restore_size: window_size,
+                window_position: None,
                 mouse_position: Point::ORIGIN,
                 mouse_button: None,
-                columns: flags.columns,
+                columns: apply_column_layout(flags.columns, settings.column_layout()),
                 channel: None,
                 last_load: None,
+                game_process: None,
+                game_exited: false,
+                session_factory: flags.session_factory,
+                sessions: vec![None],
+                session_active: 0,
                 file_dropped: Vec::new(),
                 reload_required: false,
                 settings,
+                gpu_adapters,
+                profile_name: String::new(),
                 splash_id: Some(splash_id),
                 splash_animation: 0.0,
+                crash_report_available: read_crash_report(flags.name, true).is_some(),
                 export_cancel: false,
+                export_scope: TaskScope::default(),
+                dry_run: std::env::args().any(|arg| arg == "--dry-run"),
+                sample_count: 5,
+                sample_report: None,
+                export_history: PorterExportHistory::load(flags.name),
+                export_history_pending: None,
+                export_database: PorterExportDatabase::load(flags.name),
+                export_database_pending: None,
+                export_journal: PorterExportJournal::new(flags.name),
+                export_journal_assets: HashMap::new(),
             },
             splash_command,
         )
@@ -295,25 +559,57 @@ impl Application for PorterMain {
         format!("{} v{}", self.name.to_titlecase(), self.version)
     }
 
+    fn scale_factor(&self, _: iced::window::Id) -> f64 {
+        self.settings.ui_scale() as f64 / 100.0
+    }
+
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
-        match message {
+        let command = match message {
             Message::UIEvent(event) => self.on_ui_event(event),
             Message::UIChannel(channel) => self.on_ui_channel(channel),
             Message::Scroll(viewport) => self.on_scroll(viewport),
             Message::ScrollResize(viewport) => self.on_scroll_resize(viewport),
             Message::Preview(asset, request_id) => self.on_preview(asset, request_id),
             Message::PreviewResize(viewport) => self.on_preview_resize(viewport),
+            Message::PreviewDrag(offset) => self.on_preview_drag(offset),
+            Message::PreviewDragEnd => self.on_preview_drag_end(),
+            Message::WindowMaximized(maximized) => self.on_window_maximized(maximized),
             Message::ClosePreview => self.on_close_preview(),
+            Message::PreviewZoom(zoom) => self.on_preview_zoom(zoom),
+            Message::PreviewSlice(slice) => self.on_preview_slice(slice),
+            Message::PreviewFly => self.on_preview_fly(),
+            Message::PreviewAudioWaveform(request_id, waveform) => {
+                self.on_preview_audio_waveform(request_id, waveform)
+            }
+            Message::PreviewAudioSeek(position) => self.on_preview_audio_seek(position),
+            Message::TogglePreviewAudioLoop => self.on_toggle_preview_audio_loop(),
+            Message::TogglePreviewAlwaysOnTop => self.on_toggle_preview_always_on_top(),
             Message::CloseSplash(_) => self.on_close_splash(),
             Message::UpdateSplash(splash_animation) => self.on_update_splash(splash_animation),
             Message::Sync(exporting, progress) => self.on_sync(exporting, progress),
+            Message::SyncProgress(exporting, progress, completed, total, remaining) => {
+                self.on_sync_progress(exporting, progress, completed, total, remaining)
+            }
             Message::RowPress(index) => self.on_row_press(index),
             Message::RowRelease(index) => self.on_row_release(index),
+            Message::RowContextMenu(index) => self.on_row_context_menu(index),
+            Message::CloseContextMenu => self.on_close_context_menu(),
+            Message::ContextMenuExportAs(formats) => self.on_context_menu_export_as(formats),
+            Message::ExportAs(format) => self.on_export_as(format),
+            Message::CopyAssetName(index) => self.on_copy_asset_name(index),
+            Message::CopyAssetHash(index) => self.on_copy_asset_hash(index),
+            Message::PreviewAsset(index) => self.on_preview_asset(index),
+            Message::ShowExportFolder => self.on_show_export_folder(),
             Message::LoadFile => self.on_load_file(),
             Message::LoadFileDropped => self.on_load_file_dropped(),
             Message::LoadFiles(files) => self.on_load_files(files),
             Message::LoadGame => self.on_load_game(),
+            Message::LoadPublished(published) => self.on_load_published(published),
             Message::LoadResult(result) => self.on_load_result(result),
+            Message::GameExited(pid) => self.on_game_exited(pid),
+            Message::NewSession => self.on_new_session(),
+            Message::SelectSession(index) => self.on_select_session(index),
+            Message::CloseSession(index) => self.on_close_session(index),
             Message::SearchInput(input) => self.on_search_input(input),
             Message::SearchClear => self.on_search_clear(),
             Message::SearchSubmit => self.on_search_submit(),
@@ -322,17 +618,56 @@ impl Application for PorterMain {
             Message::Website => self.on_website(),
             Message::ToggleSettings => self.on_toggle_settings(),
             Message::ToggleAbout => self.on_toggle_about(),
+            Message::ToggleHistory => self.on_toggle_history(),
+            Message::OpenHistoryFolder(index) => self.on_open_history_folder(index),
+            Message::ExportHistory(index) => self.on_export_history(index),
+            Message::ClearHistory => self.on_clear_history(),
+            Message::ClearExportDatabase => self.on_clear_export_database(),
             Message::ExportSelected => self.on_export_selected(),
             Message::ExportAll => self.on_export_all(),
+            Message::ExportSpaceWarning(assets, settings, required, available) => {
+                self.on_export_space_warning(assets, settings, required, available)
+            }
+            Message::ExportForced(assets, settings) => self.on_export_forced(assets, settings),
+            Message::ExportSample => self.on_export_sample(),
+            Message::SampleCount(count) => self.on_sample_count(count),
             Message::SaveSettings(settings) => self.on_save_settings(settings),
+            Message::ProfileNameInput(name) => self.on_profile_name_input(name),
+            Message::ToggleDryRun(value) => self.on_toggle_dry_run(value),
             Message::OpenConfigFolder => self.on_open_config_folder(),
+            Message::CreateSupportBundle => self.on_create_support_bundle(),
+            Message::ExportHookFailed(failures) => self.on_export_hook_failed(failures),
+            Message::ExportValidationFailed(failures) => self.on_export_validation_failed(failures),
+            Message::ExportSkipped(skipped) => self.on_export_skipped(skipped),
+            Message::ExportCompleted(assets) => self.on_export_completed(assets),
+            Message::ExportResume(assets) => self.on_export_resume(assets),
+            Message::ExportResumeDiscard => self.on_export_resume_discard(),
+            Message::ViewCrashReport => self.on_view_crash_report(),
+            Message::CopyCrashReport(report) => self.on_copy_crash_report(report),
+            Message::SaveSupportBundle(path) => self.on_save_support_bundle(path),
             Message::PickExportFolder => self.on_pick_export_folder(),
             Message::OpenExportFolder => self.on_open_export_folder(),
             Message::SaveExportFolder(path) => self.on_save_export_folder(path),
             Message::ColumnDrag(index, offset) => self.on_column_drag(index, offset),
             Message::ColumnDragEnd(index) => self.on_column_drag_end(index),
+            Message::ColumnPress(index) => self.on_column_press(index),
+            Message::ColumnRelease(index) => self.on_column_release(index),
+            Message::ColumnContextMenu(index) => self.on_column_context_menu(index),
+            Message::ToggleColumnTruncation(index) => self.on_toggle_column_truncation(index),
+            Message::ToggleColumn(index) => self.on_toggle_column(index),
+            Message::ResetColumns => self.on_reset_columns(),
+            Message::Thumbnail(generation, index, handle) => {
+                self.on_thumbnail(generation, index, handle)
+            }
+            Message::SelectionStatus(request_id, status) => {
+                self.on_selection_status(request_id, status)
+            }
             Message::Noop => self.on_noop(),
-        }
+        };
+
+        self.request_thumbnails();
+
+        command
     }
 
     fn subscription(&self) -> iced::Subscription<Self::Message> {
@@ -354,6 +689,8 @@ impl Application for PorterMain {
             }
         });
 
+        let mut subscriptions = vec![events, channel];
+
         if self.splash_id.is_some() {
             let splash = iced::subscription::channel("splash", 0, |mut output| async move {
                 let mut splash = 0.0;
@@ -381,10 +718,26 @@ impl Application for PorterMain {
                 }
             });
 
-            iced::Subscription::batch([events, channel, splash])
-        } else {
-            iced::Subscription::batch([events, channel])
+            subscriptions.push(splash);
+        }
+
+        if self
+            .previewer
+            .as_ref()
+            .is_some_and(|previewer| previewer.is_flying())
+        {
+            let fly = iced::subscription::channel("fly", 0, |mut output| async move {
+                loop {
+                    std::thread::sleep(Duration::from_millis(16));
+
+                    let _ = output.send(Message::PreviewFly).await;
+                }
+            });
+
+            subscriptions.push(fly);
         }
+
+        iced::Subscription::batch(subscriptions)
     }
 
     fn view(&self, id: iced::window::Id) -> Element<'_, Self::Message> {
@@ -393,22 +746,34 @@ impl Application for PorterMain {
                 vec![self.header(), self.about()]
             } else if self.show_settings {
                 vec![self.header(), self.settings()]
+            } else if self.show_history {
+                vec![self.header(), self.history()]
             } else if let Some(preview) = &self.previewer {
                 vec![
                     self.header(),
+                    self.sessions(),
                     self.search(),
-                    row([self.list(), self.preview(preview)])
-                        .width(Length::Fill)
-                        .height(Length::Fill)
-                        .align_items(Alignment::Center)
-                        .spacing(4.0)
-                        .padding([0.0, 8.0])
-                        .into(),
+                    row([
+                        self.list(),
+                        PorterDivider::new(Message::PreviewDrag, Message::PreviewDragEnd)
+                            .height(Length::Fill)
+                            .width(3.0)
+                            .style(PorterDividerStyle)
+                            .into(),
+                        self.preview(preview),
+                    ])
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .align_items(Alignment::Center)
+                    .spacing(4.0)
+                    .padding([0.0, 8.0])
+                    .into(),
                     self.controls(),
                 ]
             } else {
                 vec![
                     self.header(),
+                    self.sessions(),
                     self.search(),
                     row([self.list()])
                         .width(Length::Fill)
@@ -506,7 +871,36 @@ impl PorterMain {
             .height(Length::Shrink)
             .spacing(2.0);
 
-        for (stat_header, stat_value) in preview.statistics() {
+        let statistics = match &self.preview_audio {
+            Some((name, audio)) => vec![
+                (String::from("Name"), name.clone()),
+                (String::from("Channels"), audio.channels().to_string()),
+                (String::from("Rate"), format!("{} Hz", audio.sample_rate())),
+                (
+                    String::from("Duration"),
+                    format_audio_time(audio.duration()),
+                ),
+                (
+                    String::from("Position"),
+                    format_audio_time(audio.duration().mul_f32(self.preview_audio_position)),
+                ),
+                (
+                    String::from("Loop"),
+                    match audio.loop_points() {
+                        Some(loop_points) if self.preview_audio_loop => format!(
+                            "{} - {}",
+                            format_audio_frame_time(loop_points.start, audio.sample_rate()),
+                            format_audio_frame_time(loop_points.end, audio.sample_rate())
+                        ),
+                        Some(_) => String::from("Off"),
+                        None => String::from("None"),
+                    },
+                ),
+            ],
+            None => preview.statistics(),
+        };
+
+        for (stat_header, stat_value) in statistics {
             columns = columns.push(
                 row([
                     text(stat_header)
@@ -567,23 +961,92 @@ impl PorterMain {
         .height(Length::FillPortion(1))
         .padding(4.0);
 
+        let mut header = vec![text("Asset Preview")
+            .width(Length::Fill)
+            .style(Color::WHITE)
+            .into()];
+
+        if let Some((slice, slices)) = preview.slices() {
+            header.push(
+                slider(0..=slices as u32 - 1, slice as u32, |slice| {
+                    Message::PreviewSlice(slice as usize)
+                })
+                .width(120.0)
+                .style(PorterSliderStyle)
+                .into(),
+            );
+        }
+
+        if let Some((_, audio)) = &self.preview_audio {
+            if audio.loop_points().is_some() {
+                header.push(
+                    button(
+                        text(if self.preview_audio_loop {
+                            "Unloop"
+                        } else {
+                            "Loop"
+                        })
+                        .size(14.0),
+                    )
+                    .on_press(Message::TogglePreviewAudioLoop)
+                    .padding([0.0, 4.0])
+                    .style(PorterPreviewButtonStyle)
+                    .into(),
+                );
+            }
+        }
+
+        header.extend([
+            text(
+                preview
+                    .zoom()
+                    .map(|zoom| format!("{}%", zoom))
+                    .unwrap_or_default(),
+            )
+            .size(14.0)
+            .style(PorterLabelStyle)
+            .into(),
+            button(text("1:1").size(14.0))
+                .on_press(Message::PreviewZoom(PreviewZoom::Actual))
+                .padding([0.0, 4.0])
+                .style(PorterPreviewButtonStyle)
+                .into(),
+            button(text("Fit").size(14.0))
+                .on_press(Message::PreviewZoom(PreviewZoom::Fit))
+                .padding([0.0, 4.0])
+                .style(PorterPreviewButtonStyle)
+                .into(),
+            button(text("Fill").size(14.0))
+                .on_press(Message::PreviewZoom(PreviewZoom::Fill))
+                .padding([0.0, 4.0])
+                .style(PorterPreviewButtonStyle)
+                .into(),
+            button(
+                text(if self.preview_always_on_top {
+                    "Unpin"
+                } else {
+                    "Pin"
+                })
+                .size(14.0),
+            )
+            .on_press(Message::TogglePreviewAlwaysOnTop)
+            .padding([0.0, 8.0, 0.0, 4.0])
+            .style(PorterPreviewButtonStyle)
+            .into(),
+            button(text("\u{2715}").size(20.0).shaping(text::Shaping::Advanced))
+                .on_press(Message::ClosePreview)
+                .padding(0.0)
+                .style(PorterPreviewButtonStyle)
+                .into(),
+        ]);
+
         container(
             column([
                 container(
-                    row([
-                        text("Asset Preview")
-                            .width(Length::Fill)
-                            .style(Color::WHITE)
-                            .into(),
-                        button(text("\u{2715}").size(20.0).shaping(text::Shaping::Advanced))
-                            .on_press(Message::ClosePreview)
-                            .padding(0.0)
-                            .style(PorterPreviewButtonStyle)
-                            .into(),
-                    ])
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .align_items(Alignment::Center),
+                    row(header)
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .align_items(Alignment::Center),
                 )
                 .width(Length::Fill)
                 .height(30.0)
@@ -592,10 +1055,39 @@ impl PorterMain {
                 .style(PorterColumnHeader)
                 .into(),
                 container(porter_overlay(
-                    image(handle)
-                        .content_fit(iced::ContentFit::Cover)
-                        .width(Length::Fill)
-                        .height(Length::Fill),
+                    if let Some((_, audio)) = &self.preview_audio {
+                        container(
+                            canvas(PorterAudioWaveform {
+                                waveform: self.preview_audio_waveform.as_ref(),
+                                position: self.preview_audio_position,
+                                loop_region: audio
+                                    .loop_points()
+                                    .filter(|_| self.preview_audio_loop)
+                                    .map(|loop_points| {
+                                        let frames = audio.frames().max(1) as f32;
+
+                                        (
+                                            loop_points.start as f32 / frames,
+                                            loop_points.end as f32 / frames,
+                                        )
+                                    }),
+                            })
+                            .width(Length::Fill)
+                            .height(Length::Fill),
+                        )
+                        .padding([160.0, 16.0])
+                        .into()
+                    } else {
+                        porter_overlay(
+                            image(handle)
+                                .content_fit(iced::ContentFit::Cover)
+                                .width(Length::Fill)
+                                .height(Length::Fill),
+                            canvas(PorterBoneLabels(preview.bone_labels()))
+                                .width(Length::Fill)
+                                .height(Length::Fill),
+                        )
+                    },
                     if self.settings.preview_overlay() {
                         column([columns.into(), controls.into()])
                             .width(Length::Fill)
@@ -615,25 +1107,32 @@ impl PorterMain {
             .height(Length::Fill)
             .padding(1.0),
         )
-        .width(Length::Fill)
+        .width(self.preview_width())
         .height(Length::Fill)
         .style(PorterPreviewStyle)
         .into()
     }
 
+    /// The width of the preview pane, which leaves room for the asset list.
+    fn preview_width(&self) -> Length {
+        match self.window_state.preview_width {
+            Some(width) => Length::Fixed(width.clamp(
+                PREVIEW_WIDTH_MIN,
+                (self.window_size.width - LIST_WIDTH_MIN).max(PREVIEW_WIDTH_MIN),
+            )),
+            None => Length::Fill,
+        }
+    }
+
     /// Constructs the header view element, with app info, version, about and settings.
     pub fn header(&self) -> Element<Message> {
         container(row([
-            container(
-                button("Donate")
-                    .on_press(Message::Donate)
-                    .style(PorterButtonStyle),
-            )
-            .height(Length::Fill)
-            .width(Length::FillPortion(1))
-            .align_x(Horizontal::Left)
-            .align_y(Vertical::Center)
-            .into(),
+            container(self.header_profiles())
+                .height(Length::Fill)
+                .width(Length::FillPortion(1))
+                .align_x(Horizontal::Left)
+                .align_y(Vertical::Center)
+                .into(),
             container(
                 row([
                     text(self.name.to_uppercase())
@@ -663,6 +1162,10 @@ impl PorterMain {
                             .on_press(Message::ToggleAbout)
                             .style(PorterSwitchButtonStyle(self.show_about))
                             .into(),
+                        button("History")
+                            .on_press(Message::ToggleHistory)
+                            .style(PorterSwitchButtonStyle(self.show_history))
+                            .into(),
                         button("Settings")
                             .on_press(Message::ToggleSettings)
                             .style(PorterSwitchButtonStyle(self.show_settings))
@@ -688,6 +1191,45 @@ impl PorterMain {
         .into()
     }
 
+    /// Constructs the donate button, and the settings profile picker when there are profiles.
+    fn header_profiles(&self) -> Element<Message> {
+        let mut header = vec![button("Donate")
+            .on_press(Message::Donate)
+            .style(PorterButtonStyle)
+            .into()];
+
+        if !self.settings.profiles().is_empty() {
+            let profiles: Vec<String> = self
+                .settings
+                .profiles()
+                .iter()
+                .map(|profile| profile.name().to_string())
+                .collect();
+
+            header.push(
+                pick_list(
+                    profiles,
+                    self.settings.active_profile().map(String::from),
+                    |profile| {
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.apply_profile(profile)),
+                        )
+                    },
+                )
+                .placeholder("Profile")
+                .width(Length::Fixed(180.0))
+                .style(PorterPickListStyle)
+                .into(),
+            );
+        }
+
+        row(header)
+            .spacing(8.0)
+            .align_items(Alignment::Center)
+            .into()
+    }
+
     /// Constructs the search view element with text input, clear button, and assets loaded info.
     pub fn search(&self) -> Element<Message> {
         let mut search = vec![if self.loading || self.exporting {
@@ -721,21 +1263,45 @@ impl PorterMain {
             );
         }
 
-        search.extend([
-            button("Clear")
-                .padding([5.0, 8.0])
-                .style(PorterButtonStyle)
-                .on_press_maybe(
-                    if self.search_value.is_empty() || self.loading || self.exporting {
-                        None
-                    } else {
-                        Some(Message::SearchClear)
-                    },
-                )
-                .into(),
+        search.extend([button("Clear")
+            .padding([5.0, 8.0])
+            .style(PorterButtonStyle)
+            .on_press_maybe(
+                if self.search_value.is_empty() || self.loading || self.exporting {
+                    None
+                } else {
+                    Some(Message::SearchClear)
+                },
+            )
+            .into()]);
+
+        if !self.loading {
+            let counts = self.asset_manager.asset_type_counts();
+
+            if !counts.is_empty() {
+                let counts: Vec<String> = counts
+                    .into_iter()
+                    .map(|(asset_type, count)| format!("{} {}", asset_type, format_count(count)))
+                    .collect();
+
+                search.push(
+                    container(text(counts.join(" • ")).style(PorterLabelStyle))
+                        .padding([0.0, 8.0])
+                        .into(),
+                );
+            }
+        }
+
+        search.push(
             container(
-                text(if self.loading {
+                text(if self.loading && self.loading_published > 0 {
+                    format!("Loading... {} assets so far", self.loading_published)
+                } else if self.loading {
                     "Loading...".to_string()
+                } else if self.game_exited {
+                    "Game exited, load the game again to continue".to_string()
+                } else if let Some(status) = &self.selection_status {
+                    status.clone()
                 } else if self.search_value.is_empty() {
                     format!("{} assets loaded", self.asset_manager.len())
                 } else {
@@ -752,7 +1318,7 @@ impl PorterMain {
             .align_x(Horizontal::Right)
             .align_y(Vertical::Center)
             .into(),
-        ]);
+        );
 
         container(
             row(search)
@@ -767,6 +1333,84 @@ impl PorterMain {
         .into()
     }
 
+    /// Constructs the session tabs, or nothing when sessions are disabled.
+    pub fn sessions(&self) -> Element<Message> {
+        if self.session_factory.is_none() {
+            return Space::new(Length::Shrink, Length::Shrink).into();
+        }
+
+        let locked = self.loading || self.exporting;
+        let mut tabs: Vec<Element<_>> = Vec::with_capacity(self.sessions.len() + 1);
+
+        for (index, session) in self.sessions.iter().enumerate() {
+            let active = index == self.session_active;
+
+            let title = match session {
+                Some(session) => session.title(),
+                None => session_title(self.last_load.as_deref()),
+            };
+
+            let mut tab = vec![button(text(title))
+                .on_press_maybe(if active || locked {
+                    None
+                } else {
+                    Some(Message::SelectSession(index))
+                })
+                .style(PorterSwitchButtonStyle(active))
+                .into()];
+
+            if self.sessions.len() > 1 {
+                tab.push(
+                    button(text("×"))
+                        .on_press_maybe(if locked {
+                            None
+                        } else {
+                            Some(Message::CloseSession(index))
+                        })
+                        .style(PorterSwitchButtonStyle(false))
+                        .into(),
+                );
+            }
+
+            tabs.push(row(tab).align_items(Alignment::Center).into());
+        }
+
+        tabs.push(
+            button("+")
+                .on_press_maybe(if locked {
+                    None
+                } else {
+                    Some(Message::NewSession)
+                })
+                .style(PorterSwitchButtonStyle(false))
+                .into(),
+        );
+
+        container(
+            container(row(tabs).spacing(8.0).align_items(Alignment::Center))
+                .padding(3.0)
+                .style(PorterSwitchButtonBackgroundStyle),
+        )
+        .width(Length::Fill)
+        .padding([8.0, 8.0, 0.0, 8.0])
+        .into()
+    }
+
+    /// Finds the byte ranges of every search term in the given value.
+    pub fn search_highlights(&self, value: &str) -> Vec<Range<usize>> {
+        let mut result = Vec::new();
+
+        for term in &self.search_terms {
+            result.extend(
+                value
+                    .match_indices(term.as_str())
+                    .map(|(start, matched)| start..start + matched.len()),
+            );
+        }
+
+        result
+    }
+
     /// Constructs the controls view element with load and export buttons.
     pub fn controls(&self) -> Element<Message> {
         let mut row = row(Vec::new())
@@ -802,6 +1446,12 @@ impl PorterMain {
             );
         }
 
+        let export_all = if self.settings.incremental_export() && self.keyboard_modifiers.shift() {
+            "Re-export All"
+        } else {
+            "Export All"
+        };
+
         row = row
             .push(
                 button("Export Selected")
@@ -816,7 +1466,7 @@ impl PorterMain {
                     ),
             )
             .push(
-                button("Export All")
+                button(export_all)
                     .padding([5.0, 8.0])
                     .style(PorterButtonStyle)
                     .on_press_maybe(
@@ -844,21 +1494,40 @@ impl PorterMain {
                 );
             }
 
+            let mut progress = format!("{}%", self.export_progress.clamp(0, 100));
+
+            if self.dry_run {
+                progress = format!("Dry Run {}", progress);
+            }
+
+            if self.export_total > 0 {
+                progress = format!(
+                    "{} \u{2014} {}/{}",
+                    progress,
+                    format_count(self.export_completed),
+                    format_count(self.export_total)
+                );
+
+                if let Some(remaining) = self.export_remaining {
+                    progress = format!(
+                        "{} \u{2014} ~{} remaining",
+                        progress,
+                        remaining.as_human_duration()
+                    );
+                }
+            }
+
             row = row.push(
                 container(porter_overlay(
                     progress_bar(0.0..=100.0, self.export_progress.clamp(0, 100) as f32)
-                        .width(200.0)
+                        .width(if self.export_total > 0 { 360.0 } else { 200.0 })
                         .height(32.0)
                         .style(PorterProgressStyle),
-                    container(
-                        text(format!("{}%", self.export_progress.clamp(0, 100)))
-                            .size(16.0)
-                            .style(Color::WHITE),
-                    )
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .align_x(Horizontal::Center)
-                    .align_y(Vertical::Center),
+                    container(text(progress).size(16.0).style(Color::WHITE))
+                        .width(Length::Fill)
+                        .height(Length::Fill)
+                        .align_x(Horizontal::Center)
+                        .align_y(Vertical::Center),
                 ))
                 .width(Length::Fill)
                 .height(Length::Fill)
@@ -889,21 +1558,56 @@ impl PorterMain {
 
             let selected = self.item_selection.contains(&row_index);
 
-            for (column, (value, color)) in self
-                .columns
-                .iter()
-                .zip(self.asset_manager.asset_info(row_index, self.columns.len()))
-            {
-                columns.push(
-                    PorterText::new(value)
-                        .width(column.width.clamp(COLUMN_MIN, COLUMN_MAX).add(6.0))
-                        .height(Length::Fill)
-                        .vertical_alignment(Vertical::Center)
-                        .style(selected.then_some(Color::WHITE).unwrap_or_else(|| {
-                            color.unwrap_or_else(|| column.color.unwrap_or(Color::WHITE))
-                        }))
+            let info = self.asset_manager.asset_info(row_index, self.columns.len());
+
+            if self.thumbnails_enabled {
+                columns.push(match self.thumbnails.get(row_index) {
+                    Some(handle) => image(handle.clone())
+                        .width(THUMBNAIL_SIZE)
+                        .height(THUMBNAIL_SIZE)
                         .into(),
-                );
+                    None => Space::new(THUMBNAIL_SIZE, THUMBNAIL_SIZE).into(),
+                });
+            }
+
+            for column in self.columns.iter().filter(|column| column.visible) {
+                let Some((value, color)) = info.get(column.index).cloned() else {
+                    continue;
+                };
+
+                let highlight = if column.index == 0 {
+                    self.search_highlights(&value)
+                } else {
+                    Vec::new()
+                };
+
+                let width = column.width.clamp(COLUMN_MIN, COLUMN_MAX).add(6.0);
+
+                // Show the full value on hover when it doesn't fit in the column.
+                let full_value =
+                    (porter_text_width(&value, TEXT_SIZE) > width).then(|| value.clone());
+
+                let cell = PorterText::new(value)
+                    .highlight(highlight, SEARCH_HIGHLIGHT_COLOR)
+                    .truncation(column.truncation)
+                    .width(width)
+                    .height(Length::Fill)
+                    .vertical_alignment(Vertical::Center)
+                    .style(selected.then_some(Color::WHITE).unwrap_or_else(|| {
+                        color.unwrap_or_else(|| column.color.unwrap_or(Color::WHITE))
+                    }));
+
+                columns.push(match full_value {
+                    Some(full_value) => tooltip(
+                        cell,
+                        container(text(full_value).size(14.0).style(Color::WHITE))
+                            .padding([4.0, 8.0])
+                            .style(PorterContextMenuStyle),
+                        tooltip::Position::FollowCursor,
+                    )
+                    .into(),
+                    None => cell.into(),
+                });
             }
 
             let row = container(
@@ -923,15 +1627,18 @@ impl PorterMain {
                 if row_index == row_press {
                     mouse_area(row)
                         .on_release(Message::RowRelease(row_index))
+                        .on_right_press(Message::RowContextMenu(row_index))
                         .into()
                 } else {
                     mouse_area(row)
                         .on_press(Message::RowPress(row_index))
+                        .on_right_press(Message::RowContextMenu(row_index))
                         .into()
                 }
             } else {
                 mouse_area(row)
                     .on_press(Message::RowPress(row_index))
+                    .on_right_press(Message::RowContextMenu(row_index))
                     .into()
             });
         }
@@ -963,13 +1670,30 @@ impl PorterMain {
 
         let mut columns: Vec<Element<_, _>> = Vec::with_capacity(self.columns.len());
 
+        if self.thumbnails_enabled {
+            columns.push(Space::new(THUMBNAIL_SIZE, THUMBNAIL_SIZE).into());
+        }
+
         for (index, column) in self.columns.iter().enumerate() {
+            if !column.visible {
+                continue;
+            }
+
+            let header = PorterText::new(column.header.clone())
+                .width(column.width.clamp(COLUMN_MIN, COLUMN_MAX))
+                .height(Length::Fill)
+                .vertical_alignment(Vertical::Center)
+                .style(if self.column_press == Some(index) {
+                    COLUMN_DRAG_COLOR
+                } else {
+                    Color::WHITE
+                });
+
             columns.push(
-                PorterText::new(column.header.clone())
-                    .width(column.width.clamp(COLUMN_MIN, COLUMN_MAX))
-                    .height(Length::Fill)
-                    .vertical_alignment(Vertical::Center)
-                    .style(Color::WHITE)
+                mouse_area(header)
+                    .on_press(Message::ColumnPress(index))
+                    .on_release(Message::ColumnRelease(index))
+                    .on_right_press(Message::ColumnContextMenu(Some(index)))
                     .into(),
             );
 
@@ -1008,6 +1732,8 @@ impl PorterMain {
         .height(30.0)
         .style(PorterColumnHeader);
 
+        let header = mouse_area(header).on_right_press(Message::ColumnContextMenu(None));
+
         let empty_element = if self.loading {
             Element::from(
                 porter_spinner::Circular::new()
@@ -1061,10 +1787,138 @@ impl PorterMain {
         .padding(1.0)
         .style(PorterHeaderBackgroundStyle);
 
-        column([header.into(), list.into()])
-            .align_items(Alignment::Start)
-            .width(Length::Fill)
-            .height(Length::Fill)
+        porter_context_menu(
+            column([header.into(), list.into()])
+                .align_items(Alignment::Start)
+                .width(Length::Fill)
+                .height(Length::Fill),
+            self.column_menu
+                .map(|(index, position)| (position, self.column_menu(index)))
+                .or_else(|| {
+                    self.context_menu
+                        .map(|(row_index, position)| (position, self.context_menu(row_index)))
+                }),
+            Message::CloseContextMenu,
+        )
+    }
+
+    /// Constructs the context menu element for the asset at the given row.
+    pub fn context_menu(&self, row_index: usize) -> Element<Message> {
+        let item = |label: String, message: Option<Message>| -> Element<Message> {
+            button(text(label).size(14.0))
+                .width(Length::Fill)
+                .padding([4.0, 8.0])
+                .style(PorterContextMenuButtonStyle)
+                .on_press_maybe(message)
+                .into()
+        };
+
+        let can_export = !self.loading && !self.exporting;
+
+        let items: Vec<Element<_, _>> = if self.context_menu_formats {
+            let asset_type = self.asset_manager.asset_type(row_index);
+
+            let mut items = vec![item(
+                String::from("< Back"),
+                Some(Message::ContextMenuExportAs(false)),
+            )];
+
+            items.extend(
+                PorterExportFormat::for_asset_type(asset_type.as_deref())
+                    .into_iter()
+                    .map(|format| {
+                        item(
+                            format.name().to_string(),
+                            can_export.then_some(Message::ExportAs(format)),
+                        )
+                    }),
+            );
+
+            items
+        } else {
+            vec![
+                item(
+                    String::from("Export"),
+                    can_export.then_some(Message::ExportSelected),
+                ),
+                item(
+                    String::from("Export As..."),
+                    can_export.then_some(Message::ContextMenuExportAs(true)),
+                ),
+                item(
+                    String::from("Copy Name"),
+                    Some(Message::CopyAssetName(row_index)),
+                ),
+                item(
+                    String::from("Copy Hash"),
+                    self.asset_manager
+                        .asset_hash(row_index)
+                        .map(|_| Message::CopyAssetHash(row_index)),
+                ),
+                item(
+                    String::from("Preview"),
+                    self.preview_enabled
+                        .then_some(Message::PreviewAsset(row_index)),
+                ),
+                item(
+                    String::from("Show Export Folder"),
+                    Some(Message::ShowExportFolder),
+                ),
+            ]
+        };
+
+        container(column(items).spacing(2.0).width(180.0))
+            .padding(4.0)
+            .style(PorterContextMenuStyle)
+            .into()
+    }
+
+    /// Constructs the context menu element used to show or hide columns.
+    pub fn column_menu(&self, column_index: Option<usize>) -> Element<Message> {
+        let visible = self.columns.iter().filter(|column| column.visible).count();
+
+        let mut items: Vec<Element<_, _>> = Vec::with_capacity(self.columns.len() + 3);
+
+        if let Some((index, column)) =
+            column_index.and_then(|index| self.columns.get(index).map(|column| (index, column)))
+        {
+            items.push(
+                checkbox(
+                    "Truncate Middle",
+                    matches!(column.truncation, ColumnTruncation::Middle),
+                )
+                .on_toggle(move |_| Message::ToggleColumnTruncation(index))
+                .size(14.0)
+                .text_size(14.0)
+                .width(Length::Fill)
+                .into(),
+            );
+        }
+
+        items.extend(self.columns.iter().enumerate().map(|(index, column)| {
+            // The last visible column can't be hidden.
+            let toggle = !column.visible || visible > 1;
+
+            checkbox(column.header.clone(), column.visible)
+                .on_toggle_maybe(toggle.then_some(move |_| Message::ToggleColumn(index)))
+                .size(14.0)
+                .text_size(14.0)
+                .width(Length::Fill)
+                .into()
+        }));
+
+        items.push(
+            button(text("Reset Columns").size(14.0))
+                .width(Length::Fill)
+                .padding([4.0, 8.0])
+                .style(PorterContextMenuButtonStyle)
+                .on_press(Message::ResetColumns)
+                .into(),
+        );
+
+        container(column(items).spacing(4.0).width(180.0))
+            .padding(8.0)
+            .style(PorterContextMenuStyle)
             .into()
     }
 }
@@ -6,6 +6,7 @@ use iced::Color;
 use iced::Element;
 use iced::Length;
 
+use crate::format_support_summary;
 use crate::Message;
 use crate::PorterLabelStyle;
 use crate::PorterMain;
@@ -15,6 +16,21 @@ use crate::PORTER_DISCLAIMER;
 impl PorterMain {
     /// Constructs the about view.
     pub fn about(&self) -> Element<Message> {
+        let formats = column(
+            format_support_summary()
+                .into_iter()
+                .map(|summary| {
+                    text(summary)
+                        .size(14.0)
+                        .style(PorterLabelStyle)
+                        .horizontal_alignment(Horizontal::Center)
+                        .into()
+                })
+                .collect::<Vec<_>>(),
+        )
+        .spacing(4.0)
+        .align_items(Alignment::Center);
+
         container(
             column([
                 text(
@@ -29,6 +45,12 @@ impl PorterMain {
                     .style(PorterLabelStyle)
                     .into(),
                 vertical_space().height(20.0).into(),
+                text("Supported formats:")
+                    .size(18.0)
+                    .style(PorterLabelStyle)
+                    .into(),
+                formats.into(),
+                vertical_space().height(20.0).into(),
                 text(PORTER_DISCLAIMER).style(PorterLabelStyle).into(),
                 text(format!(
                     "\"{}\" {}.",
@@ -7,10 +7,13 @@ use iced::Font;
 use iced::Pixels;
 use iced::Settings;
 
+use crate::format_support_summary;
 use crate::porter_main_settings;
 use crate::PorterAssetManager;
 use crate::PorterMain;
 use crate::PorterMainColumn;
+use crate::PorterSettings;
+use crate::TEXT_SIZE;
 
 /// Used to build and configure the main window.
 pub struct PorterMainBuilder {
@@ -20,6 +23,7 @@ pub struct PorterMainBuilder {
     pub(crate) file_filters: Vec<(String, Vec<String>)>,
     pub(crate) multi_file: bool,
     pub(crate) preview: bool,
+    pub(crate) thumbnails: bool,
     pub(crate) animations_enabled: bool,
     pub(crate) materials_enabled: bool,
     pub(crate) sounds_enabled: bool,
@@ -27,10 +31,15 @@ pub struct PorterMainBuilder {
     pub(crate) raw_files_enabled: bool,
     pub(crate) raw_files_forcable: bool,
     pub(crate) normal_map_converter: bool,
+    pub(crate) io_threads: usize,
     pub(crate) columns: Vec<PorterMainColumn>,
     pub(crate) asset_manager: Arc<dyn PorterAssetManager>,
+    pub(crate) session_factory: Option<PorterSessionFactory>,
 }
 
+/// Constructs the asset manager for a new session tab.
+pub(crate) type PorterSessionFactory = Arc<dyn Fn() -> Arc<dyn PorterAssetManager>>;
+
 impl PorterMainBuilder {
     /// The name of the application. Used for the main window header, and the name of settings and crash files.
     pub fn name(mut self, name: &'static str) -> Self {
@@ -83,6 +92,12 @@ impl PorterMainBuilder {
         self
     }
 
+    /// Enable or disable the asset thumbnail column (Default: false).
+    pub const fn thumbnails(mut self, thumbnails: bool) -> Self {
+        self.thumbnails = thumbnails;
+        self
+    }
+
     /// Enable or disable animation support (Default: false).
     pub const fn animations_enabled(mut self, animations: bool) -> Self {
         self.animations_enabled = animations;
@@ -125,19 +140,43 @@ impl PorterMainBuilder {
         self
     }
 
+    /// Sets the number of threads used for blocking io, such as loading games and files, or zero to use the global thread pool (Default: 0).
+    ///
+    /// Asset managers should read files with `porter_threads::spawn_io` so slow reads don't starve exports of compute threads.
+    pub const fn io_threads(mut self, threads: usize) -> Self {
+        self.io_threads = threads;
+        self
+    }
+
+    /// Enables loading games or files into separate tabs, using the factory to construct the asset manager of each new tab (Default: disabled).
+    pub fn sessions<A, F>(mut self, factory: F) -> Self
+    where
+        A: PorterAssetManager + 'static,
+        F: Fn() -> A + 'static,
+    {
+        self.session_factory = Some(Arc::new(move || Arc::new(factory())));
+        self
+    }
+
     /// Runs the main window until it closes.
     pub fn run(self) {
+        if std::env::args().any(|arg| arg == "--formats") {
+            for summary in format_support_summary() {
+                println!("{}", summary);
+            }
+
+            return;
+        }
+
+        porter_threads::set_io_threads(self.io_threads);
+
         let settings = Settings {
             id: None,
-            window: porter_main_settings(),
+            window: porter_main_settings(&PorterSettings::load(self.name).window_state()),
             flags: self,
             fonts: Vec::new(),
             default_font: Font::DEFAULT,
-            default_text_size: if cfg!(target_os = "windows") {
-                Pixels(14.0)
-            } else {
-                Pixels(16.0)
-            },
+            default_text_size: Pixels(TEXT_SIZE),
             antialiasing: true,
         };
 
@@ -154,6 +193,7 @@ pub fn create_main<A: PorterAssetManager + 'static>(asset_manager: A) -> PorterM
         file_filters: Vec::new(),
         multi_file: false,
         preview: true,
+        thumbnails: false,
         animations_enabled: false,
         materials_enabled: false,
         sounds_enabled: false,
@@ -161,7 +201,9 @@ pub fn create_main<A: PorterAssetManager + 'static>(asset_manager: A) -> PorterM
         raw_files_enabled: false,
         raw_files_forcable: false,
         normal_map_converter: true,
+        io_threads: 0,
         columns: Vec::new(),
         asset_manager: Arc::new(asset_manager),
+        session_factory: None,
     }
 }
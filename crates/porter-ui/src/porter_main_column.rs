@@ -1,10 +1,17 @@
 use iced::Color;
 
+use crate::ColumnTruncation;
+use crate::PorterColumnLayout;
+
 /// A column in the list view of the main window.
 pub struct PorterMainColumn {
     pub(crate) header: String,
     pub(crate) width: f32,
+    pub(crate) default_width: f32,
     pub(crate) color: Option<Color>,
+    pub(crate) index: usize,
+    pub(crate) visible: bool,
+    pub(crate) truncation: ColumnTruncation,
 }
 
 impl PorterMainColumn {
@@ -13,7 +20,69 @@ impl PorterMainColumn {
         Self {
             header: header.into(),
             width: width as f32,
+            default_width: width as f32,
             color,
+            index: 0,
+            visible: true,
+            truncation: ColumnTruncation::End,
+        }
+    }
+}
+
+/// Assigns each column it's asset info index and applies the saved layout, columns not in the layout are appended.
+pub(crate) fn apply_column_layout(
+    columns: Vec<PorterMainColumn>,
+    layout: &[PorterColumnLayout],
+) -> Vec<PorterMainColumn> {
+    let mut remaining: Vec<Option<PorterMainColumn>> = columns
+        .into_iter()
+        .enumerate()
+        .map(|(index, mut column)| {
+            column.index = index;
+            Some(column)
+        })
+        .collect();
+
+    let mut result = Vec::with_capacity(remaining.len());
+
+    for saved in layout {
+        let Some(column) = remaining
+            .iter_mut()
+            .find(|column| matches!(column, Some(column) if column.header == saved.header))
+            .and_then(Option::take)
+        else {
+            continue;
+        };
+
+        result.push(PorterMainColumn {
+            width: saved.width as f32,
+            visible: saved.visible,
+            truncation: saved.truncation,
+            ..column
+        });
+    }
+
+    result.extend(remaining.into_iter().flatten());
+
+    // Never allow a saved layout to hide every column.
+    if !result.iter().any(|column| column.visible) {
+        if let Some(column) = result.first_mut() {
+            column.visible = true;
         }
     }
+
+    result
+}
+
+/// Builds the layout to save for the given columns.
+pub(crate) fn column_layout(columns: &[PorterMainColumn]) -> Vec<PorterColumnLayout> {
+    columns
+        .iter()
+        .map(|column| PorterColumnLayout {
+            header: column.header.clone(),
+            width: column.width as u32,
+            visible: column.visible,
+            truncation: column.truncation,
+        })
+        .collect()
 }
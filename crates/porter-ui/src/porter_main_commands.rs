@@ -1,9 +1,45 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use iced::keyboard::Key;
+use iced::widget::container;
+use iced::widget::image::Handle;
+use iced::widget::scrollable;
+use iced::widget::scrollable::AbsoluteOffset;
+use iced::Command;
+
+use porter_preview::PreviewFlyDirection;
+use porter_preview::PreviewView;
+
+use porter_texture::Image;
+use porter_texture::ImageFileType;
+
+use porter_threads::Pipeline;
+use porter_threads::TaskScope;
+
+use porter_utils::AsHumanBytes;
+
+use crate::available_space;
+use crate::column_layout;
+use crate::format_count;
 use crate::Message;
+use crate::PorterAssetLoader;
+use crate::PorterAssetManager;
+use crate::PorterExportFormat;
+use crate::PorterExportHistoryEntry;
+use crate::PorterExportJournalAsset;
 use crate::PorterMain;
+use crate::PorterSettings;
 use crate::PorterUI;
 use crate::PorterViewport;
+use crate::EXPORT_HISTORY_ASSETS_MAX;
+use crate::ROW_HEIGHT;
+use crate::ROW_OVERSCAN;
+use crate::ROW_PADDING;
+use crate::THUMBNAIL_THREADS;
+use crate::TURNTABLE_FRAMES;
 
 impl PorterMain {
     pub fn request_preview_asset(&mut self) {
@@ -28,80 +64,394 @@ impl PorterMain {
     }
 
     pub fn export_asset(&mut self, index: usize) {
-        if self.exporting {
+        self.export_assets(vec![index], false);
+    }
+
+    pub fn export_selected(&mut self) {
+        if self.item_selection.is_empty() {
             return;
         }
 
-        let manager = self.asset_manager.clone();
-        let channel = self.channel.clone();
-        let settings = self.settings.clone();
+        let assets: Vec<usize> = self.item_selection.iter().copied().collect();
 
-        self.exporting = true;
-        self.export_cancel = false;
-        self.export_progress = 0;
+        self.export_assets(assets, false);
+    }
 
-        porter_threads::spawn(move || {
-            manager.on_export(settings, vec![index], PorterUI::new(channel));
-        });
+    pub fn export_all(&mut self, force: bool) -> usize {
+        let mut assets: Vec<usize> = (0..self.asset_manager.len()).collect();
+
+        if self.settings.incremental_export() && !force {
+            let fingerprint = self.settings.export_fingerprint();
+
+            assets.retain(|index| match self.export_identity(*index) {
+                Some((name, hash)) => !self.export_database.is_exported(&name, &hash, fingerprint),
+                None => true,
+            });
+        }
+
+        let unchanged = self.asset_manager.len() - assets.len();
+
+        if !assets.is_empty() {
+            self.export_assets(assets, false);
+        }
+
+        unchanged
     }
 
-    pub fn export_selected(&mut self) {
-        if self.exporting {
-            return;
+    pub fn export_identity(&self, index: usize) -> Option<(String, String)> {
+        let hash = self.asset_manager.asset_hash(index)?;
+        let (name, _) = self.asset_manager.asset_info(index, 1).into_iter().next()?;
+
+        Some((name, hash))
+    }
+
+    pub fn export_source(&self) -> Option<String> {
+        match self.last_load.as_deref()? {
+            [] => Some(match self.asset_manager.loaded_process() {
+                Some(process) => format!("game:{}", process.name()),
+                None => String::from("game"),
+            }),
+            files => Some(format!(
+                "files:{}",
+                files
+                    .iter()
+                    .map(|file| file.display().to_string())
+                    .collect::<Vec<String>>()
+                    .join("|")
+            )),
         }
+    }
 
-        if self.item_selection.is_empty() {
+    pub fn export_sample(&mut self) {
+        if self.exporting || self.asset_manager.is_empty() {
             return;
         }
 
-        let manager = self.asset_manager.clone();
-        let channel = self.channel.clone();
-        let settings = self.settings.clone();
-        let assets: Vec<usize> = self.item_selection.iter().copied().collect();
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
 
-        self.exporting = true;
-        self.export_cancel = false;
-        self.export_progress = 0;
+        for index in 0..self.asset_manager.len() {
+            let asset_type = self
+                .asset_manager
+                .asset_type(index)
+                .unwrap_or_else(|| String::from("Assets"));
 
-        porter_threads::spawn(move || {
-            manager.on_export(settings, assets, PorterUI::new(channel));
-        });
+            groups.entry(asset_type).or_default().push(index);
+        }
+
+        let mut seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default()
+            | 1;
+
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        let mut assets = Vec::new();
+        let mut report = String::from("Exported a random sample of assets:\n");
+
+        for (asset_type, mut indices) in groups {
+            let count = (self.sample_count as usize).min(indices.len());
+
+            for i in 0..count {
+                let swap = i + (random() % (indices.len() - i) as u64) as usize;
+
+                indices.swap(i, swap);
+            }
+
+            report.push_str(&format!("\n{}: {} of {}", asset_type, count, indices.len()));
+
+            assets.extend_from_slice(&indices[0..count]);
+        }
+
+        assets.sort_unstable();
+
+        self.item_selection = assets.iter().copied().collect();
+        self.update_selection_status();
+
+        self.export_assets(assets, false);
+
+        if self.exporting {
+            self.sample_report = Some(report);
+        }
     }
 
-    pub fn export_all(&mut self) {
+    pub fn export_assets(&mut self, assets: Vec<usize>, force: bool) {
+        self.export_assets_with(assets, force, self.settings.clone());
+    }
+
+    pub fn export_assets_as(&mut self, assets: Vec<usize>, format: PorterExportFormat) {
+        self.export_assets_with(assets, false, self.settings.with_export_format(format));
+    }
+
+    pub fn export_assets_with(
+        &mut self,
+        assets: Vec<usize>,
+        force: bool,
+        settings: PorterSettings,
+    ) {
+        self.context_menu = None;
+
         if self.exporting {
             return;
         }
 
+        if !force {
+            let required = self.asset_manager.export_size(&settings, &assets);
+            let available = available_space(settings.output_directory());
+
+            if let (Some(required), Some(available)) = (required, available) {
+                if required > available {
+                    if let Some(channel) = &self.channel {
+                        let result = channel.unbounded_send(Message::ExportSpaceWarning(
+                            assets, settings, required, available,
+                        ));
+
+                        debug_assert!(result.is_ok());
+                    }
+
+                    return;
+                }
+            }
+        }
+
+        if !self.dry_run {
+            self.export_history_pending = Some(self.export_history_entry(&assets, &settings));
+            let fingerprint = settings.export_fingerprint();
+
+            // Without a known source the export can't be matched up again, so it isn't journaled.
+            if let Some(source) = self.export_source() {
+                let journal: Vec<PorterExportJournalAsset> = assets
+                    .iter()
+                    .map(|index| PorterExportJournalAsset {
+                        index: *index,
+                        hash: self.asset_manager.asset_hash(*index),
+                    })
+                    .collect();
+
+                self.export_journal.begin(&source, fingerprint, &journal);
+                self.export_journal_assets = journal
+                    .into_iter()
+                    .map(|asset| (asset.index, asset))
+                    .collect();
+            }
+
+            self.export_database_pending = Some(fingerprint);
+        }
+
         let manager = self.asset_manager.clone();
         let channel = self.channel.clone();
-        let settings = self.settings.clone();
-        let assets: Vec<usize> = (0..self.asset_manager.len()).collect();
+        let dry_run = self.dry_run;
+        let export_hook = settings.export_hook();
+        let export_collision = settings.export_collision();
+        let export_scope = TaskScope::default();
 
         self.exporting = true;
         self.export_cancel = false;
+        self.export_scope = export_scope.clone();
         self.export_progress = 0;
-
-        porter_threads::spawn(move || {
-            manager.on_export(settings, assets, PorterUI::new(channel));
+        self.export_completed = 0;
+        self.export_total = 0;
+        self.export_skipped = 0;
+        self.export_remaining = None;
+
+        log::info!(
+            "Exporting {} assets to {}",
+            assets.len(),
+            settings.output_directory().display()
+        );
+
+        porter_threads::set_export_threads(
+            settings.export_threads() as usize,
+            settings.export_priority(),
+        );
+        porter_threads::spawn_export(move || {
+            let ui = PorterUI::new(channel)
+                .dry_run(dry_run)
+                .export_hook(export_hook)
+                .export_collision(export_collision)
+                .export_scope(export_scope);
+
+            manager.on_export(settings, assets, ui);
         });
     }
 
+    pub fn export_history_entry(
+        &self,
+        assets: &[usize],
+        settings: &PorterSettings,
+    ) -> PorterExportHistoryEntry {
+        let mut types: BTreeMap<String, usize> = BTreeMap::new();
+        let mut names = Vec::with_capacity(assets.len().min(EXPORT_HISTORY_ASSETS_MAX));
+
+        for index in assets {
+            if let Some(asset_type) = self.asset_manager.asset_type(*index) {
+                *types.entry(asset_type).or_default() += 1;
+            }
+
+            if names.len() >= EXPORT_HISTORY_ASSETS_MAX {
+                continue;
+            }
+
+            if let Some((name, _)) = self.asset_manager.asset_info(*index, 1).into_iter().next() {
+                names.push(name);
+            }
+        }
+
+        let mut summary = if assets.len() == 1 {
+            names
+                .first()
+                .cloned()
+                .unwrap_or_else(|| String::from("1 asset"))
+        } else {
+            format!("{} assets", format_count(assets.len()))
+        };
+
+        if assets.len() > 1 && !types.is_empty() {
+            let types: Vec<String> = types
+                .into_iter()
+                .map(|(asset_type, count)| format!("{} {}", format_count(count), asset_type))
+                .collect();
+
+            summary.push_str(&format!(" ({})", types.join(", ")));
+        }
+
+        PorterExportHistoryEntry::new(summary, names, assets.len(), settings)
+    }
+
+    pub fn finish_export_journal(&mut self) {
+        self.export_journal_assets.clear();
+        self.export_journal.finish();
+    }
+
+    pub fn finish_export_database(&mut self) {
+        // Assets are recorded as they finish exporting, so a cancelled export keeps the ones that finished.
+        if self.export_database_pending.take().is_some() {
+            self.export_database.save(self.name);
+        }
+    }
+
+    pub fn finish_export_history(&mut self) {
+        let Some(mut entry) = self.export_history_pending.take() else {
+            return;
+        };
+
+        entry.cancelled = self.export_cancel;
+        entry.skipped = self.export_skipped as u64;
+        entry.completed = if self.export_total > 0 {
+            self.export_completed as u64
+        } else if self.export_cancel {
+            0
+        } else {
+            entry.total
+        };
+
+        self.export_history.push(entry);
+        self.export_history.save(self.name);
+    }
+
+    pub fn set_preview_view(&mut self, view: PreviewView, opposite: PreviewView) {
+        if let Some(previewer) = &mut self.previewer {
+            if self.keyboard_modifiers.command() {
+                previewer.set_view(opposite);
+            } else {
+                previewer.set_view(view);
+            }
+        }
+    }
+
+    pub fn set_preview_fly_direction(&mut self, key: &Key, active: bool) -> bool {
+        let Some(previewer) = &mut self.previewer else {
+            return false;
+        };
+
+        if !previewer.is_flying() || self.keyboard_modifiers.command() {
+            return false;
+        }
+
+        let direction = match key.as_ref() {
+            Key::Character("w") => PreviewFlyDirection::Forward,
+            Key::Character("s") => PreviewFlyDirection::Back,
+            Key::Character("a") => PreviewFlyDirection::Left,
+            Key::Character("d") => PreviewFlyDirection::Right,
+            Key::Character("e") => PreviewFlyDirection::Up,
+            Key::Character("q") => PreviewFlyDirection::Down,
+            _ => return false,
+        };
+
+        previewer.set_fly_direction(direction, active);
+
+        true
+    }
+
+    pub fn capture_preview_screenshot(&mut self) {
+        let Some(previewer) = &self.previewer else {
+            return;
+        };
+
+        let Ok(image) = previewer.capture() else {
+            return;
+        };
+
+        let name = preview_capture_name(previewer.render_name());
+
+        save_preview_captures(
+            self.settings.output_directory().join("screenshots"),
+            name,
+            vec![image],
+        );
+    }
+
+    pub fn capture_preview_turntable(&mut self) {
+        let Some(previewer) = &mut self.previewer else {
+            return;
+        };
+
+        let Ok(images) = previewer.capture_turntable(TURNTABLE_FRAMES) else {
+            return;
+        };
+
+        if images.is_empty() {
+            return;
+        }
+
+        let name = preview_capture_name(previewer.render_name());
+
+        save_preview_captures(
+            self.settings
+                .output_directory()
+                .join("screenshots")
+                .join(&name),
+            name,
+            images,
+        );
+    }
+
     pub fn load_game(&mut self) {
         let manager = self.asset_manager.clone();
         let channel = self.channel.clone();
         let settings = self.settings.clone();
 
         self.loading = true;
+        self.loading_published = 0;
 
         self.item_range = 0..0;
         self.item_selection.clear();
+        self.update_selection_status();
         self.scroll_viewport_state = PorterViewport::zero();
 
         self.last_load = Some(Vec::new());
+        self.game_process = None;
+        self.game_exited = false;
 
-        porter_threads::spawn(move || {
-            let result = manager.on_load_game(settings);
+        let loader = PorterAssetLoader::new(channel.clone());
+
+        porter_threads::spawn_io(move || {
+            let result = manager.on_load_game_streamed(settings, loader);
 
             if let Some(channel) = channel {
                 let result = channel.unbounded_send(Message::LoadResult(result));
@@ -117,15 +467,21 @@ impl PorterMain {
         let settings = self.settings.clone();
 
         self.loading = true;
+        self.loading_published = 0;
 
         self.item_range = 0..0;
         self.item_selection.clear();
+        self.update_selection_status();
         self.scroll_viewport_state = PorterViewport::zero();
 
         self.last_load = Some(files.clone());
+        self.game_process = None;
+        self.game_exited = false;
 
-        porter_threads::spawn(move || {
-            let result = manager.on_load_files(settings, files);
+        let loader = PorterAssetLoader::new(channel.clone());
+
+        porter_threads::spawn_io(move || {
+            let result = manager.on_load_files_streamed(settings, files, loader);
 
             if let Some(channel) = channel {
                 let result = channel.unbounded_send(Message::LoadResult(result));
@@ -135,6 +491,37 @@ impl PorterMain {
         });
     }
 
+    pub fn watch_game_process(&mut self) {
+        if !self
+            .last_load
+            .as_ref()
+            .is_some_and(|files| files.is_empty())
+        {
+            return;
+        }
+
+        let Some(process) = self.asset_manager.loaded_process() else {
+            return;
+        };
+
+        let Some(channel) = self.channel.clone() else {
+            return;
+        };
+
+        let pid = process.pid();
+
+        let result = process.on_exit(move || {
+            let result = channel.unbounded_send(Message::GameExited(pid));
+
+            debug_assert!(result.is_ok());
+        });
+
+        match result {
+            Ok(_) => self.game_process = Some(pid),
+            Err(e) => log::warn!("Failed to watch game process {}: {:?}", pid, e),
+        }
+    }
+
     pub fn check_reload_required(&mut self) {
         if self.reload_required && !self.exporting && !self.loading && !self.show_settings {
             self.reload_required = false;
@@ -149,7 +536,262 @@ impl PorterMain {
         }
     }
 
-    pub fn get_copy_text(&mut self) -> Option<String> {
+    pub fn measure_viewports(&self) -> Command<Message> {
+        Command::batch([
+            container::visible_bounds(self.scroll_container_id.clone()).map(Message::ScrollResize),
+            container::visible_bounds(self.previewer_container_id.clone())
+                .map(Message::PreviewResize),
+        ])
+    }
+
+    /// The scale between window event coordinates, which include the interface scale, and window creation coordinates.
+    pub fn window_scale(&self) -> f32 {
+        self.settings.ui_scale() as f32 / 100.0
+    }
+
+    pub fn switch_session(&mut self, index: usize) -> Command<Message> {
+        let Some(mut session) = self.sessions.get_mut(index).and_then(Option::take) else {
+            return Command::none();
+        };
+
+        session.swap(self);
+
+        self.update_selection_status();
+
+        self.sessions[self.session_active] = Some(session);
+        self.session_active = index;
+
+        self.context_menu = None;
+        self.thumbnails.clear();
+
+        // The previewer still shows an asset from the previous session.
+        self.preview_request_id = self.preview_request_id.wrapping_add(1);
+        self.preview_audio = None;
+        self.preview_audio_waveform = None;
+
+        if let Some(previewer) = &mut self.previewer {
+            previewer.clear_preview();
+        }
+
+        self.request_preview_asset();
+        self.check_reload_required();
+
+        scrollable::scroll_to(
+            self.scroll_id.clone(),
+            self.scroll_viewport_state.absolute_offset(),
+        )
+    }
+
+    pub fn search_match(&mut self, forward: bool) -> Command<Message> {
+        let length = self.asset_manager.len();
+
+        if self.loading || length == 0 || self.search_terms.is_empty() {
+            return Command::none();
+        }
+
+        let start = match (self.cursor_row(), forward) {
+            (Some(index), true) => index + 1,
+            (Some(index), false) => index + length - 1,
+            (None, true) => 0,
+            (None, false) => length - 1,
+        };
+
+        for offset in 0..length {
+            let index = if forward {
+                (start + offset) % length
+            } else {
+                (start + length - (offset % length)) % length
+            };
+
+            let mut info = self.asset_manager.asset_info(index, 1);
+
+            if info.is_empty() {
+                continue;
+            }
+
+            let name = info.remove(0).0;
+
+            if !self
+                .search_terms
+                .iter()
+                .any(|term| name.contains(term.as_str()))
+            {
+                continue;
+            }
+
+            self.item_selection.clear();
+            self.item_selection.insert(index);
+
+            self.item_cursor = Some(index);
+            self.item_anchor = Some(index);
+
+            self.update_selection_status();
+            self.request_preview_asset();
+
+            return self.scroll_to_row(index);
+        }
+
+        Command::none()
+    }
+
+    /// Updates the aggregate status for a multi-selection, with the count per asset type and estimated export size.
+    ///
+    /// Every selected asset is visited, so this runs in the background and should only be called when the selection changes.
+    pub fn update_selection_status(&mut self) {
+        self.selection_status_id = self.selection_status_id.wrapping_add(1);
+
+        if self.item_selection.len() < 2 {
+            self.selection_status = None;
+            return;
+        }
+
+        self.selection_status = Some(format!(
+            "{} selected",
+            format_count(self.item_selection.len())
+        ));
+
+        let manager = self.asset_manager.clone();
+        let channel = self.channel.clone();
+        let settings = self.settings.clone();
+        let selection: Vec<usize> = self.item_selection.iter().copied().collect();
+        let request_id = self.selection_status_id;
+
+        porter_threads::spawn(move || {
+            let status = selection_status(manager.as_ref(), &settings, &selection);
+
+            if let Some(channel) = channel {
+                let _ = channel.unbounded_send(Message::SelectionStatus(request_id, status));
+            }
+        });
+    }
+
+    pub fn request_thumbnails(&mut self) {
+        if !self.thumbnails_enabled || self.loading {
+            return;
+        }
+
+        let missing = self.thumbnails.request(self.item_range.clone());
+
+        if missing.is_empty() {
+            return;
+        }
+
+        let manager = self.asset_manager.clone();
+        let channel = self.channel.clone();
+        let generation = self.thumbnails.generation();
+
+        // The pipeline waits on its own threads, so it's driven from a dedicated thread instead of the thread pool.
+        porter_threads::spawn_thread(move || {
+            Pipeline::new(missing, THUMBNAIL_THREADS)
+                .stage(THUMBNAIL_THREADS, move |index| {
+                    let handle = manager
+                        .asset_thumbnail(index)
+                        .map(|(width, height, pixels)| Handle::from_pixels(width, height, pixels));
+
+                    (index, handle)
+                })
+                .for_each(|(index, handle)| {
+                    if let Some(channel) = &channel {
+                        let _ =
+                            channel.unbounded_send(Message::Thumbnail(generation, index, handle));
+                    }
+                });
+        });
+    }
+
+    pub fn cursor_row(&self) -> Option<usize> {
+        self.item_cursor
+            .filter(|index| self.item_selection.contains(index))
+            .or_else(|| self.item_selection.first().copied())
+    }
+
+    pub fn rows_per_page(&self) -> usize {
+        ((self.scroll_viewport_size.height / (ROW_HEIGHT + ROW_PADDING)).floor() as usize).max(1)
+    }
+
+    pub fn navigate_to_row(&mut self, index: usize, extend: bool) -> Command<Message> {
+        if self.loading || self.asset_manager.is_empty() {
+            return Command::none();
+        }
+
+        let index = index.min(self.asset_manager.len() - 1);
+
+        if extend {
+            let anchor = self
+                .item_anchor
+                .filter(|anchor| self.item_selection.contains(anchor))
+                .or_else(|| self.cursor_row())
+                .unwrap_or(index);
+
+            self.item_selection.clear();
+            self.item_selection
+                .extend(anchor.min(index)..=anchor.max(index));
+
+            self.item_anchor = Some(anchor);
+        } else {
+            self.item_selection.clear();
+            self.item_selection.insert(index);
+
+            self.item_anchor = Some(index);
+        }
+
+        self.item_cursor = Some(index);
+        self.update_selection_status();
+        self.request_preview_asset();
+
+        self.ensure_row_visible(index)
+    }
+
+    pub fn ensure_row_visible(&mut self, index: usize) -> Command<Message> {
+        let size_of_item = ROW_HEIGHT + ROW_PADDING;
+        let viewport_height = self.scroll_viewport_size.height;
+
+        let top = index as f32 * size_of_item;
+        let bottom = top + size_of_item;
+
+        let offsets = self.scroll_viewport_state.absolute_offset();
+
+        // Scroll just enough to bring the row to the nearest edge of the viewport.
+        let y = if top < offsets.y {
+            top
+        } else if bottom > offsets.y + viewport_height {
+            (bottom - viewport_height).max(0.0)
+        } else {
+            return Command::none();
+        };
+
+        let item_start = (y / size_of_item).floor() as usize;
+        let item_end = (item_start + ROW_OVERSCAN).min(self.asset_manager.len());
+
+        self.item_range = item_start..item_end;
+
+        scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: offsets.x, y })
+    }
+
+    pub fn scroll_to_row(&mut self, index: usize) -> Command<Message> {
+        let size_of_item = ROW_HEIGHT + ROW_PADDING;
+        let viewport_height = self.scroll_viewport_size.height;
+
+        let top = index as f32 * size_of_item;
+
+        // Only scroll when the row isn't already fully visible.
+        let offsets = self.scroll_viewport_state.absolute_offset();
+
+        if top >= offsets.y && top + size_of_item <= offsets.y + viewport_height {
+            return Command::none();
+        }
+
+        let y = (top - (viewport_height / 2.0) + (size_of_item / 2.0)).max(0.0);
+
+        let item_start = (y / size_of_item).floor() as usize;
+        let item_end = (item_start + ROW_OVERSCAN).min(self.asset_manager.len());
+
+        self.item_range = item_start..item_end;
+
+        scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: offsets.x, y })
+    }
+
+    pub fn get_copy_text(&mut self, csv: bool) -> Option<String> {
         if self.loading || self.exporting {
             return None;
         }
@@ -158,15 +800,134 @@ impl PorterMain {
             return None;
         }
 
-        let buffer = self
-            .item_selection
+        let separator = if csv { "," } else { "\t" };
+
+        let escape = |value: &str| -> String {
+            if csv {
+                if value.contains([',', '"', '\n', '\r']) {
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                } else {
+                    value.to_string()
+                }
+            } else {
+                value.replace(['\t', '\n', '\r'], " ")
+            }
+        };
+
+        let mut lines = Vec::with_capacity(self.item_selection.len() + 1);
+
+        let columns: Vec<_> = self
+            .columns
             .iter()
-            .copied()
-            .map(|index| self.asset_manager.asset_info(index, 1))
-            .map(|mut info| info.remove(0).0)
-            .collect::<Vec<_>>()
-            .join("\n");
+            .filter(|column| column.visible)
+            .collect();
+
+        lines.push(
+            columns
+                .iter()
+                .map(|column| escape(&column.header))
+                .collect::<Vec<_>>()
+                .join(separator),
+        );
+
+        for index in self.item_selection.iter().copied() {
+            let info = self.asset_manager.asset_info(index, self.columns.len());
+
+            lines.push(
+                columns
+                    .iter()
+                    .map(|column| {
+                        info.get(column.index)
+                            .map(|(value, _)| escape(value))
+                            .unwrap_or_default()
+                    })
+                    .collect::<Vec<_>>()
+                    .join(separator),
+            );
+        }
+
+        Some(lines.join("\n"))
+    }
+
+    /// Saves the current column widths, order, and visibility to the settings.
+    pub fn save_column_layout(&mut self) {
+        self.settings
+            .set_column_layout(column_layout(&self.columns));
+        self.settings.save(self.name);
+    }
+}
+
+fn preview_capture_name(name: Option<&str>) -> String {
+    let name: String = name
+        .unwrap_or("preview")
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default();
+
+    format!("{}_{}", name, timestamp)
+}
+
+fn selection_status(
+    manager: &dyn PorterAssetManager,
+    settings: &PorterSettings,
+    selection: &[usize],
+) -> String {
+    let mut types: BTreeMap<String, usize> = BTreeMap::new();
+
+    for index in selection {
+        if let Some(asset_type) = manager.asset_metadata(*index).asset_type {
+            *types.entry(asset_type).or_default() += 1;
+        }
+    }
+
+    let mut status = format!("{} selected", format_count(selection.len()));
+
+    if !types.is_empty() {
+        let types: Vec<String> = types
+            .into_iter()
+            .map(|(asset_type, count)| format!("{} {}", format_count(count), asset_type))
+            .collect();
+
+        status.push_str(&format!(" ({})", types.join(", ")));
+    }
 
-        Some(buffer)
+    if let Some(size) = manager
+        .export_size(settings, selection)
+        .filter(|size| *size > 0)
+    {
+        status.push_str(&format!(" — ~{}", size.as_human_bytes()));
     }
+
+    status
+}
+
+fn save_preview_captures(folder: PathBuf, name: String, images: Vec<Image>) {
+    porter_threads::spawn_io(move || {
+        let dirs = std::fs::create_dir_all(&folder);
+
+        debug_assert!(dirs.is_ok());
+
+        for (index, image) in images.iter().enumerate() {
+            let file = if images.len() == 1 {
+                folder.join(&name)
+            } else {
+                folder.join(format!("{}_{:03}", name, index))
+            };
+
+            let result = image.save(file.with_extension("png"), ImageFileType::Png);
+
+            debug_assert!(result.is_ok());
+        }
+    });
 }
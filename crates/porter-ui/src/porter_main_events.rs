@@ -1,5 +1,6 @@
-use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::time::Duration;
 use std::time::Instant;
 
 use iced::futures::channel::mpsc::UnboundedSender;
@@ -13,6 +14,7 @@ use iced::mouse;
 use iced::mouse::ScrollDelta;
 
 use iced::widget::container;
+use iced::widget::image;
 use iced::widget::scrollable;
 use iced::widget::scrollable::AbsoluteOffset;
 use iced::widget::scrollable::Viewport;
@@ -23,33 +25,52 @@ use iced::Command;
 use iced::Event;
 use iced::Point;
 use iced::Rectangle;
+use iced::Size;
 
 use rfd::FileDialog;
 use rfd::MessageButtons;
 use rfd::MessageDialog;
+use rfd::MessageDialogResult;
 use rfd::MessageLevel;
 
 use directories::ProjectDirs;
 
+use porter_audio::AudioWaveform;
+
 use porter_preview::PreviewKeyState;
 use porter_preview::PreviewRenderer;
+use porter_preview::PreviewView;
+use porter_preview::PreviewZoom;
 
+use porter_utils::AsHumanBytes;
 use porter_utils::StringCaseExt;
 
+use crate::archive_crash_report;
+use crate::create_support_bundle;
+use crate::format_count;
 use crate::open_folder;
+use crate::read_crash_report;
+use crate::ColumnTruncation;
 use crate::Message;
+use crate::PorterExportFormat;
+use crate::PorterExportJournalAsset;
 use crate::PorterMain;
 use crate::PorterPreviewAsset;
 use crate::PorterSearch;
+use crate::PorterSession;
 use crate::PorterSettings;
 use crate::PorterViewport;
 use crate::PreviewControlScheme;
 
+use crate::AUDIO_WAVEFORM_PEAKS;
 use crate::COLUMN_MAX;
 use crate::COLUMN_MIN;
+use crate::CRASH_REPORT_PREVIEW_MAX;
 use crate::DOUBLE_CLICK_DURATION;
+use crate::LIST_WIDTH_MIN;
 use crate::PORTER_DONATE_URL;
 use crate::PORTER_SITE_URL;
+use crate::PREVIEW_WIDTH_MIN;
 use crate::ROW_HEIGHT;
 use crate::ROW_OVERSCAN;
 use crate::ROW_PADDING;
@@ -67,13 +88,11 @@ impl PorterMain {
             Event::Mouse(mouse::Event::ButtonPressed(button)) => self.on_mouse_button_press(button),
             Event::Mouse(mouse::Event::ButtonReleased(_)) => self.on_mouse_button_released(),
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => self.on_mouse_wheel(delta),
-            Event::Window(
-                _,
-                window::Event::Resized {
-                    width: _,
-                    height: _,
-                },
-            ) => self.on_window_resize(),
+            Event::Window(id, window::Event::Resized { width, height }) => {
+                self.on_window_resize(id, width, height)
+            }
+            Event::Window(id, window::Event::Moved { x, y }) => self.on_window_moved(id, x, y),
+            Event::Window(id, window::Event::CloseRequested) => self.on_window_close_requested(id),
             Event::Window(id, window::Event::FileDropped(file)) => self.on_file_dropped(id, file),
             Event::Window(id, window::Event::Opened { .. }) => self.on_window_opened(id),
             _ => Command::none(),
@@ -81,14 +100,23 @@ impl PorterMain {
     }
 
     pub fn on_key_pressed(&mut self, key: Key) -> Command<Message> {
-        if self.loading || self.exporting || self.show_settings || self.show_about {
+        if self.loading
+            || self.exporting
+            || self.show_settings
+            || self.show_about
+            || self.show_history
+        {
+            return Command::none();
+        }
+
+        if self.set_preview_fly_direction(&key, true) {
             return Command::none();
         }
 
         match key.as_ref() {
-            Key::Character("c") => {
+            Key::Character("c") | Key::Character("C") => {
                 if self.keyboard_modifiers.command() {
-                    if let Some(buffer) = self.get_copy_text() {
+                    if let Some(buffer) = self.get_copy_text(self.keyboard_modifiers.shift()) {
                         return iced::clipboard::write(buffer);
                     }
                 }
@@ -111,6 +139,41 @@ impl PorterMain {
                     return Command::batch([text_input::focus(self.search_id.clone()), read]);
                 }
             }
+            Key::Named(Named::ArrowUp) => {
+                let index = self
+                    .cursor_row()
+                    .map(|index| index.saturating_sub(1))
+                    .unwrap_or(0);
+
+                return self.navigate_to_row(index, self.keyboard_modifiers.shift());
+            }
+            Key::Named(Named::ArrowDown) => {
+                let index = self.cursor_row().map(|index| index + 1).unwrap_or(0);
+
+                return self.navigate_to_row(index, self.keyboard_modifiers.shift());
+            }
+            Key::Named(Named::PageUp) => {
+                let index = self
+                    .cursor_row()
+                    .map(|index| index.saturating_sub(self.rows_per_page()))
+                    .unwrap_or(0);
+
+                return self.navigate_to_row(index, self.keyboard_modifiers.shift());
+            }
+            Key::Named(Named::PageDown) => {
+                let index = self
+                    .cursor_row()
+                    .map(|index| index + self.rows_per_page())
+                    .unwrap_or(0);
+
+                return self.navigate_to_row(index, self.keyboard_modifiers.shift());
+            }
+            Key::Named(Named::Home) => {
+                return self.navigate_to_row(0, self.keyboard_modifiers.shift());
+            }
+            Key::Named(Named::End) => {
+                return self.navigate_to_row(usize::MAX, self.keyboard_modifiers.shift());
+            }
             _ => {
                 // Not used.
             }
@@ -120,6 +183,10 @@ impl PorterMain {
     }
 
     pub fn on_key_released(&mut self, key: Key) -> Command<Message> {
+        if self.set_preview_fly_direction(&key, false) {
+            return Command::none();
+        }
+
         match key.as_ref() {
             Key::Character("e") => {
                 self.export_selected();
@@ -132,6 +199,7 @@ impl PorterMain {
 
                 if self.previewer.is_some() {
                     self.previewer = None;
+                    self.preview_audio = None;
 
                     return container::visible_bounds(self.scroll_container_id.clone())
                         .map(Message::ScrollResize);
@@ -157,6 +225,16 @@ impl PorterMain {
                     previewer.toggle_bones();
                 }
             }
+            Key::Character("l") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_bone_names();
+                }
+            }
+            Key::Character("k") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_skeleton_only();
+                }
+            }
             Key::Character("w") => {
                 if let Some(previewer) = &mut self.previewer {
                     previewer.toggle_wireframe();
@@ -177,6 +255,59 @@ impl PorterMain {
                     previewer.cycle_material();
                 }
             }
+            Key::Character("o") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_material_sphere();
+                }
+            }
+            Key::Character("[") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.cycle_slice(false);
+                }
+            }
+            Key::Character("]") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.cycle_slice(true);
+                }
+            }
+            Key::Character("x") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_cube_cross();
+                }
+            }
+            Key::Character("i") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_pixel_probe();
+                    previewer.probe_pixel(
+                        self.mouse_position.x - self.preview_viewport_size.x,
+                        self.mouse_position.y - self.preview_viewport_size.y,
+                    );
+                }
+            }
+            Key::Character("1") => {
+                self.set_preview_view(PreviewView::Front, PreviewView::Back);
+
+                return self.on_preview_zoom(PreviewZoom::Actual);
+            }
+            Key::Character("2") => {
+                return self.on_preview_zoom(PreviewZoom::Fit);
+            }
+            Key::Character("3") => {
+                self.set_preview_view(PreviewView::Right, PreviewView::Left);
+
+                return self.on_preview_zoom(PreviewZoom::Fill);
+            }
+            Key::Character("7") => {
+                self.set_preview_view(PreviewView::Top, PreviewView::Bottom);
+            }
+            Key::Character("5") => {
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_projection();
+                }
+            }
+            Key::Character("t") if self.previewer.is_some() => {
+                return self.on_toggle_preview_always_on_top();
+            }
             Key::Character("f") => {
                 if self.keyboard_modifiers.command() {
                     return Command::batch([
@@ -184,28 +315,21 @@ impl PorterMain {
                         text_input::select_all(self.search_id.clone()),
                     ]);
                 }
-            }
-            Key::Named(Named::ArrowUp) => {
-                if let Some(index) = self.item_selection.first().cloned() {
-                    if index > 0 && self.item_selection.len() == 1 {
-                        self.item_selection.clear();
-                        self.item_selection.insert(index - 1);
-                        self.request_preview_asset();
-                    }
+
+                if let Some(previewer) = &mut self.previewer {
+                    previewer.toggle_fly();
                 }
             }
-            Key::Named(Named::ArrowDown) => {
-                if let Some(index) = self.item_selection.first().cloned() {
-                    if !self.asset_manager.is_empty()
-                        && index < self.asset_manager.len() - 1
-                        && self.item_selection.len() == 1
-                    {
-                        self.item_selection.clear();
-                        self.item_selection.insert(index + 1);
-                        self.request_preview_asset();
-                    }
+            Key::Named(Named::F12) => {
+                if self.keyboard_modifiers.shift() {
+                    self.capture_preview_turntable();
+                } else {
+                    self.capture_preview_screenshot();
                 }
             }
+            Key::Named(Named::F3) => {
+                return self.search_match(!self.keyboard_modifiers.shift());
+            }
             _ => {
                 // Not used.
             }
@@ -224,6 +348,17 @@ impl PorterMain {
         if !self.preview_viewport_size.contains(self.mouse_position) || self.previewer.is_none() {
             self.mouse_position = position;
 
+            if let Some(previewer) = &mut self.previewer {
+                previewer.probe_pixel(
+                    position.x - self.preview_viewport_size.x,
+                    position.y - self.preview_viewport_size.y,
+                );
+                previewer.hover_bone(
+                    position.x - self.preview_viewport_size.x,
+                    position.y - self.preview_viewport_size.y,
+                );
+            }
+
             return Command::none();
         }
 
@@ -241,6 +376,15 @@ impl PorterMain {
                     shift: self.keyboard_modifiers.shift(),
                 },
             );
+
+            previewer.probe_pixel(
+                position.x - self.preview_viewport_size.x,
+                position.y - self.preview_viewport_size.y,
+            );
+            previewer.hover_bone(
+                position.x - self.preview_viewport_size.x,
+                position.y - self.preview_viewport_size.y,
+            );
         }
 
         self.mouse_position = position;
@@ -259,7 +403,13 @@ impl PorterMain {
         };
 
         if let Some(previewer) = &mut self.previewer {
-            previewer.scroll_delta(delta);
+            previewer.scroll_delta(
+                delta,
+                (
+                    self.mouse_position.x - self.preview_viewport_size.x,
+                    self.mouse_position.y - self.preview_viewport_size.y,
+                ),
+            );
         }
 
         Command::none()
@@ -273,19 +423,79 @@ impl PorterMain {
 
     pub fn on_mouse_button_released(&mut self) -> Command<Message> {
         self.row_press = None;
+        self.column_press = None;
         self.mouse_button = None;
 
         Command::none()
     }
 
-    pub fn on_window_resize(&mut self) -> Command<Message> {
+    pub fn on_window_resize(
+        &mut self,
+        id: iced::window::Id,
+        width: u32,
+        height: u32,
+    ) -> Command<Message> {
+        if id != iced::window::Id::MAIN {
+            return self.measure_viewports();
+        }
+
+        self.window_size = Size::new(width as f32, height as f32);
+
+        // Window events are scaled by the interface scale, but windows are created without it.
+        self.window_restore_size = Size::new(
+            self.window_size.width * self.window_scale(),
+            self.window_size.height * self.window_scale(),
+        );
+
+        // The size is only saved once it's known whether the window was maximized.
         Command::batch([
-            container::visible_bounds(self.scroll_container_id.clone()).map(Message::ScrollResize),
-            container::visible_bounds(self.previewer_container_id.clone())
-                .map(Message::PreviewResize),
+            self.measure_viewports(),
+            iced::window::fetch_maximized(id, Message::WindowMaximized),
         ])
     }
 
+    pub fn on_window_moved(&mut self, id: iced::window::Id, x: i32, y: i32) -> Command<Message> {
+        if id != iced::window::Id::MAIN {
+            return Command::none();
+        }
+
+        self.window_position = Some(Point::new(
+            x as f32 * self.window_scale(),
+            y as f32 * self.window_scale(),
+        ));
+
+        iced::window::fetch_maximized(id, Message::WindowMaximized)
+    }
+
+    pub fn on_window_maximized(&mut self, maximized: bool) -> Command<Message> {
+        self.window_state.maximized = maximized;
+
+        // A maximized window keeps the size and position it's restored to.
+        if !maximized {
+            self.window_state
+                .set_bounds(self.window_restore_size, self.window_position);
+        }
+
+        Command::none()
+    }
+
+    pub fn on_window_close_requested(&mut self, id: iced::window::Id) -> Command<Message> {
+        if id != iced::window::Id::MAIN {
+            return Command::none();
+        }
+
+        self.settings.set_window_state(self.window_state);
+        self.settings.save(self.name);
+
+        match self.splash_id.take() {
+            Some(splash_id) => Command::batch([
+                iced::window::close(splash_id),
+                iced::window::close(iced::window::Id::MAIN),
+            ]),
+            None => iced::window::close(iced::window::Id::MAIN),
+        }
+    }
+
     pub fn on_file_dropped(&mut self, id: iced::window::Id, file: PathBuf) -> Command<Message> {
         if id != iced::window::Id::MAIN {
             return Command::none();
@@ -316,7 +526,7 @@ impl PorterMain {
 
             use raw_window_handle::RawWindowHandle;
 
-            iced::window::run_with_handle(id, |handle| {
+            iced::window::run_with_handle(id, move |handle| {
                 let icon = crate::windows_icon();
 
                 if let RawWindowHandle::Win32(handle) = handle.as_raw() {
@@ -336,6 +546,10 @@ impl PorterMain {
                             icon as LPARAM,
                         )
                     };
+
+                    if id == iced::window::Id::MAIN {
+                        crate::porter_window_ensure_visible(handle.hwnd.get() as _);
+                    }
                 }
 
                 Message::Noop
@@ -416,11 +630,44 @@ impl PorterMain {
 
         if let Some(previewer) = &mut self.previewer {
             if let Some(asset) = asset {
+                self.preview_audio = None;
+
                 match asset {
+                    PorterPreviewAsset::Audio(name, audio) => {
+                        previewer.clear_preview();
+
+                        self.preview_audio_position = 0.0;
+                        self.preview_audio_waveform = None;
+
+                        let waveform_audio = audio.clone();
+                        let channel = self.channel.clone();
+
+                        porter_threads::spawn(move || {
+                            let waveform =
+                                AudioWaveform::from_audio(&waveform_audio, AUDIO_WAVEFORM_PEAKS);
+
+                            if let Some(channel) = &channel {
+                                let _ = channel.unbounded_send(Message::PreviewAudioWaveform(
+                                    request_id, waveform,
+                                ));
+                            }
+                        });
+
+                        self.preview_audio = Some((name, audio));
+
+                        return Command::none();
+                    }
                     PorterPreviewAsset::Model(name, model, materials) => {
                         previewer.set_preview(name, (model, materials));
                     }
+                    PorterPreviewAsset::ModelMaterials(name, model, materials) => {
+                        previewer.set_preview(name, (model, materials));
+                    }
                     PorterPreviewAsset::Image(name, image) => {
+                        previewer.set_tone_mapping(
+                            self.settings.image_tone_mapping(),
+                            self.settings.image_exposure(),
+                        );
                         previewer.set_preview(name, image);
                     }
                     PorterPreviewAsset::Material(name, images) => {
@@ -433,6 +680,28 @@ impl PorterMain {
         Command::none()
     }
 
+    pub fn on_preview_drag(&mut self, offset: f32) -> Command<Message> {
+        let width = self
+            .window_state
+            .preview_width
+            .unwrap_or(self.preview_viewport_size.width);
+
+        self.window_state.preview_width = Some(width - offset);
+
+        Command::none()
+    }
+
+    pub fn on_preview_drag_end(&mut self) -> Command<Message> {
+        if let Some(width) = &mut self.window_state.preview_width {
+            *width = width.clamp(
+                PREVIEW_WIDTH_MIN,
+                (self.window_size.width - LIST_WIDTH_MIN).max(PREVIEW_WIDTH_MIN),
+            );
+        }
+
+        self.measure_viewports()
+    }
+
     pub fn on_preview_resize(&mut self, viewport: Option<Rectangle>) -> Command<Message> {
         if let Some(viewport) = viewport {
             self.preview_viewport_size = viewport;
@@ -451,111 +720,120 @@ impl PorterMain {
 
     pub fn on_close_preview(&mut self) -> Command<Message> {
         self.previewer = None;
+        self.preview_audio = None;
+
+        if self.preview_always_on_top {
+            return self.on_toggle_preview_always_on_top();
+        }
 
         Command::none()
     }
 
-    pub fn on_close_splash(&mut self) -> Command<Message> {
-        if let Some(splash_id) = self.splash_id.take() {
-            Command::batch([
-                iced::window::close(splash_id),
-                iced::window::change_mode(iced::window::Id::MAIN, window::Mode::Windowed),
-            ])
-        } else {
-            Command::none()
+    pub fn on_preview_zoom(&mut self, zoom: PreviewZoom) -> Command<Message> {
+        if let Some(previewer) = &mut self.previewer {
+            previewer.set_zoom(zoom);
         }
+
+        Command::none()
     }
 
-    pub fn on_update_splash(&mut self, splash_animation: f32) -> Command<Message> {
-        self.splash_animation = splash_animation;
+    pub fn on_preview_slice(&mut self, slice: usize) -> Command<Message> {
+        if let Some(previewer) = &mut self.previewer {
+            previewer.set_slice(slice);
+        }
 
         Command::none()
     }
 
-    pub fn on_sync(&mut self, exporting: bool, progress: u32) -> Command<Message> {
-        self.exporting = exporting;
-        self.export_progress = progress;
-
-        self.check_reload_required();
+    pub fn on_preview_fly(&mut self) -> Command<Message> {
+        if let Some(previewer) = &mut self.previewer {
+            previewer.fly_update();
+        }
 
         Command::none()
     }
 
-    pub fn on_row_press(&mut self, index: usize) -> Command<Message> {
-        self.row_press = Some(index);
+    pub fn on_preview_audio_waveform(
+        &mut self,
+        request_id: u64,
+        waveform: AudioWaveform,
+    ) -> Command<Message> {
+        if request_id == self.preview_request_id && self.preview_audio.is_some() {
+            self.preview_audio_waveform = Some(waveform);
+        }
 
         Command::none()
     }
 
-    pub fn on_row_release(&mut self, index: usize) -> Command<Message> {
-        if let Some(press_index) = self.row_press.take() {
-            if press_index == index {
-                if self.row_press_last.elapsed() < DOUBLE_CLICK_DURATION && !self.exporting {
-                    self.export_asset(index);
-                } else if self.keyboard_modifiers.command() {
-                    if self.item_selection.contains(&index) {
-                        self.item_selection.remove(&index);
-                    } else {
-                        self.item_selection.insert(index);
-                    }
-                } else if self.keyboard_modifiers.shift() {
-                    if let Some(first) = self.item_selection.first() {
-                        match index.cmp(first) {
-                            Ordering::Less => {
-                                for i in index..*first {
-                                    self.item_selection.insert(i);
-                                }
-                            }
-                            Ordering::Greater => {
-                                for i in *first..=index {
-                                    self.item_selection.insert(i);
-                                }
-                            }
-                            Ordering::Equal => {
-                                self.item_selection.insert(index);
-                            }
-                        }
-                    } else if self.item_selection.contains(&index) {
-                        self.item_selection.remove(&index);
-                    } else {
-                        self.item_selection.insert(index);
-                    }
-                } else {
-                    self.item_selection.clear();
-                    self.item_selection.insert(index);
-                    self.request_preview_asset();
-
-                    self.row_press_last = Instant::now();
-                }
-            }
+    pub fn on_preview_audio_seek(&mut self, position: f32) -> Command<Message> {
+        if self.preview_audio.is_some() {
+            self.preview_audio_position = position.clamp(0.0, 1.0);
         }
 
         Command::none()
     }
 
-    pub fn on_load_file(&mut self) -> Command<Message> {
-        let mut file_dialog = FileDialog::new();
+    pub fn on_toggle_preview_audio_loop(&mut self) -> Command<Message> {
+        self.preview_audio_loop = !self.preview_audio_loop;
 
-        for filter in &self.file_filters {
-            file_dialog = file_dialog.add_filter(&filter.0, &filter.1);
+        Command::none()
+    }
+
+    pub fn on_toggle_preview_always_on_top(&mut self) -> Command<Message> {
+        self.preview_always_on_top = !self.preview_always_on_top;
+
+        iced::window::change_level(
+            iced::window::Id::MAIN,
+            if self.preview_always_on_top {
+                window::Level::AlwaysOnTop
+            } else {
+                window::Level::Normal
+            },
+        )
+    }
+
+    pub fn on_close_splash(&mut self) -> Command<Message> {
+        if let Some(splash_id) = self.splash_id.take() {
+            Command::batch([
+                iced::window::close(splash_id),
+                iced::window::change_mode(iced::window::Id::MAIN, window::Mode::Windowed),
+                iced::window::maximize(iced::window::Id::MAIN, self.window_state.maximized),
+                self.check_crash_report(),
+            ])
+        } else {
+            Command::none()
+        }
+    }
+
+    pub fn check_crash_report(&mut self) -> Command<Message> {
+        if read_crash_report(self.name, false).is_none() {
+            return Command::none();
         }
 
-        let multi_file = self.multi_file;
+        archive_crash_report(self.name);
+
+        self.crash_report_available = true;
+
+        let title = self.name.to_titlecase();
 
         let Some(channel) = self.channel.clone() else {
             return Command::none();
         };
 
         iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
-            let file_dialog = file_dialog.set_parent(handle);
+            let dialog = MessageDialog::new()
+                .set_title(title.clone())
+                .set_description(format!(
+                    "{} closed unexpectedly the last time it ran.\n\nDo you want to view the crash report?",
+                    title
+                ))
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::YesNo)
+                .set_parent(handle);
 
             let dialog = move || {
-                if multi_file {
-                    if let Some(files) = file_dialog.pick_files() {
-                        let _ = channel.unbounded_send(Message::LoadFiles(files));
-                    }
-                } else if let Some(file) = file_dialog.pick_file() {
-                    let _ = channel.unbounded_send(Message::LoadFiles(vec![file]));
+                if matches!(dialog.show(), MessageDialogResult::Yes) {
+                    let _ = channel.unbounded_send(Message::ViewCrashReport);
                 }
             };
 
@@ -569,40 +847,537 @@ impl PorterMain {
         })
     }
 
-    pub fn on_load_files(&mut self, files: Vec<PathBuf>) -> Command<Message> {
-        self.load_files(files);
+    pub fn on_view_crash_report(&mut self) -> Command<Message> {
+        let Some(report) = read_crash_report(self.name, true) else {
+            return Command::none();
+        };
 
-        Command::none()
-    }
+        let title = self.name.to_titlecase();
 
-    pub fn on_load_file_dropped(&mut self) -> Command<Message> {
-        if self.exporting || self.loading {
+        let Some(channel) = self.channel.clone() else {
             return Command::none();
-        }
+        };
 
-        // We need to only take the files which match a filter in the application.
-        // Then load those files only, to prevent issues with the asset manager.
-        let mut target_extension: Option<String> = None;
+        // Backtraces can be very long, the full report is still copied.
+        let mut description: String = report.chars().take(CRASH_REPORT_PREVIEW_MAX).collect();
 
-        'outer: for filter in &self.file_filters {
-            for file in &self.file_dropped {
-                if let Some(extension) = file.extension() {
-                    let extension = extension.to_string_lossy().to_string();
+        if description.len() < report.len() {
+            description.push_str("\n...");
+        }
 
-                    if filter.1.contains(&extension) {
-                        target_extension = Some(extension);
-                        break 'outer;
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(format!("{} - Last Crash", title))
+                .set_description(description)
+                .set_level(MessageLevel::Info)
+                .set_buttons(MessageButtons::OkCancelCustom(
+                    String::from("Copy Report"),
+                    String::from("Close"),
+                ))
+                .set_parent(handle);
+
+            let dialog = move || {
+                if let MessageDialogResult::Custom(button) = dialog.show() {
+                    if button == "Copy Report" {
+                        let _ = channel.unbounded_send(Message::CopyCrashReport(report));
                     }
                 }
-            }
-        }
+            };
 
-        let Some(target_extension) = target_extension else {
-            self.file_dropped.clear();
-            self.file_dropped.shrink_to_fit();
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
 
-            return Command::none();
-        };
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_copy_crash_report(&mut self, report: String) -> Command<Message> {
+        iced::clipboard::write(report)
+    }
+
+    pub fn on_update_splash(&mut self, splash_animation: f32) -> Command<Message> {
+        self.splash_animation = splash_animation;
+
+        Command::none()
+    }
+
+    pub fn on_sync(&mut self, exporting: bool, progress: u32) -> Command<Message> {
+        if self.exporting && !exporting {
+            if self.export_cancel {
+                log::info!("Export cancelled");
+            } else {
+                log::info!("Export finished");
+            }
+
+            self.finish_export_history();
+            self.finish_export_database();
+            self.finish_export_journal();
+        }
+
+        self.exporting = exporting;
+        self.export_progress = progress;
+
+        self.check_reload_required();
+
+        if !exporting {
+            if let Some(report) = self.sample_report.take() {
+                return self.show_message(report);
+            }
+        }
+
+        Command::none()
+    }
+
+    pub fn on_sync_progress(
+        &mut self,
+        exporting: bool,
+        progress: u32,
+        completed: usize,
+        total: usize,
+        remaining: Option<Duration>,
+    ) -> Command<Message> {
+        self.export_completed = completed;
+        self.export_total = total;
+        self.export_remaining = remaining;
+
+        self.on_sync(exporting, progress)
+    }
+
+    pub fn on_export_completed(&mut self, assets: Vec<usize>) -> Command<Message> {
+        let journal: Vec<PorterExportJournalAsset> = assets
+            .iter()
+            .filter_map(|index| self.export_journal_assets.get(index).cloned())
+            .collect();
+
+        self.export_journal.complete(&journal);
+
+        if let Some(fingerprint) = self.export_database_pending {
+            for index in assets {
+                if let Some((name, hash)) = self.export_identity(index) {
+                    self.export_database.insert(name, hash, fingerprint);
+                }
+            }
+        }
+
+        Command::none()
+    }
+
+    pub fn on_export_resume(&mut self, assets: Vec<usize>) -> Command<Message> {
+        self.export_assets(assets, false);
+
+        Command::none()
+    }
+
+    pub fn on_export_resume_discard(&mut self) -> Command<Message> {
+        self.export_journal.finish();
+
+        Command::none()
+    }
+
+    pub fn check_export_journal(&mut self) -> Command<Message> {
+        if self.exporting {
+            return Command::none();
+        }
+
+        let Some(source) = self.export_source() else {
+            return Command::none();
+        };
+
+        let fingerprint = self.settings.export_fingerprint();
+
+        // Only the journaled rows are checked, and each must still be the same asset.
+        let assets: Vec<usize> = self
+            .export_journal
+            .remaining(&source, fingerprint)
+            .into_iter()
+            .filter(|asset| {
+                asset.index < self.asset_manager.len()
+                    && self.asset_manager.asset_hash(asset.index) == asset.hash
+            })
+            .map(|asset| asset.index)
+            .collect();
+
+        // The journal may be from assets that aren't loaded, so it's kept until they are.
+        if assets.is_empty() {
+            return Command::none();
+        }
+
+        let title = self.name.to_titlecase();
+
+        let Some(channel) = self.channel.clone() else {
+            return Command::none();
+        };
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(format!(
+                    "A previous export didn't finish, {} assets were not exported.\n\nDo you want to resume the export?",
+                    format_count(assets.len())
+                ))
+                .set_level(MessageLevel::Info)
+                .set_buttons(MessageButtons::YesNo)
+                .set_parent(handle);
+
+            let dialog = move || {
+                let result = if matches!(dialog.show(), MessageDialogResult::Yes) {
+                    channel.unbounded_send(Message::ExportResume(assets))
+                } else {
+                    channel.unbounded_send(Message::ExportResumeDiscard)
+                };
+
+                debug_assert!(result.is_ok());
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_export_skipped(&mut self, skipped: usize) -> Command<Message> {
+        self.export_skipped = skipped;
+
+        Command::none()
+    }
+
+    pub fn on_export_hook_failed(&mut self, failures: Vec<String>) -> Command<Message> {
+        for failure in &failures {
+            log::warn!("Post export command failed: {}", failure);
+        }
+
+        let title = self.name.to_titlecase();
+
+        let mut description = format!(
+            "The post export command failed {} time(s):\n\n",
+            failures.len()
+        );
+
+        for failure in failures.iter().take(10) {
+            description.push_str(failure);
+            description.push('\n');
+        }
+
+        if failures.len() > 10 {
+            description.push_str(&format!("...and {} more.", failures.len() - 10));
+        }
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(description)
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::Ok)
+                .set_parent(handle);
+
+            let dialog = move || {
+                dialog.show();
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_export_validation_failed(&mut self, failures: Vec<String>) -> Command<Message> {
+        for failure in &failures {
+            log::warn!("Model failed validation: {}", failure);
+        }
+
+        let title = self.name.to_titlecase();
+
+        let mut description = format!(
+            "{} model(s) were not exported because they contain corrupt data:\n\n",
+            failures.len()
+        );
+
+        for failure in failures.iter().take(10) {
+            description.push_str(failure);
+            description.push('\n');
+        }
+
+        if failures.len() > 10 {
+            description.push_str(&format!("...and {} more.", failures.len() - 10));
+        }
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(description)
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::Ok)
+                .set_parent(handle);
+
+            let dialog = move || {
+                dialog.show();
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn show_message(&mut self, description: String) -> Command<Message> {
+        let title = self.name.to_titlecase();
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(description)
+                .set_level(MessageLevel::Info)
+                .set_buttons(MessageButtons::Ok)
+                .set_parent(handle);
+
+            let dialog = move || {
+                dialog.show();
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_row_press(&mut self, index: usize) -> Command<Message> {
+        self.row_press = Some(index);
+
+        Command::none()
+    }
+
+    pub fn on_row_release(&mut self, index: usize) -> Command<Message> {
+        if let Some(press_index) = self.row_press.take() {
+            if press_index == index {
+                if self.row_press_last.elapsed() < DOUBLE_CLICK_DURATION && !self.exporting {
+                    self.export_asset(index);
+                } else if self.keyboard_modifiers.command() {
+                    if self.item_selection.contains(&index) {
+                        self.item_selection.remove(&index);
+                    } else {
+                        self.item_selection.insert(index);
+                    }
+
+                    self.item_cursor = Some(index);
+                    self.item_anchor = Some(index);
+                } else if self.keyboard_modifiers.shift() {
+                    self.item_cursor = Some(index);
+
+                    if let Some(anchor) = self.item_anchor.or(self.item_selection.first().copied())
+                    {
+                        self.item_selection
+                            .extend(anchor.min(index)..=anchor.max(index));
+
+                        self.item_anchor = Some(anchor);
+                    } else if self.item_selection.contains(&index) {
+                        self.item_selection.remove(&index);
+                    } else {
+                        self.item_selection.insert(index);
+                    }
+                } else {
+                    self.item_selection.clear();
+                    self.item_selection.insert(index);
+                    self.request_preview_asset();
+
+                    self.item_cursor = Some(index);
+                    self.item_anchor = Some(index);
+
+                    self.row_press_last = Instant::now();
+                }
+            }
+        }
+
+        self.update_selection_status();
+
+        Command::none()
+    }
+
+    pub fn on_row_context_menu(&mut self, index: usize) -> Command<Message> {
+        if self.loading || index >= self.asset_manager.len() {
+            return Command::none();
+        }
+
+        if !self.item_selection.contains(&index) {
+            self.item_selection.clear();
+            self.item_selection.insert(index);
+            self.update_selection_status();
+            self.request_preview_asset();
+        }
+
+        self.row_press = None;
+        self.column_menu = None;
+        self.context_menu = Some((index, self.mouse_position));
+        self.context_menu_formats = false;
+
+        Command::none()
+    }
+
+    pub fn on_close_context_menu(&mut self) -> Command<Message> {
+        self.context_menu = None;
+        self.column_menu = None;
+
+        Command::none()
+    }
+
+    pub fn on_context_menu_export_as(&mut self, formats: bool) -> Command<Message> {
+        self.context_menu_formats = formats;
+
+        Command::none()
+    }
+
+    pub fn on_export_as(&mut self, format: PorterExportFormat) -> Command<Message> {
+        let assets: Vec<usize> = self.item_selection.iter().copied().collect();
+
+        if !assets.is_empty() {
+            self.export_assets_as(assets, format);
+        }
+
+        Command::none()
+    }
+
+    pub fn on_copy_asset_name(&mut self, index: usize) -> Command<Message> {
+        self.context_menu = None;
+
+        let mut info = self.asset_manager.asset_info(index, 1);
+
+        if info.is_empty() {
+            return Command::none();
+        }
+
+        iced::clipboard::write(info.remove(0).0)
+    }
+
+    pub fn on_copy_asset_hash(&mut self, index: usize) -> Command<Message> {
+        self.context_menu = None;
+
+        match self.asset_manager.asset_hash(index) {
+            Some(hash) => iced::clipboard::write(hash),
+            None => Command::none(),
+        }
+    }
+
+    pub fn on_preview_asset(&mut self, index: usize) -> Command<Message> {
+        self.context_menu = None;
+
+        if !self.preview_enabled {
+            return Command::none();
+        }
+
+        self.item_selection.clear();
+        self.item_selection.insert(index);
+        self.update_selection_status();
+
+        if self.previewer.is_some() {
+            self.request_preview_asset();
+
+            return Command::none();
+        }
+
+        self.previewer = Some(PreviewRenderer::new());
+        self.request_preview_asset();
+
+        Command::batch([
+            container::visible_bounds(self.scroll_container_id.clone()).map(Message::ScrollResize),
+            container::visible_bounds(self.previewer_container_id.clone())
+                .map(Message::PreviewResize),
+        ])
+    }
+
+    pub fn on_show_export_folder(&mut self) -> Command<Message> {
+        self.context_menu = None;
+
+        self.on_open_export_folder()
+    }
+
+    pub fn on_load_file(&mut self) -> Command<Message> {
+        let mut file_dialog = FileDialog::new();
+
+        for filter in &self.file_filters {
+            file_dialog = file_dialog.add_filter(&filter.0, &filter.1);
+        }
+
+        let multi_file = self.multi_file;
+
+        let Some(channel) = self.channel.clone() else {
+            return Command::none();
+        };
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let file_dialog = file_dialog.set_parent(handle);
+
+            let dialog = move || {
+                if multi_file {
+                    if let Some(files) = file_dialog.pick_files() {
+                        let _ = channel.unbounded_send(Message::LoadFiles(files));
+                    }
+                } else if let Some(file) = file_dialog.pick_file() {
+                    let _ = channel.unbounded_send(Message::LoadFiles(vec![file]));
+                }
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_load_files(&mut self, files: Vec<PathBuf>) -> Command<Message> {
+        self.load_files(files);
+
+        Command::none()
+    }
+
+    pub fn on_load_file_dropped(&mut self) -> Command<Message> {
+        if self.exporting || self.loading {
+            return Command::none();
+        }
+
+        // We need to only take the files which match a filter in the application.
+        // Then load those files only, to prevent issues with the asset manager.
+        let mut target_extension: Option<String> = None;
+
+        'outer: for filter in &self.file_filters {
+            for file in &self.file_dropped {
+                if let Some(extension) = file.extension() {
+                    let extension = extension.to_string_lossy().to_string();
+
+                    if filter.1.contains(&extension) {
+                        target_extension = Some(extension);
+                        break 'outer;
+                    }
+                }
+            }
+        }
+
+        let Some(target_extension) = target_extension else {
+            self.file_dropped.clear();
+            self.file_dropped.shrink_to_fit();
+
+            return Command::none();
+        };
 
         let files_to_load: Vec<_> = self
             .file_dropped
@@ -625,8 +1400,24 @@ impl PorterMain {
         Command::none()
     }
 
-    pub fn on_load_game(&mut self) -> Command<Message> {
-        self.load_game();
+    pub fn on_load_game(&mut self) -> Command<Message> {
+        self.load_game();
+
+        Command::none()
+    }
+
+    pub fn on_load_published(&mut self, published: usize) -> Command<Message> {
+        if !self.loading {
+            return Command::none();
+        }
+
+        self.loading_published = published;
+
+        // Only the visible rows change, the asset list is searched again once loading finishes.
+        let item_start = self.item_range.start;
+        let item_end = (item_start + ROW_OVERSCAN).min(self.asset_manager.len());
+
+        self.item_range = item_start.min(item_end)..item_end;
 
         Command::none()
     }
@@ -635,9 +1426,12 @@ impl PorterMain {
         self.loading = false;
 
         self.search_value = String::new();
+        self.search_terms.clear();
         self.item_selection.clear();
+        self.update_selection_status();
 
         self.asset_manager.search_assets(None);
+        self.thumbnails.clear();
 
         self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
         self.scroll_viewport_state = PorterViewport::zero();
@@ -645,6 +1439,8 @@ impl PorterMain {
         self.check_reload_required();
 
         if let Err(e) = result {
+            log::error!("Failed to load: {}", e);
+
             let title = self.name.to_titlecase();
 
             Command::batch([
@@ -671,12 +1467,111 @@ impl PorterMain {
                 scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 }),
             ])
         } else {
-            scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 })
+            self.watch_game_process();
+
+            Command::batch([
+                self.check_export_journal(),
+                scrollable::scroll_to(self.scroll_id.clone(), AbsoluteOffset { x: 0.0, y: 0.0 }),
+            ])
+        }
+    }
+
+    pub fn on_game_exited(&mut self, pid: u64) -> Command<Message> {
+        // Exits from a previously loaded game are ignored, and other sessions show theirs once selected.
+        if self.game_process != Some(pid) {
+            for session in self.sessions.iter_mut().flatten() {
+                session.game_exited(pid);
+            }
+
+            return Command::none();
+        }
+
+        log::info!("Game process {} exited", pid);
+
+        self.game_process = None;
+        self.game_exited = true;
+
+        let title = self.name.to_titlecase();
+
+        let Some(channel) = self.channel.clone() else {
+            return Command::none();
+        };
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description("The game has exited, the loaded assets can no longer be previewed or exported.\n\nDo you want to load the game again?")
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::YesNo)
+                .set_parent(handle);
+
+            let dialog = move || {
+                if matches!(dialog.show(), MessageDialogResult::Yes) {
+                    let result = channel.unbounded_send(Message::LoadGame);
+
+                    debug_assert!(result.is_ok());
+                }
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_new_session(&mut self) -> Command<Message> {
+        if self.loading || self.exporting {
+            return Command::none();
+        }
+
+        let Some(factory) = &self.session_factory else {
+            return Command::none();
+        };
+
+        self.sessions.push(Some(PorterSession::new(factory())));
+
+        self.switch_session(self.sessions.len() - 1)
+    }
+
+    pub fn on_select_session(&mut self, index: usize) -> Command<Message> {
+        if self.loading || self.exporting || index == self.session_active {
+            return Command::none();
+        }
+
+        self.switch_session(index)
+    }
+
+    pub fn on_close_session(&mut self, index: usize) -> Command<Message> {
+        if self.loading
+            || self.exporting
+            || self.sessions.len() <= 1
+            || index >= self.sessions.len()
+        {
+            return Command::none();
+        }
+
+        let command = if index == self.session_active {
+            self.switch_session(if index == 0 { 1 } else { index - 1 })
+        } else {
+            Command::none()
+        };
+
+        self.sessions.remove(index);
+
+        if index < self.session_active {
+            self.session_active -= 1;
         }
+
+        command
     }
 
     pub fn on_search_input(&mut self, input: String) -> Command<Message> {
         self.search_value = input;
+        self.search_terms = PorterSearch::compile(self.search_value.clone()).contained_names();
 
         if self.asset_manager.loaded_len() > SEARCH_REALTIME_MAX && !self.search_value.is_empty() {
             Command::none()
@@ -688,8 +1583,10 @@ impl PorterMain {
     pub fn on_search_clear(&mut self) -> Command<Message> {
         self.search_value = String::new();
         self.item_selection.clear();
+        self.update_selection_status();
 
         self.asset_manager.search_assets(None);
+        self.thumbnails.clear();
 
         self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
         self.scroll_viewport_state = PorterViewport::zero();
@@ -699,10 +1596,12 @@ impl PorterMain {
 
     pub fn on_search_submit(&mut self) -> Command<Message> {
         self.item_selection.clear();
+        self.update_selection_status();
 
         let search = PorterSearch::compile(self.search_value.clone());
 
         self.asset_manager.search_assets(Some(search));
+        self.thumbnails.clear();
 
         self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
         self.scroll_viewport_state = PorterViewport::zero();
@@ -712,6 +1611,7 @@ impl PorterMain {
 
     pub fn on_cancel_export(&mut self) -> Command<Message> {
         self.export_cancel = true;
+        self.export_scope.cancel();
 
         self.asset_manager.cancel_export();
 
@@ -732,6 +1632,7 @@ impl PorterMain {
 
     pub fn on_toggle_settings(&mut self) -> Command<Message> {
         self.show_about = false;
+        self.show_history = false;
         self.show_settings = !self.show_settings;
 
         self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
@@ -753,6 +1654,7 @@ impl PorterMain {
 
     pub fn on_toggle_about(&mut self) -> Command<Message> {
         self.show_settings = false;
+        self.show_history = false;
         self.show_about = !self.show_about;
 
         self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
@@ -770,6 +1672,89 @@ impl PorterMain {
         }
     }
 
+    pub fn on_toggle_history(&mut self) -> Command<Message> {
+        self.show_settings = false;
+        self.show_about = false;
+        self.show_history = !self.show_history;
+
+        self.item_range = 0..ROW_OVERSCAN.min(self.asset_manager.len());
+        self.scroll_viewport_state = PorterViewport::zero();
+
+        if !self.show_history {
+            Command::batch([
+                container::visible_bounds(self.scroll_container_id.clone())
+                    .map(Message::ScrollResize),
+                container::visible_bounds(self.previewer_container_id.clone())
+                    .map(Message::PreviewResize),
+            ])
+        } else {
+            Command::none()
+        }
+    }
+
+    pub fn on_open_history_folder(&mut self, index: usize) -> Command<Message> {
+        if let Some(entry) = self.export_history.entries().get(index) {
+            open_folder(&entry.output_directory);
+        }
+
+        Command::none()
+    }
+
+    pub fn on_export_history(&mut self, index: usize) -> Command<Message> {
+        if self.loading || self.exporting {
+            return Command::none();
+        }
+
+        let Some(entry) = self.export_history.entries().get(index).cloned() else {
+            return Command::none();
+        };
+
+        let mut commands = vec![self.on_toggle_history()];
+
+        if !self.search_value.is_empty() {
+            commands.push(self.on_search_clear());
+        }
+
+        let names: HashSet<&str> = entry.assets.iter().map(String::as_str).collect();
+
+        let assets: Vec<usize> = (0..self.asset_manager.len())
+            .filter(|index| {
+                self.asset_manager
+                    .asset_info(*index, 1)
+                    .first()
+                    .is_some_and(|(name, _)| names.contains(name.as_str()))
+            })
+            .collect();
+
+        if assets.is_empty() {
+            commands.push(self.show_message(String::from(
+                "None of the assets from this export are currently loaded.",
+            )));
+        } else {
+            // The export runs again with the current settings, switched to the profile and folder it used.
+            let settings = self.settings.update(|settings| {
+                if let Some(profile) = &entry.profile {
+                    settings.apply_profile(profile);
+                }
+
+                settings.set_output_directory(entry.output_directory.clone());
+            });
+
+            self.item_selection = assets.iter().copied().collect();
+            self.update_selection_status();
+            self.export_assets_with(assets, false, settings);
+        }
+
+        Command::batch(commands)
+    }
+
+    pub fn on_clear_history(&mut self) -> Command<Message> {
+        self.export_history.clear();
+        self.export_history.save(self.name);
+
+        Command::none()
+    }
+
     pub fn on_export_selected(&mut self) -> Command<Message> {
         self.export_selected();
 
@@ -777,19 +1762,123 @@ impl PorterMain {
     }
 
     pub fn on_export_all(&mut self) -> Command<Message> {
-        self.export_all();
+        let unchanged = self.export_all(self.keyboard_modifiers.shift());
+
+        if unchanged > 0 && unchanged == self.asset_manager.len() {
+            return self.show_message(format!(
+                "All {} assets were already exported with the current settings.\n\nHold shift while clicking Export All to export them again.",
+                format_count(unchanged)
+            ));
+        }
+
+        Command::none()
+    }
+
+    pub fn on_clear_export_database(&mut self) -> Command<Message> {
+        self.export_database.clear();
+        self.export_database.save(self.name);
+
+        Command::none()
+    }
+
+    pub fn on_export_space_warning(
+        &mut self,
+        assets: Vec<usize>,
+        settings: PorterSettings,
+        required: u64,
+        available: u64,
+    ) -> Command<Message> {
+        let title = self.name.to_titlecase();
+
+        let Some(channel) = self.channel.clone() else {
+            return Command::none();
+        };
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description(format!(
+                    "This export requires an estimated {} of free space, but only {} is available in the export directory.\n\nDo you want to export anyway?",
+                    required.as_human_bytes(),
+                    available.as_human_bytes()
+                ))
+                .set_level(MessageLevel::Warning)
+                .set_buttons(MessageButtons::YesNo)
+                .set_parent(handle);
+
+            let dialog = move || {
+                if matches!(dialog.show(), MessageDialogResult::Yes) {
+                    let _ = channel.unbounded_send(Message::ExportForced(assets, settings));
+                }
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
+    pub fn on_export_forced(
+        &mut self,
+        assets: Vec<usize>,
+        settings: PorterSettings,
+    ) -> Command<Message> {
+        self.export_assets_with(assets, true, settings);
+
+        Command::none()
+    }
+
+    pub fn on_export_sample(&mut self) -> Command<Message> {
+        self.export_sample();
+
+        Command::none()
+    }
+
+    pub fn on_sample_count(&mut self, count: u32) -> Command<Message> {
+        self.sample_count = count;
+
+        Command::none()
+    }
+
+    pub fn on_toggle_dry_run(&mut self, value: bool) -> Command<Message> {
+        self.dry_run = value;
 
         Command::none()
     }
 
     pub fn on_save_settings(&mut self, settings: PorterSettings) -> Command<Message> {
-        if !self.reload_required {
-            self.reload_required = self.settings.reload_required(&settings);
+        if self.settings.reload_required(&settings) {
+            self.reload_required = true;
+
+            for session in self.sessions.iter_mut().flatten() {
+                session.set_reload_required();
+            }
+        }
+
+        if self.settings.debug_logging() != settings.debug_logging() {
+            porter_console::set_log_target_level("porter", settings.log_level());
         }
 
         self.settings = settings;
         self.settings.save(self.name);
 
+        if let Some(previewer) = &mut self.previewer {
+            previewer.set_tone_mapping(
+                self.settings.image_tone_mapping(),
+                self.settings.image_exposure(),
+            );
+        }
+
+        Command::none()
+    }
+
+    pub fn on_profile_name_input(&mut self, name: String) -> Command<Message> {
+        self.profile_name = name;
+
         Command::none()
     }
 
@@ -803,6 +1892,59 @@ impl PorterMain {
         Command::none()
     }
 
+    pub fn on_create_support_bundle(&mut self) -> Command<Message> {
+        let file_name = format!("{}_support.zip", self.name.to_lowercase());
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let rfd = FileDialog::new()
+                .add_filter("Zip Archive", &["zip"])
+                .set_file_name(file_name)
+                .set_parent(handle)
+                .save_file();
+
+            if let Some(path) = rfd {
+                Message::SaveSupportBundle(path)
+            } else {
+                Message::Noop
+            }
+        })
+    }
+
+    pub fn on_save_support_bundle(&mut self, path: PathBuf) -> Command<Message> {
+        let title = self.name.to_titlecase();
+
+        let result = create_support_bundle(&path, self.name, self.version, &self.settings);
+
+        if result.is_ok() {
+            if let Some(parent) = path.parent() {
+                open_folder(parent);
+            }
+
+            return Command::none();
+        }
+
+        iced::window::run_with_handle(iced::window::Id::MAIN, move |handle| {
+            let dialog = MessageDialog::new()
+                .set_title(title)
+                .set_description("Failed to create the support bundle, please make sure the location is writable.")
+                .set_level(MessageLevel::Error)
+                .set_buttons(MessageButtons::Ok)
+                .set_parent(handle);
+
+            let dialog = move || {
+                dialog.show();
+            };
+
+            #[cfg(target_os = "windows")]
+            std::thread::spawn(dialog);
+
+            #[cfg(not(target_os = "windows"))]
+            dialog();
+
+            Message::Noop
+        })
+    }
+
     pub fn on_pick_export_folder(&mut self) -> Command<Message> {
         let settings = self.settings.clone();
 
@@ -846,6 +1988,97 @@ impl PorterMain {
             column.width = column.width.clamp(COLUMN_MIN, COLUMN_MAX);
         }
 
+        self.save_column_layout();
+
+        Command::none()
+    }
+
+    pub fn on_column_press(&mut self, index: usize) -> Command<Message> {
+        self.column_press = Some(index);
+
+        Command::none()
+    }
+
+    pub fn on_column_release(&mut self, index: usize) -> Command<Message> {
+        let Some(press_index) = self.column_press.take() else {
+            return Command::none();
+        };
+
+        if press_index != index && press_index < self.columns.len() && index < self.columns.len() {
+            let column = self.columns.remove(press_index);
+
+            self.columns.insert(index, column);
+            self.save_column_layout();
+        }
+
+        Command::none()
+    }
+
+    pub fn on_column_context_menu(&mut self, index: Option<usize>) -> Command<Message> {
+        self.context_menu = None;
+        self.column_menu = Some((index, self.mouse_position));
+
+        Command::none()
+    }
+
+    pub fn on_toggle_column_truncation(&mut self, index: usize) -> Command<Message> {
+        if let Some(column) = self.columns.get_mut(index) {
+            column.truncation = match column.truncation {
+                ColumnTruncation::End => ColumnTruncation::Middle,
+                ColumnTruncation::Middle => ColumnTruncation::End,
+            };
+        }
+
+        self.save_column_layout();
+
+        Command::none()
+    }
+
+    pub fn on_toggle_column(&mut self, index: usize) -> Command<Message> {
+        let visible = self.columns.iter().filter(|column| column.visible).count();
+
+        if let Some(column) = self.columns.get_mut(index) {
+            if !column.visible || visible > 1 {
+                column.visible = !column.visible;
+            }
+        }
+
+        self.save_column_layout();
+
+        Command::none()
+    }
+
+    pub fn on_reset_columns(&mut self) -> Command<Message> {
+        self.columns.sort_by_key(|column| column.index);
+
+        for column in &mut self.columns {
+            column.width = column.default_width;
+            column.visible = true;
+        }
+
+        self.column_menu = None;
+        self.save_column_layout();
+
+        Command::none()
+    }
+
+    pub fn on_thumbnail(
+        &mut self,
+        generation: u64,
+        index: usize,
+        handle: Option<image::Handle>,
+    ) -> Command<Message> {
+        self.thumbnails.insert(generation, index, handle);
+
+        Command::none()
+    }
+
+    pub fn on_selection_status(&mut self, request_id: u64, status: String) -> Command<Message> {
+        // A status for an older selection arrives late when the selection changes while it's built.
+        if request_id == self.selection_status_id {
+            self.selection_status = Some(status);
+        }
+
         Command::none()
     }
 
@@ -0,0 +1,122 @@
+use iced::alignment::*;
+use iced::widget::*;
+
+use iced::Alignment;
+use iced::Color;
+use iced::Element;
+use iced::Length;
+
+use crate::Message;
+use crate::PorterButtonStyle;
+use crate::PorterLabelStyle;
+use crate::PorterMain;
+use crate::PorterOverlayBackgroundStyle;
+use crate::PorterScrollStyle;
+
+impl PorterMain {
+    /// Constructs the export history view.
+    pub fn history(&self) -> Element<Message> {
+        let entries = self.export_history.entries();
+
+        let mut history = vec![row([
+            text("Export History")
+                .size(20.0)
+                .width(Length::Fill)
+                .style(PorterLabelStyle)
+                .into(),
+            button("Clear History")
+                .on_press_maybe((!entries.is_empty()).then_some(Message::ClearHistory))
+                .style(PorterButtonStyle)
+                .into(),
+        ])
+        .align_items(Alignment::Center)
+        .into()];
+
+        if entries.is_empty() {
+            history.push(
+                container(text("No exports yet.").style(PorterLabelStyle))
+                    .width(Length::Fill)
+                    .padding(16.0)
+                    .align_x(Horizontal::Center)
+                    .into(),
+            );
+        }
+
+        for (index, entry) in entries.iter().enumerate() {
+            history.push(
+                container(
+                    row([
+                        column([
+                            row([
+                                text(&entry.summary).style(Color::WHITE).into(),
+                                text(entry.elapsed())
+                                    .size(14.0)
+                                    .style(PorterLabelStyle)
+                                    .into(),
+                            ])
+                            .spacing(8.0)
+                            .align_items(Alignment::Center)
+                            .into(),
+                            text(match &entry.profile {
+                                Some(profile) => format!(
+                                    "Profile: {} • Formats: {}",
+                                    profile,
+                                    entry.formats.join(", ")
+                                ),
+                                None => format!("Formats: {}", entry.formats.join(", ")),
+                            })
+                            .size(14.0)
+                            .style(PorterLabelStyle)
+                            .into(),
+                            text(format!("Folder: {}", entry.output_directory.display()))
+                                .size(14.0)
+                                .style(PorterLabelStyle)
+                                .into(),
+                            text(entry.result())
+                                .size(14.0)
+                                .style(PorterLabelStyle)
+                                .into(),
+                        ])
+                        .width(Length::Fill)
+                        .spacing(2.0)
+                        .into(),
+                        button("Open Folder")
+                            .on_press(Message::OpenHistoryFolder(index))
+                            .style(PorterButtonStyle)
+                            .into(),
+                        button("Export Again")
+                            .on_press_maybe(
+                                if self.asset_manager.loaded_len() == 0
+                                    || self.loading
+                                    || self.exporting
+                                {
+                                    None
+                                } else {
+                                    Some(Message::ExportHistory(index))
+                                },
+                            )
+                            .style(PorterButtonStyle)
+                            .into(),
+                    ])
+                    .spacing(8.0)
+                    .align_items(Alignment::Center),
+                )
+                .width(Length::Fill)
+                .padding(8.0)
+                .style(PorterOverlayBackgroundStyle)
+                .into(),
+            );
+        }
+
+        scrollable(
+            column(history)
+                .spacing(8.0)
+                .padding(16.0)
+                .width(Length::Fill),
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .style(PorterScrollStyle)
+        .into()
+    }
+}
@@ -5,14 +5,23 @@ use iced::Element;
 use iced::Length;
 
 use porter_animation::AnimationFileType;
+use porter_audio::AudioChannelMode;
 use porter_audio::AudioFileType;
 use porter_model::ModelFileType;
+use porter_model::ModelUnits;
 use porter_texture::ImageFileType;
+use porter_texture::ImageToneMapping;
 
+use porter_threads::ThreadPriority;
+
+use crate::format_count;
+use crate::ExportUpAxis;
 use crate::ImageNormalMapProcessing;
 use crate::Message;
 use crate::PorterButtonStyle;
 use crate::PorterCheckboxStyle;
+use crate::PorterExportCollision;
+use crate::PorterExportHookMode;
 use crate::PorterLabelStyle;
 use crate::PorterLabelSuccessStyle;
 use crate::PorterLabelWarningStyle;
@@ -24,6 +33,9 @@ use crate::PorterSliderStyle;
 use crate::PorterTextInputStyle;
 use crate::PreviewControlScheme;
 
+/// The interface scales, in percent, that can be picked in settings.
+const UI_SCALES: [u32; 6] = [75, 100, 125, 150, 175, 200];
+
 impl PorterMain {
     /// Constructs the settings view.
     pub fn settings(&self) -> Element<Message> {
@@ -155,6 +167,145 @@ impl PorterMain {
             .spacing(4.0)
             .into(),
             vertical_space().height(2.0).into(),
+            text("Customize the exported files subfolders, where {game}, {type}, and {name} are replaced:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            text_input("{type}/{name}", self.settings.output_template())
+                .on_input(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_output_template(value)),
+                    )
+                })
+                .width(500.0)
+                .style(PorterTextInputStyle)
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose what happens when an exported file already exists:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                vec!["Overwrite", "Skip", "Rename"],
+                match self.settings.export_collision() {
+                    PorterExportCollision::Overwrite => Some("Overwrite"),
+                    PorterExportCollision::Skip => Some("Skip"),
+                    PorterExportCollision::Rename => Some("Rename"),
+                },
+                |selected| {
+                    let collision = match selected {
+                        "Skip" => PorterExportCollision::Skip,
+                        "Rename" => PorterExportCollision::Rename,
+                        _ => PorterExportCollision::Overwrite,
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_export_collision(collision)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not export all skips assets already exported with the same settings:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row(vec![
+                checkbox(
+                    "Only export new or changed assets",
+                    self.settings.incremental_export(),
+                )
+                .on_toggle(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_incremental_export(value)),
+                    )
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
+                button(text(format!(
+                    "Reset ({} exported)",
+                    format_count(self.export_database.len())
+                )))
+                .on_press(Message::ClearExportDatabase)
+                .style(PorterButtonStyle)
+                .into(),
+            ])
+            .spacing(8.0)
+            .align_items(Alignment::Center)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the number of assets exported in parallel (Lower values reduce system load):")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                slider(
+                    0..=porter_threads::available_threads() as u32,
+                    self.settings.export_threads(),
+                    |value| {
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.set_export_threads(value)),
+                        )
+                    },
+                )
+                .step(1u32)
+                .style(PorterSliderStyle)
+                .into(),
+                text(match self.settings.export_threads() {
+                    0 => String::from("Automatic"),
+                    threads => threads.to_string(),
+                })
+                .width(100.0)
+                .style(PorterLabelStyle)
+                .into(),
+            ])
+            .width(500.0)
+            .spacing(8.0)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose the export priority (Lower priorities keep the system responsive during long exports):")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                vec!["Normal", "Low", "Background"],
+                match self.settings.export_priority() {
+                    ThreadPriority::Normal => Some("Normal"),
+                    ThreadPriority::Low => Some("Low"),
+                    ThreadPriority::Background => Some("Background"),
+                },
+                |selected| {
+                    let priority = match selected {
+                        "Low" => ThreadPriority::Low,
+                        "Background" => ThreadPriority::Background,
+                        _ => ThreadPriority::Normal,
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_export_priority(priority)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to validate exports without writing any files:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            checkbox("Dry run", self.dry_run)
+                .on_toggle(Message::ToggleDryRun)
+                .style(PorterCheckboxStyle)
+                .into(),
+            vertical_space().height(2.0).into(),
             text("Choose whether or not to automatically scale assets (Recommended):")
                 .style(PorterLabelStyle)
                 .into(),
@@ -168,6 +319,20 @@ impl PorterMain {
                 })
                 .style(PorterCheckboxStyle)
                 .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to mirror models and animations across the X axis (Left handed rigs):")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            checkbox("Mirror assets", self.settings.mirror_x_axis())
+                .on_toggle(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_mirror_x_axis(value)),
+                    )
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
             vertical_space().height(4.0).into(),
             text("Settings - Models")
                 .size(20.0)
@@ -243,6 +408,100 @@ impl PorterMain {
                 })
                 .style(PorterCheckboxStyle)
                 .into(),
+            checkbox("FBX (ASCII)", model_format_enabled(ModelFileType::FbxAscii))
+                .on_toggle(|value| {
+                    Message::SaveSettings(self.settings.update(|settings| {
+                        settings.set_model_file_type(ModelFileType::FbxAscii, value)
+                    }))
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose what units to convert models to, for every model file type:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                vec!["Source", "Centimeters", "Meters", "Inches"],
+                match self.settings.export_units() {
+                    None => Some("Source"),
+                    Some(ModelUnits::Centimeters) => Some("Centimeters"),
+                    Some(ModelUnits::Meters) => Some("Meters"),
+                    Some(ModelUnits::Inches) => Some("Inches"),
+                },
+                |selected| {
+                    let units = match selected {
+                        "Centimeters" => Some(ModelUnits::Centimeters),
+                        "Meters" => Some(ModelUnits::Meters),
+                        "Inches" => Some(ModelUnits::Inches),
+                        _ => None,
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_export_units(units)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose what up axis to convert models to, for every model file type:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                vec!["Source", "Y Up", "Z Up"],
+                match self.settings.export_up_axis() {
+                    ExportUpAxis::Source => Some("Source"),
+                    ExportUpAxis::Y => Some("Y Up"),
+                    ExportUpAxis::Z => Some("Z Up"),
+                },
+                |selected| {
+                    let up_axis = match selected {
+                        "Y Up" => ExportUpAxis::Y,
+                        "Z Up" => ExportUpAxis::Z,
+                        _ => ExportUpAxis::Source,
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_export_up_axis(up_axis)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to rename hashed textures with the name database:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            checkbox("Resolve texture names", self.settings.resolve_texture_names())
+                .on_toggle(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_resolve_texture_names(value)),
+                    )
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose whether or not to check models for corrupt data before exporting:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            checkbox("Validate models", self.settings.validate_models())
+                .on_toggle(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_validate_models(value)),
+                    )
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
             vertical_space().height(4.0).into(),
             text("Settings - Images")
                 .size(20.0)
@@ -260,6 +519,7 @@ impl PorterMain {
                     ImageFileType::Png => Some("PNG"),
                     ImageFileType::Tiff => Some("TIFF"),
                     ImageFileType::Tga => Some("TGA"),
+                    _ => None,
                 },
                 |selected| {
                     let format = match selected {
@@ -306,6 +566,61 @@ impl PorterMain {
             }
         }
 
+        settings.extend([
+            vertical_space().height(2.0).into(),
+            text("Choose how high dynamic range images are tone mapped:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                vec!["None (Clamp)", "Reinhard", "ACES"],
+                match self.settings.image_tone_mapping() {
+                    ImageToneMapping::None => Some("None (Clamp)"),
+                    ImageToneMapping::Reinhard => Some("Reinhard"),
+                    ImageToneMapping::Aces => Some("ACES"),
+                },
+                |selected| {
+                    let tone_mapping = match selected {
+                        "None (Clamp)" => ImageToneMapping::None,
+                        "Reinhard" => ImageToneMapping::Reinhard,
+                        "ACES" => ImageToneMapping::Aces,
+                        _ => ImageToneMapping::None,
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_image_tone_mapping(tone_mapping)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Set the high dynamic range image exposure in stops:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                slider(-4.0..=4.0, self.settings.image_exposure(), |value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_image_exposure(value)),
+                    )
+                })
+                .step(0.25f32)
+                .style(PorterSliderStyle)
+                .into(),
+                text(format!("{:+.2}", self.settings.image_exposure()))
+                    .width(100.0)
+                    .style(PorterLabelStyle)
+                    .into(),
+            ])
+            .width(500.0)
+            .spacing(8.0)
+            .into(),
+        ]);
+
         if self.normal_map_converter {
             settings.extend([
                 vertical_space().height(2.0).into(),
@@ -363,6 +678,36 @@ impl PorterMain {
                     })
                     .style(PorterCheckboxStyle)
                     .into(),
+                checkbox("FBX", anim_format_enabled(AnimationFileType::Fbx))
+                    .on_toggle(|value| {
+                        Message::SaveSettings(self.settings.update(|settings| {
+                            settings.set_anim_file_type(AnimationFileType::Fbx, value)
+                        }))
+                    })
+                    .style(PorterCheckboxStyle)
+                    .into(),
+                checkbox("Valve SMD", anim_format_enabled(AnimationFileType::Smd))
+                    .on_toggle(|value| {
+                        Message::SaveSettings(self.settings.update(|settings| {
+                            settings.set_anim_file_type(AnimationFileType::Smd, value)
+                        }))
+                    })
+                    .style(PorterCheckboxStyle)
+                    .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose whether or not to remove redundant keyframes (Reduces file size):")
+                    .style(PorterLabelStyle)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                checkbox("Compress animations", self.settings.compress_animations())
+                    .on_toggle(|value| {
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.set_compress_animations(value)),
+                        )
+                    })
+                    .style(PorterCheckboxStyle)
+                    .into(),
                 vertical_space().height(4.0).into(),
             ]);
         }
@@ -394,6 +739,65 @@ impl PorterMain {
                     })
                     .style(PorterCheckboxStyle)
                     .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose how audio with more than two channels is exported:")
+                    .style(PorterLabelStyle)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                pick_list(
+                    vec!["Keep Channels", "Downmix to Stereo", "Split Channels"],
+                    match self.settings.audio_channel_mode() {
+                        AudioChannelMode::Keep => Some("Keep Channels"),
+                        AudioChannelMode::Downmix => Some("Downmix to Stereo"),
+                        AudioChannelMode::Split => Some("Split Channels"),
+                    },
+                    |selected| {
+                        let mode = match selected {
+                            "Keep Channels" => AudioChannelMode::Keep,
+                            "Downmix to Stereo" => AudioChannelMode::Downmix,
+                            "Split Channels" => AudioChannelMode::Split,
+                            _ => AudioChannelMode::Keep,
+                        };
+
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.set_audio_channel_mode(mode)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(200.0))
+                .style(PorterPickListStyle)
+                .into(),
+                vertical_space().height(2.0).into(),
+                text("Choose a sample rate to resample audio to:")
+                    .style(PorterLabelStyle)
+                    .into(),
+                vertical_space().height(0.0).into(),
+                pick_list(
+                    vec![
+                        "Original", "22050 Hz", "32000 Hz", "44100 Hz", "48000 Hz", "96000 Hz",
+                    ],
+                    match self.settings.audio_sample_rate() {
+                        None => Some("Original"),
+                        Some(22050) => Some("22050 Hz"),
+                        Some(32000) => Some("32000 Hz"),
+                        Some(44100) => Some("44100 Hz"),
+                        Some(48000) => Some("48000 Hz"),
+                        Some(96000) => Some("96000 Hz"),
+                        Some(_) => None,
+                    },
+                    |selected| {
+                        let sample_rate = selected.trim_end_matches(" Hz").parse::<u32>().ok();
+
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.set_audio_sample_rate(sample_rate)),
+                        )
+                    },
+                )
+                .width(Length::Fixed(200.0))
+                .style(PorterPickListStyle)
+                .into(),
                 vertical_space().height(4.0).into(),
             ]);
         }
@@ -468,6 +872,43 @@ impl PorterMain {
             .spacing(8.0)
             .into(),
             vertical_space().height(4.0).into(),
+            text("Settings - Profiles")
+                .size(20.0)
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(2.0).into(),
+            text("Save the export formats, scale, and directory as a profile, switchable from the header:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                text_input("Profile name", &self.profile_name)
+                    .on_input(Message::ProfileNameInput)
+                    .width(300.0)
+                    .style(PorterTextInputStyle)
+                    .into(),
+                button("Save Profile")
+                    .on_press_maybe((!self.profile_name.trim().is_empty()).then(|| {
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.save_profile(&self.profile_name)),
+                        )
+                    }))
+                    .style(PorterButtonStyle)
+                    .into(),
+                button("Delete Profile")
+                    .on_press_maybe(self.settings.active_profile().map(|profile| {
+                        Message::SaveSettings(
+                            self.settings
+                                .update(|settings| settings.delete_profile(profile)),
+                        )
+                    }))
+                    .style(PorterButtonStyle)
+                    .into(),
+            ])
+            .spacing(4.0)
+            .into(),
+            vertical_space().height(4.0).into(),
             text("Settings - Advanced")
                 .size(20.0)
                 .style(PorterLabelStyle)
@@ -496,12 +937,158 @@ impl PorterMain {
             ]);
         }
 
+        let mut gpu_adapters = vec![String::from("Default")];
+
+        gpu_adapters.extend(self.gpu_adapters.iter().cloned());
+
         settings.extend([
+            vertical_space().height(2.0).into(),
+            text("Choose the gpu used to preview and convert images (Requires restart):")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                gpu_adapters,
+                Some(
+                    self.settings
+                        .gpu_adapter()
+                        .unwrap_or_else(|| String::from("Default")),
+                ),
+                |selected| {
+                    let adapter = if selected == "Default" {
+                        None
+                    } else {
+                        Some(selected)
+                    };
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_gpu_adapter(adapter)),
+                    )
+                },
+            )
+            .width(Length::Fixed(300.0))
+            .style(PorterPickListStyle)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Choose the interface scale, for monitors where the automatic scaling is too small or large:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            pick_list(
+                UI_SCALES
+                    .iter()
+                    .map(|scale| format!("{}%", scale))
+                    .collect::<Vec<_>>(),
+                Some(format!("{}%", self.settings.ui_scale())),
+                |selected| {
+                    let scale = selected.trim_end_matches('%').parse().unwrap_or(100);
+
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_ui_scale(scale)),
+                    )
+                },
+            )
+            .width(Length::Fixed(150.0))
+            .style(PorterPickListStyle)
+            .into(),
+        ]);
+
+        settings.extend([
+            vertical_space().height(2.0).into(),
+            text("Run a command after exporting, {path}, {dir}, {name}, {ext}, {type}, {files}, {count}, and {output} are replaced:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            text_input(
+                "Post export command",
+                self.settings.post_export_command(),
+            )
+            .on_input(|value| {
+                Message::SaveSettings(
+                    self.settings
+                        .update(|settings| settings.set_post_export_command(value)),
+                )
+            })
+            .width(500.0)
+            .style(PorterTextInputStyle)
+            .into(),
+            checkbox(
+                "Run once per export with all files",
+                self.settings.post_export_mode() == PorterExportHookMode::PerBatch,
+            )
+            .on_toggle(|value| {
+                Message::SaveSettings(self.settings.update(|settings| {
+                    settings.set_post_export_mode(if value {
+                        PorterExportHookMode::PerBatch
+                    } else {
+                        PorterExportHookMode::PerFile
+                    })
+                }))
+            })
+            .style(PorterCheckboxStyle)
+            .into(),
+            row([
+                slider(1..=600, self.settings.post_export_timeout(), |value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_post_export_timeout(value)),
+                    )
+                })
+                .style(PorterSliderStyle)
+                .into(),
+                text(format!("{}s timeout", self.settings.post_export_timeout()))
+                    .width(100.0)
+                    .style(PorterLabelStyle)
+                    .into(),
+            ])
+            .width(500.0)
+            .spacing(8.0)
+            .align_items(Alignment::Center)
+            .into(),
+            vertical_space().height(2.0).into(),
+            text("Spot-check by exporting a random sample of each asset type:")
+                .style(PorterLabelStyle)
+                .into(),
+            vertical_space().height(0.0).into(),
+            row([
+                slider(1..=50, self.sample_count, Message::SampleCount)
+                    .style(PorterSliderStyle)
+                    .into(),
+                text(self.sample_count.to_string())
+                    .width(50.0)
+                    .style(PorterLabelStyle)
+                    .into(),
+                button("Export Sample")
+                    .on_press_maybe(
+                        if self.asset_manager.is_empty() || self.loading || self.exporting {
+                            None
+                        } else {
+                            Some(Message::ExportSample)
+                        },
+                    )
+                    .style(PorterButtonStyle)
+                    .into(),
+            ])
+            .width(500.0)
+            .spacing(8.0)
+            .align_items(Alignment::Center)
+            .into(),
             vertical_space().height(2.0).into(),
             text("Troubleshooting options:")
                 .style(PorterLabelStyle)
                 .into(),
             vertical_space().height(0.0).into(),
+            checkbox("Enable debug logging", self.settings.debug_logging())
+                .on_toggle(|value| {
+                    Message::SaveSettings(
+                        self.settings
+                            .update(|settings| settings.set_debug_logging(value)),
+                    )
+                })
+                .style(PorterCheckboxStyle)
+                .into(),
             row([
                 button("Reset Settings")
                     .on_press(Message::SaveSettings(PorterSettings::default()))
@@ -511,6 +1098,17 @@ impl PorterMain {
                     .on_press(Message::OpenConfigFolder)
                     .style(PorterButtonStyle)
                     .into(),
+                button("Create Support Bundle")
+                    .on_press(Message::CreateSupportBundle)
+                    .style(PorterButtonStyle)
+                    .into(),
+                button("View Last Crash")
+                    .on_press_maybe(
+                        self.crash_report_available
+                            .then_some(Message::ViewCrashReport),
+                    )
+                    .style(PorterButtonStyle)
+                    .into(),
             ])
             .align_items(Alignment::Center)
             .spacing(8.0)
@@ -1,3 +1,5 @@
+use porter_audio::Audio;
+
 use porter_model::MaterialTextureRefUsage;
 use porter_model::Model;
 
@@ -10,6 +12,10 @@ pub enum PorterPreviewAsset {
     Image(String, Image),
     /// A model asset for preview.
     Model(String, Model, Vec<Option<Image>>),
+    /// A model asset for preview, with every texture of each material so normal maps can be shown.
+    ModelMaterials(String, Model, Vec<Vec<(MaterialTextureRefUsage, Image)>>),
     /// A material asset for preview.
     Material(String, Vec<(MaterialTextureRefUsage, Image)>),
+    /// An audio asset for preview.
+    Audio(String, Audio),
 }
@@ -155,6 +155,18 @@ impl PorterSearch {
         }
     }
 
+    /// The names this search command requires assets to contain.
+    pub fn contained_names(&self) -> Vec<String> {
+        self.search_names
+            .iter()
+            .flatten()
+            .filter_map(|name| match name {
+                PorterSearchName::Contained(name) => Some(name.clone()),
+                PorterSearchName::NotContained(_) => None,
+            })
+            .collect()
+    }
+
     /// Determines if the given asset matches this search command.
     #[inline(always)]
     pub fn matches(&self, asset: PorterSearchAsset) -> bool {
@@ -0,0 +1,107 @@
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::PorterAssetManager;
+use crate::PorterMain;
+use crate::PorterViewport;
+
+/// A game or set of files loaded into its own tab, with an independent asset list and search.
+pub struct PorterSession {
+    asset_manager: Arc<dyn PorterAssetManager>,
+    item_range: Range<usize>,
+    item_selection: BTreeSet<usize>,
+    item_cursor: Option<usize>,
+    item_anchor: Option<usize>,
+    search_value: String,
+    search_terms: Vec<String>,
+    scroll_viewport_state: PorterViewport,
+    last_load: Option<Vec<PathBuf>>,
+    game_process: Option<u64>,
+    game_exited: bool,
+    reload_required: bool,
+}
+
+impl PorterSession {
+    /// Constructs a new empty session with the given asset manager.
+    pub fn new(asset_manager: Arc<dyn PorterAssetManager>) -> Self {
+        Self {
+            asset_manager,
+            item_range: 0..0,
+            item_selection: BTreeSet::new(),
+            item_cursor: None,
+            item_anchor: None,
+            search_value: String::new(),
+            search_terms: Vec::new(),
+            scroll_viewport_state: PorterViewport::zero(),
+            last_load: None,
+            game_process: None,
+            game_exited: false,
+            reload_required: false,
+        }
+    }
+
+    /// Swaps the state of this session with the active session of the main window.
+    pub fn swap(&mut self, main: &mut PorterMain) {
+        std::mem::swap(&mut self.asset_manager, &mut main.asset_manager);
+        std::mem::swap(&mut self.item_range, &mut main.item_range);
+        std::mem::swap(&mut self.item_selection, &mut main.item_selection);
+        std::mem::swap(&mut self.item_cursor, &mut main.item_cursor);
+        std::mem::swap(&mut self.item_anchor, &mut main.item_anchor);
+        std::mem::swap(&mut self.search_value, &mut main.search_value);
+        std::mem::swap(&mut self.search_terms, &mut main.search_terms);
+        std::mem::swap(
+            &mut self.scroll_viewport_state,
+            &mut main.scroll_viewport_state,
+        );
+        std::mem::swap(&mut self.last_load, &mut main.last_load);
+        std::mem::swap(&mut self.game_process, &mut main.game_process);
+        std::mem::swap(&mut self.game_exited, &mut main.game_exited);
+        std::mem::swap(&mut self.reload_required, &mut main.reload_required);
+    }
+
+    /// Marks that the session must be loaded again before it's used, if anything was loaded.
+    pub fn set_reload_required(&mut self) {
+        if self.last_load.is_some() {
+            self.reload_required = true;
+        }
+    }
+
+    /// Marks the session's game as exited, if it matches the process id.
+    pub fn game_exited(&mut self, pid: u64) -> bool {
+        if self.game_process != Some(pid) {
+            return false;
+        }
+
+        self.game_process = None;
+        self.game_exited = true;
+
+        true
+    }
+
+    /// The title of the session's tab.
+    pub fn title(&self) -> String {
+        session_title(self.last_load.as_deref())
+    }
+}
+
+/// Gets the title of a session's tab from what was last loaded into it.
+pub fn session_title(last_load: Option<&[PathBuf]>) -> String {
+    let files = match last_load {
+        None => return String::from("Empty"),
+        Some([]) => return String::from("Game"),
+        Some(files) => files,
+    };
+
+    let name = files[0]
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| String::from("Files"));
+
+    if files.len() > 1 {
+        format!("{} (+{})", name, files.len() - 1)
+    } else {
+        name
+    }
+}
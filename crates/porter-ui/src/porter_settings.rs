@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Duration;
 
 use bincode::Decode;
 use bincode::Encode;
@@ -8,10 +9,32 @@ use directories::UserDirs;
 
 use bitflags::bitflags;
 
+use iced::Point;
+use iced::Size;
+
+use porter_animation::AnimationConvertOptions;
 use porter_animation::AnimationFileType;
+use porter_animation::CurveTolerance;
+use porter_audio::AudioChannelMode;
 use porter_audio::AudioFileType;
+use porter_console::LevelFilter;
+use porter_math::Axis;
+use porter_model::ModelConversion;
 use porter_model::ModelFileType;
+use porter_model::ModelUnits;
+use porter_texture::ImageConvertOptions;
 use porter_texture::ImageFileType;
+use porter_texture::ImageToneMapping;
+
+use porter_threads::ThreadPriority;
+
+use porter_utils::HashXXH64;
+use porter_utils::SanitizeFilename;
+
+use crate::PorterExportCollision;
+use crate::PorterExportFormat;
+use crate::PorterExportHook;
+use crate::PorterExportHookMode;
 
 #[derive(Debug, Decode, Encode, Clone, Copy)]
 struct PorterLoadSettings(u32);
@@ -47,6 +70,7 @@ bitflags! {
         const EXPORT_CAST = 1 << 5;
         const EXPORT_MAYA = 1 << 6;
         const EXPORT_FBX = 1 << 7;
+        const EXPORT_FBX_ASCII = 1 << 8;
     }
 }
 
@@ -54,6 +78,9 @@ bitflags! {
     impl PorterAnimSettings: u32 {
         const EXPORT_SEANIM_REMOVED = 1 << 0;
         const EXPORT_CAST = 1 << 1;
+        const COMPRESS = 1 << 2;
+        const EXPORT_FBX = 1 << 3;
+        const EXPORT_SMD = 1 << 4;
     }
 }
 
@@ -77,6 +104,167 @@ pub enum PreviewControlScheme {
     Blender,
 }
 
+#[derive(Debug, Decode, Encode, Clone, Copy, PartialEq, Eq)]
+pub enum ExportUpAxis {
+    Source,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Decode, Encode, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnTruncation {
+    End,
+    Middle,
+}
+
+/// The saved layout of a single column in the asset list.
+#[derive(Debug, Decode, Encode, Clone)]
+pub struct PorterColumnLayout {
+    pub(crate) header: String,
+    pub(crate) width: u32,
+    pub(crate) visible: bool,
+    pub(crate) truncation: ColumnTruncation,
+}
+
+/// The saved size, position, and layout of the main window.
+#[derive(Debug, Decode, Encode, Clone, Copy)]
+pub struct PorterWindowState {
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+    pub(crate) position: Option<(f32, f32)>,
+    pub(crate) maximized: bool,
+    pub(crate) preview_width: Option<f32>,
+}
+
+impl PorterWindowState {
+    /// The saved size, which is never smaller than the minimum size.
+    pub fn size(&self, min_size: Size) -> Size {
+        Size::new(
+            self.width.clamp(min_size.width, WINDOW_SIZE_MAX),
+            self.height.clamp(min_size.height, WINDOW_SIZE_MAX),
+        )
+    }
+
+    /// The saved position, or none if it's outside of any reasonable desktop, such as the position of a minimized window.
+    ///
+    /// The position may still be on a disconnected monitor, which is only corrected on Windows once the window opens.
+    pub fn position(&self) -> Option<Point> {
+        let (x, y) = self.position?;
+
+        if x.abs() > WINDOW_POSITION_MAX || y.abs() > WINDOW_POSITION_MAX {
+            return None;
+        }
+
+        Some(Point::new(x, y))
+    }
+
+    /// Saves the size and position of the window, ignoring the bounds reported while it's minimized.
+    pub fn set_bounds(&mut self, size: Size, position: Option<Point>) {
+        if size.width <= 0.0 || size.height <= 0.0 {
+            return;
+        }
+
+        self.width = size.width;
+        self.height = size.height;
+
+        if let Some(position) = position.filter(|position| {
+            position.x.abs() <= WINDOW_POSITION_MAX && position.y.abs() <= WINDOW_POSITION_MAX
+        }) {
+            self.position = Some((position.x, position.y));
+        }
+    }
+}
+
+impl Default for PorterWindowState {
+    fn default() -> Self {
+        Self {
+            width: 920.0,
+            height: 582.0,
+            position: None,
+            maximized: false,
+            preview_width: None,
+        }
+    }
+}
+
+/// The largest window size that will be restored.
+const WINDOW_SIZE_MAX: f32 = 16384.0;
+
+/// The largest distance from the primary monitor that a window position will be restored at.
+const WINDOW_POSITION_MAX: f32 = 16384.0;
+
+/// The default output template, grouping assets by their type.
+const DEFAULT_OUTPUT_TEMPLATE: &str = "{type}/{name}";
+
+/// A named snapshot of the export settings, such as the formats used by a specific tool.
+#[derive(Debug, Decode, Encode, Clone)]
+pub struct PorterSettingsProfile {
+    name: String,
+    model_settings: PorterModelSettings,
+    anim_settings: PorterAnimSettings,
+    audio_settings: PorterAudioSettings,
+    audio_channel_mode: AudioChannelMode,
+    audio_sample_rate: Option<u32>,
+    image_file_type: ImageFileType,
+    image_normal_map_processing: ImageNormalMapProcessing,
+    image_tone_mapping: ImageToneMapping,
+    image_exposure: f32,
+    output_directory: Option<PathBuf>,
+    output_template: String,
+    auto_scale: bool,
+    mirror_x_axis: bool,
+    export_units: Option<ModelUnits>,
+    export_up_axis: ExportUpAxis,
+    resolve_texture_names: bool,
+    validate_models: bool,
+}
+
+impl PorterSettingsProfile {
+    /// The name of the profile.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// The layout version of the settings, which must be bumped whenever fields are added, removed, or reordered.
+const SETTINGS_VERSION: u32 = 2;
+
+/// The first layout of the settings, migrated to the current layout on load.
+#[derive(Decode)]
+struct PorterSettingsV1 {
+    _version: u32,
+    load_settings: PorterLoadSettings,
+    model_settings: PorterModelSettings,
+    anim_settings: PorterAnimSettings,
+    audio_settings: PorterAudioSettings,
+    image_file_type: ImageFileType,
+    image_normal_map_processing: ImageNormalMapProcessing,
+    output_directory: Option<PathBuf>,
+    preview_controls: PreviewControlScheme,
+    preview_overlay: bool,
+    auto_scale: bool,
+    far_clip: u32,
+}
+
+impl From<PorterSettingsV1> for PorterSettings {
+    fn from(value: PorterSettingsV1) -> Self {
+        Self {
+            load_settings: value.load_settings,
+            model_settings: value.model_settings,
+            anim_settings: value.anim_settings,
+            audio_settings: value.audio_settings,
+            image_file_type: value.image_file_type,
+            image_normal_map_processing: value.image_normal_map_processing,
+            output_directory: value.output_directory,
+            preview_controls: value.preview_controls,
+            preview_overlay: value.preview_overlay,
+            auto_scale: value.auto_scale,
+            far_clip: value.far_clip,
+            ..Default::default()
+        }
+    }
+}
+
 /// Global application settings.
 #[derive(Debug, Decode, Encode, Clone)]
 pub struct PorterSettings {
@@ -85,13 +273,37 @@ pub struct PorterSettings {
     model_settings: PorterModelSettings,
     anim_settings: PorterAnimSettings,
     audio_settings: PorterAudioSettings,
+    audio_channel_mode: AudioChannelMode,
+    audio_sample_rate: Option<u32>,
     image_file_type: ImageFileType,
     image_normal_map_processing: ImageNormalMapProcessing,
+    image_tone_mapping: ImageToneMapping,
+    image_exposure: f32,
     output_directory: Option<PathBuf>,
+    output_template: String,
+    export_collision: PorterExportCollision,
+    incremental_export: bool,
+    export_threads: u32,
+    export_priority: ThreadPriority,
+    debug_logging: bool,
     preview_controls: PreviewControlScheme,
     preview_overlay: bool,
     auto_scale: bool,
     far_clip: u32,
+    gpu_adapter: Option<String>,
+    ui_scale: u32,
+    mirror_x_axis: bool,
+    export_units: Option<ModelUnits>,
+    export_up_axis: ExportUpAxis,
+    resolve_texture_names: bool,
+    validate_models: bool,
+    post_export_command: String,
+    post_export_mode: PorterExportHookMode,
+    post_export_timeout: u32,
+    column_layout: Vec<PorterColumnLayout>,
+    window_state: PorterWindowState,
+    profiles: Vec<PorterSettingsProfile>,
+    active_profile: Option<String>,
 }
 
 impl PorterSettings {
@@ -110,9 +322,20 @@ impl PorterSettings {
         .map_or(Default::default(), |buffer| {
             let config = bincode::config::standard();
 
-            bincode::decode_from_slice(&buffer, config)
-                .unwrap_or_default()
-                .0
+            // The version is always encoded first, so older layouts can be detected before decoding them.
+            let version: u32 =
+                bincode::decode_from_slice(&buffer, config).map_or(0, |(version, _)| version);
+
+            match version {
+                1 => bincode::decode_from_slice::<PorterSettingsV1, _>(&buffer, config)
+                    .map_or(Default::default(), |(settings, _)| settings.into()),
+                SETTINGS_VERSION => {
+                    bincode::decode_from_slice(&buffer, config)
+                        .unwrap_or_default()
+                        .0
+                }
+                _ => Default::default(),
+            }
         })
     }
 
@@ -242,7 +465,7 @@ impl PorterSettings {
 
     /// The model file types to export to.
     pub fn model_file_types(&self) -> Vec<ModelFileType> {
-        let mut result = Vec::with_capacity(8);
+        let mut result = Vec::with_capacity(9);
 
         if self
             .model_settings
@@ -293,6 +516,13 @@ impl PorterSettings {
             result.push(ModelFileType::Fbx);
         }
 
+        if self
+            .model_settings
+            .contains(PorterModelSettings::EXPORT_FBX_ASCII)
+        {
+            result.push(ModelFileType::FbxAscii);
+        }
+
         result
     }
 
@@ -306,32 +536,73 @@ impl PorterSettings {
             ModelFileType::Cast => PorterModelSettings::EXPORT_CAST,
             ModelFileType::Maya => PorterModelSettings::EXPORT_MAYA,
             ModelFileType::Fbx => PorterModelSettings::EXPORT_FBX,
+            ModelFileType::FbxAscii => PorterModelSettings::EXPORT_FBX_ASCII,
         };
 
+        // Both fbx modes write to the same file, so only one of them can be enabled.
+        if value {
+            match file_type {
+                ModelFileType::Fbx => self
+                    .model_settings
+                    .remove(PorterModelSettings::EXPORT_FBX_ASCII),
+                ModelFileType::FbxAscii => {
+                    self.model_settings.remove(PorterModelSettings::EXPORT_FBX)
+                }
+                _ => {}
+            }
+        }
+
         self.model_settings.set(flag, value);
     }
 
     /// The animation file types to export to.
     pub fn anim_file_types(&self) -> Vec<AnimationFileType> {
-        let mut result = Vec::with_capacity(1);
+        let mut result = Vec::with_capacity(3);
 
         if self.anim_settings.contains(PorterAnimSettings::EXPORT_CAST) {
             result.push(AnimationFileType::Cast);
         }
 
+        if self.anim_settings.contains(PorterAnimSettings::EXPORT_FBX) {
+            result.push(AnimationFileType::Fbx);
+        }
+
+        if self.anim_settings.contains(PorterAnimSettings::EXPORT_SMD) {
+            result.push(AnimationFileType::Smd);
+        }
+
         result
     }
 
     /// Sets whether or not an anim file type is in use.
     pub fn set_anim_file_type(&mut self, file_type: AnimationFileType, value: bool) {
         let flag = match file_type {
-            AnimationFileType::SEAnim    => PorterAnimSettings::EXPORT_SEANIM_REMOVED,
+            AnimationFileType::SEAnim => PorterAnimSettings::EXPORT_SEANIM_REMOVED,
             AnimationFileType::Cast => PorterAnimSettings::EXPORT_CAST,
+            AnimationFileType::Fbx => PorterAnimSettings::EXPORT_FBX,
+            AnimationFileType::Smd => PorterAnimSettings::EXPORT_SMD,
         };
 
         self.anim_settings.set(flag, value);
     }
 
+    /// Whether or not to remove redundant animation keyframes before exporting.
+    pub fn compress_animations(&self) -> bool {
+        self.anim_settings.contains(PorterAnimSettings::COMPRESS)
+    }
+
+    /// Sets whether or not to remove redundant animation keyframes before exporting.
+    pub fn set_compress_animations(&mut self, value: bool) {
+        self.anim_settings.set(PorterAnimSettings::COMPRESS, value);
+    }
+
+    /// The animation conversion options used when exporting, pass these to `Animation::save_with_options`.
+    pub fn anim_convert_options(&self) -> AnimationConvertOptions {
+        AnimationConvertOptions::new()
+            .compress(self.compress_animations().then(CurveTolerance::default))
+            .conversion(self.model_conversion())
+    }
+
     /// The audio file types to export to.
     pub fn audio_file_types(&self) -> Vec<AudioFileType> {
         let mut result = Vec::with_capacity(3);
@@ -363,6 +634,26 @@ impl PorterSettings {
         self.audio_settings.set(flag, value);
     }
 
+    /// How audio with more than two channels is exported.
+    pub fn audio_channel_mode(&self) -> AudioChannelMode {
+        self.audio_channel_mode
+    }
+
+    /// Sets how audio with more than two channels is exported.
+    pub fn set_audio_channel_mode(&mut self, mode: AudioChannelMode) {
+        self.audio_channel_mode = mode;
+    }
+
+    /// The sample rate audio is resampled to on export, or none to keep the source rate.
+    pub fn audio_sample_rate(&self) -> Option<u32> {
+        self.audio_sample_rate
+    }
+
+    /// Sets the sample rate audio is resampled to on export.
+    pub fn set_audio_sample_rate(&mut self, sample_rate: Option<u32>) {
+        self.audio_sample_rate = sample_rate;
+    }
+
     /// The image file type to export to.
     pub fn image_file_type(&self) -> ImageFileType {
         self.image_file_type
@@ -383,6 +674,33 @@ impl PorterSettings {
         self.image_normal_map_processing = processing;
     }
 
+    /// The tone mapping used when converting high dynamic range images to low dynamic range.
+    pub fn image_tone_mapping(&self) -> ImageToneMapping {
+        self.image_tone_mapping
+    }
+
+    /// Sets the tone mapping used when converting high dynamic range images.
+    pub fn set_image_tone_mapping(&mut self, tone_mapping: ImageToneMapping) {
+        self.image_tone_mapping = tone_mapping;
+    }
+
+    /// The exposure in stops applied before tone mapping.
+    pub fn image_exposure(&self) -> f32 {
+        self.image_exposure.clamp(-8.0, 8.0)
+    }
+
+    /// Sets the exposure in stops applied before tone mapping.
+    pub fn set_image_exposure(&mut self, exposure: f32) {
+        self.image_exposure = exposure;
+    }
+
+    /// The image conversion options used when exporting high dynamic range images.
+    pub fn image_convert_options(&self) -> ImageConvertOptions {
+        ImageConvertOptions::new()
+            .tone_mapping(self.image_tone_mapping())
+            .exposure(self.image_exposure())
+    }
+
     /// An output directory used to save assets.
     pub fn output_directory(&self) -> PathBuf {
         if let Some(output_directory) = self.output_directory.clone() {
@@ -406,6 +724,88 @@ impl PorterSettings {
         self.output_directory = Some(path);
     }
 
+    /// The template used to organize exported assets into subfolders of the output directory.
+    pub fn output_template(&self) -> &str {
+        &self.output_template
+    }
+
+    /// Sets the output template, where {game}, {type}, and {name} are replaced.
+    pub fn set_output_template<T: Into<String>>(&mut self, template: T) {
+        self.output_template = template.into();
+    }
+
+    /// Gets what happens when an exported file already exists.
+    pub fn export_collision(&self) -> PorterExportCollision {
+        self.export_collision
+    }
+
+    /// Sets what happens when an exported file already exists.
+    pub fn set_export_collision(&mut self, collision: PorterExportCollision) {
+        self.export_collision = collision;
+    }
+
+    /// Whether or not export all only exports new or changed assets.
+    pub fn incremental_export(&self) -> bool {
+        self.incremental_export
+    }
+
+    /// Sets whether or not export all only exports new or changed assets.
+    pub fn set_incremental_export(&mut self, value: bool) {
+        self.incremental_export = value;
+    }
+
+    /// Gets the number of parallel export threads, or zero to decide automatically.
+    pub fn export_threads(&self) -> u32 {
+        self.export_threads
+            .min(porter_threads::available_threads() as u32)
+    }
+
+    /// Sets the number of parallel export threads, or zero to decide automatically.
+    pub fn set_export_threads(&mut self, threads: u32) {
+        self.export_threads = threads;
+    }
+
+    /// Gets the priority of the export threads.
+    pub fn export_priority(&self) -> ThreadPriority {
+        self.export_priority
+    }
+
+    /// Sets the priority of the export threads.
+    pub fn set_export_priority(&mut self, priority: ThreadPriority) {
+        self.export_priority = priority;
+    }
+
+    /// The folder an asset is exported to, expanded from the output template inside of the output directory.
+    pub fn output_path<G: AsRef<str>, T: AsRef<str>, N: AsRef<str>>(
+        &self,
+        game: G,
+        asset_type: T,
+        name: N,
+    ) -> PathBuf {
+        let mut path = self.output_directory();
+
+        let template = if self.output_template.trim().is_empty() {
+            DEFAULT_OUTPUT_TEMPLATE
+        } else {
+            &self.output_template
+        };
+
+        for segment in template.split(['/', '\\']) {
+            // Each segment is sanitized after expanding, so values can't add folders or leave the output directory.
+            let segment = segment
+                .replace("{game}", &game.as_ref().to_lowercase())
+                .replace("{type}", &asset_type.as_ref().to_lowercase())
+                .replace("{name}", name.as_ref())
+                .sanitized();
+
+            if !segment.trim().is_empty() {
+                path.push(segment);
+            }
+        }
+
+        path
+    }
+
     /// Gets the preview control scheme.
     pub fn preview_controls(&self) -> PreviewControlScheme {
         self.preview_controls
@@ -436,6 +836,132 @@ impl PorterSettings {
         self.auto_scale = value;
     }
 
+    /// Whether or not to mirror models and animations across the x axis, converting left handed rigs.
+    pub fn mirror_x_axis(&self) -> bool {
+        self.mirror_x_axis
+    }
+
+    /// Sets whether or not to mirror models and animations across the x axis.
+    pub fn set_mirror_x_axis(&mut self, value: bool) {
+        self.mirror_x_axis = value;
+    }
+
+    /// The units models are converted to on export, or none to keep the source units.
+    pub fn export_units(&self) -> Option<ModelUnits> {
+        self.export_units
+    }
+
+    /// Sets the units models are converted to on export.
+    pub fn set_export_units(&mut self, units: Option<ModelUnits>) {
+        self.export_units = units;
+    }
+
+    /// The up axis models are converted to on export.
+    pub fn export_up_axis(&self) -> ExportUpAxis {
+        self.export_up_axis
+    }
+
+    /// Sets the up axis models are converted to on export.
+    pub fn set_export_up_axis(&mut self, up_axis: ExportUpAxis) {
+        self.export_up_axis = up_axis;
+    }
+
+    /// Whether or not hashed texture names are resolved with the name database when exporting.
+    pub fn resolve_texture_names(&self) -> bool {
+        self.resolve_texture_names
+    }
+
+    /// Sets whether or not hashed texture names are resolved with the name database when exporting.
+    pub fn set_resolve_texture_names(&mut self, value: bool) {
+        self.resolve_texture_names = value;
+    }
+
+    /// Whether or not models are validated before exporting, skipping any that would produce a corrupt file.
+    pub fn validate_models(&self) -> bool {
+        self.validate_models
+    }
+
+    /// Sets whether or not models are validated before exporting.
+    pub fn set_validate_models(&mut self, value: bool) {
+        self.validate_models = value;
+    }
+
+    /// The conversion applied to models before they are exported to any format.
+    pub fn model_conversion(&self) -> ModelConversion {
+        let up_axis = match self.export_up_axis {
+            ExportUpAxis::Source => None,
+            ExportUpAxis::Y => Some(Axis::Y),
+            ExportUpAxis::Z => Some(Axis::Z),
+        };
+
+        ModelConversion::new()
+            .units(self.export_units)
+            .up_axis(up_axis)
+            .mirror(self.mirror_x_axis.then_some(Axis::X))
+    }
+
+    /// Gets the command to run after exporting, empty when disabled.
+    pub fn post_export_command(&self) -> &str {
+        &self.post_export_command
+    }
+
+    /// Sets the command to run after exporting.
+    pub fn set_post_export_command<C: Into<String>>(&mut self, command: C) {
+        self.post_export_command = command.into();
+    }
+
+    /// Gets when the post export command is run.
+    pub fn post_export_mode(&self) -> PorterExportHookMode {
+        self.post_export_mode
+    }
+
+    /// Sets when the post export command is run.
+    pub fn set_post_export_mode(&mut self, mode: PorterExportHookMode) {
+        self.post_export_mode = mode;
+    }
+
+    /// Gets the post export command timeout in seconds.
+    pub fn post_export_timeout(&self) -> u32 {
+        self.post_export_timeout.clamp(1, 600)
+    }
+
+    /// Sets the post export command timeout in seconds.
+    pub fn set_post_export_timeout(&mut self, timeout: u32) {
+        self.post_export_timeout = timeout;
+    }
+
+    /// Builds the post export hook, if a command was configured.
+    pub fn export_hook(&self) -> Option<PorterExportHook> {
+        if self.post_export_command.trim().is_empty() {
+            return None;
+        }
+
+        Some(
+            PorterExportHook::new(&self.post_export_command, self.output_directory())
+                .mode(self.post_export_mode)
+                .timeout(Duration::from_secs(self.post_export_timeout() as u64)),
+        )
+    }
+
+    /// Whether or not debug messages are written to the log.
+    pub fn debug_logging(&self) -> bool {
+        self.debug_logging
+    }
+
+    /// Sets whether or not debug messages are written to the log.
+    pub fn set_debug_logging(&mut self, value: bool) {
+        self.debug_logging = value;
+    }
+
+    /// The log level used for the porter crates.
+    pub fn log_level(&self) -> LevelFilter {
+        if self.debug_logging {
+            LevelFilter::Debug
+        } else {
+            LevelFilter::Info
+        }
+    }
+
     /// Gets the far clip distance for preview.
     pub fn far_clip(&self) -> u32 {
         self.far_clip.clamp(10000, 1000000)
@@ -446,6 +972,200 @@ impl PorterSettings {
         self.far_clip = far_clip;
     }
 
+    /// The name of the gpu adapter to use, or `None` for the default adapter.
+    pub fn gpu_adapter(&self) -> Option<String> {
+        self.gpu_adapter.clone()
+    }
+
+    /// Sets the name of the gpu adapter to use, which takes effect on the next launch.
+    pub fn set_gpu_adapter(&mut self, adapter: Option<String>) {
+        self.gpu_adapter = adapter;
+    }
+
+    /// The interface scale in percent, applied on top of the monitor's scaling.
+    pub fn ui_scale(&self) -> u32 {
+        self.ui_scale.clamp(75, 200)
+    }
+
+    /// Sets the interface scale in percent, from 75% to 200%.
+    pub fn set_ui_scale(&mut self, scale: u32) {
+        self.ui_scale = scale;
+    }
+
+    /// Returns a copy of the settings which only exports to the given format for its asset type.
+    pub fn with_export_format(&self, format: PorterExportFormat) -> Self {
+        let mut settings = self.clone();
+
+        match format {
+            PorterExportFormat::Image(file_type) => {
+                settings.set_image_file_type(file_type);
+            }
+            PorterExportFormat::Model(file_type) => {
+                for file_type in ModelFileType::ALL {
+                    settings.set_model_file_type(file_type, false);
+                }
+
+                settings.set_model_file_type(file_type, true);
+            }
+            PorterExportFormat::Animation(file_type) => {
+                for file_type in AnimationFileType::ALL {
+                    settings.set_anim_file_type(file_type, false);
+                }
+
+                settings.set_anim_file_type(file_type, true);
+            }
+            PorterExportFormat::Audio(file_type) => {
+                for file_type in AudioFileType::ALL {
+                    settings.set_audio_file_type(file_type, false);
+                }
+
+                settings.set_audio_file_type(file_type, true);
+            }
+        }
+
+        settings
+    }
+
+    /// The saved layout of the asset list columns, in display order.
+    pub fn column_layout(&self) -> &[PorterColumnLayout] {
+        &self.column_layout
+    }
+
+    /// Sets the layout of the asset list columns.
+    pub fn set_column_layout(&mut self, layout: Vec<PorterColumnLayout>) {
+        self.column_layout = layout;
+    }
+
+    /// The size, position, and layout of the main window when it was last closed.
+    pub fn window_state(&self) -> PorterWindowState {
+        self.window_state
+    }
+
+    /// Sets the size, position, and layout of the main window, which is restored on the next launch.
+    pub fn set_window_state(&mut self, state: PorterWindowState) {
+        self.window_state = state;
+    }
+
+    /// The saved settings profiles.
+    pub fn profiles(&self) -> &[PorterSettingsProfile] {
+        &self.profiles
+    }
+
+    /// The name of the last applied or saved profile.
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+
+    /// Saves the current export settings as a profile, replacing any profile with the same name.
+    pub fn save_profile<N: AsRef<str>>(&mut self, name: N) {
+        let name = name.as_ref().trim();
+
+        if name.is_empty() {
+            return;
+        }
+
+        let profile = self.profile(name);
+
+        match self
+            .profiles
+            .iter_mut()
+            .find(|existing| profile_name_eq(&existing.name, name))
+        {
+            Some(existing) => *existing = profile,
+            None => self.profiles.push(profile),
+        }
+
+        self.active_profile = Some(name.to_string());
+    }
+
+    /// Creates a profile with the given name from the current export settings.
+    fn profile(&self, name: &str) -> PorterSettingsProfile {
+        PorterSettingsProfile {
+            name: name.to_string(),
+            model_settings: self.model_settings,
+            anim_settings: self.anim_settings,
+            audio_settings: self.audio_settings,
+            audio_channel_mode: self.audio_channel_mode,
+            audio_sample_rate: self.audio_sample_rate,
+            image_file_type: self.image_file_type,
+            image_normal_map_processing: self.image_normal_map_processing,
+            image_tone_mapping: self.image_tone_mapping,
+            image_exposure: self.image_exposure,
+            output_directory: self.output_directory.clone(),
+            output_template: self.output_template.clone(),
+            auto_scale: self.auto_scale,
+            mirror_x_axis: self.mirror_x_axis,
+            export_units: self.export_units,
+            export_up_axis: self.export_up_axis,
+            resolve_texture_names: self.resolve_texture_names,
+            validate_models: self.validate_models,
+        }
+    }
+
+    /// A fingerprint of the current export settings, which changes when exported files would be different.
+    ///
+    /// Settings which don't change the exported files, such as the output directory and model validation, are ignored.
+    pub fn export_fingerprint(&self) -> u64 {
+        let config = bincode::config::standard();
+
+        let profile = PorterSettingsProfile {
+            output_directory: None,
+            validate_models: false,
+            ..self.profile("")
+        };
+
+        bincode::encode_to_vec(profile, config)
+            .map(|buffer| buffer.as_slice().hash_xxh64())
+            .unwrap_or_default()
+    }
+
+    /// Applies the export settings from the profile with the given name.
+    pub fn apply_profile<N: AsRef<str>>(&mut self, name: N) {
+        let Some(profile) = self
+            .profiles
+            .iter()
+            .find(|profile| profile_name_eq(&profile.name, name.as_ref()))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.model_settings = profile.model_settings;
+        self.anim_settings = profile.anim_settings;
+        self.audio_settings = profile.audio_settings;
+        self.audio_channel_mode = profile.audio_channel_mode;
+        self.audio_sample_rate = profile.audio_sample_rate;
+        self.image_file_type = profile.image_file_type;
+        self.image_normal_map_processing = profile.image_normal_map_processing;
+        self.image_tone_mapping = profile.image_tone_mapping;
+        self.image_exposure = profile.image_exposure;
+        self.output_directory = profile.output_directory;
+        self.output_template = profile.output_template;
+        self.auto_scale = profile.auto_scale;
+        self.mirror_x_axis = profile.mirror_x_axis;
+        self.export_units = profile.export_units;
+        self.export_up_axis = profile.export_up_axis;
+        self.resolve_texture_names = profile.resolve_texture_names;
+        self.validate_models = profile.validate_models;
+        self.active_profile = Some(profile.name);
+    }
+
+    /// Deletes the profile with the given name.
+    pub fn delete_profile<N: AsRef<str>>(&mut self, name: N) {
+        let name = name.as_ref();
+
+        self.profiles
+            .retain(|profile| !profile_name_eq(&profile.name, name));
+
+        if self
+            .active_profile
+            .as_deref()
+            .is_some_and(|active| profile_name_eq(active, name))
+        {
+            self.active_profile = None;
+        }
+    }
+
     /// Update settings and returns a copy.
     pub fn update<F: FnOnce(&mut Self)>(&self, callback: F) -> Self {
         let mut settings = self.clone();
@@ -456,23 +1176,52 @@ impl PorterSettings {
     }
 }
 
+/// Whether or not two profile names refer to the same profile, names are trimmed and ignore case.
+fn profile_name_eq(left: &str, right: &str) -> bool {
+    left.trim().eq_ignore_ascii_case(right.trim())
+}
+
 impl Default for PorterSettings {
     fn default() -> Self {
         Self {
-            version: 1,
+            version: SETTINGS_VERSION,
             load_settings: PorterLoadSettings::all()
                 & !PorterLoadSettings::LOAD_RAW_FILES
                 & !PorterLoadSettings::LOAD_FORCE_RAW_FILES,
             model_settings: PorterModelSettings::EXPORT_CAST,
             anim_settings: PorterAnimSettings::EXPORT_CAST,
             audio_settings: PorterAudioSettings::EXPORT_WAV,
+            audio_channel_mode: AudioChannelMode::Keep,
+            audio_sample_rate: None,
             image_file_type: ImageFileType::Dds,
             image_normal_map_processing: ImageNormalMapProcessing::None,
+            image_tone_mapping: ImageToneMapping::None,
+            image_exposure: 0.0,
             output_directory: None,
+            output_template: String::from(DEFAULT_OUTPUT_TEMPLATE),
+            export_collision: PorterExportCollision::Overwrite,
+            incremental_export: false,
+            export_threads: 0,
+            export_priority: ThreadPriority::Normal,
+            debug_logging: false,
             preview_controls: PreviewControlScheme::Maya,
             preview_overlay: true,
             auto_scale: true,
             far_clip: 10000,
+            gpu_adapter: None,
+            ui_scale: 100,
+            mirror_x_axis: false,
+            export_units: None,
+            export_up_axis: ExportUpAxis::Source,
+            resolve_texture_names: true,
+            validate_models: cfg!(debug_assertions),
+            post_export_command: String::new(),
+            post_export_mode: PorterExportHookMode::PerFile,
+            post_export_timeout: 60,
+            column_layout: Vec::new(),
+            window_state: PorterWindowState::default(),
+            profiles: Vec::new(),
+            active_profile: None,
         }
     }
 }
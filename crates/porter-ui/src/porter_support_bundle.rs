@@ -0,0 +1,203 @@
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::io::Write;
+use std::path::Path;
+
+use directories::BaseDirs;
+use directories::ProjectDirs;
+
+use porter_utils::HashCrc;
+
+use crate::format_support_summary;
+use crate::PorterSettings;
+
+/// The zip local file header signature.
+const ZIP_LOCAL_HEADER: u32 = 0x04034B50;
+/// The zip central directory header signature.
+const ZIP_CENTRAL_HEADER: u32 = 0x02014B50;
+/// The zip end of central directory signature.
+const ZIP_END_HEADER: u32 = 0x06054B50;
+/// The zip version required to extract stored files.
+const ZIP_VERSION: u16 = 10;
+/// The dos date for 1980-01-01, the earliest date zip supports.
+const ZIP_DATE: u16 = 0x21;
+
+/// Masks the users home directory and name from the given text.
+fn mask_sensitive<S: AsRef<str>>(text: S) -> String {
+    let mut text = text.as_ref().to_owned();
+
+    if let Some(base_directories) = BaseDirs::new() {
+        let home = base_directories.home_dir().to_string_lossy().to_string();
+
+        if !home.is_empty() {
+            text = text.replace(&home, "<home>");
+        }
+    }
+
+    for variable in ["USERNAME", "USER"] {
+        if let Ok(user) = std::env::var(variable) {
+            if user.len() > 1 {
+                text = text.replace(&user, "<user>");
+            }
+        }
+    }
+
+    text
+}
+
+/// Gathers the system and gpu information.
+fn system_info(name: &str, version: &str) -> String {
+    let threads = std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1);
+
+    let gpu = match porter_gpu::try_gpu_instance() {
+        Some(instance) => {
+            let adapter = instance.adapter_info();
+
+            format!(
+                "GPU: {} ({:?})\nGPU Driver: {} {}\nGPU Lost: {}\n",
+                adapter.name,
+                adapter.backend,
+                adapter.driver,
+                adapter.driver_info,
+                instance.is_lost(),
+            )
+        }
+        None => String::from("GPU: None\n"),
+    };
+
+    format!(
+        "Tool: {} v{}\nOS: {} ({})\nThreads: {}\n{}",
+        name,
+        version,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        threads,
+        gpu,
+    )
+}
+
+/// Writes the given files to a zip archive without compression.
+fn write_zip<P: AsRef<Path>>(path: P, files: Vec<(String, Vec<u8>)>) -> Result<(), io::Error> {
+    let mut output = BufWriter::new(File::create(path.as_ref())?);
+    let mut central = Vec::new();
+    let mut offset: u32 = 0;
+
+    for (file_name, data) in &files {
+        let crc = data.as_slice().hash_crc32();
+        let size = data.len() as u32;
+        let name = file_name.as_bytes();
+
+        let mut header = Vec::with_capacity(30 + name.len());
+
+        header.extend_from_slice(&ZIP_LOCAL_HEADER.to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&ZIP_DATE.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(name);
+
+        central.extend_from_slice(&ZIP_CENTRAL_HEADER.to_le_bytes());
+        central.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        central.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&ZIP_DATE.to_le_bytes());
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0; 12]);
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name);
+
+        output.write_all(&header)?;
+        output.write_all(data)?;
+
+        offset += header.len() as u32 + size;
+    }
+
+    output.write_all(&central)?;
+    output.write_all(&ZIP_END_HEADER.to_le_bytes())?;
+    output.write_all(&[0; 4])?;
+    output.write_all(&(files.len() as u16).to_le_bytes())?;
+    output.write_all(&(files.len() as u16).to_le_bytes())?;
+    output.write_all(&(central.len() as u32).to_le_bytes())?;
+    output.write_all(&offset.to_le_bytes())?;
+    output.write_all(&0u16.to_le_bytes())?;
+
+    let mut output = output.into_inner().map_err(|e| e.into_error())?;
+
+    output.flush()?;
+
+    Ok(())
+}
+
+/// Creates a support bundle with the logs, settings, crash files, format support, and system info.
+pub fn create_support_bundle<P: AsRef<Path>>(
+    path: P,
+    name: &str,
+    version: &str,
+    settings: &PorterSettings,
+) -> Result<(), io::Error> {
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+
+    files.push((
+        String::from("system.txt"),
+        mask_sensitive(system_info(name, version)).into_bytes(),
+    ));
+
+    files.push((
+        String::from("settings.txt"),
+        mask_sensitive(format!("{:#?}", settings)).into_bytes(),
+    ));
+
+    files.push((
+        String::from("formats.txt"),
+        format_support_summary().join("\n").into_bytes(),
+    ));
+
+    if let Some(project_directory) = ProjectDirs::from("com", "DTZxPorter", "GameTools") {
+        let prefix = name.to_lowercase();
+
+        if let Ok(entries) = std::fs::read_dir(project_directory.config_dir()) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+
+                let Some(file_name) = path.file_name().and_then(|x| x.to_str()) else {
+                    continue;
+                };
+
+                let is_bundled = file_name.starts_with(&prefix)
+                    && path
+                        .extension()
+                        .is_some_and(|extension| extension == "crash" || extension == "log");
+
+                if !is_bundled {
+                    continue;
+                }
+
+                if let Ok(buffer) = std::fs::read(&path) {
+                    let buffer = if path.extension().is_some_and(|extension| extension == "log") {
+                        mask_sensitive(String::from_utf8_lossy(&buffer)).into_bytes()
+                    } else {
+                        buffer
+                    };
+
+                    files.push((file_name.to_owned(), buffer));
+                }
+            }
+        }
+    }
+
+    write_zip(path, files)
+}
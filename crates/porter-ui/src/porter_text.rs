@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use iced::advanced;
 use iced::advanced::layout;
 use iced::advanced::layout::Limits;
 use iced::advanced::layout::Node;
 use iced::advanced::mouse::Cursor;
+use iced::advanced::renderer::Quad;
 use iced::advanced::text::Paragraph;
 use iced::advanced::widget::Tree;
 use iced::advanced::Widget;
@@ -16,6 +18,8 @@ use iced::alignment::Vertical;
 use iced::widget::text;
 use iced::widget::text::LineHeight;
 use iced::widget::text::Shaping;
+use iced::Border;
+use iced::Color;
 use iced::Element;
 use iced::Font;
 use iced::Length;
@@ -26,6 +30,26 @@ use iced::Size;
 
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::ColumnTruncation;
+
+/// Measures the width of the content on a single line using the default font.
+pub fn porter_text_width(content: &str, size: f32) -> f32 {
+    let text = advanced::text::Text {
+        content,
+        size: Pixels(size),
+        line_height: LineHeight::default(),
+        bounds: Size::INFINITY,
+        font: Font::DEFAULT,
+        horizontal_alignment: Horizontal::Left,
+        vertical_alignment: Vertical::Top,
+        shaping: Shaping::Basic,
+    };
+
+    <iced::Renderer as advanced::text::Renderer>::Paragraph::with_text(text)
+        .min_bounds()
+        .width
+}
+
 /// Used to render better text wrapping.
 pub struct PorterText<'a, Message, Theme, Renderer>
 where
@@ -42,6 +66,9 @@ where
     font: Option<Font>,
     shaping: Shaping,
     style: <Theme as text::StyleSheet>::Style,
+    highlight: Vec<Range<usize>>,
+    highlight_color: Color,
+    truncation: ColumnTruncation,
     _phantom: PhantomData<&'a (Message, Renderer)>,
 }
 
@@ -64,6 +91,9 @@ where
             vertical_alignment: alignment::Vertical::Top,
             shaping: Shaping::Basic,
             style: Default::default(),
+            highlight: Vec::new(),
+            highlight_color: Color::TRANSPARENT,
+            truncation: ColumnTruncation::End,
             _phantom: PhantomData,
         }
     }
@@ -118,6 +148,19 @@ where
         self
     }
 
+    /// Sets the byte ranges of the content to highlight, only supported with left alignment.
+    pub fn highlight(mut self, ranges: Vec<Range<usize>>, color: Color) -> Self {
+        self.highlight = ranges;
+        self.highlight_color = color;
+        self
+    }
+
+    /// Sets where the content is cut when it doesn't fit, only supported with left alignment.
+    pub fn truncation(mut self, truncation: ColumnTruncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
     /// Sets the [`Shaping`] strategy of the [`PorterText`].
     pub fn shaping(mut self, shaping: Shaping) -> Self {
         self.shaping = shaping;
@@ -170,7 +213,45 @@ where
         let paragraph = <Renderer as advanced::text::Renderer>::Paragraph::with_text(text);
         let measure_full = paragraph.min_bounds().width;
 
-        let render_str = if measure_full > width {
+        let position = |index: usize| {
+            paragraph
+                .grapheme_position(0, index)
+                .map_or(measure_full, |position| position.x)
+        };
+
+        // Highlights past this point are cut off by the truncation.
+        let mut visible_width = width;
+
+        let render_str = if measure_full > width
+            && matches!(self.truncation, ColumnTruncation::Middle)
+        {
+            let graphemes: Vec<&str> = self.content.graphemes(true).collect();
+            let available = (width - size.0).max(0.0);
+
+            let mut head = 0;
+
+            while head < graphemes.len() && position(head + 1) <= available / 2.0 {
+                head += 1;
+            }
+
+            let head_width = position(head);
+
+            let mut tail = head;
+
+            while tail < graphemes.len() && measure_full - position(tail) > available - head_width {
+                tail += 1;
+            }
+
+            visible_width = head_width;
+
+            graphemes[..head]
+                .iter()
+                .chain(["…"].iter())
+                .chain(graphemes[tail..].iter())
+                .copied()
+                .collect::<Vec<_>>()
+                .join("")
+        } else if measure_full > width {
             let mut index = 0;
 
             while let Some(position) = paragraph.grapheme_position(0, index) {
@@ -206,6 +287,49 @@ where
             Vertical::Bottom => bounds.y + bounds.height,
         };
 
+        if width > 0.0 && matches!(self.horizontal_alignment, Horizontal::Left) {
+            let line_height = self.line_height.to_absolute(size).0;
+
+            let top = match self.vertical_alignment {
+                Vertical::Top => bounds.y,
+                Vertical::Center => bounds.center_y() - (line_height / 2.0),
+                Vertical::Bottom => bounds.y + bounds.height - line_height,
+            };
+
+            for range in &self.highlight {
+                let (Some(prefix), Some(matched)) = (
+                    self.content.get(..range.start),
+                    self.content.get(..range.end),
+                ) else {
+                    continue;
+                };
+
+                let start = prefix.graphemes(true).count();
+                let end = matched.graphemes(true).count();
+
+                let start_x = position(start);
+                let end_x = position(end).min(visible_width);
+
+                if start_x >= end_x {
+                    continue;
+                }
+
+                renderer.fill_quad(
+                    Quad {
+                        bounds: Rectangle {
+                            x: bounds.x + start_x,
+                            y: top,
+                            width: end_x - start_x,
+                            height: line_height,
+                        },
+                        border: Border::with_radius(2.0),
+                        shadow: Default::default(),
+                    },
+                    self.highlight_color,
+                );
+            }
+        }
+
         if width > 0.0 {
             let text = iced::advanced::Text {
                 content: &render_str,
@@ -439,6 +439,81 @@ impl From<PorterPreviewButtonStyle> for Button {
     }
 }
 
+/// The style for the context menu background.
+pub struct PorterContextMenuStyle;
+
+impl container::StyleSheet for PorterContextMenuStyle {
+    type Style = Theme;
+
+    fn appearance(&self, _: &Self::Style) -> container::Appearance {
+        container::Appearance {
+            text_color: None,
+            background: Some(Background::Color(Color::from_rgb8(0x1C, 0x1C, 0x1C))),
+            border: Border {
+                width: 1.0,
+                color: Color::from_rgba8(0x27, 0x9B, 0xD4, 0.75),
+                ..Border::with_radius(4.0)
+            },
+            shadow: Shadow {
+                color: Color::from_rgba8(0x0, 0x0, 0x0, 0.5),
+                offset: iced::Vector::new(0.0, 2.0),
+                blur_radius: 8.0,
+            },
+        }
+    }
+}
+
+impl From<PorterContextMenuStyle> for Container {
+    fn from(value: PorterContextMenuStyle) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
+/// The style for the context menu items.
+pub struct PorterContextMenuButtonStyle;
+
+impl button::StyleSheet for PorterContextMenuButtonStyle {
+    type Style = Theme;
+
+    fn active(&self, _: &Self::Style) -> button::Appearance {
+        button::Appearance {
+            shadow_offset: Default::default(),
+            background: None,
+            border: Border::with_radius(2.0),
+            shadow: Default::default(),
+            text_color: Color::WHITE,
+        }
+    }
+
+    fn hovered(&self, style: &Self::Style) -> button::Appearance {
+        let active = self.active(style);
+
+        button::Appearance {
+            background: Some(Background::Color(Color::from_rgba8(0x27, 0x9B, 0xD4, 0.75))),
+            ..active
+        }
+    }
+
+    fn pressed(&self, style: &Self::Style) -> button::Appearance {
+        self.hovered(style)
+    }
+
+    fn disabled(&self, style: &Self::Style) -> button::Appearance {
+        let active = self.active(style);
+
+        button::Appearance {
+            text_color: Color::from_rgb8(0x5C, 0x5C, 0x5C),
+            ..active
+        }
+    }
+}
+
+impl From<PorterContextMenuButtonStyle> for Button {
+    fn from(value: PorterContextMenuButtonStyle) -> Self {
+        Self::Custom(Box::new(value))
+    }
+}
+
 /// The style for the column headers.
 pub struct PorterColumnHeader;
 
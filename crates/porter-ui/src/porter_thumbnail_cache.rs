@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ops::Range;
+
+use iced::widget::image::Handle;
+
+/// A least recently used cache of asset list thumbnails, keyed by row index.
+pub struct PorterThumbnailCache {
+    capacity: usize,
+    generation: u64,
+    tick: u64,
+    entries: HashMap<usize, (u64, Option<Handle>)>,
+    pending: HashSet<usize>,
+}
+
+impl PorterThumbnailCache {
+    /// Constructs a new thumbnail cache which holds up to capacity thumbnails.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            generation: 0,
+            tick: 0,
+            entries: HashMap::new(),
+            pending: HashSet::new(),
+        }
+    }
+
+    /// The generation of the cache, which changes every time the cache is cleared.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Clears all thumbnails, used when the row indices no longer refer to the same assets.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.pending.clear();
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Gets the thumbnail for the given row, if it's loaded.
+    pub fn get(&self, index: usize) -> Option<&Handle> {
+        self.entries
+            .get(&index)
+            .and_then(|(_, handle)| handle.as_ref())
+    }
+
+    /// Marks the rows in range as recently used and returns the ones which still need to be loaded.
+    pub fn request(&mut self, range: Range<usize>) -> Vec<usize> {
+        self.tick = self.tick.wrapping_add(1);
+
+        let mut missing = Vec::new();
+
+        for index in range {
+            if let Some((tick, _)) = self.entries.get_mut(&index) {
+                *tick = self.tick;
+            } else if self.pending.insert(index) {
+                missing.push(index);
+            }
+        }
+
+        missing
+    }
+
+    /// Stores a loaded thumbnail, evicting the least recently used one when full.
+    pub fn insert(&mut self, generation: u64, index: usize, handle: Option<Handle>) {
+        if generation != self.generation {
+            return;
+        }
+
+        self.pending.remove(&index);
+
+        if self.entries.len() >= self.capacity && !self.entries.contains_key(&index) {
+            let oldest = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (tick, _))| *tick)
+                .map(|(index, _)| *index);
+
+            if let Some(oldest) = oldest {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.entries.insert(index, (self.tick, handle));
+    }
+}
@@ -1,14 +1,39 @@
 use iced::futures::channel::mpsc::UnboundedSender;
 
+use std::collections::HashSet;
+use std::io::Error;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
+
+use porter_model::ModelDiagnostic;
+
+use porter_threads::TaskScope;
+
+use porter_utils::AtomicProgress;
 
 use crate::Message;
+use crate::PorterExportCollision;
+use crate::PorterExportHook;
 use crate::PorterPreviewAsset;
 
 /// Used to syncronize with the ui.
 #[derive(Clone)]
 pub struct PorterUI {
     channel: Arc<Option<UnboundedSender<Message>>>,
+    dry_run: bool,
+    export_hook: Option<Arc<PorterExportHook>>,
+    export_hook_files: Arc<Mutex<Vec<PathBuf>>>,
+    export_hook_failures: Arc<Mutex<Vec<String>>>,
+    export_validation_failures: Arc<Mutex<Vec<String>>>,
+    export_collision: PorterExportCollision,
+    export_claimed: Arc<Mutex<HashSet<PathBuf>>>,
+    export_skipped: Arc<AtomicUsize>,
+    export_completed: Arc<Mutex<Vec<usize>>>,
+    export_scope: TaskScope,
 }
 
 impl PorterUI {
@@ -16,11 +41,226 @@ impl PorterUI {
     pub fn new(channel: Option<UnboundedSender<Message>>) -> Self {
         Self {
             channel: Arc::new(channel),
+            dry_run: false,
+            export_hook: None,
+            export_hook_files: Arc::new(Mutex::new(Vec::new())),
+            export_hook_failures: Arc::new(Mutex::new(Vec::new())),
+            export_validation_failures: Arc::new(Mutex::new(Vec::new())),
+            export_collision: PorterExportCollision::Overwrite,
+            export_claimed: Arc::new(Mutex::new(HashSet::new())),
+            export_skipped: Arc::new(AtomicUsize::new(0)),
+            export_completed: Arc::new(Mutex::new(Vec::new())),
+            export_scope: TaskScope::default(),
+        }
+    }
+
+    /// Sets whether or not the export is a dry run.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Whether or not the export is a dry run, which should run the full pipeline without writing any files.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Sets the post export hook to run for exported files.
+    pub fn export_hook(mut self, export_hook: Option<PorterExportHook>) -> Self {
+        self.export_hook = export_hook.map(Arc::new);
+        self
+    }
+
+    /// Sets what happens when an exported file already exists.
+    pub fn export_collision(mut self, export_collision: PorterExportCollision) -> Self {
+        self.export_collision = export_collision;
+        self
+    }
+
+    /// Sets the task scope the export runs in, which is cancelled when the export is cancelled.
+    pub fn export_scope(mut self, export_scope: TaskScope) -> Self {
+        self.export_scope = export_scope;
+        self
+    }
+
+    /// The task scope of the export, used to export assets in parallel, check for cancellation, and report progress.
+    pub fn scope(&self) -> &TaskScope {
+        &self.export_scope
+    }
+
+    /// Resolves the path a file should be exported to, or `None` if the file already exists and should be skipped.
+    ///
+    /// Skipped files are counted and reported in the export summary. Fails when a renamed file has no free path left.
+    pub fn export_path<P: AsRef<Path>>(&self, path: P) -> Result<Option<PathBuf>, Error> {
+        // The claimed paths are locked while resolving, so two threads can't pick the same free path.
+        let mut claimed = self
+            .export_claimed
+            .lock()
+            .map_err(|_| Error::other("Export paths are unavailable!"))?;
+
+        let result = self.export_collision.resolve(path, &mut claimed)?;
+
+        if result.is_none() {
+            self.export_skipped.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(result)
+    }
+
+    /// Reports an asset finished exporting, so it isn't exported again when resuming an interrupted export.
+    ///
+    /// Only reported assets are recorded for incremental exports, so assets that failed to export are exported again.
+    pub fn asset_exported(&self, asset: usize) {
+        if let Ok(mut completed) = self.export_completed.lock() {
+            completed.push(asset);
+        }
+    }
+
+    /// Sends the assets that finished exporting since the last sync to the ui.
+    fn sync_completed(&self) {
+        let completed = self
+            .export_completed
+            .lock()
+            .map(|mut completed| std::mem::take(&mut *completed))
+            .unwrap_or_default();
+
+        if completed.is_empty() {
+            return;
+        }
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::ExportCompleted(completed));
+
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    /// Reports the number of skipped files to the ui, resetting the count.
+    fn finish_export_skipped(&self) {
+        let skipped = self.export_skipped.swap(0, Ordering::Relaxed);
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::ExportSkipped(skipped));
+
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    /// Reports a file was exported, running the post export hook if one is configured.
+    pub fn exported<P: AsRef<Path>>(&self, path: P, asset_type: &str) {
+        if self.dry_run {
+            return;
+        }
+
+        let Some(export_hook) = self.export_hook.as_ref() else {
+            return;
+        };
+
+        if export_hook.is_per_file() {
+            if let Err(error) = export_hook.run_file(path.as_ref(), asset_type) {
+                if let Ok(mut failures) = self.export_hook_failures.lock() {
+                    failures.push(error.to_string());
+                }
+            }
+        } else if let Ok(mut files) = self.export_hook_files.lock() {
+            files.push(path.as_ref().to_path_buf());
+        }
+    }
+
+    /// Reports a model wasn't exported because it failed validation, the problems are shown once the export finishes.
+    pub fn validation_failed<N: AsRef<str>>(&self, name: N, diagnostics: &[ModelDiagnostic]) {
+        let Some(diagnostic) = diagnostics.iter().find(|x| x.is_error()) else {
+            return;
+        };
+
+        let errors: usize = diagnostics
+            .iter()
+            .filter(|x| x.is_error())
+            .map(|x| x.count())
+            .sum();
+
+        let failure = if errors > 1 {
+            format!(
+                "{}: {} (and {} more)",
+                name.as_ref(),
+                diagnostic,
+                errors - 1
+            )
+        } else {
+            format!("{}: {}", name.as_ref(), diagnostic)
+        };
+
+        if let Ok(mut failures) = self.export_validation_failures.lock() {
+            failures.push(failure);
+        }
+    }
+
+    /// Reports any models which failed validation to the ui.
+    fn finish_export_validation(&self) {
+        let failures = self
+            .export_validation_failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default();
+
+        if failures.is_empty() {
+            return;
+        }
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::ExportValidationFailed(failures));
+
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    /// Runs the batch post export hook, and reports any failures to the ui.
+    fn finish_export_hook(&self) {
+        let Some(export_hook) = self.export_hook.as_ref() else {
+            return;
+        };
+
+        let files = self
+            .export_hook_files
+            .lock()
+            .map(|mut files| std::mem::take(&mut *files))
+            .unwrap_or_default();
+
+        if !files.is_empty() {
+            if let Err(error) = export_hook.run_batch(&files) {
+                if let Ok(mut failures) = self.export_hook_failures.lock() {
+                    failures.push(error.to_string());
+                }
+            }
+        }
+
+        let failures = self
+            .export_hook_failures
+            .lock()
+            .map(|mut failures| std::mem::take(&mut *failures))
+            .unwrap_or_default();
+
+        if failures.is_empty() {
+            return;
+        }
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::ExportHookFailed(failures));
+
+            debug_assert!(result.is_ok());
         }
     }
 
     /// Syncs the ui with the current export progress.
     pub fn sync(&self, exporting: bool, progress: u32) {
+        self.sync_completed();
+
+        if !exporting {
+            self.finish_export_hook();
+            self.finish_export_validation();
+            self.finish_export_skipped();
+        }
+
         if let Some(channel) = self.channel.as_ref() {
             let result = channel.unbounded_send(Message::Sync(exporting, progress));
 
@@ -28,6 +268,29 @@ impl PorterUI {
         }
     }
 
+    /// Syncs the ui with the current export progress, including the item counts and estimated time remaining.
+    pub fn sync_progress(&self, exporting: bool, progress: &AtomicProgress) {
+        self.sync_completed();
+
+        if !exporting {
+            self.finish_export_hook();
+            self.finish_export_validation();
+            self.finish_export_skipped();
+        }
+
+        if let Some(channel) = self.channel.as_ref() {
+            let result = channel.unbounded_send(Message::SyncProgress(
+                exporting,
+                progress.progress(),
+                progress.completed(),
+                progress.total(),
+                progress.remaining(),
+            ));
+
+            debug_assert!(result.is_ok());
+        }
+    }
+
     /// Reports a preview asset is ready.
     pub fn preview(&self, asset: Option<PorterPreviewAsset>, request_id: u64) {
         if let Some(channel) = self.channel.as_ref() {
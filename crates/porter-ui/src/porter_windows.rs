@@ -5,17 +5,67 @@ use iced::window::Settings;
 
 use iced::Size;
 
-/// Utility to create the main window settings.
-pub fn porter_main_settings() -> Settings {
+use crate::PorterWindowState;
+
+/// The smallest size of the main window.
+pub const MAIN_WINDOW_MIN_SIZE: Size = Size::new(920.0, 582.0);
+
+/// Utility to create the main window settings, restoring the saved size and position.
+pub fn porter_main_settings(state: &PorterWindowState) -> Settings {
     Settings {
-        size: Size::new(920.0, 582.0),
-        position: Position::Centered,
-        min_size: Some(Size::new(920.0, 582.0)),
+        size: state.size(MAIN_WINDOW_MIN_SIZE),
+        position: state
+            .position()
+            .map_or(Position::Centered, Position::Specific),
+        min_size: Some(MAIN_WINDOW_MIN_SIZE),
         visible: false,
+        exit_on_close_request: false,
         ..Default::default()
     }
 }
 
+/// Moves the window to the center of the primary monitor when it isn't on any monitor, such as after the monitor it was restored on was disconnected.
+///
+/// This is only done on Windows, iced doesn't expose the monitor list, and other platforms leave window placement to the window manager.
+#[cfg(target_os = "windows")]
+pub fn porter_window_ensure_visible(hwnd: windows_sys::Win32::Foundation::HWND) {
+    use windows_sys::Win32::Foundation::*;
+    use windows_sys::Win32::Graphics::Gdi::*;
+    use windows_sys::Win32::UI::WindowsAndMessaging::*;
+
+    // SAFETY: The window handle is provided by iced, and the structures are sized before use.
+    unsafe {
+        if !MonitorFromWindow(hwnd, MONITOR_DEFAULTTONULL).is_null() {
+            return;
+        }
+
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTOPRIMARY);
+
+        let mut info: MONITORINFO = std::mem::zeroed();
+        let mut rect: RECT = std::mem::zeroed();
+
+        info.cbSize = std::mem::size_of::<MONITORINFO>() as u32;
+
+        if GetMonitorInfoW(monitor, &mut info) == 0 || GetWindowRect(hwnd, &mut rect) == 0 {
+            return;
+        }
+
+        let work = info.rcWork;
+        let width = rect.right - rect.left;
+        let height = rect.bottom - rect.top;
+
+        SetWindowPos(
+            hwnd,
+            std::ptr::null_mut(),
+            work.left + ((work.right - work.left - width) / 2).max(0),
+            work.top + ((work.bottom - work.top - height) / 2).max(0),
+            0,
+            0,
+            SWP_NOSIZE | SWP_NOZORDER | SWP_NOACTIVATE,
+        );
+    }
+}
+
 /// Utility to create the splash window settings.
 pub fn porter_splash_settings() -> Settings {
     Settings {
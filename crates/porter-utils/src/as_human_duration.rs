@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+/// Utility trait to convert durations to text.
+pub trait AsHumanDuration {
+    /// Converts a duration to a short human readable string.
+    fn as_human_duration(&self) -> String;
+}
+
+impl AsHumanDuration for Duration {
+    fn as_human_duration(&self) -> String {
+        let seconds = self.as_secs();
+
+        if seconds < 60 {
+            format!("{}s", seconds.max(1))
+        } else if seconds < 60 * 60 {
+            format!("{}m", seconds.div_ceil(60))
+        } else {
+            format!("{}h {}m", seconds / (60 * 60), (seconds % (60 * 60)) / 60)
+        }
+    }
+}
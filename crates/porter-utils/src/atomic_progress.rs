@@ -1,10 +1,14 @@
 use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
 
 struct AtomicProgressInner {
     total: AtomicUsize,
     complete: AtomicUsize,
+    started: Mutex<Instant>,
 }
 
 /// Used to track progress across multi-threading operations.
@@ -21,6 +25,7 @@ impl AtomicProgress {
             inner: Arc::new(AtomicProgressInner {
                 total: AtomicUsize::new(0),
                 complete: AtomicUsize::new(0),
+                started: Mutex::new(Instant::now()),
             }),
         }
     }
@@ -29,6 +34,10 @@ impl AtomicProgress {
     pub fn reset(&self, total: usize) {
         self.inner.total.store(total, Ordering::Relaxed);
         self.inner.complete.store(0, Ordering::Relaxed);
+
+        if let Ok(mut started) = self.inner.started.lock() {
+            *started = Instant::now();
+        }
     }
 
     /// Increments the completed count.
@@ -36,6 +45,54 @@ impl AtomicProgress {
         self.inner.complete.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Gets the number of completed items.
+    pub fn completed(&self) -> usize {
+        self.inner.complete.load(Ordering::Relaxed)
+    }
+
+    /// Gets the total number of items.
+    pub fn total(&self) -> usize {
+        self.inner.total.load(Ordering::Relaxed)
+    }
+
+    /// Gets the time elapsed since the progress was reset.
+    pub fn elapsed(&self) -> Duration {
+        self.inner
+            .started
+            .lock()
+            .map(|started| started.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// Gets the number of items completed per second.
+    pub fn rate(&self) -> f32 {
+        let elapsed = self.elapsed().as_secs_f32();
+
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+
+        self.completed() as f32 / elapsed
+    }
+
+    /// Gets the estimated time remaining until all items are completed.
+    pub fn remaining(&self) -> Option<Duration> {
+        let completed = self.completed();
+        let total = self.total();
+
+        if completed == 0 || completed >= total {
+            return None;
+        }
+
+        let rate = self.rate();
+
+        if rate <= 0.0 {
+            return None;
+        }
+
+        Some(Duration::from_secs_f32((total - completed) as f32 / rate))
+    }
+
     /// Gets the progress value out of 100%.
     pub fn progress(&self) -> u32 {
         let completed = self.inner.complete.load(Ordering::Relaxed);
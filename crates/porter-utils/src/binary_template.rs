@@ -0,0 +1,316 @@
+use std::io::Error;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use serde_json::Value;
+
+/// The type of a field in a binary template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryTemplateType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+    /// A null terminated string, read up to the end of the data.
+    CString,
+    /// Raw bytes shown as hex.
+    Bytes,
+}
+
+impl BinaryTemplateType {
+    /// Parses a kaitai struct type name such as 'u4' or 'f4'.
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "u1" => Self::U8,
+            "s1" => Self::I8,
+            "u2" => Self::U16,
+            "s2" => Self::I16,
+            "u4" => Self::U32,
+            "s4" => Self::I32,
+            "u8" => Self::U64,
+            "s8" => Self::I64,
+            "f4" => Self::F32,
+            "f8" => Self::F64,
+            "strz" => Self::CString,
+            _ => return None,
+        })
+    }
+
+    /// The size in bytes of a single element, strings are sized when read.
+    fn size(&self) -> usize {
+        match self {
+            Self::U8 | Self::I8 | Self::Bytes | Self::CString => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 | Self::F32 => 4,
+            Self::U64 | Self::I64 | Self::F64 => 8,
+        }
+    }
+}
+
+/// A single field definition in a binary template.
+#[derive(Debug, Clone)]
+pub struct BinaryTemplateField {
+    pub offset: usize,
+    pub field_type: BinaryTemplateType,
+    pub count: usize,
+    pub name: String,
+}
+
+/// A field parsed from data using a binary template.
+#[derive(Debug, Clone)]
+pub struct BinaryTemplateValue {
+    pub offset: usize,
+    pub size: usize,
+    pub name: String,
+    pub value: String,
+}
+
+/// A list of offset, type, and name definitions used to annotate binary data.
+///
+/// Templates are loaded from a kaitai struct like json definition, where each entry in `seq` is a field:
+/// ```json
+/// {
+///     "meta": { "endian": "le" },
+///     "seq": [
+///         { "id": "magic", "type": "u4" },
+///         { "id": "name", "type": "strz", "pos": "0x10" },
+///         { "id": "hash", "size": 16 },
+///         { "id": "bounds", "type": "f4", "repeat-expr": 6 }
+///     ]
+/// }
+/// ```
+/// Fields without a `pos` follow the previous field. Supported types are u1, s1, u2, s2, u4, s4, u8, s8, f4, f8, and strz,
+/// fields with a `size` and no `type` are raw bytes.
+#[derive(Debug, Default, Clone)]
+pub struct BinaryTemplate {
+    big_endian: bool,
+    fields: Vec<BinaryTemplateField>,
+}
+
+impl BinaryTemplate {
+    /// Constructs a new empty little endian binary template.
+    pub fn new() -> Self {
+        Self {
+            big_endian: false,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Loads a binary template from the given json file.
+    pub fn load<P: AsRef<Path>>(file: P) -> Result<Self, Error> {
+        Self::parse(&std::fs::read_to_string(file)?)
+    }
+
+    /// Parses a binary template from the given json source.
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let invalid = |message: String| Error::new(ErrorKind::InvalidData, message);
+
+        let source: Value =
+            serde_json::from_str(source).map_err(|error| invalid(error.to_string()))?;
+
+        let big_endian = match source.pointer("/meta/endian") {
+            None => false,
+            Some(Value::String(endian)) if endian == "le" => false,
+            Some(Value::String(endian)) if endian == "be" => true,
+            Some(_) => return Err(invalid(String::from("meta: endian must be 'le' or 'be'"))),
+        };
+
+        let Some(seq) = source.get("seq").and_then(Value::as_array) else {
+            return Err(invalid(String::from("expected a 'seq' array")));
+        };
+
+        let mut fields = Vec::with_capacity(seq.len());
+        let mut next_offset: usize = 0;
+
+        for (index, entry) in seq.iter().enumerate() {
+            let invalid = |message: &str| invalid(format!("seq[{}]: {}", index, message));
+
+            let name = entry
+                .get("id")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("expected an 'id'"))?;
+
+            let offset = match entry.get("pos") {
+                None => next_offset,
+                Some(pos) => parse_integer(pos).ok_or_else(|| invalid("invalid pos"))?,
+            };
+
+            let (field_type, count) = match (entry.get("type"), entry.get("size")) {
+                (Some(field_type), None) => {
+                    let field_type = field_type
+                        .as_str()
+                        .and_then(BinaryTemplateType::parse)
+                        .ok_or_else(|| invalid("unknown type"))?;
+
+                    let count = match entry.get("repeat-expr") {
+                        None => 1,
+                        Some(count) => {
+                            parse_integer(count).ok_or_else(|| invalid("invalid repeat-expr"))?
+                        }
+                    };
+
+                    (field_type, count)
+                }
+                (None, Some(size)) => (
+                    BinaryTemplateType::Bytes,
+                    parse_integer(size).ok_or_else(|| invalid("invalid size"))?,
+                ),
+                _ => return Err(invalid("expected either a 'type' or a 'size'")),
+            };
+
+            next_offset = field_type
+                .size()
+                .checked_mul(count)
+                .and_then(|size| size.checked_add(offset))
+                .ok_or_else(|| invalid("field is too large"))?;
+
+            fields.push(BinaryTemplateField {
+                offset,
+                field_type,
+                count,
+                name: name.to_string(),
+            });
+        }
+
+        Ok(Self { big_endian, fields })
+    }
+
+    /// Returns the fields in this template.
+    pub fn fields(&self) -> &[BinaryTemplateField] {
+        &self.fields
+    }
+
+    /// Adds a field to this template.
+    pub fn push(&mut self, field: BinaryTemplateField) {
+        self.fields.push(field);
+    }
+
+    /// Parses each field from the given data, fields past the end of the data are skipped.
+    pub fn annotate(&self, data: &[u8]) -> Result<Vec<BinaryTemplateValue>, Error> {
+        let mut result = Vec::with_capacity(self.fields.len());
+
+        for field in &self.fields {
+            let Some(remaining) = data.get(field.offset..) else {
+                continue;
+            };
+
+            let (size, value) = match field.field_type {
+                BinaryTemplateType::CString => {
+                    let length = remaining
+                        .iter()
+                        .position(|byte| *byte == 0)
+                        .unwrap_or(remaining.len());
+
+                    (
+                        (length + 1).min(remaining.len()),
+                        format!("\"{}\"", String::from_utf8_lossy(&remaining[..length])),
+                    )
+                }
+                BinaryTemplateType::Bytes => {
+                    let Some(bytes) = remaining.get(..field.count) else {
+                        continue;
+                    };
+
+                    (
+                        field.count,
+                        bytes
+                            .iter()
+                            .map(|byte| format!("{:02X}", byte))
+                            .collect::<Vec<_>>()
+                            .join(" "),
+                    )
+                }
+                field_type => {
+                    let size = field_type.size().checked_mul(field.count).ok_or_else(|| {
+                        Error::new(
+                            ErrorKind::InvalidData,
+                            format!("{}: field is too large", field.name),
+                        )
+                    })?;
+
+                    let Some(bytes) = remaining.get(..size) else {
+                        continue;
+                    };
+
+                    let values: Vec<String> = bytes
+                        .chunks_exact(field_type.size())
+                        .map(|bytes| format_value(field_type, bytes, self.big_endian))
+                        .collect();
+
+                    if field.count == 1 {
+                        (size, values.join(""))
+                    } else {
+                        (size, format!("[{}]", values.join(", ")))
+                    }
+                }
+            };
+
+            result.push(BinaryTemplateValue {
+                offset: field.offset,
+                size,
+                name: field.name.clone(),
+                value,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+/// Parses a json integer, or a string with a decimal or '0x' prefixed hex integer.
+fn parse_integer(value: &Value) -> Option<usize> {
+    match value {
+        Value::Number(number) => number
+            .as_u64()
+            .and_then(|value| usize::try_from(value).ok()),
+        Value::String(value) => match value.strip_prefix("0x") {
+            Some(hex) => usize::from_str_radix(hex, 16).ok(),
+            None => value.parse().ok(),
+        },
+        _ => None,
+    }
+}
+
+/// Formats a single value of the given type and endian.
+fn format_value(field_type: BinaryTemplateType, bytes: &[u8], big_endian: bool) -> String {
+    let mut buffer = [0u8; 8];
+
+    if big_endian {
+        buffer[8 - bytes.len()..].copy_from_slice(bytes);
+        buffer.reverse();
+    } else {
+        buffer[..bytes.len()].copy_from_slice(bytes);
+    }
+
+    match field_type {
+        BinaryTemplateType::U8 => buffer[0].to_string(),
+        BinaryTemplateType::I8 => (buffer[0] as i8).to_string(),
+        BinaryTemplateType::U16 => u16::from_le_bytes([buffer[0], buffer[1]]).to_string(),
+        BinaryTemplateType::I16 => i16::from_le_bytes([buffer[0], buffer[1]]).to_string(),
+        BinaryTemplateType::U32 => {
+            let value = u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]);
+
+            format!("{} (0x{:X})", value, value)
+        }
+        BinaryTemplateType::I32 => {
+            i32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]).to_string()
+        }
+        BinaryTemplateType::U64 => {
+            let value = u64::from_le_bytes(buffer);
+
+            format!("{} (0x{:X})", value, value)
+        }
+        BinaryTemplateType::I64 => i64::from_le_bytes(buffer).to_string(),
+        BinaryTemplateType::F32 => {
+            f32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]).to_string()
+        }
+        BinaryTemplateType::F64 => f64::from_le_bytes(buffer).to_string(),
+        BinaryTemplateType::CString | BinaryTemplateType::Bytes => String::new(),
+    }
+}
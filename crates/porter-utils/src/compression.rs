@@ -0,0 +1,175 @@
+use std::io;
+use std::io::Read;
+
+/// A compression method used to decompress data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionMethod {
+    /// Raw lz4 block data.
+    Lz4,
+    /// Lz4 frame data.
+    Lz4Frame,
+    /// Zstandard frame data, requires the `zstd` feature.
+    Zstd,
+    /// Deflate data with a zlib header, requires the `zlib` feature.
+    Zlib,
+    /// Raw deflate data without a header, requires the `zlib` feature.
+    Deflate,
+    /// Oodle compressed data, requires the `oodle` feature and an installed oodle library.
+    Oodle,
+}
+
+impl CompressionMethod {
+    /// All of the compression methods.
+    pub const ALL: [Self; 6] = [
+        Self::Lz4,
+        Self::Lz4Frame,
+        Self::Zstd,
+        Self::Zlib,
+        Self::Deflate,
+        Self::Oodle,
+    ];
+
+    /// Whether or not the compression method is enabled in this build.
+    pub const fn is_enabled(&self) -> bool {
+        match self {
+            Self::Lz4 | Self::Lz4Frame => true,
+            Self::Zstd => cfg!(feature = "zstd"),
+            Self::Zlib | Self::Deflate => cfg!(feature = "zlib"),
+            Self::Oodle => cfg!(feature = "oodle"),
+        }
+    }
+}
+
+/// Utility to create an error for a method that isn't enabled.
+fn unsupported(method: CompressionMethod) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("Compression method {:?} is not enabled", method),
+    )
+}
+
+/// Utility to read an entire stream which should be the expected size.
+fn read_expected<R: Read>(reader: R, expected_size: usize) -> Result<Vec<u8>, io::Error> {
+    let mut result = Vec::new();
+
+    result
+        .try_reserve_exact(expected_size)
+        .map_err(|e| io::Error::new(io::ErrorKind::OutOfMemory, e))?;
+
+    reader
+        .take(expected_size as u64 + 1)
+        .read_to_end(&mut result)?;
+
+    Ok(result)
+}
+
+/// Decompresses the input buffer using the given method, the result must match the expected size.
+pub fn decompress<I: AsRef<[u8]>>(
+    method: CompressionMethod,
+    input: I,
+    expected_size: usize,
+) -> Result<Vec<u8>, io::Error> {
+    let input = input.as_ref();
+
+    let result = match method {
+        CompressionMethod::Lz4 => {
+            let mut result = Vec::new();
+
+            result
+                .try_reserve_exact(expected_size)
+                .map_err(|e| io::Error::new(io::ErrorKind::OutOfMemory, e))?;
+
+            result.resize(expected_size, 0);
+
+            let size = lz4_flex::decompress_into(input, &mut result)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            result.truncate(size);
+            result
+        }
+        #[cfg(feature = "oodle")]
+        CompressionMethod::Oodle => crate::oodle_decompress(input, expected_size)?,
+        #[cfg(not(feature = "oodle"))]
+        CompressionMethod::Oodle => return Err(unsupported(method)),
+        _ => read_expected(decompress_stream(method, input)?, expected_size)?,
+    };
+
+    if result.len() != expected_size {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "Decompressed size {} does not match expected size {}",
+                result.len(),
+                expected_size
+            ),
+        ));
+    }
+
+    Ok(result)
+}
+
+/// Decompresses the input buffer using the given method into the output buffer, returning the number of bytes written.
+pub fn decompress_into<I: AsRef<[u8]>>(
+    method: CompressionMethod,
+    input: I,
+    output: &mut [u8],
+) -> Result<usize, io::Error> {
+    let input = input.as_ref();
+
+    match method {
+        CompressionMethod::Lz4 => lz4_flex::decompress_into(input, output)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+        #[cfg(feature = "oodle")]
+        CompressionMethod::Oodle => {
+            crate::oodle_decompress_into(input, output)?;
+
+            Ok(output.len())
+        }
+        #[cfg(not(feature = "oodle"))]
+        CompressionMethod::Oodle => Err(unsupported(method)),
+        _ => {
+            let mut reader = decompress_stream(method, input)?;
+            let mut written = 0;
+
+            while written < output.len() {
+                let read = reader.read(&mut output[written..])?;
+
+                if read == 0 {
+                    break;
+                }
+
+                written += read;
+            }
+
+            Ok(written)
+        }
+    }
+}
+
+/// Creates a streaming decompressor for the given method which reads from the input.
+///
+/// Raw lz4 blocks and oodle data can not be streamed, use [`CompressionMethod::Lz4Frame`] instead.
+pub fn decompress_stream<'a, R: Read + 'a>(
+    method: CompressionMethod,
+    input: R,
+) -> Result<Box<dyn Read + 'a>, io::Error> {
+    match method {
+        CompressionMethod::Lz4 => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Lz4 block data can not be streamed",
+        )),
+        CompressionMethod::Oodle => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Oodle data can not be streamed",
+        )),
+        CompressionMethod::Lz4Frame => Ok(Box::new(lz4_flex::frame::FrameDecoder::new(input))),
+        #[cfg(feature = "zstd")]
+        CompressionMethod::Zstd => Ok(Box::new(zstd::stream::read::Decoder::new(input)?)),
+        #[cfg(feature = "zlib")]
+        CompressionMethod::Zlib => Ok(Box::new(flate2::read::ZlibDecoder::new(input))),
+        #[cfg(feature = "zlib")]
+        CompressionMethod::Deflate => Ok(Box::new(flate2::read::DeflateDecoder::new(input))),
+        #[allow(unreachable_patterns)]
+        _ => Err(unsupported(method)),
+    }
+}
@@ -1,3 +1,8 @@
+use std::io::Error;
+use std::io::Write;
+
+use crate::BinaryTemplate;
+
 /// Utility trait used to print hex blocks to the console for easy debugging.
 pub trait DebugHex {
     /// Prints the value to the console using pretty printed hex values.
@@ -38,3 +43,41 @@ impl DebugHex for Vec<u8> {
         (&self[0..]).debug_hex()
     }
 }
+
+/// Utility trait used to write binary data annotated with the fields of a template.
+pub trait DebugTemplate {
+    /// Writes each field of the template with its offset, raw hex bytes, and parsed value to the output.
+    fn debug_template<W: Write>(&self, template: &BinaryTemplate, output: W) -> Result<(), Error>;
+}
+
+impl DebugTemplate for &[u8] {
+    fn debug_template<W: Write>(
+        &self,
+        template: &BinaryTemplate,
+        mut output: W,
+    ) -> Result<(), Error> {
+        for value in template.annotate(self)? {
+            let bytes = &self[value.offset..value.offset + value.size.min(16)];
+
+            let hex = bytes
+                .iter()
+                .map(|byte| format!("{:02X}", byte))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            writeln!(
+                output,
+                "{:08X}  {:<47}  {} = {}",
+                value.offset, hex, value.name, value.value
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DebugTemplate for Vec<u8> {
+    fn debug_template<W: Write>(&self, template: &BinaryTemplate, output: W) -> Result<(), Error> {
+        (&self[0..]).debug_template(template, output)
+    }
+}
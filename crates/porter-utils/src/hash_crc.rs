@@ -0,0 +1,190 @@
+/// A crc32 algorithm with a configurable polynomial.
+#[derive(Debug, Clone, Copy)]
+pub struct Crc32 {
+    table: [u32; 256],
+    init: u32,
+    xor_out: u32,
+    reflected: bool,
+}
+
+impl Crc32 {
+    /// The crc32 algorithm used by zip, png, and most archives.
+    pub const ISO_HDLC: Self = Self::new(0x04C11DB7, 0xFFFFFFFF, 0xFFFFFFFF, true);
+    /// The crc32 algorithm used by mpeg-2 and some console archives.
+    pub const MPEG_2: Self = Self::new(0x04C11DB7, 0xFFFFFFFF, 0, false);
+    /// The crc32c (castagnoli) algorithm.
+    pub const ISCSI: Self = Self::new(0x1EDC6F41, 0xFFFFFFFF, 0xFFFFFFFF, true);
+
+    /// Constructs a new crc32 algorithm from the given polynomial (in normal form), initial value, final xor value, and whether or not input is reflected.
+    pub const fn new(polynomial: u32, init: u32, xor_out: u32, reflected: bool) -> Self {
+        let mut table = [0u32; 256];
+        let mut i = 0;
+
+        while i < 256 {
+            let mut crc = if reflected {
+                i as u32
+            } else {
+                (i as u32) << 24
+            };
+
+            let mut bit = 0;
+
+            while bit < 8 {
+                crc = if reflected {
+                    if crc & 1 == 1 {
+                        (crc >> 1) ^ polynomial.reverse_bits()
+                    } else {
+                        crc >> 1
+                    }
+                } else if crc & 0x80000000 == 0x80000000 {
+                    (crc << 1) ^ polynomial
+                } else {
+                    crc << 1
+                };
+
+                bit += 1;
+            }
+
+            table[i] = crc;
+            i += 1;
+        }
+
+        Self {
+            table,
+            init,
+            xor_out,
+            reflected,
+        }
+    }
+
+    /// Computes the checksum for the given buffer.
+    pub fn checksum(&self, buffer: &[u8]) -> u32 {
+        let crc = if self.reflected {
+            buffer.iter().fold(self.init, |crc, &byte| {
+                self.table[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+            })
+        } else {
+            buffer.iter().fold(self.init, |crc, &byte| {
+                self.table[(((crc >> 24) ^ byte as u32) & 0xFF) as usize] ^ (crc << 8)
+            })
+        };
+
+        crc ^ self.xor_out
+    }
+}
+
+/// A crc64 algorithm with a configurable polynomial.
+#[derive(Debug, Clone, Copy)]
+pub struct Crc64 {
+    table: [u64; 256],
+    init: u64,
+    xor_out: u64,
+    reflected: bool,
+}
+
+impl Crc64 {
+    /// The crc64 algorithm defined by ecma-182.
+    pub const ECMA_182: Self = Self::new(0x42F0E1EBA9EA3693, 0, 0, false);
+    /// The crc64 algorithm used by xz.
+    pub const XZ: Self = Self::new(
+        0x42F0E1EBA9EA3693,
+        0xFFFFFFFFFFFFFFFF,
+        0xFFFFFFFFFFFFFFFF,
+        true,
+    );
+
+    /// Constructs a new crc64 algorithm from the given polynomial (in normal form), initial value, final xor value, and whether or not input is reflected.
+    pub const fn new(polynomial: u64, init: u64, xor_out: u64, reflected: bool) -> Self {
+        let mut table = [0u64; 256];
+        let mut i = 0;
+
+        while i < 256 {
+            let mut crc = if reflected {
+                i as u64
+            } else {
+                (i as u64) << 56
+            };
+
+            let mut bit = 0;
+
+            while bit < 8 {
+                crc = if reflected {
+                    if crc & 1 == 1 {
+                        (crc >> 1) ^ polynomial.reverse_bits()
+                    } else {
+                        crc >> 1
+                    }
+                } else if crc & 0x8000000000000000 == 0x8000000000000000 {
+                    (crc << 1) ^ polynomial
+                } else {
+                    crc << 1
+                };
+
+                bit += 1;
+            }
+
+            table[i] = crc;
+            i += 1;
+        }
+
+        Self {
+            table,
+            init,
+            xor_out,
+            reflected,
+        }
+    }
+
+    /// Computes the checksum for the given buffer.
+    pub fn checksum(&self, buffer: &[u8]) -> u64 {
+        let crc = if self.reflected {
+            buffer.iter().fold(self.init, |crc, &byte| {
+                self.table[((crc ^ byte as u64) & 0xFF) as usize] ^ (crc >> 8)
+            })
+        } else {
+            buffer.iter().fold(self.init, |crc, &byte| {
+                self.table[(((crc >> 56) ^ byte as u64) & 0xFF) as usize] ^ (crc << 8)
+            })
+        };
+
+        crc ^ self.xor_out
+    }
+}
+
+/// Utility to hash data with crc algo.
+pub trait HashCrc {
+    /// Creates a crc32 (iso-hdlc) checksum for this data.
+    fn hash_crc32(&self) -> u32;
+    /// Creates a crc64 (xz) checksum for this data.
+    fn hash_crc64(&self) -> u64;
+}
+
+impl HashCrc for &[u8] {
+    fn hash_crc32(&self) -> u32 {
+        Crc32::ISO_HDLC.checksum(self)
+    }
+
+    fn hash_crc64(&self) -> u64 {
+        Crc64::XZ.checksum(self)
+    }
+}
+
+impl HashCrc for &str {
+    fn hash_crc32(&self) -> u32 {
+        Crc32::ISO_HDLC.checksum(self.as_bytes())
+    }
+
+    fn hash_crc64(&self) -> u64 {
+        Crc64::XZ.checksum(self.as_bytes())
+    }
+}
+
+impl HashCrc for String {
+    fn hash_crc32(&self) -> u32 {
+        Crc32::ISO_HDLC.checksum(self.as_bytes())
+    }
+
+    fn hash_crc64(&self) -> u64 {
+        Crc64::XZ.checksum(self.as_bytes())
+    }
+}
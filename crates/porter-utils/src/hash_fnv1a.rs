@@ -0,0 +1,60 @@
+/// Fnv1a 32bit offset basis.
+const FNV1A32_OFFSET: u32 = 0x811c9dc5;
+/// Fnv1a 32bit prime.
+const FNV1A32_PRIME: u32 = 0x01000193;
+/// Fnv1a 64bit offset basis.
+const FNV1A64_OFFSET: u64 = 0xcbf29ce484222325;
+/// Fnv1a 64bit prime.
+const FNV1A64_PRIME: u64 = 0x100000001b3;
+
+/// Computes the fnv1a 32bit hash for the given buffer.
+fn fnv1a32(buffer: &[u8]) -> u32 {
+    buffer.iter().fold(FNV1A32_OFFSET, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(FNV1A32_PRIME)
+    })
+}
+
+/// Computes the fnv1a 64bit hash for the given buffer.
+fn fnv1a64(buffer: &[u8]) -> u64 {
+    buffer.iter().fold(FNV1A64_OFFSET, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV1A64_PRIME)
+    })
+}
+
+/// Utility to hash data with fnv1a algo.
+pub trait HashFnv1a {
+    /// Creates a fnv1a 32bit checksum for this data.
+    fn hash_fnv1a32(&self) -> u32;
+    /// Creates a fnv1a 64bit checksum for this data.
+    fn hash_fnv1a64(&self) -> u64;
+}
+
+impl HashFnv1a for &[u8] {
+    fn hash_fnv1a32(&self) -> u32 {
+        fnv1a32(self)
+    }
+
+    fn hash_fnv1a64(&self) -> u64 {
+        fnv1a64(self)
+    }
+}
+
+impl HashFnv1a for &str {
+    fn hash_fnv1a32(&self) -> u32 {
+        fnv1a32(self.as_bytes())
+    }
+
+    fn hash_fnv1a64(&self) -> u64 {
+        fnv1a64(self.as_bytes())
+    }
+}
+
+impl HashFnv1a for String {
+    fn hash_fnv1a32(&self) -> u32 {
+        fnv1a32(self.as_bytes())
+    }
+
+    fn hash_fnv1a64(&self) -> u64 {
+        fnv1a64(self.as_bytes())
+    }
+}
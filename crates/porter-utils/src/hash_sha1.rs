@@ -0,0 +1,101 @@
+/// Sha1 initial state.
+const SHA1_INIT: [u32; 5] = [0x67452301, 0xEFCDAB89, 0x98BADCFE, 0x10325476, 0xC3D2E1F0];
+
+/// Processes a single 64 byte block into the state.
+fn sha1_block(state: &mut [u32; 5], block: &[u8]) {
+    let mut words = [0u32; 80];
+
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    for i in 16..80 {
+        words[i] = (words[i - 3] ^ words[i - 8] ^ words[i - 14] ^ words[i - 16]).rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+    for (i, word) in words.iter().enumerate() {
+        let (f, k) = match i {
+            0..=19 => ((b & c) | (!b & d), 0x5A827999),
+            20..=39 => (b ^ c ^ d, 0x6ED9EBA1),
+            40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1BBCDC),
+            _ => (b ^ c ^ d, 0xCA62C1D6),
+        };
+
+        let temp = a
+            .rotate_left(5)
+            .wrapping_add(f)
+            .wrapping_add(e)
+            .wrapping_add(k)
+            .wrapping_add(*word);
+
+        e = d;
+        d = c;
+        c = b.rotate_left(30);
+        b = a;
+        a = temp;
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+    state[4] = state[4].wrapping_add(e);
+}
+
+/// Computes the sha1 digest for the given buffer.
+fn sha1(buffer: &[u8]) -> [u8; 20] {
+    let mut state = SHA1_INIT;
+    let mut chunks = buffer.chunks_exact(64);
+
+    for chunk in &mut chunks {
+        sha1_block(&mut state, chunk);
+    }
+
+    let remainder = chunks.remainder();
+    let mut last = [0u8; 128];
+
+    last[0..remainder.len()].copy_from_slice(remainder);
+    last[remainder.len()] = 0x80;
+
+    let length = if remainder.len() < 56 { 64 } else { 128 };
+
+    last[length - 8..length].copy_from_slice(&((buffer.len() as u64) * 8).to_be_bytes());
+
+    for chunk in last[0..length].chunks_exact(64) {
+        sha1_block(&mut state, chunk);
+    }
+
+    let mut result = [0u8; 20];
+
+    for (bytes, word) in result.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_be_bytes());
+    }
+
+    result
+}
+
+/// Utility to hash data with sha1 algo.
+pub trait HashSha1 {
+    /// Creates a sha1 digest for this data.
+    fn hash_sha1(&self) -> [u8; 20];
+}
+
+impl HashSha1 for &[u8] {
+    fn hash_sha1(&self) -> [u8; 20] {
+        sha1(self)
+    }
+}
+
+impl HashSha1 for &str {
+    fn hash_sha1(&self) -> [u8; 20] {
+        sha1(self.as_bytes())
+    }
+}
+
+impl HashSha1 for String {
+    fn hash_sha1(&self) -> [u8; 20] {
+        sha1(self.as_bytes())
+    }
+}
@@ -3,18 +3,26 @@ mod array_write_ext;
 mod as_aligned;
 mod as_byte_slice;
 mod as_human_bytes;
+mod as_human_duration;
 mod as_this_slice;
 mod atomic_cancel;
 mod atomic_progress;
 mod atomic_semaphore;
+mod binary_template;
 mod bit_sink;
 mod bit_stream;
+mod compression;
 mod debug_bits;
 mod debug_hex;
 mod extract_digits;
+mod hash_crc;
+mod hash_fnv1a;
 mod hash_murmur64a;
+mod hash_sha1;
 mod hash_xxh64;
 mod name_database;
+#[cfg(feature = "oodle")]
+mod oodle;
 mod option_ext;
 mod pattern;
 mod result_ext;
@@ -34,18 +42,26 @@ pub use array_write_ext::*;
 pub use as_aligned::*;
 pub use as_byte_slice::*;
 pub use as_human_bytes::*;
+pub use as_human_duration::*;
 pub use as_this_slice::*;
 pub use atomic_cancel::*;
 pub use atomic_progress::*;
 pub use atomic_semaphore::*;
+pub use binary_template::*;
 pub use bit_sink::*;
 pub use bit_stream::*;
+pub use compression::*;
 pub use debug_bits::*;
 pub use debug_hex::*;
 pub use extract_digits::*;
+pub use hash_crc::*;
+pub use hash_fnv1a::*;
 pub use hash_murmur64a::*;
+pub use hash_sha1::*;
 pub use hash_xxh64::*;
 pub use name_database::*;
+#[cfg(feature = "oodle")]
+pub use oodle::*;
 pub use option_ext::*;
 pub use pattern::*;
 pub use result_ext::*;
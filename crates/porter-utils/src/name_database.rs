@@ -1,17 +1,52 @@
 use std::collections::HashMap;
 use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
 use std::io::Cursor;
 use std::io::Read;
 use std::io::Write;
 use std::ops;
 use std::path::Path;
+use std::path::PathBuf;
 
 use lz4_flex::decompress_into;
 
+use crate::HashFnv1a;
+use crate::HashMurMur64A;
+use crate::HashXXH64;
+use crate::SanitizeFilename;
 use crate::StringReadExt;
 use crate::StructReadExt;
 use crate::StructWriteExt;
 
+/// A hash algorithm used to map a name to it's hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameHashAlgorithm {
+    XXH64,
+    MurMur64A,
+    Fnv1a,
+}
+
+impl NameHashAlgorithm {
+    /// Hashes the given name with this algorithm.
+    pub fn hash(&self, name: &str) -> u64 {
+        match self {
+            Self::XXH64 => name.hash_xxh64(),
+            Self::MurMur64A => name.hash_murmur64a(),
+            Self::Fnv1a => name.hash_fnv1a64(),
+        }
+    }
+
+    /// The short name of this algorithm.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::XXH64 => "xxh64",
+            Self::MurMur64A => "murmur64a",
+            Self::Fnv1a => "fnv1a",
+        }
+    }
+}
+
 /// A database of asset hash:name pairs used to link a packed asset to it's source name.
 #[repr(transparent)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -81,6 +116,195 @@ impl NameDatabase {
         })
     }
 
+    /// Reads and merges multiple name databases from the given file paths.
+    pub fn load_many<P: AsRef<Path>>(files: &[P]) -> Result<Self, std::io::Error> {
+        let mut result = Self::new();
+
+        for file in files {
+            result.merge(Self::load(file)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Loads the cached name database for the given algorithms, or rebuilds it from the given name lists when any of them are newer than the cache.
+    pub fn load_cached<C: AsRef<Path>, P: AsRef<Path>>(
+        cache: C,
+        lists: &[P],
+        algorithms: &[NameHashAlgorithm],
+    ) -> Result<Self, std::io::Error> {
+        let cache = Self::cache_path(cache.as_ref(), algorithms);
+        let cache = cache.as_path();
+
+        let cache_modified = std::fs::metadata(cache).and_then(|metadata| metadata.modified());
+
+        if let Ok(cache_modified) = cache_modified {
+            let stale = lists.iter().any(|list| {
+                std::fs::metadata(list)
+                    .and_then(|metadata| metadata.modified())
+                    .map_or(true, |modified| modified > cache_modified)
+            });
+
+            if !stale {
+                if let Ok(database) = Self::load(cache) {
+                    return Ok(database);
+                }
+            }
+        }
+
+        let mut result = Self::new();
+
+        for list in lists {
+            result.append_list(list, algorithms)?;
+        }
+
+        result.save(cache)?;
+
+        Ok(result)
+    }
+
+    /// Builds the cache path for the given algorithms, by appending their names to the file stem.
+    fn cache_path(cache: &Path, algorithms: &[NameHashAlgorithm]) -> PathBuf {
+        let mut names: Vec<&str> = algorithms
+            .iter()
+            .map(|algorithm| algorithm.name())
+            .collect();
+
+        names.sort_unstable();
+        names.dedup();
+
+        let mut file_name = cache.file_stem().unwrap_or_default().to_os_string();
+
+        for name in names {
+            file_name.push("_");
+            file_name.push(name);
+        }
+
+        if let Some(extension) = cache.extension() {
+            file_name.push(".");
+            file_name.push(extension);
+        }
+
+        cache.with_file_name(file_name)
+    }
+
+    /// Appends the names to the database, hashed with each of the given algorithms.
+    pub fn append_names<I: IntoIterator<Item = S>, S: AsRef<str>>(
+        &mut self,
+        names: I,
+        algorithms: &[NameHashAlgorithm],
+    ) {
+        for name in names {
+            let name = name.as_ref().trim();
+
+            if name.is_empty() {
+                continue;
+            }
+
+            for algorithm in algorithms {
+                self.inner
+                    .entry(algorithm.hash(name))
+                    .or_insert_with(|| name.to_owned());
+            }
+        }
+    }
+
+    /// Appends a plain text name list with one name per line to the database, hashed with each of the given algorithms.
+    pub fn append_list<P: AsRef<Path>>(
+        &mut self,
+        file: P,
+        algorithms: &[NameHashAlgorithm],
+    ) -> Result<(), std::io::Error> {
+        let file = BufReader::new(File::open(file.as_ref())?);
+
+        for line in file.lines() {
+            self.append_names([line?], algorithms);
+        }
+
+        Ok(())
+    }
+
+    /// Merges the other database into this one, existing names are kept.
+    pub fn merge(&mut self, other: NameDatabase) {
+        for (hash, name) in other.inner {
+            self.inner.entry(hash).or_insert(name);
+        }
+    }
+
+    /// Looks up the name for the given hash.
+    pub fn resolve(&self, hash: u64) -> Option<&str> {
+        self.inner.get(&hash).map(|name| name.as_str())
+    }
+
+    /// Parses the hash from a name which is only a hash, such as `0x1a2b3c4d` or `ximage_1a2b3c4d5e6f7a8b`, without a `0x` prefix it must be all 16 digits.
+    pub fn hash_from_name(name: &str) -> Option<u64> {
+        let hash = name.rsplit('_').next().unwrap_or(name);
+
+        let hash = match hash.strip_prefix("0x").or_else(|| hash.strip_prefix("0X")) {
+            Some(hash) if (1..=16).contains(&hash.len()) => hash,
+            None if hash.len() == 16 => hash,
+            _ => return None,
+        };
+
+        if !hash.bytes().all(|x| x.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u64::from_str_radix(hash, 16).ok()
+    }
+
+    /// Looks up the name for a name which is only a hash.
+    pub fn resolve_name(&self, name: &str) -> Option<&str> {
+        self.resolve(Self::hash_from_name(name)?)
+    }
+
+    /// Rewrites a file path whose file name is only a hash to the name in the database, keeping the folder and extension.
+    pub fn resolve_file_name(&self, path: &str) -> Option<String> {
+        let (folder, file_name) = match path.rfind(['/', '\\']) {
+            Some(index) => path.split_at(index + 1),
+            None => ("", path),
+        };
+
+        let (stem, extension) = match file_name.rfind('.') {
+            Some(index) => file_name.split_at(index),
+            None => (file_name, ""),
+        };
+
+        let name = self.resolve_name(stem)?.to_owned().sanitized();
+
+        Some(format!("{}{}{}", folder, name, extension))
+    }
+
+    /// Looks up the name for the given hash, hashing each of the names with each of the given algorithms until one matches.
+    pub fn resolve_with<'a, I: IntoIterator<Item = &'a str>>(
+        hash: u64,
+        names: I,
+        algorithms: &[NameHashAlgorithm],
+    ) -> Option<(&'a str, NameHashAlgorithm)> {
+        for name in names {
+            for algorithm in algorithms {
+                if algorithm.hash(name) == hash {
+                    return Some((name, *algorithm));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Finds all of the names which contain the given text, ignoring case.
+    pub fn search<'a>(&'a self, text: &str) -> impl Iterator<Item = (u64, &'a str)> + 'a {
+        let text = text.to_lowercase();
+
+        self.inner.iter().filter_map(move |(hash, name)| {
+            if name.to_lowercase().contains(&text) {
+                Some((*hash, name.as_str()))
+            } else {
+                None
+            }
+        })
+    }
+
     /// Saves a name database to the given file path.
     pub fn save<P: AsRef<Path>>(&self, file: P) -> Result<(), std::io::Error> {
         let mut file = File::create(file.as_ref())?;
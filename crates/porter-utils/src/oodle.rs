@@ -0,0 +1,189 @@
+use std::ffi::c_void;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use libloading::Library;
+
+/// The signature of the OodleLZ_Decompress export.
+type OodleLZDecompress = unsafe extern "system" fn(
+    comp_buf: *const c_void,
+    comp_buf_size: isize,
+    raw_buf: *mut c_void,
+    raw_len: isize,
+    fuzz_safe: i32,
+    check_crc: i32,
+    verbosity: i32,
+    dec_buf_base: *mut c_void,
+    dec_buf_size: isize,
+    fp_callback: *mut c_void,
+    callback_user_data: *mut c_void,
+    decoder_memory: *mut c_void,
+    decoder_memory_size: isize,
+    thread_phase: i32,
+) -> isize;
+
+/// Decode all thread phases in one call.
+const OODLE_THREAD_PHASE_ALL: i32 = 3;
+
+/// The globally loaded oodle library.
+static OODLE: OnceLock<Oodle> = OnceLock::new();
+
+/// A dynamically loaded oodle library.
+pub struct Oodle {
+    decompress: OodleLZDecompress,
+    _library: Library,
+}
+
+impl Oodle {
+    /// Loads the oodle library at the given path.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, io::Error> {
+        // SAFETY: Loading a library runs its initialization routines, we trust the oodle library shipped with the game.
+        let library = unsafe { Library::new(path.as_ref()) }
+            .map_err(|e| io::Error::new(io::ErrorKind::NotFound, e))?;
+
+        // SAFETY: The signature matches the OodleLZ_Decompress export in every oo2core version.
+        let decompress = unsafe { library.get::<OodleLZDecompress>(b"OodleLZ_Decompress\0") }
+            .map(|symbol| *symbol)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(Self {
+            decompress,
+            _library: library,
+        })
+    }
+
+    /// Searches the given directory for an oodle library and loads it, preferring the newest version.
+    pub fn find<P: AsRef<Path>>(directory: P) -> Result<Self, io::Error> {
+        let mut candidates: Vec<_> = std::fs::read_dir(directory)?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                    return false;
+                };
+
+                let name = name.to_lowercase();
+
+                (name.starts_with("oo2core") || name.starts_with("liboo2core"))
+                    && (name.ends_with(".dll") || name.ends_with(".dylib") || name.contains(".so"))
+            })
+            .collect();
+
+        candidates.sort();
+
+        for candidate in candidates.into_iter().rev() {
+            if let Ok(oodle) = Self::load(candidate) {
+                return Ok(oodle);
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Failed to find an oodle library",
+        ))
+    }
+
+    /// Decompresses the input buffer into the output buffer, which must be the exact decompressed size.
+    pub fn decompress_into<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+        output: &mut [u8],
+    ) -> Result<(), io::Error> {
+        let input = input.as_ref();
+
+        // SAFETY: Both buffers are valid for their given lengths, and fuzz safe decoding never reads or writes out of bounds.
+        let result = unsafe {
+            (self.decompress)(
+                input.as_ptr() as _,
+                input.len() as isize,
+                output.as_mut_ptr() as _,
+                output.len() as isize,
+                1,
+                0,
+                0,
+                std::ptr::null_mut(),
+                0,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                0,
+                OODLE_THREAD_PHASE_ALL,
+            )
+        };
+
+        if result != output.len() as isize {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Oodle decompressed {} bytes, expected {}",
+                    result.max(0),
+                    output.len()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Decompresses the input buffer, the result must match the expected size.
+    pub fn decompress<I: AsRef<[u8]>>(
+        &self,
+        input: I,
+        expected_size: usize,
+    ) -> Result<Vec<u8>, io::Error> {
+        let mut result = Vec::new();
+
+        result
+            .try_reserve_exact(expected_size)
+            .map_err(|e| io::Error::new(io::ErrorKind::OutOfMemory, e))?;
+
+        result.resize(expected_size, 0);
+
+        self.decompress_into(input, &mut result)?;
+
+        Ok(result)
+    }
+}
+
+/// Installs the given oodle library for use with [`oodle_decompress`], fails if one is already installed.
+pub fn oodle_install(oodle: Oodle) -> Result<(), io::Error> {
+    OODLE.set(oodle).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "An oodle library is already installed",
+        )
+    })
+}
+
+/// Whether or not an oodle library has been installed.
+pub fn oodle_installed() -> bool {
+    OODLE.get().is_some()
+}
+
+/// Decompresses the input buffer using the installed oodle library, the result must match the expected size.
+pub fn oodle_decompress<I: AsRef<[u8]>>(
+    input: I,
+    expected_size: usize,
+) -> Result<Vec<u8>, io::Error> {
+    let Some(oodle) = OODLE.get() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "An oodle library has not been installed",
+        ));
+    };
+
+    oodle.decompress(input, expected_size)
+}
+
+/// Decompresses the input buffer into the output buffer using the installed oodle library.
+pub fn oodle_decompress_into<I: AsRef<[u8]>>(input: I, output: &mut [u8]) -> Result<(), io::Error> {
+    let Some(oodle) = OODLE.get() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "An oodle library has not been installed",
+        ));
+    };
+
+    oodle.decompress_into(input, output)
+}